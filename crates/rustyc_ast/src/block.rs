@@ -4,7 +4,7 @@ use rustyc_span::Span;
 
 use crate::statements::Statement;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Block {
     statements: Vec<Rc<Statement>>,
     span: Span,
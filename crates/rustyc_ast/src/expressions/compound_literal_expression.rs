@@ -0,0 +1,32 @@
+use std::rc::Rc;
+
+use crate::{initializers::Initializer, types::Ty, NodeId};
+
+#[derive(Clone, Debug)]
+pub struct CompoundLiteralExpression {
+    id: NodeId,
+    ty: Rc<Ty>,
+    initializer: Rc<Initializer>,
+}
+
+impl CompoundLiteralExpression {
+    pub fn new(id: NodeId, ty: Rc<Ty>, initializer: Rc<Initializer>) -> Self {
+        Self {
+            id,
+            ty,
+            initializer,
+        }
+    }
+
+    pub fn get_id(&self) -> NodeId {
+        self.id
+    }
+
+    pub fn get_ty(&self) -> Rc<Ty> {
+        Rc::clone(&self.ty)
+    }
+
+    pub fn get_initializer(&self) -> Rc<Initializer> {
+        Rc::clone(&self.initializer)
+    }
+}
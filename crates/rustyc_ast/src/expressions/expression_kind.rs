@@ -1,6 +1,8 @@
 use super::{
-    AssignmentExpression, BinaryExpression, FunctionCallExpression, NumberExpression,
-    UnaryExpression, VariableExpression,
+    AllocaExpression, AssignmentExpression, BinaryExpression, CompoundLiteralExpression,
+    FloatNumberExpression, FunctionCallExpression, GenericSelectionExpression, MemberExpression,
+    NumberExpression, SizeOfExpression, StatementExpression, StringExpression, UnaryExpression,
+    VaArgExpression, VaEndExpression, VaStartExpression, VariableExpression,
 };
 
 #[derive(Clone, Debug)]
@@ -10,5 +12,16 @@ pub enum ExpressionKind {
     Unary(UnaryExpression),
     Variable(VariableExpression),
     Number(NumberExpression),
+    FloatNumber(FloatNumberExpression),
+    String(StringExpression),
     FunctionCall(FunctionCallExpression),
+    Member(MemberExpression),
+    SizeOf(SizeOfExpression),
+    CompoundLiteral(CompoundLiteralExpression),
+    Statement(StatementExpression),
+    GenericSelection(GenericSelectionExpression),
+    VaStart(VaStartExpression),
+    VaArg(VaArgExpression),
+    VaEnd(VaEndExpression),
+    Alloca(AllocaExpression),
 }
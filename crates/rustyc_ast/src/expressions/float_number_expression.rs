@@ -0,0 +1,22 @@
+#[derive(Clone, Debug)]
+pub struct FloatNumberExpression {
+    value: f64,
+    is_single_precision: bool,
+}
+
+impl FloatNumberExpression {
+    pub fn new(value: f64, is_single_precision: bool) -> Self {
+        Self {
+            value,
+            is_single_precision,
+        }
+    }
+
+    pub fn get_value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn is_single_precision(&self) -> bool {
+        self.is_single_precision
+    }
+}
@@ -4,17 +4,17 @@ use super::Expression;
 
 #[derive(Clone, Debug)]
 pub struct FunctionCallExpression {
-    name: String,
+    callee: Rc<Expression>,
     arguments: Vec<Rc<Expression>>,
 }
 
 impl FunctionCallExpression {
-    pub fn new(name: String, arguments: Vec<Rc<Expression>>) -> Self {
-        Self { name, arguments }
+    pub fn new(callee: Rc<Expression>, arguments: Vec<Rc<Expression>>) -> Self {
+        Self { callee, arguments }
     }
 
-    pub fn get_name(&self) -> &str {
-        &self.name
+    pub fn get_callee(&self) -> Rc<Expression> {
+        Rc::clone(&self.callee)
     }
 
     pub fn get_arguments(&self) -> &[Rc<Expression>] {
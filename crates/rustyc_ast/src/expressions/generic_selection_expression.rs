@@ -0,0 +1,52 @@
+use std::rc::Rc;
+
+use crate::types::Ty;
+
+use super::Expression;
+
+#[derive(Clone, Debug)]
+pub struct GenericSelectionExpression {
+    controlling_expression: Rc<Expression>,
+    associations: Vec<GenericAssociation>,
+}
+
+impl GenericSelectionExpression {
+    pub fn new(
+        controlling_expression: Rc<Expression>,
+        associations: Vec<GenericAssociation>,
+    ) -> Self {
+        Self {
+            controlling_expression,
+            associations,
+        }
+    }
+
+    pub fn get_controlling_expression(&self) -> Rc<Expression> {
+        Rc::clone(&self.controlling_expression)
+    }
+
+    pub fn get_associations(&self) -> &[GenericAssociation] {
+        &self.associations
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct GenericAssociation {
+    ty: Option<Rc<Ty>>,
+    expression: Rc<Expression>,
+}
+
+impl GenericAssociation {
+    pub fn new(ty: Option<Rc<Ty>>, expression: Rc<Expression>) -> Self {
+        Self { ty, expression }
+    }
+
+    /// Returns `None` for the `default` association.
+    pub fn get_ty(&self) -> Option<Rc<Ty>> {
+        self.ty.clone()
+    }
+
+    pub fn get_expression(&self) -> Rc<Expression> {
+        Rc::clone(&self.expression)
+    }
+}
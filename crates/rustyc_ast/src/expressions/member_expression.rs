@@ -0,0 +1,23 @@
+use std::rc::Rc;
+
+use super::Expression;
+
+#[derive(Clone, Debug)]
+pub struct MemberExpression {
+    base: Rc<Expression>,
+    name: String,
+}
+
+impl MemberExpression {
+    pub fn new(base: Rc<Expression>, name: String) -> Self {
+        Self { base, name }
+    }
+
+    pub fn get_base(&self) -> Rc<Expression> {
+        Rc::clone(&self.base)
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,26 @@
+use std::rc::Rc;
+
+use crate::types::Ty;
+
+use super::Expression;
+
+#[derive(Clone, Debug)]
+pub struct SizeOfExpression {
+    operand: SizeOfOperand,
+}
+
+impl SizeOfExpression {
+    pub fn new(operand: SizeOfOperand) -> Self {
+        Self { operand }
+    }
+
+    pub fn get_operand(&self) -> &SizeOfOperand {
+        &self.operand
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum SizeOfOperand {
+    Ty(Rc<Ty>),
+    Expression(Rc<Expression>),
+}
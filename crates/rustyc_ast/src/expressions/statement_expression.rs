@@ -0,0 +1,19 @@
+use std::rc::Rc;
+
+use crate::Block;
+
+/// A GNU statement expression.
+#[derive(Clone, Debug)]
+pub struct StatementExpression {
+    block: Rc<Block>,
+}
+
+impl StatementExpression {
+    pub fn new(block: Rc<Block>) -> Self {
+        Self { block }
+    }
+
+    pub fn get_block(&self) -> Rc<Block> {
+        Rc::clone(&self.block)
+    }
+}
@@ -0,0 +1,21 @@
+use crate::NodeId;
+
+#[derive(Clone, Debug)]
+pub struct StringExpression {
+    id: NodeId,
+    value: String,
+}
+
+impl StringExpression {
+    pub fn new(id: NodeId, value: String) -> Self {
+        Self { id, value }
+    }
+
+    pub fn get_id(&self) -> NodeId {
+        self.id
+    }
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+}
@@ -0,0 +1,25 @@
+use std::rc::Rc;
+
+use crate::types::Ty;
+
+use super::Expression;
+
+#[derive(Clone, Debug)]
+pub struct VaArgExpression {
+    list: Rc<Expression>,
+    ty: Rc<Ty>,
+}
+
+impl VaArgExpression {
+    pub fn new(list: Rc<Expression>, ty: Rc<Ty>) -> Self {
+        Self { list, ty }
+    }
+
+    pub fn get_list(&self) -> Rc<Expression> {
+        Rc::clone(&self.list)
+    }
+
+    pub fn get_ty(&self) -> Rc<Ty> {
+        Rc::clone(&self.ty)
+    }
+}
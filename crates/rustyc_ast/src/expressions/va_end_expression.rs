@@ -0,0 +1,18 @@
+use std::rc::Rc;
+
+use super::Expression;
+
+#[derive(Clone, Debug)]
+pub struct VaEndExpression {
+    list: Rc<Expression>,
+}
+
+impl VaEndExpression {
+    pub fn new(list: Rc<Expression>) -> Self {
+        Self { list }
+    }
+
+    pub fn get_list(&self) -> Rc<Expression> {
+        Rc::clone(&self.list)
+    }
+}
@@ -1,11 +1,18 @@
+use crate::NodeId;
+
 #[derive(Clone, Debug)]
 pub struct VariableExpression {
+    id: NodeId,
     name: String,
 }
 
 impl VariableExpression {
-    pub fn new(name: String) -> Self {
-        Self { name }
+    pub fn new(id: NodeId, name: String) -> Self {
+        Self { id, name }
+    }
+
+    pub fn get_id(&self) -> NodeId {
+        self.id
     }
 
     pub fn get_name(&self) -> &str {
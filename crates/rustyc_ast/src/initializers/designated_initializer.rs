@@ -0,0 +1,35 @@
+use std::rc::Rc;
+
+use rustyc_span::Span;
+
+use super::{Designator, Initializer};
+
+/// An element of a braced initializer list, as in `[1].x = 2`.
+#[derive(Clone, Debug)]
+pub struct DesignatedInitializer {
+    designators: Vec<Designator>,
+    initializer: Rc<Initializer>,
+    span: Span,
+}
+
+impl DesignatedInitializer {
+    pub fn new(designators: Vec<Designator>, initializer: Rc<Initializer>, span: Span) -> Self {
+        Self {
+            designators,
+            initializer,
+            span,
+        }
+    }
+
+    pub fn get_designators(&self) -> &[Designator] {
+        &self.designators
+    }
+
+    pub fn get_initializer(&self) -> Rc<Initializer> {
+        Rc::clone(&self.initializer)
+    }
+
+    pub fn get_span(&self) -> &Span {
+        &self.span
+    }
+}
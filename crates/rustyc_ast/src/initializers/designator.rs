@@ -0,0 +1,9 @@
+use std::rc::Rc;
+
+use crate::expressions::Expression;
+
+#[derive(Clone, Debug)]
+pub enum Designator {
+    Index(Rc<Expression>),
+    Member(String),
+}
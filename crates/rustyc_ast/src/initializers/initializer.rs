@@ -0,0 +1,11 @@
+use std::rc::Rc;
+
+use crate::expressions::Expression;
+
+use super::DesignatedInitializer;
+
+#[derive(Clone, Debug)]
+pub enum Initializer {
+    Expression(Rc<Expression>),
+    List(Vec<DesignatedInitializer>),
+}
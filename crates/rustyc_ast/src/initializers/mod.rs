@@ -0,0 +1,7 @@
+mod designated_initializer;
+mod designator;
+mod initializer;
+
+pub use designated_initializer::DesignatedInitializer;
+pub use designator::Designator;
+pub use initializer::Initializer;
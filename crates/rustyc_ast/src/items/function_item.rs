@@ -1,42 +1,71 @@
 use std::rc::Rc;
 
-use crate::Block;
+use crate::{types::Ty, Block, NodeId, StorageClass};
+
+use super::Parameter;
 
 pub struct FunctionItem {
+    id: NodeId,
     name: String,
-    parameters: Vec<String>,
-    body: Rc<Block>,
-    local_variables: Vec<String>,
+    storage_class: Option<StorageClass>,
+    return_ty: Rc<Ty>,
+    parameters: Option<Vec<Parameter>>,
+    is_variadic: bool,
+    body: Option<Rc<Block>>,
 }
 
 impl FunctionItem {
     pub fn new(
+        id: NodeId,
         name: String,
-        parameters: Vec<String>,
-        body: Rc<Block>,
-        local_variables: Vec<String>,
+        storage_class: Option<StorageClass>,
+        return_ty: Rc<Ty>,
+        parameters: Option<Vec<Parameter>>,
+        is_variadic: bool,
+        body: Option<Rc<Block>>,
     ) -> Self {
         Self {
+            id,
             name,
+            storage_class,
+            return_ty,
             parameters,
+            is_variadic,
             body,
-            local_variables,
         }
     }
 
+    pub fn get_id(&self) -> NodeId {
+        self.id
+    }
+
     pub fn get_name(&self) -> &str {
         &self.name
     }
 
-    pub fn get_parameters(&self) -> &[String] {
-        &self.parameters
+    pub fn get_storage_class(&self) -> Option<StorageClass> {
+        self.storage_class
+    }
+
+    pub fn get_return_ty(&self) -> Rc<Ty> {
+        Rc::clone(&self.return_ty)
+    }
+
+    pub fn get_parameters(&self) -> &[Parameter] {
+        self.parameters.as_deref().unwrap_or_default()
+    }
+
+    /// Checks whether the function is declared with a prototype.
+    pub fn has_prototype(&self) -> bool {
+        self.parameters.is_some()
     }
 
-    pub fn get_body(&self) -> Rc<Block> {
-        Rc::clone(&self.body)
+    pub fn is_variadic(&self) -> bool {
+        self.is_variadic
     }
 
-    pub fn get_local_variables(&self) -> &[String] {
-        &self.local_variables
+    /// Returns `None` if the item only declares the function.
+    pub fn get_body(&self) -> Option<Rc<Block>> {
+        self.body.clone()
     }
 }
@@ -0,0 +1,50 @@
+use std::rc::Rc;
+
+use crate::{initializers::Initializer, types::Ty, NodeId, StorageClass};
+
+/// A variable declared at file scope.
+pub struct GlobalItem {
+    id: NodeId,
+    name: String,
+    ty: Rc<Ty>,
+    storage_class: Option<StorageClass>,
+    initializer: Option<Rc<Initializer>>,
+}
+
+impl GlobalItem {
+    pub fn new(
+        id: NodeId,
+        name: String,
+        ty: Rc<Ty>,
+        storage_class: Option<StorageClass>,
+        initializer: Option<Rc<Initializer>>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            ty,
+            storage_class,
+            initializer,
+        }
+    }
+
+    pub fn get_id(&self) -> NodeId {
+        self.id
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_ty(&self) -> Rc<Ty> {
+        Rc::clone(&self.ty)
+    }
+
+    pub fn get_storage_class(&self) -> Option<StorageClass> {
+        self.storage_class
+    }
+
+    pub fn get_initializer(&self) -> Option<Rc<Initializer>> {
+        self.initializer.clone()
+    }
+}
@@ -1,7 +1,12 @@
 use std::rc::Rc;
 
-use super::FunctionItem;
+use crate::{types::Ty, StaticAssertion};
+
+use super::{FunctionItem, GlobalItem};
 
 pub enum ItemKind {
     Function(Rc<FunctionItem>),
+    Global(Rc<GlobalItem>),
+    StaticAssertion(Rc<StaticAssertion>),
+    TagDeclaration(Rc<Ty>),
 }
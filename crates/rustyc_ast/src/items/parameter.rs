@@ -0,0 +1,35 @@
+use std::rc::Rc;
+
+use rustyc_span::Span;
+
+use crate::{types::Ty, NodeId};
+
+#[derive(Clone)]
+pub struct Parameter {
+    id: NodeId,
+    name: Option<String>,
+    ty: Rc<Ty>,
+    span: Span,
+}
+
+impl Parameter {
+    pub fn new(id: NodeId, name: Option<String>, ty: Rc<Ty>, span: Span) -> Self {
+        Self { id, name, ty, span }
+    }
+
+    pub fn get_id(&self) -> NodeId {
+        self.id
+    }
+
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn get_ty(&self) -> Rc<Ty> {
+        Rc::clone(&self.ty)
+    }
+
+    pub fn get_span(&self) -> &Span {
+        &self.span
+    }
+}
@@ -1,7 +1,15 @@
 pub mod expressions;
+pub mod initializers;
 pub mod items;
 pub mod statements;
+pub mod types;
 
 mod block;
+mod node_id;
+mod static_assertion;
+mod storage_class;
 
 pub use block::Block;
+pub use node_id::NodeId;
+pub use static_assertion::StaticAssertion;
+pub use storage_class::StorageClass;
@@ -0,0 +1,15 @@
+/// Identifies an AST node that introduces or refers to a name.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NodeId {
+    value: u64,
+}
+
+impl NodeId {
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    pub fn get(&self) -> u64 {
+        self.value
+    }
+}
@@ -0,0 +1,28 @@
+use std::rc::Rc;
+
+use crate::expressions::Expression;
+
+use super::Statement;
+
+#[derive(Clone, Debug)]
+pub struct CaseStatement {
+    expression: Rc<Expression>,
+    statement: Rc<Statement>,
+}
+
+impl CaseStatement {
+    pub fn new(expression: Rc<Expression>, statement: Rc<Statement>) -> Self {
+        Self {
+            expression,
+            statement,
+        }
+    }
+
+    pub fn get_expression(&self) -> Rc<Expression> {
+        Rc::clone(&self.expression)
+    }
+
+    pub fn get_statement(&self) -> Rc<Statement> {
+        Rc::clone(&self.statement)
+    }
+}
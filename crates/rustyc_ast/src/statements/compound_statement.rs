@@ -2,7 +2,7 @@ use std::rc::Rc;
 
 use crate::Block;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct CompoundStatement {
     block: Rc<Block>,
 }
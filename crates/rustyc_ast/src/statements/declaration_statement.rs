@@ -0,0 +1,16 @@
+use super::Declarator;
+
+#[derive(Clone, Debug)]
+pub struct DeclarationStatement {
+    declarators: Vec<Declarator>,
+}
+
+impl DeclarationStatement {
+    pub fn new(declarators: Vec<Declarator>) -> Self {
+        Self { declarators }
+    }
+
+    pub fn get_declarators(&self) -> &[Declarator] {
+        &self.declarators
+    }
+}
@@ -0,0 +1,59 @@
+use std::rc::Rc;
+
+use rustyc_span::Span;
+
+use crate::{initializers::Initializer, types::Ty, NodeId, StorageClass};
+
+#[derive(Clone, Debug)]
+pub struct Declarator {
+    id: NodeId,
+    name: String,
+    ty: Rc<Ty>,
+    storage_class: Option<StorageClass>,
+    initializer: Option<Rc<Initializer>>,
+    span: Span,
+}
+
+impl Declarator {
+    pub fn new(
+        id: NodeId,
+        name: String,
+        ty: Rc<Ty>,
+        storage_class: Option<StorageClass>,
+        initializer: Option<Rc<Initializer>>,
+        span: Span,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            ty,
+            storage_class,
+            initializer,
+            span,
+        }
+    }
+
+    pub fn get_id(&self) -> NodeId {
+        self.id
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_ty(&self) -> Rc<Ty> {
+        Rc::clone(&self.ty)
+    }
+
+    pub fn get_storage_class(&self) -> Option<StorageClass> {
+        self.storage_class
+    }
+
+    pub fn get_initializer(&self) -> Option<Rc<Initializer>> {
+        self.initializer.clone()
+    }
+
+    pub fn get_span(&self) -> &Span {
+        &self.span
+    }
+}
@@ -0,0 +1,18 @@
+use std::rc::Rc;
+
+use super::Statement;
+
+#[derive(Clone, Debug)]
+pub struct DefaultStatement {
+    statement: Rc<Statement>,
+}
+
+impl DefaultStatement {
+    pub fn new(statement: Rc<Statement>) -> Self {
+        Self { statement }
+    }
+
+    pub fn get_statement(&self) -> Rc<Statement> {
+        Rc::clone(&self.statement)
+    }
+}
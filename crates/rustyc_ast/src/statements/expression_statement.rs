@@ -2,7 +2,7 @@ use std::rc::Rc;
 
 use crate::expressions::Expression;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct ExpressionStatement {
     expression: Rc<Expression>,
 }
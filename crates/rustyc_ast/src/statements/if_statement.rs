@@ -4,7 +4,7 @@ use crate::expressions::Expression;
 
 use super::Statement;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct IfStatement {
     condition_expression: Rc<Expression>,
     then_statement: Rc<Statement>,
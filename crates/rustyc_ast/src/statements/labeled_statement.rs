@@ -0,0 +1,23 @@
+use std::rc::Rc;
+
+use super::Statement;
+
+#[derive(Clone, Debug)]
+pub struct LabeledStatement {
+    label: String,
+    statement: Rc<Statement>,
+}
+
+impl LabeledStatement {
+    pub fn new(label: String, statement: Rc<Statement>) -> Self {
+        Self { label, statement }
+    }
+
+    pub fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn get_statement(&self) -> Rc<Statement> {
+        Rc::clone(&self.statement)
+    }
+}
@@ -4,7 +4,7 @@ use crate::expressions::Expression;
 
 use super::Statement;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct LoopStatement {
     initialization_statement: Option<Rc<Statement>>,
     condition_expression: Option<Rc<Expression>>,
@@ -1,15 +1,31 @@
+mod case_statement;
 mod compound_statement;
+mod declaration_statement;
+mod declarator;
+mod default_statement;
+mod do_while_statement;
 mod expression_statement;
+mod goto_statement;
 mod if_statement;
+mod labeled_statement;
 mod loop_statement;
 mod return_statement;
 mod statement;
 mod statement_kind;
+mod switch_statement;
 
+pub use case_statement::CaseStatement;
 pub use compound_statement::CompoundStatement;
+pub use declaration_statement::DeclarationStatement;
+pub use declarator::Declarator;
+pub use default_statement::DefaultStatement;
+pub use do_while_statement::DoWhileStatement;
 pub use expression_statement::ExpressionStatement;
+pub use goto_statement::GotoStatement;
 pub use if_statement::IfStatement;
+pub use labeled_statement::LabeledStatement;
 pub use loop_statement::LoopStatement;
 pub use return_statement::ReturnStatement;
 pub use statement::Statement;
 pub use statement_kind::StatementKind;
+pub use switch_statement::SwitchStatement;
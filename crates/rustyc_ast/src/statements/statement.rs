@@ -2,7 +2,7 @@ use rustyc_span::Span;
 
 use super::StatementKind;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Statement {
     kind: StatementKind,
     span: Span,
@@ -1,13 +1,32 @@
-use super::{CompoundStatement, ExpressionStatement, IfStatement, LoopStatement, ReturnStatement};
+use std::rc::Rc;
+
+use crate::{types::Ty, StaticAssertion};
+
+use super::{
+    CaseStatement, CompoundStatement, DeclarationStatement, DefaultStatement, DoWhileStatement,
+    ExpressionStatement, GotoStatement, IfStatement, LabeledStatement, LoopStatement,
+    ReturnStatement, SwitchStatement,
+};
 
 // TODO: Convert all statement kinds to dedicated structs (relevant for
 // expressions and as well, and for the HIR).
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub enum StatementKind {
     Return(ReturnStatement),
     If(IfStatement),
     Loop(LoopStatement),
+    DoWhile(DoWhileStatement),
+    Switch(SwitchStatement),
+    Case(CaseStatement),
+    Default(DefaultStatement),
+    Labeled(LabeledStatement),
+    Goto(GotoStatement),
+    Break,
+    Continue,
     Compound(CompoundStatement),
+    Declaration(DeclarationStatement),
     Expression(ExpressionStatement),
+    StaticAssertion(Rc<StaticAssertion>),
+    TagDeclaration(Rc<Ty>),
 }
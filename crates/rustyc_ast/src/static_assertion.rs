@@ -0,0 +1,24 @@
+use std::rc::Rc;
+
+use crate::expressions::Expression;
+
+/// A `_Static_assert` declaration, at file scope or within a block.
+#[derive(Clone, Debug)]
+pub struct StaticAssertion {
+    condition: Rc<Expression>,
+    message: String,
+}
+
+impl StaticAssertion {
+    pub fn new(condition: Rc<Expression>, message: String) -> Self {
+        Self { condition, message }
+    }
+
+    pub fn get_condition(&self) -> Rc<Expression> {
+        Rc::clone(&self.condition)
+    }
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+}
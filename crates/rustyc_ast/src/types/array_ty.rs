@@ -0,0 +1,25 @@
+use std::rc::Rc;
+
+use crate::expressions::Expression;
+
+use super::Ty;
+
+#[derive(Clone, Debug)]
+pub struct ArrayTy {
+    element_ty: Rc<Ty>,
+    length: Option<Rc<Expression>>,
+}
+
+impl ArrayTy {
+    pub fn new(element_ty: Rc<Ty>, length: Option<Rc<Expression>>) -> Self {
+        Self { element_ty, length }
+    }
+
+    pub fn get_element_ty(&self) -> Rc<Ty> {
+        Rc::clone(&self.element_ty)
+    }
+
+    pub fn get_length(&self) -> Option<Rc<Expression>> {
+        self.length.clone()
+    }
+}
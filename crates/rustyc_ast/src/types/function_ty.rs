@@ -0,0 +1,33 @@
+use std::rc::Rc;
+
+use super::Ty;
+
+#[derive(Clone, Debug)]
+pub struct FunctionTy {
+    return_ty: Rc<Ty>,
+    parameters: Option<Vec<Rc<Ty>>>,
+    is_variadic: bool,
+}
+
+impl FunctionTy {
+    pub fn new(return_ty: Rc<Ty>, parameters: Option<Vec<Rc<Ty>>>, is_variadic: bool) -> Self {
+        Self {
+            return_ty,
+            parameters,
+            is_variadic,
+        }
+    }
+
+    pub fn get_return_ty(&self) -> Rc<Ty> {
+        Rc::clone(&self.return_ty)
+    }
+
+    /// Returns `None` for functions declared without a prototype.
+    pub fn get_parameters(&self) -> Option<&[Rc<Ty>]> {
+        self.parameters.as_deref()
+    }
+
+    pub fn is_variadic(&self) -> bool {
+        self.is_variadic
+    }
+}
@@ -0,0 +1,13 @@
+mod array_ty;
+mod function_ty;
+mod qualifiers;
+mod struct_ty;
+mod ty;
+mod ty_kind;
+
+pub use array_ty::ArrayTy;
+pub use function_ty::FunctionTy;
+pub use qualifiers::Qualifiers;
+pub use struct_ty::{StructMember, StructTy};
+pub use ty::Ty;
+pub use ty_kind::TyKind;
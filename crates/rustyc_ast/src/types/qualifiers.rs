@@ -0,0 +1,29 @@
+/// The `const`, `volatile` and `restrict` qualifiers written on a type.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Qualifiers {
+    is_const: bool,
+    is_volatile: bool,
+    is_restrict: bool,
+}
+
+impl Qualifiers {
+    pub fn new(is_const: bool, is_volatile: bool, is_restrict: bool) -> Self {
+        Self {
+            is_const,
+            is_volatile,
+            is_restrict,
+        }
+    }
+
+    pub fn is_const(&self) -> bool {
+        self.is_const
+    }
+
+    pub fn is_volatile(&self) -> bool {
+        self.is_volatile
+    }
+
+    pub fn is_restrict(&self) -> bool {
+        self.is_restrict
+    }
+}
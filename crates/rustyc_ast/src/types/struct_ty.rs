@@ -0,0 +1,88 @@
+use std::{cell::OnceCell, rc::Rc};
+
+use rustyc_span::Span;
+
+use crate::{expressions::Expression, NodeId};
+
+use super::Ty;
+
+/// A structure type, shared by every specifier that refers to the same tag.
+#[derive(Debug)]
+pub struct StructTy {
+    id: NodeId,
+    tag: Option<String>,
+    members: OnceCell<Vec<StructMember>>,
+}
+
+impl StructTy {
+    pub fn new(id: NodeId, tag: Option<String>) -> Self {
+        Self {
+            id,
+            tag,
+            members: OnceCell::new(),
+        }
+    }
+
+    pub fn get_id(&self) -> NodeId {
+        self.id
+    }
+
+    pub fn get_tag(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+
+    pub fn get_members(&self) -> Option<&[StructMember]> {
+        self.members.get().map(Vec::as_slice)
+    }
+
+    pub fn is_defined(&self) -> bool {
+        self.members.get().is_some()
+    }
+
+    pub fn define(&self, members: Vec<StructMember>) {
+        assert!(
+            self.members.set(members).is_ok(),
+            "a structure is only defined once"
+        );
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StructMember {
+    name: Option<String>,
+    ty: Rc<Ty>,
+    width: Option<Rc<Expression>>,
+    span: Span,
+}
+
+impl StructMember {
+    pub fn new(
+        name: Option<String>,
+        ty: Rc<Ty>,
+        width: Option<Rc<Expression>>,
+        span: Span,
+    ) -> Self {
+        Self {
+            name,
+            ty,
+            width,
+            span,
+        }
+    }
+
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn get_ty(&self) -> Rc<Ty> {
+        Rc::clone(&self.ty)
+    }
+
+    pub fn get_width(&self) -> Option<Rc<Expression>> {
+        self.width.clone()
+    }
+
+    pub fn get_span(&self) -> &Span {
+        &self.span
+    }
+}
@@ -0,0 +1,32 @@
+use rustyc_span::Span;
+
+use super::{Qualifiers, TyKind};
+
+#[derive(Clone, Debug)]
+pub struct Ty {
+    kind: TyKind,
+    qualifiers: Qualifiers,
+    span: Span,
+}
+
+impl Ty {
+    pub fn new(kind: TyKind, qualifiers: Qualifiers, span: Span) -> Self {
+        Self {
+            kind,
+            qualifiers,
+            span,
+        }
+    }
+
+    pub fn get_kind(&self) -> &TyKind {
+        &self.kind
+    }
+
+    pub fn get_qualifiers(&self) -> Qualifiers {
+        self.qualifiers
+    }
+
+    pub fn get_span(&self) -> &Span {
+        &self.span
+    }
+}
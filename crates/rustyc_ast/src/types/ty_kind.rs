@@ -0,0 +1,20 @@
+use std::rc::Rc;
+
+use super::{ArrayTy, FunctionTy, StructTy, Ty};
+
+#[derive(Clone, Debug)]
+pub enum TyKind {
+    Void,
+    Bool,
+    Char,
+    UnsignedChar,
+    Int,
+    UnsignedInt,
+    Float,
+    Double,
+    Pointer(Rc<Ty>),
+    Array(ArrayTy),
+    Function(FunctionTy),
+    Struct(Rc<StructTy>),
+    VaList,
+}
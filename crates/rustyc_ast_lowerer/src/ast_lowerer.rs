@@ -1,30 +1,215 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
 
-use rustyc_ty::TyContext;
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{FunctionId, Linkage, Res};
+use rustyc_resolver::Resolutions;
+use rustyc_ty::{FunctionTy, Ty, TyContext, TyId, TyMatcher};
 
-use crate::item_lowerer::ItemLowerer;
+use crate::{global_table::GlobalTable, item_lowerer::ItemLowerer, ty_lowerer::TyLowerer};
 
 pub struct AstLowerer {
     ast: Vec<Rc<rustyc_ast::items::Item>>,
+    resolutions: Rc<Resolutions>,
+    global_table: Rc<RefCell<GlobalTable>>,
     ty_context: Rc<RefCell<TyContext>>,
 }
 
 impl AstLowerer {
-    pub fn new(ast: Vec<Rc<rustyc_ast::items::Item>>, ty_context: Rc<RefCell<TyContext>>) -> Self {
-        Self { ast, ty_context }
+    pub fn new(
+        ast: Vec<Rc<rustyc_ast::items::Item>>,
+        resolutions: Rc<Resolutions>,
+        ty_context: Rc<RefCell<TyContext>>,
+    ) -> Self {
+        Self {
+            ast,
+            resolutions,
+            global_table: Rc::new(RefCell::new(GlobalTable::new(Rc::clone(&ty_context)))),
+            ty_context,
+        }
     }
 
-    pub fn lower(self) -> Rc<Vec<Rc<rustyc_hir::items::Item>>> {
-        Rc::new(
-            self.ast
-                .iter()
-                .map(|item| self.lower_item(Rc::clone(item)))
-                .collect(),
-        )
+    pub fn lower(self) -> rustyc_diagnostics::Result<Rc<Vec<Rc<rustyc_hir::items::Item>>>> {
+        let function_tys = Rc::new(self.lower_function_tys()?);
+        let internal_functions = Rc::new(self.get_internal_functions());
+        self.declare_globals()?;
+
+        let mut items = Vec::new();
+
+        for item in self.ast.iter() {
+            if let Some(item) = self.lower_item(
+                Rc::clone(item),
+                Rc::clone(&function_tys),
+                Rc::clone(&internal_functions),
+            )? {
+                items.push(item);
+            }
+        }
+
+        items.append(&mut self.global_table.borrow_mut().take_items());
+
+        Ok(Rc::new(items))
+    }
+
+    // A function has internal linkage if any of its declarations is `static`.
+    fn get_internal_functions(&self) -> HashSet<FunctionId> {
+        self.ast
+            .iter()
+            .filter_map(|item| match item.get_kind() {
+                rustyc_ast::items::ItemKind::Function(function)
+                    if function.get_storage_class() == Some(rustyc_ast::StorageClass::Static) =>
+                {
+                    match self.resolutions.get(function.get_id()) {
+                        Some(Res::Function(id)) => Some(id),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    // Global variables can be referenced before their tentative definitions
+    // or before the declaration that gives them internal linkage, so all of
+    // them are declared up front.
+    fn declare_globals(&self) -> rustyc_diagnostics::Result<()> {
+        let mut global_table = self.global_table.borrow_mut();
+
+        for item in self.ast.iter() {
+            let rustyc_ast::items::ItemKind::Global(global) = item.get_kind() else {
+                continue;
+            };
+
+            let Some(Res::Global(id)) = self.resolutions.get(global.get_id()) else {
+                continue;
+            };
+
+            let linkage = match global.get_storage_class() {
+                Some(rustyc_ast::StorageClass::Static) => Linkage::Internal,
+                _ => Linkage::External,
+            };
+
+            global_table.declare(
+                id,
+                global.get_name().to_owned(),
+                self.lower_ty(global.get_ty())?,
+                linkage,
+                item.get_span().clone(),
+            )?;
+
+            if global.get_storage_class() != Some(rustyc_ast::StorageClass::Extern)
+                || global.get_initializer().is_some()
+            {
+                global_table.define(id);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Calls can precede the declaration of their callee, so the types of all
+    // of the functions are collected up front.
+    fn lower_function_tys(&self) -> rustyc_diagnostics::Result<HashMap<FunctionId, TyId>> {
+        let mut function_tys = HashMap::new();
+
+        for item in self.ast.iter() {
+            let rustyc_ast::items::ItemKind::Function(function) = item.get_kind() else {
+                continue;
+            };
+
+            if let Some(Res::Function(id)) = self.resolutions.get(function.get_id()) {
+                let parameters = function
+                    .has_prototype()
+                    .then(|| {
+                        function
+                            .get_parameters()
+                            .iter()
+                            .map(|parameter| self.lower_parameter_ty(parameter.get_ty()))
+                            .collect::<rustyc_diagnostics::Result<_>>()
+                    })
+                    .transpose()?;
+
+                let function_ty = FunctionTy::new(
+                    self.lower_ty(function.get_return_ty())?,
+                    parameters,
+                    function.is_variadic(),
+                );
+                let function_ty = self
+                    .ty_context
+                    .borrow_mut()
+                    .register(Ty::Function(function_ty));
+
+                let previous_ty = function_tys
+                    .get(&id)
+                    .copied()
+                    .or_else(|| self.get_implicit_function_ty(id));
+
+                if let Some(previous_ty) = previous_ty {
+                    let ty_matcher = TyMatcher::new(Rc::clone(&self.ty_context));
+
+                    if !ty_matcher.is_compatible(previous_ty, function_ty) {
+                        return Err(Diagnostic::new_error(
+                            rustyc_diagnostics::Error::ConflictingTypes(
+                                function.get_name().to_owned(),
+                            ),
+                            item.get_span().clone(),
+                        ));
+                    }
+                }
+
+                // Calls are checked against the declaration with a prototype.
+                if function.has_prototype() || !function_tys.contains_key(&id) {
+                    function_tys.insert(id, function_ty);
+                }
+            }
+        }
+
+        Ok(function_tys)
+    }
+
+    // A function called before its declaration is implicitly declared as
+    // `int ()`.
+    fn get_implicit_function_ty(&self, id: FunctionId) -> Option<TyId> {
+        if !self.resolutions.is_implicit_function(id) {
+            return None;
+        }
+
+        let mut ty_context = self.ty_context.borrow_mut();
+        let int_ty = ty_context.register(Ty::Int);
+
+        Some(ty_context.register(Ty::Function(FunctionTy::new(int_ty, None, false))))
+    }
+
+    fn lower_ty(&self, ty: Rc<rustyc_ast::types::Ty>) -> rustyc_diagnostics::Result<TyId> {
+        let ty_lowerer = TyLowerer::new(ty, Rc::clone(&self.ty_context));
+        ty_lowerer.lower()
+    }
+
+    fn lower_parameter_ty(
+        &self,
+        ty: Rc<rustyc_ast::types::Ty>,
+    ) -> rustyc_diagnostics::Result<TyId> {
+        let ty_lowerer = TyLowerer::new(ty, Rc::clone(&self.ty_context));
+        ty_lowerer.lower_parameter()
     }
 
-    fn lower_item(&self, item: Rc<rustyc_ast::items::Item>) -> Rc<rustyc_hir::items::Item> {
-        let item_lowerer = ItemLowerer::new(item, Rc::clone(&self.ty_context));
+    fn lower_item(
+        &self,
+        item: Rc<rustyc_ast::items::Item>,
+        function_tys: Rc<HashMap<FunctionId, TyId>>,
+        internal_functions: Rc<HashSet<FunctionId>>,
+    ) -> rustyc_diagnostics::Result<Option<Rc<rustyc_hir::items::Item>>> {
+        let item_lowerer = ItemLowerer::new(
+            item,
+            Rc::clone(&self.resolutions),
+            function_tys,
+            internal_functions,
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
         item_lowerer.lower()
     }
 }
@@ -1,38 +1,71 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use rustyc_ty::TyContext;
+use rustyc_hir::FunctionId;
+use rustyc_resolver::Resolutions;
+use rustyc_ty::{TyContext, TyId};
 
-use crate::statement_lowerer::StatementLowerer;
+use crate::{
+    global_table::GlobalTable, local_table::LocalTable, statement_lowerer::StatementLowerer,
+};
 
 pub struct BlockLowerer {
     block: Rc<rustyc_ast::Block>,
+    function: Rc<rustyc_ast::items::FunctionItem>,
+    resolutions: Rc<Resolutions>,
+    function_tys: Rc<HashMap<FunctionId, TyId>>,
+    local_table: Rc<RefCell<LocalTable>>,
+    global_table: Rc<RefCell<GlobalTable>>,
     ty_context: Rc<RefCell<TyContext>>,
 }
 
 impl BlockLowerer {
-    pub fn new(block: Rc<rustyc_ast::Block>, ty_context: Rc<RefCell<TyContext>>) -> Self {
-        Self { block, ty_context }
+    pub fn new(
+        block: Rc<rustyc_ast::Block>,
+        function: Rc<rustyc_ast::items::FunctionItem>,
+        resolutions: Rc<Resolutions>,
+        function_tys: Rc<HashMap<FunctionId, TyId>>,
+        local_table: Rc<RefCell<LocalTable>>,
+        global_table: Rc<RefCell<GlobalTable>>,
+        ty_context: Rc<RefCell<TyContext>>,
+    ) -> Self {
+        Self {
+            block,
+            function,
+            resolutions,
+            function_tys,
+            local_table,
+            global_table,
+            ty_context,
+        }
     }
 
-    pub fn lower(self) -> Rc<rustyc_hir::Block> {
-        let statements: Vec<Rc<rustyc_hir::statements::Statement>> = self
+    pub fn lower(self) -> rustyc_diagnostics::Result<Rc<rustyc_hir::Block>> {
+        let statements = self
             .block
             .get_statements()
             .iter()
             .map(|statement| self.lower_statement(Rc::clone(statement)))
-            .collect();
+            .collect::<rustyc_diagnostics::Result<Vec<_>>>()?;
 
-        Rc::new(rustyc_hir::Block::new(
+        Ok(Rc::new(rustyc_hir::Block::new(
             statements,
             self.block.get_span().clone(),
-        ))
+        )))
     }
 
     pub fn lower_statement(
         &self,
         statement: Rc<rustyc_ast::statements::Statement>,
-    ) -> Rc<rustyc_hir::statements::Statement> {
-        let statement_lowerer = StatementLowerer::new(statement, Rc::clone(&self.ty_context));
+    ) -> rustyc_diagnostics::Result<Rc<rustyc_hir::statements::Statement>> {
+        let statement_lowerer = StatementLowerer::new(
+            statement,
+            Rc::clone(&self.function),
+            Rc::clone(&self.resolutions),
+            Rc::clone(&self.function_tys),
+            Rc::clone(&self.local_table),
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
         statement_lowerer.lower()
     }
 }
@@ -1,131 +1,271 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use rustyc_ty::{Ty, TyId, TyMatcher};
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{initializers::Initializer, FunctionId, Linkage, Local, Res};
+use rustyc_resolver::Resolutions;
+use rustyc_ty::{FunctionTy, Qualifiers, Ty, TyId, TyMatcher};
+
+use crate::{
+    block_lowerer::BlockLowerer, global_table::GlobalTable,
+    initializer_lowerer::InitializerLowerer, local_table::LocalTable, ty_converter::TyConverter,
+    ty_lowerer::TyLowerer,
+};
 
 pub struct ExpressionLowerer {
-    expression: Rc<rustyc_ast::Expression>,
+    expression: Rc<rustyc_ast::expressions::Expression>,
+    function: Option<Rc<rustyc_ast::items::FunctionItem>>,
+    resolutions: Rc<Resolutions>,
+    function_tys: Rc<HashMap<FunctionId, TyId>>,
+    local_table: Rc<RefCell<LocalTable>>,
+    global_table: Rc<RefCell<GlobalTable>>,
     ty_matcher: TyMatcher,
+    ty_converter: TyConverter,
     ty_context: Rc<RefCell<rustyc_ty::TyContext>>,
 }
 
 impl ExpressionLowerer {
     pub fn new(
-        expression: Rc<rustyc_ast::Expression>,
+        expression: Rc<rustyc_ast::expressions::Expression>,
+        function: Option<Rc<rustyc_ast::items::FunctionItem>>,
+        resolutions: Rc<Resolutions>,
+        function_tys: Rc<HashMap<FunctionId, TyId>>,
+        local_table: Rc<RefCell<LocalTable>>,
+        global_table: Rc<RefCell<GlobalTable>>,
         ty_context: Rc<RefCell<rustyc_ty::TyContext>>,
     ) -> Self {
         Self {
             expression,
+            function,
+            resolutions,
+            function_tys,
+            local_table,
+            global_table,
             ty_matcher: TyMatcher::new(Rc::clone(&ty_context)),
+            ty_converter: TyConverter::new(Rc::clone(&ty_context)),
             ty_context,
         }
     }
 
-    pub fn lower(self) -> Rc<rustyc_hir::Expression> {
+    pub fn lower(self) -> rustyc_diagnostics::Result<Rc<rustyc_hir::expressions::Expression>> {
+        let ty_converter = TyConverter::new(Rc::clone(&self.ty_context));
+        Ok(ty_converter.decay(self.lower_without_decay()?))
+    }
+
+    fn lower_without_decay(
+        self,
+    ) -> rustyc_diagnostics::Result<Rc<rustyc_hir::expressions::Expression>> {
+        // A generic selection is replaced by the selected expression.
+        if let rustyc_ast::expressions::ExpressionKind::GenericSelection(expression) =
+            self.expression.get_kind()
+        {
+            return self.lower_generic_selection(expression);
+        }
+
         let (hir_expression_kind, ty) = match self.expression.get_kind() {
-            rustyc_ast::ExpressionKind::Assignment(left, right) => {
-                self.lower_assignment(Rc::clone(left), Rc::clone(right))
+            rustyc_ast::expressions::ExpressionKind::Assignment(expression) => {
+                self.lower_assignment(expression)?
+            }
+            rustyc_ast::expressions::ExpressionKind::Binary(expression) => {
+                self.lower_binary(expression)?
+            }
+            rustyc_ast::expressions::ExpressionKind::Unary(expression) => {
+                self.lower_unary(expression)?
+            }
+            rustyc_ast::expressions::ExpressionKind::Variable(expression) => {
+                self.lower_variable(expression)?
+            }
+            rustyc_ast::expressions::ExpressionKind::Number(expression) => {
+                self.lower_number(expression)
+            }
+            rustyc_ast::expressions::ExpressionKind::FloatNumber(expression) => {
+                self.lower_float_number(expression)
+            }
+            rustyc_ast::expressions::ExpressionKind::String(expression) => {
+                self.lower_string(expression)?
+            }
+            rustyc_ast::expressions::ExpressionKind::FunctionCall(expression) => {
+                self.lower_function_call(expression)?
+            }
+            rustyc_ast::expressions::ExpressionKind::Member(expression) => {
+                self.lower_member(expression)?
+            }
+            rustyc_ast::expressions::ExpressionKind::SizeOf(expression) => {
+                self.lower_size_of(expression)?
+            }
+            rustyc_ast::expressions::ExpressionKind::CompoundLiteral(expression) => {
+                self.lower_compound_literal(expression)?
+            }
+            rustyc_ast::expressions::ExpressionKind::Statement(expression) => {
+                self.lower_statement_expression(expression)?
+            }
+            rustyc_ast::expressions::ExpressionKind::GenericSelection(_) => unreachable!(),
+            rustyc_ast::expressions::ExpressionKind::VaStart(expression) => {
+                self.lower_va_start(expression)?
             }
-            rustyc_ast::ExpressionKind::Binary(operator, left, right) => {
-                self.lower_binary(operator, Rc::clone(left), Rc::clone(right))
+            rustyc_ast::expressions::ExpressionKind::VaArg(expression) => {
+                self.lower_va_arg(expression)?
             }
-            rustyc_ast::ExpressionKind::Unary(operator, right) => {
-                self.lower_unary(operator, Rc::clone(right))
+            rustyc_ast::expressions::ExpressionKind::VaEnd(expression) => {
+                self.lower_va_end(expression)?
             }
-            rustyc_ast::ExpressionKind::Variable(variable) => self.lower_variable(variable),
-            rustyc_ast::ExpressionKind::Number(number) => self.lower_number(*number),
-            rustyc_ast::ExpressionKind::FunctionCall(name, arguments) => {
-                self.lower_function_call(name, arguments)
+            rustyc_ast::expressions::ExpressionKind::Alloca(expression) => {
+                self.lower_alloca(expression)?
             }
         };
 
-        Rc::new(rustyc_hir::Expression::new(
+        Ok(Rc::new(rustyc_hir::expressions::Expression::new(
             hir_expression_kind,
             ty,
             self.expression.get_span().clone(),
-        ))
+        )))
     }
 
-    fn lower_binary_operator(operator: &rustyc_ast::BinaryOperator) -> rustyc_hir::BinaryOperator {
+    fn lower_binary_operator(
+        operator: &rustyc_ast::expressions::BinaryOperator,
+    ) -> rustyc_hir::expressions::BinaryOperator {
         match operator {
-            rustyc_ast::BinaryOperator::Equal => rustyc_hir::BinaryOperator::Equal,
-            rustyc_ast::BinaryOperator::NotEqual => rustyc_hir::BinaryOperator::NotEqual,
-            rustyc_ast::BinaryOperator::LessThan => rustyc_hir::BinaryOperator::LessThan,
-            rustyc_ast::BinaryOperator::LessThanOrEqual => {
-                rustyc_hir::BinaryOperator::LessThanOrEqual
-            }
-            rustyc_ast::BinaryOperator::Add => rustyc_hir::BinaryOperator::Add,
-            rustyc_ast::BinaryOperator::Subtract => rustyc_hir::BinaryOperator::Subtract,
-            rustyc_ast::BinaryOperator::Multiply => rustyc_hir::BinaryOperator::Multiply,
-            rustyc_ast::BinaryOperator::Divide => rustyc_hir::BinaryOperator::Divide,
+            rustyc_ast::expressions::BinaryOperator::Equal => {
+                rustyc_hir::expressions::BinaryOperator::Equal
+            }
+            rustyc_ast::expressions::BinaryOperator::NotEqual => {
+                rustyc_hir::expressions::BinaryOperator::NotEqual
+            }
+            rustyc_ast::expressions::BinaryOperator::LessThan => {
+                rustyc_hir::expressions::BinaryOperator::LessThan
+            }
+            rustyc_ast::expressions::BinaryOperator::LessThanOrEqual => {
+                rustyc_hir::expressions::BinaryOperator::LessThanOrEqual
+            }
+            rustyc_ast::expressions::BinaryOperator::Add => {
+                rustyc_hir::expressions::BinaryOperator::Add
+            }
+            rustyc_ast::expressions::BinaryOperator::Subtract => {
+                rustyc_hir::expressions::BinaryOperator::Subtract
+            }
+            rustyc_ast::expressions::BinaryOperator::Multiply => {
+                rustyc_hir::expressions::BinaryOperator::Multiply
+            }
+            rustyc_ast::expressions::BinaryOperator::Divide => {
+                rustyc_hir::expressions::BinaryOperator::Divide
+            }
         }
     }
 
-    fn lower_unary_operator(operator: &rustyc_ast::UnaryOperator) -> rustyc_hir::UnaryOperator {
+    fn lower_unary_operator(
+        operator: &rustyc_ast::expressions::UnaryOperator,
+    ) -> rustyc_hir::expressions::UnaryOperator {
         match operator {
-            rustyc_ast::UnaryOperator::Negate => rustyc_hir::UnaryOperator::Negate,
-            rustyc_ast::UnaryOperator::AddressOf => rustyc_hir::UnaryOperator::AddressOf,
-            rustyc_ast::UnaryOperator::Dereference => rustyc_hir::UnaryOperator::Dereference,
+            rustyc_ast::expressions::UnaryOperator::Negate => {
+                rustyc_hir::expressions::UnaryOperator::Negate
+            }
+            rustyc_ast::expressions::UnaryOperator::AddressOf => {
+                rustyc_hir::expressions::UnaryOperator::AddressOf
+            }
+            rustyc_ast::expressions::UnaryOperator::Dereference => {
+                rustyc_hir::expressions::UnaryOperator::Dereference
+            }
         }
     }
 
     fn lower_assignment(
         &self,
-        left: Rc<rustyc_ast::Expression>,
-        right: Rc<rustyc_ast::Expression>,
-    ) -> (rustyc_hir::ExpressionKind, TyId) {
-        let hir_left = self.lower_expression(left);
-        let hir_right = self.lower_expression(right);
-
+        expression: &rustyc_ast::expressions::AssignmentExpression,
+    ) -> rustyc_diagnostics::Result<(rustyc_hir::expressions::ExpressionKind, TyId)> {
+        let hir_left = self.lower_expression_without_decay(expression.get_left())?;
         let ty = hir_left.get_ty();
+        let hir_right = self
+            .ty_converter
+            .convert(self.lower_expression(expression.get_right())?, ty);
 
-        (
-            rustyc_hir::ExpressionKind::Assignment(hir_left, hir_right),
+        Ok((
+            rustyc_hir::expressions::ExpressionKind::Assignment(
+                rustyc_hir::expressions::AssignmentExpression::new(hir_left, hir_right),
+            ),
             ty,
-        )
+        ))
     }
 
     fn lower_binary(
         &self,
-        operator: &rustyc_ast::BinaryOperator,
-        left: Rc<rustyc_ast::Expression>,
-        right: Rc<rustyc_ast::Expression>,
-    ) -> (rustyc_hir::ExpressionKind, TyId) {
-        let hir_left = self.lower_expression(left);
-        let hir_right = self.lower_expression(right);
+        expression: &rustyc_ast::expressions::BinaryExpression,
+    ) -> rustyc_diagnostics::Result<(rustyc_hir::expressions::ExpressionKind, TyId)> {
+        let mut hir_left = self.lower_expression(expression.get_left())?;
+        let mut hir_right = self.lower_expression(expression.get_right())?;
+
+        if let Some(common_ty) = self
+            .ty_converter
+            .get_common_ty(hir_left.get_ty(), hir_right.get_ty())
+        {
+            hir_left = self.ty_converter.convert(hir_left, common_ty);
+            hir_right = self.ty_converter.convert(hir_right, common_ty);
+        }
 
         let ty = hir_left.get_ty();
 
-        match operator {
-            rustyc_ast::BinaryOperator::Add => self.lower_add(hir_left, hir_right),
-            rustyc_ast::BinaryOperator::Subtract => self.lower_subtract(hir_left, hir_right),
-            _ => (
-                rustyc_hir::ExpressionKind::Binary(
+        Ok(match expression.get_operator() {
+            rustyc_ast::expressions::BinaryOperator::Add => self.lower_add(hir_left, hir_right),
+            rustyc_ast::expressions::BinaryOperator::Subtract => {
+                self.lower_subtract(hir_left, hir_right)
+            }
+            operator @ (rustyc_ast::expressions::BinaryOperator::Equal
+            | rustyc_ast::expressions::BinaryOperator::NotEqual
+            | rustyc_ast::expressions::BinaryOperator::LessThan
+            | rustyc_ast::expressions::BinaryOperator::LessThanOrEqual) => (
+                Self::new_binary_expression_kind(
+                    Self::lower_binary_operator(operator),
+                    hir_left,
+                    hir_right,
+                ),
+                self.ty_context.borrow_mut().register(Ty::Int),
+            ),
+            operator => (
+                Self::new_binary_expression_kind(
                     Self::lower_binary_operator(operator),
                     hir_left,
                     hir_right,
                 ),
                 ty,
             ),
-        }
+        })
     }
 
     fn lower_unary(
         &self,
-        operator: &rustyc_ast::UnaryOperator,
-        right: Rc<rustyc_ast::Expression>,
-    ) -> (rustyc_hir::ExpressionKind, TyId) {
-        let hir_right = self.lower_expression(right);
+        expression: &rustyc_ast::expressions::UnaryExpression,
+    ) -> rustyc_diagnostics::Result<(rustyc_hir::expressions::ExpressionKind, TyId)> {
+        let operator = expression.get_operator();
+        let mut hir_operand = match operator {
+            rustyc_ast::expressions::UnaryOperator::AddressOf => {
+                self.lower_expression_without_decay(expression.get_operand())?
+            }
+            _ => self.lower_expression(expression.get_operand())?,
+        };
+
+        if matches!(operator, rustyc_ast::expressions::UnaryOperator::Negate) {
+            hir_operand = self.ty_converter.promote(hir_operand);
+        }
+
+        // Dereferencing a function pointer or designator yields a function
+        // designator, which decays right back into the same pointer.
+        if matches!(
+            operator,
+            rustyc_ast::expressions::UnaryOperator::Dereference
+        ) {
+            if let Some(function_ty) = self.get_dereferenced_function_ty(hir_operand.get_ty()) {
+                return Ok((hir_operand.get_kind().clone(), function_ty));
+            }
+        }
 
         let ty = match operator {
-            rustyc_ast::UnaryOperator::Negate => hir_right.get_ty(),
-            rustyc_ast::UnaryOperator::AddressOf => self
+            rustyc_ast::expressions::UnaryOperator::Negate => hir_operand.get_ty(),
+            rustyc_ast::expressions::UnaryOperator::AddressOf => self
                 .ty_context
                 .borrow_mut()
-                .register(Ty::Pointer(hir_right.get_ty())),
-            rustyc_ast::UnaryOperator::Dereference => {
+                .register(Ty::Pointer(hir_operand.get_ty())),
+            rustyc_ast::expressions::UnaryOperator::Dereference => {
                 let int_ty = self.ty_context.borrow_mut().register(Ty::Int);
 
-                if let Ty::Pointer(base) = self.ty_context.borrow().get(hir_right.get_ty()) {
+                if let Ty::Pointer(base) = self.ty_context.borrow().get(hir_operand.get_ty()) {
                     *base
                 } else {
                     int_ty
@@ -133,84 +273,630 @@ impl ExpressionLowerer {
             }
         };
 
-        (
-            rustyc_hir::ExpressionKind::Unary(Self::lower_unary_operator(operator), hir_right),
+        Ok((
+            rustyc_hir::expressions::ExpressionKind::Unary(
+                rustyc_hir::expressions::UnaryExpression::new(
+                    Self::lower_unary_operator(operator),
+                    hir_operand,
+                ),
+            ),
             ty,
-        )
+        ))
     }
 
-    fn lower_variable(&self, variable: &str) -> (rustyc_hir::ExpressionKind, TyId) {
+    fn lower_variable(
+        &self,
+        expression: &rustyc_ast::expressions::VariableExpression,
+    ) -> rustyc_diagnostics::Result<(rustyc_hir::expressions::ExpressionKind, TyId)> {
+        let unresolved_name = || {
+            Diagnostic::new_error(
+                rustyc_diagnostics::Error::UnresolvedName(expression.get_name().to_owned()),
+                self.expression.get_span().clone(),
+            )
+        };
+
+        let res = self
+            .resolutions
+            .get(expression.get_id())
+            .ok_or_else(unresolved_name)?;
+
+        let ty = match res {
+            Res::Local(local_id) | Res::Parameter(local_id) => self
+                .local_table
+                .borrow()
+                .get(local_id)
+                .map(|local| local.get_ty())
+                .ok_or_else(unresolved_name)?,
+            Res::Function(function_id) => {
+                return Ok((
+                    rustyc_hir::expressions::ExpressionKind::Function(
+                        rustyc_hir::expressions::FunctionExpression::new(
+                            function_id,
+                            expression.get_name().to_owned(),
+                        ),
+                    ),
+                    self.get_function_ty(function_id),
+                ))
+            }
+            Res::Global(global_id) => {
+                let global_table = self.global_table.borrow();
+                let (name, ty, linkage) =
+                    global_table.get(global_id).ok_or_else(unresolved_name)?;
+
+                return Ok((
+                    rustyc_hir::expressions::ExpressionKind::Global(
+                        rustyc_hir::expressions::GlobalExpression::new(
+                            global_id,
+                            name.to_owned(),
+                            linkage,
+                        ),
+                    ),
+                    ty,
+                ));
+            }
+        };
+
+        Ok((
+            rustyc_hir::expressions::ExpressionKind::Variable(
+                rustyc_hir::expressions::VariableExpression::new(res),
+            ),
+            ty,
+        ))
+    }
+
+    fn lower_number(
+        &self,
+        expression: &rustyc_ast::expressions::NumberExpression,
+    ) -> (rustyc_hir::expressions::ExpressionKind, TyId) {
         (
-            rustyc_hir::ExpressionKind::Variable(variable.to_owned()),
+            Self::new_number_expression_kind(expression.get_value()),
             self.ty_context.borrow_mut().register(Ty::Int),
         )
     }
 
-    fn lower_number(&self, number: u64) -> (rustyc_hir::ExpressionKind, TyId) {
+    /// Lowers a string literal into the `char` array variable holding it.
+    fn lower_string(
+        &self,
+        expression: &rustyc_ast::expressions::StringExpression,
+    ) -> rustyc_diagnostics::Result<(rustyc_hir::expressions::ExpressionKind, TyId)> {
+        let Some(Res::Global(global_id)) = self.resolutions.get(expression.get_id()) else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::InvalidExpression,
+                self.expression.get_span().clone(),
+            ));
+        };
+
+        let value = expression.get_value();
+        let char_ty = self.ty_context.borrow_mut().register(Ty::Char);
+        let ty = self
+            .ty_context
+            .borrow_mut()
+            .register(Ty::Array(char_ty, Some(value.len() as u64 + 1)));
+        let span = self.expression.get_span().clone();
+
+        let name = format!("str.{}", global_id.get());
+        let initializer = Initializer::List(InitializerLowerer::new_string_elements(
+            value, 0, char_ty, &span,
+        ));
+
+        let mut global_table = self.global_table.borrow_mut();
+        global_table.declare(global_id, name.clone(), ty, Linkage::Internal, span)?;
+        global_table.define(global_id);
+        global_table.set_initializer(global_id, ty, Rc::new(initializer));
+
+        Ok((
+            rustyc_hir::expressions::ExpressionKind::Global(
+                rustyc_hir::expressions::GlobalExpression::new(global_id, name, Linkage::Internal),
+            ),
+            ty,
+        ))
+    }
+
+    fn lower_float_number(
+        &self,
+        expression: &rustyc_ast::expressions::FloatNumberExpression,
+    ) -> (rustyc_hir::expressions::ExpressionKind, TyId) {
+        let ty = if expression.is_single_precision() {
+            Ty::Float
+        } else {
+            Ty::Double
+        };
+
         (
-            rustyc_hir::ExpressionKind::Number(number),
-            self.ty_context.borrow_mut().register(Ty::Int),
+            rustyc_hir::expressions::ExpressionKind::FloatNumber(
+                rustyc_hir::expressions::FloatNumberExpression::new(expression.get_value()),
+            ),
+            self.ty_context.borrow_mut().register(ty),
         )
     }
 
     fn lower_function_call(
         &self,
-        name: &str,
-        arguments: &[Rc<rustyc_ast::Expression>],
-    ) -> (rustyc_hir::ExpressionKind, TyId) {
-        (
-            rustyc_hir::ExpressionKind::FunctionCall(
-                name.to_owned(),
-                arguments
-                    .iter()
-                    .map(|argument| self.lower_expression(Rc::clone(argument)))
-                    .collect(),
+        expression: &rustyc_ast::expressions::FunctionCallExpression,
+    ) -> rustyc_diagnostics::Result<(rustyc_hir::expressions::ExpressionKind, TyId)> {
+        let callee = self.lower_expression(expression.get_callee())?;
+
+        let function_ty = self
+            .ty_context
+            .borrow()
+            .get_callee_function_ty(callee.get_ty())
+            .cloned();
+
+        // Calling something that isn't a function is reported by the type
+        // checker.
+        let ty = match &function_ty {
+            Some(function_ty) => function_ty.get_return_ty(),
+            None => self.ty_context.borrow_mut().register(Ty::Int),
+        };
+
+        let parameters = function_ty
+            .as_ref()
+            .and_then(|function_ty| function_ty.get_parameters().map(<[TyId]>::to_vec))
+            .unwrap_or_default();
+
+        // Arguments are converted to the types of their parameters, and the
+        // ones that have none (variadic or unprototyped) are promoted.
+        let arguments = expression
+            .get_arguments()
+            .iter()
+            .enumerate()
+            .map(|(i, argument)| {
+                let argument = self.lower_expression(Rc::clone(argument))?;
+
+                Ok(match parameters.get(i) {
+                    Some(parameter_ty) => self.ty_converter.convert(argument, *parameter_ty),
+                    None => self.ty_converter.promote_argument(argument),
+                })
+            })
+            .collect::<rustyc_diagnostics::Result<_>>()?;
+
+        let fixed_argument_count = function_ty
+            .filter(|function_ty| function_ty.is_variadic())
+            .and_then(|function_ty| {
+                function_ty
+                    .get_parameters()
+                    .map(|parameters| parameters.len())
+            });
+
+        Ok((
+            rustyc_hir::expressions::ExpressionKind::FunctionCall(
+                rustyc_hir::expressions::FunctionCallExpression::new(
+                    callee,
+                    arguments,
+                    fixed_argument_count,
+                ),
             ),
-            self.ty_context.borrow_mut().register(Ty::Int),
+            ty,
+        ))
+    }
+
+    fn lower_member(
+        &self,
+        expression: &rustyc_ast::expressions::MemberExpression,
+    ) -> rustyc_diagnostics::Result<(rustyc_hir::expressions::ExpressionKind, TyId)> {
+        let base = self.lower_expression(expression.get_base())?;
+
+        let Ty::Struct(struct_id) = *self.ty_context.borrow().get(base.get_ty()) else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::MemberOfNonStruct,
+                self.expression.get_span().clone(),
+            ));
+        };
+
+        let member = self
+            .ty_context
+            .borrow()
+            .get_struct_layout(struct_id)
+            .ok_or_else(|| {
+                Diagnostic::new_error(
+                    rustyc_diagnostics::Error::IncompleteStructAccess,
+                    self.expression.get_span().clone(),
+                )
+            })?
+            .get_member(expression.get_name())
+            .cloned()
+            .ok_or_else(|| {
+                Diagnostic::new_error(
+                    rustyc_diagnostics::Error::NoSuchMember(expression.get_name().to_owned()),
+                    self.expression.get_span().clone(),
+                )
+            })?;
+
+        // An unsigned bit-field narrower than `int` is promoted to `int` when
+        // it is read, and storing an `int` into it sets the same bits.
+        let is_promoted = member
+            .get_bit_field()
+            .is_some_and(|bit_field| bit_field.get_width() < 64)
+            && matches!(
+                self.ty_context.borrow().get(member.get_ty()),
+                Ty::UnsignedInt
+            );
+
+        let member_ty = if is_promoted {
+            self.ty_context.borrow_mut().register(Ty::Int)
+        } else {
+            member.get_ty()
+        };
+
+        // The members of a qualified structure have the same qualifiers.
+        let qualifiers = self.ty_context.borrow().get_qualifiers(base.get_ty());
+        let ty = self.ty_context.borrow_mut().qualify(member_ty, qualifiers);
+
+        Ok((
+            rustyc_hir::expressions::ExpressionKind::Member(
+                rustyc_hir::expressions::MemberExpression::new(base, member),
+            ),
+            ty,
+        ))
+    }
+
+    fn lower_size_of(
+        &self,
+        expression: &rustyc_ast::expressions::SizeOfExpression,
+    ) -> rustyc_diagnostics::Result<(rustyc_hir::expressions::ExpressionKind, TyId)> {
+        let size_ty = self.ty_context.borrow_mut().register(Ty::UnsignedInt);
+
+        let ty = match expression.get_operand() {
+            rustyc_ast::expressions::SizeOfOperand::Ty(ty) => {
+                let ty_lowerer = TyLowerer::new(Rc::clone(ty), Rc::clone(&self.ty_context));
+
+                match self.lower_array_length(ty)? {
+                    Some(length) => {
+                        match TyLowerer::evaluate_length(
+                            Rc::clone(&length),
+                            Rc::clone(&self.ty_context),
+                        )? {
+                            Some(length) => ty_lowerer.lower_array(Some(length))?,
+                            None => {
+                                let ty = ty_lowerer.lower_array(None)?;
+                                return Ok((self.new_variable_array_size(ty, length), size_ty));
+                            }
+                        }
+                    }
+                    None => ty_lowerer.lower()?,
+                }
+            }
+            rustyc_ast::expressions::SizeOfOperand::Expression(operand) => {
+                let operand = self.lower_expression_without_decay(Rc::clone(operand))?;
+
+                if self.ty_matcher.is_variable_array(operand.get_ty()) {
+                    return Ok((
+                        rustyc_hir::expressions::ExpressionKind::SizeOf(
+                            rustyc_hir::expressions::SizeOfExpression::new(operand),
+                        ),
+                        size_ty,
+                    ));
+                }
+
+                if let rustyc_hir::expressions::ExpressionKind::Member(member) = operand.get_kind()
+                {
+                    if member.is_bit_field() {
+                        return Err(Diagnostic::new_error(
+                            rustyc_diagnostics::Error::SizeOfBitField,
+                            self.expression.get_span().clone(),
+                        ));
+                    }
+                }
+
+                operand.get_ty()
+            }
+        };
+
+        let size = self.ty_context.borrow().get_size(ty).ok_or_else(|| {
+            Diagnostic::new_error(
+                rustyc_diagnostics::Error::SizeOfIncompleteType,
+                self.expression.get_span().clone(),
+            )
+        })?;
+
+        Ok((Self::new_number_expression_kind(size), size_ty))
+    }
+
+    fn lower_array_length(
+        &self,
+        ty: &rustyc_ast::types::Ty,
+    ) -> rustyc_diagnostics::Result<Option<Rc<rustyc_hir::expressions::Expression>>> {
+        let rustyc_ast::types::TyKind::Array(array_ty) = ty.get_kind() else {
+            return Ok(None);
+        };
+
+        array_ty
+            .get_length()
+            .map(|length| {
+                self.lower_expression(length)
+                    .map(|length| self.ty_converter.convert_size(length))
+            })
+            .transpose()
+    }
+
+    fn new_variable_array_size(
+        &self,
+        ty: TyId,
+        length: Rc<rustyc_hir::expressions::Expression>,
+    ) -> rustyc_hir::expressions::ExpressionKind {
+        let element_size = match self.ty_context.borrow().get(ty) {
+            Ty::VariableArray(element_ty) => self.ty_context.borrow().get_size(*element_ty),
+            _ => None,
+        }
+        .unwrap_or(1);
+
+        let element_size = Rc::new(rustyc_hir::expressions::Expression::new(
+            Self::new_number_expression_kind(element_size),
+            length.get_ty(),
+            self.expression.get_span().clone(),
+        ));
+
+        Self::new_binary_expression_kind(
+            rustyc_hir::expressions::BinaryOperator::Multiply,
+            length,
+            element_size,
         )
     }
 
+    fn lower_compound_literal(
+        &self,
+        expression: &rustyc_ast::expressions::CompoundLiteralExpression,
+    ) -> rustyc_diagnostics::Result<(rustyc_hir::expressions::ExpressionKind, TyId)> {
+        let Some(Res::Local(local_id)) = self.resolutions.get(expression.get_id()) else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::InvalidExpression,
+                self.expression.get_span().clone(),
+            ));
+        };
+
+        let ty_lowerer = TyLowerer::new(expression.get_ty(), Rc::clone(&self.ty_context));
+        let ty = ty_lowerer.lower()?;
+
+        self.local_table.borrow_mut().insert(Local::new(
+            local_id,
+            String::new(),
+            ty,
+            self.expression.get_span().clone(),
+        ));
+
+        let initializer_lowerer = InitializerLowerer::new(
+            expression.get_initializer(),
+            ty,
+            self.expression.get_span().clone(),
+            self.function.clone(),
+            Rc::clone(&self.resolutions),
+            Rc::clone(&self.function_tys),
+            Rc::clone(&self.local_table),
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
+        let (initialized_ty, initializer) = initializer_lowerer.lower()?;
+
+        // An array of unknown length is completed by its initializer.
+        if initialized_ty != ty {
+            self.local_table.borrow_mut().insert(Local::new(
+                local_id,
+                String::new(),
+                initialized_ty,
+                self.expression.get_span().clone(),
+            ));
+        }
+
+        Ok((
+            rustyc_hir::expressions::ExpressionKind::CompoundLiteral(
+                rustyc_hir::expressions::CompoundLiteralExpression::new(local_id, initializer),
+            ),
+            initialized_ty,
+        ))
+    }
+
+    fn lower_statement_expression(
+        &self,
+        expression: &rustyc_ast::expressions::StatementExpression,
+    ) -> rustyc_diagnostics::Result<(rustyc_hir::expressions::ExpressionKind, TyId)> {
+        let Some(function) = &self.function else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::StatementExpressionOutsideFunction,
+                self.expression.get_span().clone(),
+            ));
+        };
+
+        let block_lowerer = BlockLowerer::new(
+            expression.get_block(),
+            Rc::clone(function),
+            Rc::clone(&self.resolutions),
+            Rc::clone(&self.function_tys),
+            Rc::clone(&self.local_table),
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
+        let block = block_lowerer.lower()?;
+
+        let ty = match block
+            .get_statements()
+            .last()
+            .map(|statement| statement.get_kind())
+        {
+            Some(rustyc_hir::statements::StatementKind::Expression(statement)) => {
+                statement.get_expression().get_ty()
+            }
+            _ => self.ty_context.borrow_mut().register(Ty::Void),
+        };
+
+        Ok((
+            rustyc_hir::expressions::ExpressionKind::Statement(
+                rustyc_hir::expressions::StatementExpression::new(block),
+            ),
+            ty,
+        ))
+    }
+
+    /// Selects the association compatible with the converted controlling expression.
+    fn lower_generic_selection(
+        &self,
+        expression: &rustyc_ast::expressions::GenericSelectionExpression,
+    ) -> rustyc_diagnostics::Result<Rc<rustyc_hir::expressions::Expression>> {
+        let controlling_ty = self
+            .lower_expression(expression.get_controlling_expression())?
+            .get_ty();
+        let controlling_ty = if self.ty_matcher.is_function(controlling_ty) {
+            self.ty_context
+                .borrow_mut()
+                .register(Ty::Pointer(controlling_ty))
+        } else {
+            controlling_ty
+        };
+
+        let mut selected = None;
+        let mut default = None;
+        let mut association_tys: Vec<TyId> = Vec::new();
+
+        for association in expression.get_associations().iter() {
+            let Some(ty) = association.get_ty() else {
+                if default.is_some() {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::DuplicateGenericDefault,
+                        association.get_expression().get_span().clone(),
+                    ));
+                }
+
+                default = Some(association.get_expression());
+                continue;
+            };
+
+            let ty_lowerer = TyLowerer::new(ty, Rc::clone(&self.ty_context));
+            let ty = ty_lowerer.lower()?;
+
+            if association_tys.contains(&ty) {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::DuplicateGenericAssociation,
+                    association.get_expression().get_span().clone(),
+                ));
+            }
+
+            association_tys.push(ty);
+
+            // The controlling expression is never qualified, so neither is a
+            // compatible association type.
+            let is_unqualified =
+                self.ty_context.borrow().get_qualifiers(ty) == Qualifiers::default();
+
+            if is_unqualified && self.ty_matcher.is_same_unqualified(controlling_ty, ty) {
+                selected = Some(association.get_expression());
+            }
+        }
+
+        let selected = selected.or(default).ok_or_else(|| {
+            Diagnostic::new_error(
+                rustyc_diagnostics::Error::GenericSelectionNoMatch,
+                self.expression.get_span().clone(),
+            )
+        })?;
+
+        self.lower_expression(selected)
+    }
+
+    fn lower_va_start(
+        &self,
+        expression: &rustyc_ast::expressions::VaStartExpression,
+    ) -> rustyc_diagnostics::Result<(rustyc_hir::expressions::ExpressionKind, TyId)> {
+        Ok((
+            rustyc_hir::expressions::ExpressionKind::VaStart(
+                rustyc_hir::expressions::VaStartExpression::new(
+                    self.lower_expression(expression.get_list())?,
+                    self.lower_expression(expression.get_last_parameter())?,
+                ),
+            ),
+            self.ty_context.borrow_mut().register(Ty::Int),
+        ))
+    }
+
+    fn lower_va_arg(
+        &self,
+        expression: &rustyc_ast::expressions::VaArgExpression,
+    ) -> rustyc_diagnostics::Result<(rustyc_hir::expressions::ExpressionKind, TyId)> {
+        let ty_lowerer = TyLowerer::new(expression.get_ty(), Rc::clone(&self.ty_context));
+
+        Ok((
+            rustyc_hir::expressions::ExpressionKind::VaArg(
+                rustyc_hir::expressions::VaArgExpression::new(
+                    self.lower_expression(expression.get_list())?,
+                ),
+            ),
+            ty_lowerer.lower()?,
+        ))
+    }
+
+    fn lower_va_end(
+        &self,
+        expression: &rustyc_ast::expressions::VaEndExpression,
+    ) -> rustyc_diagnostics::Result<(rustyc_hir::expressions::ExpressionKind, TyId)> {
+        Ok((
+            rustyc_hir::expressions::ExpressionKind::VaEnd(
+                rustyc_hir::expressions::VaEndExpression::new(
+                    self.lower_expression(expression.get_list())?,
+                ),
+            ),
+            self.ty_context.borrow_mut().register(Ty::Int),
+        ))
+    }
+
+    fn lower_alloca(
+        &self,
+        expression: &rustyc_ast::expressions::AllocaExpression,
+    ) -> rustyc_diagnostics::Result<(rustyc_hir::expressions::ExpressionKind, TyId)> {
+        let size = self
+            .ty_converter
+            .convert_size(self.lower_expression(expression.get_size())?);
+
+        let void_ty = self.ty_context.borrow_mut().register(Ty::Void);
+
+        Ok((
+            rustyc_hir::expressions::ExpressionKind::Alloca(
+                rustyc_hir::expressions::AllocaExpression::new(size),
+            ),
+            self.ty_context.borrow_mut().register(Ty::Pointer(void_ty)),
+        ))
+    }
+
     fn lower_add(
         &self,
-        left: Rc<rustyc_hir::Expression>,
-        right: Rc<rustyc_hir::Expression>,
-    ) -> (rustyc_hir::ExpressionKind, TyId) {
-        if self.ty_matcher.is_pointer(left.get_ty()) && self.ty_matcher.is_int(right.get_ty()) {
+        left: Rc<rustyc_hir::expressions::Expression>,
+        right: Rc<rustyc_hir::expressions::Expression>,
+    ) -> (rustyc_hir::expressions::ExpressionKind, TyId) {
+        if self.ty_matcher.is_pointer(left.get_ty()) && self.ty_matcher.is_integer(right.get_ty()) {
             return self.lower_pointer_number_arithmetic(
                 left,
                 right,
-                rustyc_hir::BinaryOperator::Add,
+                rustyc_hir::expressions::BinaryOperator::Add,
             );
         }
 
-        if self.ty_matcher.is_int(left.get_ty()) {
-            if self.ty_matcher.is_pointer(right.get_ty()) {
-                return self.lower_pointer_number_arithmetic(
-                    right,
-                    left,
-                    rustyc_hir::BinaryOperator::Add,
-                );
-            }
+        if self.ty_matcher.is_integer(left.get_ty()) && self.ty_matcher.is_pointer(right.get_ty()) {
+            return self.lower_pointer_number_arithmetic(
+                right,
+                left,
+                rustyc_hir::expressions::BinaryOperator::Add,
+            );
         }
 
         let ty = left.get_ty();
 
         (
-            rustyc_hir::ExpressionKind::Binary(rustyc_hir::BinaryOperator::Add, left, right),
+            Self::new_binary_expression_kind(
+                rustyc_hir::expressions::BinaryOperator::Add,
+                left,
+                right,
+            ),
             ty,
         )
     }
 
     fn lower_subtract(
         &self,
-        left: Rc<rustyc_hir::Expression>,
-        right: Rc<rustyc_hir::Expression>,
-    ) -> (rustyc_hir::ExpressionKind, TyId) {
-        if self.ty_matcher.is_pointer(left.get_ty()) && self.ty_matcher.is_int(right.get_ty()) {
+        left: Rc<rustyc_hir::expressions::Expression>,
+        right: Rc<rustyc_hir::expressions::Expression>,
+    ) -> (rustyc_hir::expressions::ExpressionKind, TyId) {
+        if self.ty_matcher.is_pointer(left.get_ty()) && self.ty_matcher.is_integer(right.get_ty()) {
             return self.lower_pointer_number_arithmetic(
                 left,
                 right,
-                rustyc_hir::BinaryOperator::Subtract,
+                rustyc_hir::expressions::BinaryOperator::Subtract,
             );
         }
 
@@ -221,32 +907,39 @@ impl ExpressionLowerer {
         let ty = left.get_ty();
 
         (
-            rustyc_hir::ExpressionKind::Binary(rustyc_hir::BinaryOperator::Subtract, left, right),
+            Self::new_binary_expression_kind(
+                rustyc_hir::expressions::BinaryOperator::Subtract,
+                left,
+                right,
+            ),
             ty,
         )
     }
 
     fn lower_pointer_number_arithmetic(
         &self,
-        pointer: Rc<rustyc_hir::Expression>,
-        number: Rc<rustyc_hir::Expression>,
-        operator: rustyc_hir::BinaryOperator,
-    ) -> (rustyc_hir::ExpressionKind, TyId) {
+        pointer: Rc<rustyc_hir::expressions::Expression>,
+        number: Rc<rustyc_hir::expressions::Expression>,
+        operator: rustyc_hir::expressions::BinaryOperator,
+    ) -> (rustyc_hir::expressions::ExpressionKind, TyId) {
+        let number = self.ty_converter.promote(number);
+
         let pointer_ty = pointer.get_ty();
         let number_ty = number.get_ty();
+        let stride = self.get_pointee_size(pointer_ty);
 
         let number_span = number.get_span().clone();
 
         (
-            rustyc_hir::ExpressionKind::Binary(
+            Self::new_binary_expression_kind(
                 operator,
                 pointer,
-                Rc::new(rustyc_hir::Expression::new(
-                    rustyc_hir::ExpressionKind::Binary(
-                        rustyc_hir::BinaryOperator::Multiply,
+                Rc::new(rustyc_hir::expressions::Expression::new(
+                    Self::new_binary_expression_kind(
+                        rustyc_hir::expressions::BinaryOperator::Multiply,
                         number,
-                        Rc::new(rustyc_hir::Expression::new(
-                            rustyc_hir::ExpressionKind::Number(8),
+                        Rc::new(rustyc_hir::expressions::Expression::new(
+                            Self::new_number_expression_kind(stride),
                             self.ty_context.borrow_mut().register(Ty::Int),
                             number_span.clone(),
                         )),
@@ -261,25 +954,26 @@ impl ExpressionLowerer {
 
     fn lower_pointer_pointer_subtract(
         &self,
-        left: Rc<rustyc_hir::Expression>,
-        right: Rc<rustyc_hir::Expression>,
-    ) -> (rustyc_hir::ExpressionKind, TyId) {
+        left: Rc<rustyc_hir::expressions::Expression>,
+        right: Rc<rustyc_hir::expressions::Expression>,
+    ) -> (rustyc_hir::expressions::ExpressionKind, TyId) {
         let int_ty = self.ty_context.borrow_mut().register(Ty::Int);
+        let stride = self.get_pointee_size(left.get_ty());
 
         (
-            rustyc_hir::ExpressionKind::Binary(
-                rustyc_hir::BinaryOperator::Divide,
-                Rc::new(rustyc_hir::Expression::new(
-                    rustyc_hir::ExpressionKind::Binary(
-                        rustyc_hir::BinaryOperator::Subtract,
+            Self::new_binary_expression_kind(
+                rustyc_hir::expressions::BinaryOperator::Divide,
+                Rc::new(rustyc_hir::expressions::Expression::new(
+                    Self::new_binary_expression_kind(
+                        rustyc_hir::expressions::BinaryOperator::Subtract,
                         left,
                         right,
                     ),
                     int_ty,
                     self.expression.get_span().clone(),
                 )),
-                Rc::new(rustyc_hir::Expression::new(
-                    rustyc_hir::ExpressionKind::Number(8),
+                Rc::new(rustyc_hir::expressions::Expression::new(
+                    Self::new_number_expression_kind(stride),
                     int_ty,
                     self.expression.get_span().clone(),
                 )),
@@ -288,11 +982,83 @@ impl ExpressionLowerer {
         )
     }
 
+    // Functions that are called without being declared implicitly have the
+    // type `int ()`.
+    fn get_function_ty(&self, function_id: FunctionId) -> TyId {
+        if let Some(ty) = self.function_tys.get(&function_id) {
+            return *ty;
+        }
+
+        let int_ty = self.ty_context.borrow_mut().register(Ty::Int);
+
+        self.ty_context
+            .borrow_mut()
+            .register(Ty::Function(FunctionTy::new(int_ty, None, false)))
+    }
+
+    /// Returns the pointee size, which is 1 for `void` and functions, as in GNU C.
+    fn get_pointee_size(&self, pointer_ty: TyId) -> u64 {
+        let ty_context = self.ty_context.borrow();
+
+        match ty_context.get(pointer_ty) {
+            Ty::Pointer(base) => ty_context.get_size(*base).unwrap_or(1),
+            _ => 1,
+        }
+    }
+
+    fn get_dereferenced_function_ty(&self, ty: TyId) -> Option<TyId> {
+        match self.ty_context.borrow().get(ty) {
+            Ty::Function(_) => Some(ty),
+            Ty::Pointer(base) if self.ty_matcher.is_function(*base) => Some(*base),
+            _ => None,
+        }
+    }
+
+    fn new_binary_expression_kind(
+        operator: rustyc_hir::expressions::BinaryOperator,
+        left: Rc<rustyc_hir::expressions::Expression>,
+        right: Rc<rustyc_hir::expressions::Expression>,
+    ) -> rustyc_hir::expressions::ExpressionKind {
+        rustyc_hir::expressions::ExpressionKind::Binary(
+            rustyc_hir::expressions::BinaryExpression::new(operator, left, right),
+        )
+    }
+
+    fn new_number_expression_kind(value: u64) -> rustyc_hir::expressions::ExpressionKind {
+        rustyc_hir::expressions::ExpressionKind::Number(
+            rustyc_hir::expressions::NumberExpression::new(value),
+        )
+    }
+
     fn lower_expression(
         &self,
-        expression: Rc<rustyc_ast::Expression>,
-    ) -> Rc<rustyc_hir::Expression> {
-        let expression_lowerer = Self::new(expression, Rc::clone(&self.ty_context));
+        expression: Rc<rustyc_ast::expressions::Expression>,
+    ) -> rustyc_diagnostics::Result<Rc<rustyc_hir::expressions::Expression>> {
+        let expression_lowerer = Self::new(
+            expression,
+            self.function.clone(),
+            Rc::clone(&self.resolutions),
+            Rc::clone(&self.function_tys),
+            Rc::clone(&self.local_table),
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
         expression_lowerer.lower()
     }
+
+    fn lower_expression_without_decay(
+        &self,
+        expression: Rc<rustyc_ast::expressions::Expression>,
+    ) -> rustyc_diagnostics::Result<Rc<rustyc_hir::expressions::Expression>> {
+        let expression_lowerer = Self::new(
+            expression,
+            self.function.clone(),
+            Rc::clone(&self.resolutions),
+            Rc::clone(&self.function_tys),
+            Rc::clone(&self.local_table),
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
+        expression_lowerer.lower_without_decay()
+    }
 }
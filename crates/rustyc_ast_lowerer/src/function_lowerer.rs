@@ -1,36 +1,129 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use rustyc_ty::TyContext;
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{FunctionId, Linkage, Local, LocalId, Res};
+use rustyc_resolver::Resolutions;
+use rustyc_ty::{TyContext, TyId};
 
-use crate::block_lowerer::BlockLowerer;
+use crate::{
+    block_lowerer::BlockLowerer, global_table::GlobalTable, local_table::LocalTable,
+    ty_lowerer::TyLowerer,
+};
 
 pub struct FunctionLowerer {
     function: Rc<rustyc_ast::items::FunctionItem>,
+    linkage: Linkage,
+    resolutions: Rc<Resolutions>,
+    function_tys: Rc<HashMap<FunctionId, TyId>>,
+    local_table: Rc<RefCell<LocalTable>>,
+    global_table: Rc<RefCell<GlobalTable>>,
     ty_context: Rc<RefCell<TyContext>>,
 }
 
 impl FunctionLowerer {
     pub fn new(
         function: Rc<rustyc_ast::items::FunctionItem>,
+        linkage: Linkage,
+        resolutions: Rc<Resolutions>,
+        function_tys: Rc<HashMap<FunctionId, TyId>>,
+        global_table: Rc<RefCell<GlobalTable>>,
         ty_context: Rc<RefCell<TyContext>>,
     ) -> Self {
         Self {
             function,
+            linkage,
+            resolutions,
+            function_tys,
+            local_table: Rc::new(RefCell::new(LocalTable::new())),
+            global_table,
             ty_context,
         }
     }
 
-    pub fn lower(self) -> Rc<rustyc_hir::items::FunctionItem> {
-        Rc::new(rustyc_hir::items::FunctionItem::new(
+    pub fn lower(self) -> rustyc_diagnostics::Result<Rc<rustyc_hir::items::FunctionItem>> {
+        let Some(Res::Function(id)) = self.resolutions.get(self.function.get_id()) else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::UnresolvedName(self.function.get_name().to_owned()),
+                self.function.get_return_ty().get_span().clone(),
+            ));
+        };
+
+        let parameters = self
+            .function
+            .get_parameters()
+            .iter()
+            .map(|parameter| self.lower_parameter(parameter))
+            .collect::<rustyc_diagnostics::Result<Vec<LocalId>>>()?;
+
+        let return_ty = self.lower_ty(self.function.get_return_ty())?;
+
+        let body = self
+            .function
+            .get_body()
+            .map(|body| self.lower_block(body))
+            .transpose()?;
+
+        Ok(Rc::new(rustyc_hir::items::FunctionItem::new(
+            id,
             self.function.get_name().to_owned(),
-            self.function.get_parameters().to_vec(),
-            self.lower_block(self.function.get_body()),
-            self.function.get_local_variables().to_vec(),
-        ))
+            self.linkage,
+            return_ty,
+            parameters,
+            self.function.is_variadic(),
+            body,
+            self.local_table.borrow_mut().take_locals(),
+        )))
+    }
+
+    fn lower_parameter(
+        &self,
+        parameter: &rustyc_ast::items::Parameter,
+    ) -> rustyc_diagnostics::Result<LocalId> {
+        let Some(Res::Parameter(local_id)) = self.resolutions.get(parameter.get_id()) else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::UnresolvedName(
+                    parameter.get_name().unwrap_or_default().to_owned(),
+                ),
+                parameter.get_span().clone(),
+            ));
+        };
+
+        self.local_table.borrow_mut().insert(Local::new(
+            local_id,
+            parameter.get_name().unwrap_or_default().to_owned(),
+            self.lower_parameter_ty(parameter.get_ty())?,
+            parameter.get_span().clone(),
+        ));
+
+        Ok(local_id)
     }
 
-    pub fn lower_block(&self, block: Rc<rustyc_ast::Block>) -> Rc<rustyc_hir::Block> {
-        let block_lowerer = BlockLowerer::new(block, Rc::clone(&self.ty_context));
+    fn lower_block(
+        &self,
+        block: Rc<rustyc_ast::Block>,
+    ) -> rustyc_diagnostics::Result<Rc<rustyc_hir::Block>> {
+        let block_lowerer = BlockLowerer::new(
+            block,
+            Rc::clone(&self.function),
+            Rc::clone(&self.resolutions),
+            Rc::clone(&self.function_tys),
+            Rc::clone(&self.local_table),
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
         block_lowerer.lower()
     }
+
+    fn lower_ty(&self, ty: Rc<rustyc_ast::types::Ty>) -> rustyc_diagnostics::Result<TyId> {
+        let ty_lowerer = TyLowerer::new(ty, Rc::clone(&self.ty_context));
+        ty_lowerer.lower()
+    }
+
+    fn lower_parameter_ty(
+        &self,
+        ty: Rc<rustyc_ast::types::Ty>,
+    ) -> rustyc_diagnostics::Result<TyId> {
+        let ty_lowerer = TyLowerer::new(ty, Rc::clone(&self.ty_context));
+        ty_lowerer.lower_parameter()
+    }
 }
@@ -0,0 +1,137 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{initializers::Initializer, GlobalId, Linkage};
+use rustyc_span::Span;
+use rustyc_ty::{TyContext, TyId, TyMatcher};
+
+struct Global {
+    name: String,
+    ty: TyId,
+    linkage: Linkage,
+    initializer: Option<Rc<Initializer>>,
+    span: Span,
+}
+
+/// Collects the variables with static storage duration of the translation unit.
+pub struct GlobalTable {
+    globals: HashMap<GlobalId, Global>,
+    definitions: Vec<GlobalId>,
+    names: HashSet<String>,
+    ty_context: Rc<RefCell<TyContext>>,
+}
+
+impl GlobalTable {
+    pub fn new(ty_context: Rc<RefCell<TyContext>>) -> Self {
+        Self {
+            globals: HashMap::new(),
+            definitions: Vec::new(),
+            names: HashSet::new(),
+            ty_context,
+        }
+    }
+
+    /// Declares a variable; once declared `static`, it has internal linkage.
+    pub fn declare(
+        &mut self,
+        id: GlobalId,
+        name: String,
+        ty: TyId,
+        linkage: Linkage,
+        span: Span,
+    ) -> rustyc_diagnostics::Result<()> {
+        let Some(global) = self.globals.get_mut(&id) else {
+            self.names.insert(name.clone());
+            self.globals.insert(
+                id,
+                Global {
+                    name,
+                    ty,
+                    linkage,
+                    initializer: None,
+                    span,
+                },
+            );
+
+            return Ok(());
+        };
+
+        if !TyMatcher::new(Rc::clone(&self.ty_context)).is_compatible(global.ty, ty) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ConflictingTypes(name),
+                span,
+            ));
+        }
+
+        // A later declaration can give the length of an array.
+        if self.ty_context.borrow().get_size(global.ty).is_none() {
+            global.ty = ty;
+        }
+
+        if linkage == Linkage::Internal {
+            global.linkage = Linkage::Internal;
+        }
+
+        Ok(())
+    }
+
+    /// Marks a declared variable as defined, so that storage is emitted for it.
+    pub fn define(&mut self, id: GlobalId) {
+        if !self.definitions.contains(&id) {
+            self.definitions.push(id);
+        }
+    }
+
+    /// The initializer completes the type of an array of unknown length.
+    pub fn set_initializer(&mut self, id: GlobalId, ty: TyId, initializer: Rc<Initializer>) {
+        if let Some(global) = self.globals.get_mut(&id) {
+            global.ty = ty;
+            global.initializer = Some(initializer);
+        }
+    }
+
+    /// Returns the name, type and linkage of a declared variable.
+    pub fn get(&self, id: GlobalId) -> Option<(&str, TyId, Linkage)> {
+        self.globals
+            .get(&id)
+            .map(|global| (global.name.as_str(), global.ty, global.linkage))
+    }
+
+    /// Mangles the name of a `static` local into a unique symbol name.
+    pub fn allocate_static_local_name(&self, function_name: &str, name: &str) -> String {
+        let base_name = format!("{function_name}.{name}");
+
+        (0..)
+            .map(|index| match index {
+                0 => base_name.clone(),
+                index => format!("{base_name}.{index}"),
+            })
+            .find(|name| !self.names.contains(name))
+            .unwrap()
+    }
+
+    pub fn take_items(&mut self) -> Vec<Rc<rustyc_hir::items::Item>> {
+        std::mem::take(&mut self.definitions)
+            .into_iter()
+            .filter_map(|id| self.globals.remove(&id).map(|global| (id, global)))
+            .map(|(id, global)| {
+                Rc::new(rustyc_hir::items::Item::new(
+                    rustyc_hir::items::ItemKind::Global(Rc::new(
+                        rustyc_hir::items::GlobalItem::new(
+                            id,
+                            global.name,
+                            global.ty,
+                            global.linkage,
+                            global.initializer,
+                        ),
+                    )),
+                    global.span,
+                ))
+            })
+            .collect()
+    }
+}
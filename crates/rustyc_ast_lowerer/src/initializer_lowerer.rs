@@ -0,0 +1,527 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use rustyc_ast::initializers::{DesignatedInitializer, Designator};
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{
+    initializers::{Initializer, InitializerElement},
+    ConstantEvaluator, FunctionId,
+};
+use rustyc_resolver::Resolutions;
+use rustyc_span::Span;
+use rustyc_ty::{BitField, Ty, TyContext, TyId, TyMatcher};
+
+use crate::{
+    expression_lowerer::ExpressionLowerer, global_table::GlobalTable, local_table::LocalTable,
+    ty_converter::TyConverter,
+};
+
+pub struct InitializerLowerer {
+    initializer: Rc<rustyc_ast::initializers::Initializer>,
+    ty: TyId,
+    span: Span,
+    function: Option<Rc<rustyc_ast::items::FunctionItem>>,
+    resolutions: Rc<Resolutions>,
+    function_tys: Rc<HashMap<FunctionId, TyId>>,
+    local_table: Rc<RefCell<LocalTable>>,
+    global_table: Rc<RefCell<GlobalTable>>,
+    ty_matcher: TyMatcher,
+    ty_converter: TyConverter,
+    ty_context: Rc<RefCell<TyContext>>,
+}
+
+impl InitializerLowerer {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        initializer: Rc<rustyc_ast::initializers::Initializer>,
+        ty: TyId,
+        span: Span,
+        function: Option<Rc<rustyc_ast::items::FunctionItem>>,
+        resolutions: Rc<Resolutions>,
+        function_tys: Rc<HashMap<FunctionId, TyId>>,
+        local_table: Rc<RefCell<LocalTable>>,
+        global_table: Rc<RefCell<GlobalTable>>,
+        ty_context: Rc<RefCell<TyContext>>,
+    ) -> Self {
+        Self {
+            initializer,
+            ty,
+            span,
+            function,
+            resolutions,
+            function_tys,
+            local_table,
+            global_table,
+            ty_matcher: TyMatcher::new(Rc::clone(&ty_context)),
+            ty_converter: TyConverter::new(Rc::clone(&ty_context)),
+            ty_context,
+        }
+    }
+
+    /// An array of unknown length gets its length from the initializer.
+    pub fn lower(self) -> rustyc_diagnostics::Result<(TyId, Rc<Initializer>)> {
+        if self.ty_matcher.is_variable_array(self.ty) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::VariableArrayInitializer,
+                self.span.clone(),
+            ));
+        }
+
+        match self.initializer.as_ref() {
+            rustyc_ast::initializers::Initializer::Expression(expression) => {
+                if let Some(value) = self.get_string_initializer(self.ty, expression) {
+                    let mut elements = Vec::new();
+                    let length =
+                        self.place_string(self.ty, 0, value, expression.get_span(), &mut elements)?;
+
+                    return Ok((
+                        self.complete_ty(length),
+                        Rc::new(Initializer::List(elements)),
+                    ));
+                }
+
+                if self.ty_matcher.is_array(self.ty) {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::ArrayInitializerNotList,
+                        expression.get_span().clone(),
+                    ));
+                }
+
+                let expression = self
+                    .ty_converter
+                    .convert(self.lower_expression(Rc::clone(expression))?, self.ty);
+
+                Ok((self.ty, Rc::new(Initializer::Expression(expression))))
+            }
+            rustyc_ast::initializers::Initializer::List(list) => {
+                let mut elements = Vec::new();
+                let length = self.place_list(self.ty, 0, None, list, &mut elements)?;
+
+                Ok((
+                    self.complete_ty(length),
+                    Rc::new(Initializer::List(elements)),
+                ))
+            }
+        }
+    }
+
+    fn complete_ty(&self, length: u64) -> TyId {
+        let mut ty_context = self.ty_context.borrow_mut();
+
+        let Ty::Array(element_ty, None) = ty_context.get(self.ty) else {
+            return self.ty;
+        };
+
+        let ty = Ty::Array(*element_ty, Some(length));
+        let qualifiers = ty_context.get_qualifiers(self.ty);
+        ty_context.register_qualified(ty, qualifiers)
+    }
+
+    /// Returns the number of elements the list gives to an array.
+    fn place_list(
+        &self,
+        ty: TyId,
+        offset: u64,
+        bit_field: Option<BitField>,
+        list: &[DesignatedInitializer],
+        elements: &mut Vec<InitializerElement>,
+    ) -> rustyc_diagnostics::Result<u64> {
+        // The string initializing a character array may be enclosed in braces.
+        if let [element] = list {
+            if let rustyc_ast::initializers::Initializer::Expression(expression) =
+                element.get_initializer().as_ref()
+            {
+                if let Some(value) = self.get_string_initializer(ty, expression) {
+                    if element.get_designators().is_empty() {
+                        return self.place_string(ty, offset, value, element.get_span(), elements);
+                    }
+                }
+            }
+        }
+
+        if self.ty_matcher.is_array(ty) || self.ty_matcher.is_struct(ty) {
+            let mut position = 0;
+            return self.place_elements(ty, offset, list, &mut position, 0, true, elements);
+        }
+
+        // The initializer of a scalar may be enclosed in braces.
+        let Some(first) = list.first() else {
+            return Ok(0);
+        };
+
+        if !first.get_designators().is_empty() {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ScalarInitializerDesignator,
+                first.get_span().clone(),
+            ));
+        }
+
+        if let Some(second) = list.get(1) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ExcessScalarInitializerElements,
+                second.get_span().clone(),
+            ));
+        }
+
+        match first.get_initializer().as_ref() {
+            rustyc_ast::initializers::Initializer::Expression(expression) => {
+                self.place_expression(ty, offset, bit_field, Rc::clone(expression), elements)?
+            }
+            rustyc_ast::initializers::Initializer::List(list) => {
+                self.place_list(ty, offset, bit_field, list, elements)?;
+            }
+        }
+
+        Ok(0)
+    }
+
+    /// Without braces, the elements that don't fit are left to the enclosing aggregate.
+    #[allow(clippy::too_many_arguments)]
+    fn place_elements(
+        &self,
+        ty: TyId,
+        offset: u64,
+        list: &[DesignatedInitializer],
+        position: &mut usize,
+        mut index: u64,
+        is_braced: bool,
+        elements: &mut Vec<InitializerElement>,
+    ) -> rustyc_diagnostics::Result<u64> {
+        let mut length = index;
+
+        while let Some(element) = list.get(*position) {
+            if !element.get_designators().is_empty() {
+                if !is_braced {
+                    break;
+                }
+
+                index = self.place_designation(
+                    ty,
+                    offset,
+                    element.get_designators(),
+                    list,
+                    position,
+                    elements,
+                )?;
+            } else {
+                let Some((subobject_ty, subobject_offset, bit_field)) =
+                    self.get_subobject(ty, offset, index)
+                else {
+                    if !is_braced {
+                        break;
+                    }
+
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::ExcessInitializerElements,
+                        element.get_span().clone(),
+                    ));
+                };
+
+                self.place_subobject(
+                    subobject_ty,
+                    subobject_offset,
+                    bit_field,
+                    list,
+                    position,
+                    elements,
+                )?;
+                index += 1;
+            }
+
+            length = length.max(index);
+        }
+
+        Ok(length)
+    }
+
+    /// Returns the index of the subobject that follows the designated one.
+    fn place_designation(
+        &self,
+        ty: TyId,
+        offset: u64,
+        designators: &[Designator],
+        list: &[DesignatedInitializer],
+        position: &mut usize,
+        elements: &mut Vec<InitializerElement>,
+    ) -> rustyc_diagnostics::Result<u64> {
+        let span = list[*position].get_span().clone();
+
+        let (index, (subobject_ty, subobject_offset, bit_field)) = match &designators[0] {
+            Designator::Index(expression) => {
+                if !self.ty_matcher.is_array(ty) {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::IndexDesignatorForNonArray,
+                        span,
+                    ));
+                }
+
+                let constant_evaluator = ConstantEvaluator::new(
+                    self.lower_expression(Rc::clone(expression))?,
+                    Rc::clone(&self.ty_context),
+                );
+                let index = constant_evaluator.evaluate_integer()?;
+
+                u64::try_from(index)
+                    .ok()
+                    .and_then(|index| Some((index, self.get_subobject(ty, offset, index)?)))
+                    .ok_or_else(|| {
+                        Diagnostic::new_error(
+                            rustyc_diagnostics::Error::DesignatorIndexOutOfBounds(index),
+                            expression.get_span().clone(),
+                        )
+                    })?
+            }
+            Designator::Member(name) => {
+                let Ty::Struct(struct_id) = *self.ty_context.borrow().get(ty) else {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::MemberDesignatorForNonStruct,
+                        span,
+                    ));
+                };
+
+                let index = self
+                    .ty_context
+                    .borrow()
+                    .get_struct_layout(struct_id)
+                    .and_then(|layout| {
+                        layout
+                            .get_members()
+                            .iter()
+                            .position(|member| member.get_name() == Some(name))
+                    });
+
+                index
+                    .and_then(|index| {
+                        let index = index as u64;
+                        Some((index, self.get_subobject(ty, offset, index)?))
+                    })
+                    .ok_or_else(|| {
+                        Diagnostic::new_error(
+                            rustyc_diagnostics::Error::NoSuchMember(name.clone()),
+                            span,
+                        )
+                    })?
+            }
+        };
+
+        if designators.len() == 1 {
+            self.place_subobject(
+                subobject_ty,
+                subobject_offset,
+                bit_field,
+                list,
+                position,
+                elements,
+            )?;
+        } else {
+            // The elements that follow initialize the next subobjects of the
+            // innermost designated aggregate first.
+            let next_index = self.place_designation(
+                subobject_ty,
+                subobject_offset,
+                &designators[1..],
+                list,
+                position,
+                elements,
+            )?;
+            self.place_elements(
+                subobject_ty,
+                subobject_offset,
+                list,
+                position,
+                next_index,
+                false,
+                elements,
+            )?;
+        }
+
+        Ok(index + 1)
+    }
+
+    /// An expression of another type than an aggregate subobject starts its elided braces.
+    fn place_subobject(
+        &self,
+        ty: TyId,
+        offset: u64,
+        bit_field: Option<BitField>,
+        list: &[DesignatedInitializer],
+        position: &mut usize,
+        elements: &mut Vec<InitializerElement>,
+    ) -> rustyc_diagnostics::Result<()> {
+        let element = &list[*position];
+
+        let expression = match element.get_initializer().as_ref() {
+            rustyc_ast::initializers::Initializer::List(list) => {
+                self.place_list(ty, offset, bit_field, list, elements)?;
+                *position += 1;
+                return Ok(());
+            }
+            rustyc_ast::initializers::Initializer::Expression(expression) => Rc::clone(expression),
+        };
+
+        if let Some(value) = self.get_string_initializer(ty, &expression) {
+            self.place_string(ty, offset, value, element.get_span(), elements)?;
+            *position += 1;
+            return Ok(());
+        }
+
+        let is_elided = self.ty_matcher.is_array(ty)
+            || (self.ty_matcher.is_struct(ty)
+                && !self.ty_matcher.is_same_unqualified(
+                    self.lower_expression(Rc::clone(&expression))?.get_ty(),
+                    ty,
+                ));
+
+        if !is_elided {
+            self.place_expression(ty, offset, bit_field, expression, elements)?;
+            *position += 1;
+            return Ok(());
+        }
+
+        let start = *position;
+        self.place_elements(ty, offset, list, position, 0, false, elements)?;
+
+        if *position == start {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ExcessInitializerElements,
+                element.get_span().clone(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn place_expression(
+        &self,
+        ty: TyId,
+        offset: u64,
+        bit_field: Option<BitField>,
+        expression: Rc<rustyc_ast::expressions::Expression>,
+        elements: &mut Vec<InitializerElement>,
+    ) -> rustyc_diagnostics::Result<()> {
+        let expression = self
+            .ty_converter
+            .convert(self.lower_expression(expression)?, ty);
+        elements.push(InitializerElement::new(offset, ty, bit_field, expression));
+
+        Ok(())
+    }
+
+    /// Places a string into a character array, returning the array length.
+    fn place_string(
+        &self,
+        ty: TyId,
+        offset: u64,
+        value: &str,
+        span: &Span,
+        elements: &mut Vec<InitializerElement>,
+    ) -> rustyc_diagnostics::Result<u64> {
+        let Ty::Array(element_ty, length) = *self.ty_context.borrow().get(ty) else {
+            return Ok(0);
+        };
+
+        // Only the terminating null character can be left out of the array.
+        if length.is_some_and(|length| value.len() as u64 > length) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::StringInitializerTooLong,
+                span.clone(),
+            ));
+        }
+
+        elements.extend(Self::new_string_elements(value, offset, element_ty, span));
+
+        Ok(value.len() as u64 + 1)
+    }
+
+    /// The terminating null character is left to the zeroed bytes.
+    pub fn new_string_elements(
+        value: &str,
+        offset: u64,
+        element_ty: TyId,
+        span: &Span,
+    ) -> Vec<InitializerElement> {
+        value
+            .bytes()
+            .enumerate()
+            .map(|(index, byte)| {
+                let expression = Rc::new(rustyc_hir::expressions::Expression::new(
+                    rustyc_hir::expressions::ExpressionKind::Number(
+                        rustyc_hir::expressions::NumberExpression::new(byte.into()),
+                    ),
+                    element_ty,
+                    span.clone(),
+                ));
+
+                InitializerElement::new(offset + index as u64, element_ty, None, expression)
+            })
+            .collect()
+    }
+
+    fn get_string_initializer<'b>(
+        &self,
+        ty: TyId,
+        expression: &'b rustyc_ast::expressions::Expression,
+    ) -> Option<&'b str> {
+        let rustyc_ast::expressions::ExpressionKind::String(string) = expression.get_kind() else {
+            return None;
+        };
+
+        let element_ty = match self.ty_context.borrow().get(ty) {
+            Ty::Array(element_ty, _) => *element_ty,
+            _ => return None,
+        };
+
+        self.ty_matcher
+            .is_character(element_ty)
+            .then_some(string.get_value())
+    }
+
+    /// Returns the type, offset and bit-field of the subobject at `index`.
+    fn get_subobject(
+        &self,
+        ty: TyId,
+        offset: u64,
+        index: u64,
+    ) -> Option<(TyId, u64, Option<BitField>)> {
+        let ty_context = self.ty_context.borrow();
+
+        match ty_context.get(ty) {
+            Ty::Array(element_ty, length) => {
+                if length.is_some_and(|length| index >= length) {
+                    return None;
+                }
+
+                let size = ty_context.get_size(*element_ty)?;
+                Some((*element_ty, offset + index * size, None))
+            }
+            Ty::Struct(struct_id) => {
+                let member = ty_context
+                    .get_struct_layout(*struct_id)?
+                    .get_members()
+                    .get(index as usize)?;
+
+                Some((
+                    member.get_ty(),
+                    offset + member.get_offset(),
+                    member.get_bit_field(),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    fn lower_expression(
+        &self,
+        expression: Rc<rustyc_ast::expressions::Expression>,
+    ) -> rustyc_diagnostics::Result<Rc<rustyc_hir::expressions::Expression>> {
+        let expression_lowerer = ExpressionLowerer::new(
+            expression,
+            self.function.clone(),
+            Rc::clone(&self.resolutions),
+            Rc::clone(&self.function_tys),
+            Rc::clone(&self.local_table),
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
+        expression_lowerer.lower()
+    }
+}
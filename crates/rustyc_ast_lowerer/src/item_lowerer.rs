@@ -1,37 +1,150 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
 
-use rustyc_ty::TyContext;
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{FunctionId, Linkage, Res};
+use rustyc_resolver::Resolutions;
+use rustyc_ty::{TyContext, TyId};
 
-use crate::function_lowerer::FunctionLowerer;
+use crate::{
+    function_lowerer::FunctionLowerer, global_table::GlobalTable,
+    initializer_lowerer::InitializerLowerer, local_table::LocalTable,
+    static_assertion_lowerer::StaticAssertionLowerer, ty_lowerer::TyLowerer,
+};
 
 pub struct ItemLowerer {
     item: Rc<rustyc_ast::items::Item>,
+    resolutions: Rc<Resolutions>,
+    function_tys: Rc<HashMap<FunctionId, TyId>>,
+    internal_functions: Rc<HashSet<FunctionId>>,
+    global_table: Rc<RefCell<GlobalTable>>,
     ty_context: Rc<RefCell<TyContext>>,
 }
 
 impl ItemLowerer {
-    pub fn new(item: Rc<rustyc_ast::items::Item>, ty_context: Rc<RefCell<TyContext>>) -> Self {
-        Self { item, ty_context }
+    pub fn new(
+        item: Rc<rustyc_ast::items::Item>,
+        resolutions: Rc<Resolutions>,
+        function_tys: Rc<HashMap<FunctionId, TyId>>,
+        internal_functions: Rc<HashSet<FunctionId>>,
+        global_table: Rc<RefCell<GlobalTable>>,
+        ty_context: Rc<RefCell<TyContext>>,
+    ) -> Self {
+        Self {
+            item,
+            resolutions,
+            function_tys,
+            internal_functions,
+            global_table,
+            ty_context,
+        }
     }
 
-    pub fn lower(self) -> Rc<rustyc_hir::items::Item> {
+    pub fn lower(self) -> rustyc_diagnostics::Result<Option<Rc<rustyc_hir::items::Item>>> {
         let hir_item_kind = match self.item.get_kind() {
             rustyc_ast::items::ItemKind::Function(function) => {
-                rustyc_hir::items::ItemKind::Function(self.lower_function(Rc::clone(function)))
+                rustyc_hir::items::ItemKind::Function(self.lower_function(Rc::clone(function))?)
+            }
+            rustyc_ast::items::ItemKind::Global(global) => {
+                self.lower_global(global)?;
+                return Ok(None);
+            }
+            rustyc_ast::items::ItemKind::StaticAssertion(static_assertion) => {
+                self.lower_static_assertion(Rc::clone(static_assertion))?;
+                return Ok(None);
+            }
+            rustyc_ast::items::ItemKind::TagDeclaration(ty) => {
+                let ty_lowerer = TyLowerer::new(Rc::clone(ty), Rc::clone(&self.ty_context));
+                ty_lowerer.lower()?;
+                return Ok(None);
             }
         };
 
-        Rc::new(rustyc_hir::items::Item::new(
+        Ok(Some(Rc::new(rustyc_hir::items::Item::new(
             hir_item_kind,
             self.item.get_span().clone(),
-        ))
+        ))))
     }
 
     fn lower_function(
         &self,
         function: Rc<rustyc_ast::items::FunctionItem>,
-    ) -> Rc<rustyc_hir::items::FunctionItem> {
-        let lowerer = FunctionLowerer::new(function, Rc::clone(&self.ty_context));
+    ) -> rustyc_diagnostics::Result<Rc<rustyc_hir::items::FunctionItem>> {
+        let linkage = match self.resolutions.get(function.get_id()) {
+            Some(Res::Function(id)) if self.internal_functions.contains(&id) => Linkage::Internal,
+            _ => Linkage::External,
+        };
+
+        let lowerer = FunctionLowerer::new(
+            function,
+            linkage,
+            Rc::clone(&self.resolutions),
+            Rc::clone(&self.function_tys),
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
         lowerer.lower()
     }
+
+    fn lower_static_assertion(
+        &self,
+        static_assertion: Rc<rustyc_ast::StaticAssertion>,
+    ) -> rustyc_diagnostics::Result<()> {
+        let static_assertion_lowerer = StaticAssertionLowerer::new(
+            static_assertion,
+            self.item.get_span().clone(),
+            None,
+            Rc::clone(&self.resolutions),
+            Rc::clone(&self.function_tys),
+            Rc::new(RefCell::new(LocalTable::new())),
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
+        static_assertion_lowerer.lower()
+    }
+
+    fn lower_global(
+        &self,
+        global: &rustyc_ast::items::GlobalItem,
+    ) -> rustyc_diagnostics::Result<()> {
+        let Some(Res::Global(id)) = self.resolutions.get(global.get_id()) else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::UnresolvedName(global.get_name().to_owned()),
+                self.item.get_span().clone(),
+            ));
+        };
+
+        let Some(initializer) = global.get_initializer() else {
+            return Ok(());
+        };
+
+        let Some(ty) = self.global_table.borrow().get(id).map(|(_, ty, _)| ty) else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::UnresolvedName(global.get_name().to_owned()),
+                self.item.get_span().clone(),
+            ));
+        };
+
+        let initializer_lowerer = InitializerLowerer::new(
+            initializer,
+            ty,
+            self.item.get_span().clone(),
+            None,
+            Rc::clone(&self.resolutions),
+            Rc::clone(&self.function_tys),
+            Rc::new(RefCell::new(LocalTable::new())),
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
+        let (ty, initializer) = initializer_lowerer.lower()?;
+
+        self.global_table
+            .borrow_mut()
+            .set_initializer(id, ty, initializer);
+
+        Ok(())
+    }
 }
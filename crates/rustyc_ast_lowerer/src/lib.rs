@@ -2,7 +2,13 @@ mod ast_lowerer;
 mod block_lowerer;
 mod expression_lowerer;
 mod function_lowerer;
+mod global_table;
+mod initializer_lowerer;
 mod item_lowerer;
+mod local_table;
 mod statement_lowerer;
+mod static_assertion_lowerer;
+mod ty_converter;
+mod ty_lowerer;
 
 pub use ast_lowerer::AstLowerer;
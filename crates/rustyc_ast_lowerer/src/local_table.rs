@@ -0,0 +1,31 @@
+use std::collections::HashMap;
+
+use rustyc_hir::{Local, LocalId};
+
+/// Collects the local bindings of a function while it is being lowered.
+#[derive(Default)]
+pub struct LocalTable {
+    locals: HashMap<LocalId, Local>,
+}
+
+impl LocalTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, local: Local) {
+        self.locals.insert(local.get_id(), local);
+    }
+
+    pub fn get(&self, id: LocalId) -> Option<&Local> {
+        self.locals.get(&id)
+    }
+
+    pub fn take_locals(&mut self) -> Vec<Local> {
+        let mut locals = std::mem::take(&mut self.locals)
+            .into_values()
+            .collect::<Vec<_>>();
+        locals.sort_by_key(|local| local.get_id().get());
+        locals
+    }
+}
@@ -1,130 +1,496 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use rustyc_ty::TyContext;
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{FunctionId, Linkage, Local, Res};
+use rustyc_resolver::Resolutions;
+use rustyc_ty::{TyContext, TyId};
 
-use crate::{block_lowerer::BlockLowerer, expression_lowerer::ExpressionLowerer};
+use crate::{
+    block_lowerer::BlockLowerer, expression_lowerer::ExpressionLowerer, global_table::GlobalTable,
+    initializer_lowerer::InitializerLowerer, local_table::LocalTable,
+    static_assertion_lowerer::StaticAssertionLowerer, ty_converter::TyConverter,
+    ty_lowerer::TyLowerer,
+};
 
 pub struct StatementLowerer {
     statement: Rc<rustyc_ast::statements::Statement>,
+    function: Rc<rustyc_ast::items::FunctionItem>,
+    resolutions: Rc<Resolutions>,
+    function_tys: Rc<HashMap<FunctionId, TyId>>,
+    local_table: Rc<RefCell<LocalTable>>,
+    global_table: Rc<RefCell<GlobalTable>>,
     ty_context: Rc<RefCell<TyContext>>,
 }
 
 impl StatementLowerer {
     pub fn new(
         statement: Rc<rustyc_ast::statements::Statement>,
+        function: Rc<rustyc_ast::items::FunctionItem>,
+        resolutions: Rc<Resolutions>,
+        function_tys: Rc<HashMap<FunctionId, TyId>>,
+        local_table: Rc<RefCell<LocalTable>>,
+        global_table: Rc<RefCell<GlobalTable>>,
         ty_context: Rc<RefCell<TyContext>>,
     ) -> Self {
         Self {
             statement,
+            function,
+            resolutions,
+            function_tys,
+            local_table,
+            global_table,
             ty_context,
         }
     }
 
-    pub fn lower(self) -> Rc<rustyc_hir::statements::Statement> {
+    pub fn lower(self) -> rustyc_diagnostics::Result<Rc<rustyc_hir::statements::Statement>> {
         let hir_statement_kind = match self.statement.get_kind() {
             rustyc_ast::statements::StatementKind::Return(statement) => {
                 rustyc_hir::statements::StatementKind::Return(
-                    self.lower_return_statement(statement),
+                    self.lower_return_statement(statement)?,
                 )
             }
             rustyc_ast::statements::StatementKind::If(statement) => {
-                rustyc_hir::statements::StatementKind::If(self.lower_if_statement(statement))
+                rustyc_hir::statements::StatementKind::If(self.lower_if_statement(statement)?)
             }
             rustyc_ast::statements::StatementKind::Loop(statement) => {
-                rustyc_hir::statements::StatementKind::Loop(self.lower_loop_statement(statement))
+                rustyc_hir::statements::StatementKind::Loop(self.lower_loop_statement(statement)?)
+            }
+            rustyc_ast::statements::StatementKind::DoWhile(statement) => {
+                rustyc_hir::statements::StatementKind::DoWhile(
+                    self.lower_do_while_statement(statement)?,
+                )
+            }
+            rustyc_ast::statements::StatementKind::Switch(statement) => {
+                rustyc_hir::statements::StatementKind::Switch(
+                    self.lower_switch_statement(statement)?,
+                )
+            }
+            rustyc_ast::statements::StatementKind::Case(statement) => {
+                rustyc_hir::statements::StatementKind::Case(self.lower_case_statement(statement)?)
+            }
+            rustyc_ast::statements::StatementKind::Default(statement) => {
+                rustyc_hir::statements::StatementKind::Default(
+                    self.lower_default_statement(statement)?,
+                )
+            }
+            rustyc_ast::statements::StatementKind::Labeled(statement) => {
+                rustyc_hir::statements::StatementKind::Labeled(
+                    self.lower_labeled_statement(statement)?,
+                )
+            }
+            rustyc_ast::statements::StatementKind::Goto(statement) => {
+                rustyc_hir::statements::StatementKind::Goto(
+                    rustyc_hir::statements::GotoStatement::new(statement.get_label().to_owned()),
+                )
+            }
+            rustyc_ast::statements::StatementKind::Break => {
+                rustyc_hir::statements::StatementKind::Break
+            }
+            rustyc_ast::statements::StatementKind::Continue => {
+                rustyc_hir::statements::StatementKind::Continue
             }
             rustyc_ast::statements::StatementKind::Compound(statement) => {
                 rustyc_hir::statements::StatementKind::Compound(
-                    self.lower_compound_statement(statement),
+                    self.lower_compound_statement(statement)?,
+                )
+            }
+            rustyc_ast::statements::StatementKind::Declaration(statement) => {
+                rustyc_hir::statements::StatementKind::Declaration(
+                    self.lower_declaration_statement(statement)?,
                 )
             }
             rustyc_ast::statements::StatementKind::Expression(expression) => {
                 rustyc_hir::statements::StatementKind::Expression(
-                    self.lower_expression_statement(expression),
+                    self.lower_expression_statement(expression)?,
+                )
+            }
+            // Structures are laid out where they are defined, so that their
+            // bit-fields are checked even if they are never used.
+            rustyc_ast::statements::StatementKind::TagDeclaration(ty) => {
+                self.lower_ty(Rc::clone(ty))?;
+
+                rustyc_hir::statements::StatementKind::Declaration(
+                    rustyc_hir::statements::DeclarationStatement::new(Vec::new()),
+                )
+            }
+            rustyc_ast::statements::StatementKind::StaticAssertion(static_assertion) => {
+                self.lower_static_assertion(Rc::clone(static_assertion))?;
+
+                rustyc_hir::statements::StatementKind::Declaration(
+                    rustyc_hir::statements::DeclarationStatement::new(Vec::new()),
                 )
             }
         };
 
-        Rc::new(rustyc_hir::statements::Statement::new(
+        Ok(Rc::new(rustyc_hir::statements::Statement::new(
             hir_statement_kind,
             self.statement.get_span().clone(),
-        ))
+        )))
     }
 
     fn lower_return_statement(
         &self,
         statement: &rustyc_ast::statements::ReturnStatement,
-    ) -> rustyc_hir::statements::ReturnStatement {
-        rustyc_hir::statements::ReturnStatement::new(
-            self.lower_expression(statement.get_expression()),
-        )
+    ) -> rustyc_diagnostics::Result<rustyc_hir::statements::ReturnStatement> {
+        let ty_converter = TyConverter::new(Rc::clone(&self.ty_context));
+        let return_ty = self.lower_ty(self.function.get_return_ty())?;
+
+        Ok(rustyc_hir::statements::ReturnStatement::new(
+            statement
+                .get_expression()
+                .map(|expression| {
+                    Ok(ty_converter.convert(self.lower_expression(expression)?, return_ty))
+                })
+                .transpose()?,
+        ))
     }
 
     fn lower_if_statement(
         &self,
         statement: &rustyc_ast::statements::IfStatement,
-    ) -> rustyc_hir::statements::IfStatement {
-        rustyc_hir::statements::IfStatement::new(
-            self.lower_expression(statement.get_condition_expression()),
-            self.lower_statement(statement.get_then_statement()),
+    ) -> rustyc_diagnostics::Result<rustyc_hir::statements::IfStatement> {
+        Ok(rustyc_hir::statements::IfStatement::new(
+            self.lower_expression(statement.get_condition_expression())?,
+            self.lower_statement(statement.get_then_statement())?,
             statement
                 .get_else_statement()
-                .map(|value| self.lower_statement(value)),
-        )
+                .map(|value| self.lower_statement(value))
+                .transpose()?,
+        ))
     }
 
     fn lower_loop_statement(
         &self,
         statement: &rustyc_ast::statements::LoopStatement,
-    ) -> rustyc_hir::statements::LoopStatement {
-        rustyc_hir::statements::LoopStatement::new(
+    ) -> rustyc_diagnostics::Result<rustyc_hir::statements::LoopStatement> {
+        Ok(rustyc_hir::statements::LoopStatement::new(
             statement
                 .get_initialization_statement()
-                .map(|value| self.lower_statement(value)),
+                .map(|value| self.lower_statement(value))
+                .transpose()?,
             statement
                 .get_condition_expression()
-                .map(|value| self.lower_expression(value)),
+                .map(|value| self.lower_expression(value))
+                .transpose()?,
             statement
                 .get_incrementation_expression()
-                .map(|value| self.lower_expression(value)),
-            self.lower_statement(statement.get_then_statement()),
-        )
+                .map(|value| self.lower_expression(value))
+                .transpose()?,
+            self.lower_statement(statement.get_then_statement())?,
+        ))
+    }
+
+    fn lower_do_while_statement(
+        &self,
+        statement: &rustyc_ast::statements::DoWhileStatement,
+    ) -> rustyc_diagnostics::Result<rustyc_hir::statements::DoWhileStatement> {
+        Ok(rustyc_hir::statements::DoWhileStatement::new(
+            self.lower_statement(statement.get_then_statement())?,
+            self.lower_expression(statement.get_condition_expression())?,
+        ))
+    }
+
+    fn lower_switch_statement(
+        &self,
+        statement: &rustyc_ast::statements::SwitchStatement,
+    ) -> rustyc_diagnostics::Result<rustyc_hir::statements::SwitchStatement> {
+        Ok(rustyc_hir::statements::SwitchStatement::new(
+            self.lower_expression(statement.get_condition_expression())?,
+            self.lower_statement(statement.get_then_statement())?,
+        ))
+    }
+
+    fn lower_case_statement(
+        &self,
+        statement: &rustyc_ast::statements::CaseStatement,
+    ) -> rustyc_diagnostics::Result<rustyc_hir::statements::CaseStatement> {
+        Ok(rustyc_hir::statements::CaseStatement::new(
+            self.lower_expression(statement.get_expression())?,
+            self.lower_statement(statement.get_statement())?,
+        ))
+    }
+
+    fn lower_default_statement(
+        &self,
+        statement: &rustyc_ast::statements::DefaultStatement,
+    ) -> rustyc_diagnostics::Result<rustyc_hir::statements::DefaultStatement> {
+        Ok(rustyc_hir::statements::DefaultStatement::new(
+            self.lower_statement(statement.get_statement())?,
+        ))
+    }
+
+    fn lower_labeled_statement(
+        &self,
+        statement: &rustyc_ast::statements::LabeledStatement,
+    ) -> rustyc_diagnostics::Result<rustyc_hir::statements::LabeledStatement> {
+        Ok(rustyc_hir::statements::LabeledStatement::new(
+            statement.get_label().to_owned(),
+            self.lower_statement(statement.get_statement())?,
+        ))
     }
 
     fn lower_compound_statement(
         &self,
         statement: &rustyc_ast::statements::CompoundStatement,
-    ) -> rustyc_hir::statements::CompoundStatement {
-        rustyc_hir::statements::CompoundStatement::new(self.lower_block(statement.get_block()))
+    ) -> rustyc_diagnostics::Result<rustyc_hir::statements::CompoundStatement> {
+        Ok(rustyc_hir::statements::CompoundStatement::new(
+            self.lower_block(statement.get_block())?,
+        ))
+    }
+
+    fn lower_declaration_statement(
+        &self,
+        statement: &rustyc_ast::statements::DeclarationStatement,
+    ) -> rustyc_diagnostics::Result<rustyc_hir::statements::DeclarationStatement> {
+        let mut declarators = Vec::new();
+
+        // Only automatic variables are initialized when the declaration is
+        // reached, the other ones end up in the global table.
+        for declarator in statement.get_declarators().iter() {
+            match declarator.get_storage_class() {
+                Some(rustyc_ast::StorageClass::Static) => {
+                    self.lower_static_declarator(declarator)?
+                }
+                Some(rustyc_ast::StorageClass::Extern) => {
+                    self.lower_extern_declarator(declarator)?
+                }
+                None => declarators.push(self.lower_declarator(declarator)?),
+            }
+        }
+
+        Ok(rustyc_hir::statements::DeclarationStatement::new(
+            declarators,
+        ))
+    }
+
+    fn lower_static_declarator(
+        &self,
+        declarator: &rustyc_ast::statements::Declarator,
+    ) -> rustyc_diagnostics::Result<()> {
+        let Some(Res::Global(global_id)) = self.resolutions.get(declarator.get_id()) else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::UnresolvedName(declarator.get_name().to_owned()),
+                declarator.get_span().clone(),
+            ));
+        };
+
+        let ty = self.lower_ty(declarator.get_ty())?;
+
+        let mut global_table = self.global_table.borrow_mut();
+        let name = global_table
+            .allocate_static_local_name(self.function.get_name(), declarator.get_name());
+        global_table.declare(
+            global_id,
+            name,
+            ty,
+            Linkage::Internal,
+            declarator.get_span().clone(),
+        )?;
+        global_table.define(global_id);
+        drop(global_table);
+
+        if let Some(initializer) = declarator.get_initializer() {
+            let (ty, initializer) = self.lower_initializer(initializer, ty, declarator)?;
+
+            self.global_table
+                .borrow_mut()
+                .set_initializer(global_id, ty, initializer);
+        }
+
+        Ok(())
+    }
+
+    fn lower_extern_declarator(
+        &self,
+        declarator: &rustyc_ast::statements::Declarator,
+    ) -> rustyc_diagnostics::Result<()> {
+        let Some(Res::Global(global_id)) = self.resolutions.get(declarator.get_id()) else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::UnresolvedName(declarator.get_name().to_owned()),
+                declarator.get_span().clone(),
+            ));
+        };
+
+        // The variable may not be declared at file scope, in which case it is
+        // defined by another translation unit.
+        self.global_table.borrow_mut().declare(
+            global_id,
+            declarator.get_name().to_owned(),
+            self.lower_ty(declarator.get_ty())?,
+            Linkage::External,
+            declarator.get_span().clone(),
+        )?;
+
+        Ok(())
+    }
+
+    fn lower_declarator(
+        &self,
+        declarator: &rustyc_ast::statements::Declarator,
+    ) -> rustyc_diagnostics::Result<rustyc_hir::statements::Declarator> {
+        let Some(Res::Local(local_id)) = self.resolutions.get(declarator.get_id()) else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::UnresolvedName(declarator.get_name().to_owned()),
+                declarator.get_span().clone(),
+            ));
+        };
+
+        let (ty, length) = self.lower_automatic_ty(declarator.get_ty())?;
+
+        // The local is registered before its initializer is lowered, since the
+        // binding is already visible inside of it.
+        self.local_table.borrow_mut().insert(Local::new(
+            local_id,
+            declarator.get_name().to_owned(),
+            ty,
+            declarator.get_span().clone(),
+        ));
+
+        let Some(initializer) = declarator.get_initializer() else {
+            return Ok(rustyc_hir::statements::Declarator::new(
+                local_id, length, None,
+            ));
+        };
+
+        let (initialized_ty, initializer) = self.lower_initializer(initializer, ty, declarator)?;
+
+        // An array of unknown length is completed by its initializer.
+        if initialized_ty != ty {
+            self.local_table.borrow_mut().insert(Local::new(
+                local_id,
+                declarator.get_name().to_owned(),
+                initialized_ty,
+                declarator.get_span().clone(),
+            ));
+        }
+
+        Ok(rustyc_hir::statements::Declarator::new(
+            local_id,
+            length,
+            Some(initializer),
+        ))
+    }
+
+    fn lower_initializer(
+        &self,
+        initializer: Rc<rustyc_ast::initializers::Initializer>,
+        ty: TyId,
+        declarator: &rustyc_ast::statements::Declarator,
+    ) -> rustyc_diagnostics::Result<(TyId, Rc<rustyc_hir::initializers::Initializer>)> {
+        let initializer_lowerer = InitializerLowerer::new(
+            initializer,
+            ty,
+            declarator.get_span().clone(),
+            Some(Rc::clone(&self.function)),
+            Rc::clone(&self.resolutions),
+            Rc::clone(&self.function_tys),
+            Rc::clone(&self.local_table),
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
+        initializer_lowerer.lower()
     }
 
     fn lower_expression_statement(
         &self,
         statement: &rustyc_ast::statements::ExpressionStatement,
-    ) -> rustyc_hir::statements::ExpressionStatement {
-        rustyc_hir::statements::ExpressionStatement::new(
-            self.lower_expression(statement.get_expression()),
-        )
+    ) -> rustyc_diagnostics::Result<rustyc_hir::statements::ExpressionStatement> {
+        Ok(rustyc_hir::statements::ExpressionStatement::new(
+            self.lower_expression(statement.get_expression())?,
+        ))
+    }
+
+    fn lower_static_assertion(
+        &self,
+        static_assertion: Rc<rustyc_ast::StaticAssertion>,
+    ) -> rustyc_diagnostics::Result<()> {
+        let static_assertion_lowerer = StaticAssertionLowerer::new(
+            static_assertion,
+            self.statement.get_span().clone(),
+            Some(Rc::clone(&self.function)),
+            Rc::clone(&self.resolutions),
+            Rc::clone(&self.function_tys),
+            Rc::clone(&self.local_table),
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
+        static_assertion_lowerer.lower()
     }
 
     fn lower_statement(
         &self,
         statement: Rc<rustyc_ast::statements::Statement>,
-    ) -> Rc<rustyc_hir::statements::Statement> {
-        let statement_lowerer = Self::new(statement, Rc::clone(&self.ty_context));
+    ) -> rustyc_diagnostics::Result<Rc<rustyc_hir::statements::Statement>> {
+        let statement_lowerer = Self::new(
+            statement,
+            Rc::clone(&self.function),
+            Rc::clone(&self.resolutions),
+            Rc::clone(&self.function_tys),
+            Rc::clone(&self.local_table),
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
         statement_lowerer.lower()
     }
 
     fn lower_expression(
         &self,
         expression: Rc<rustyc_ast::expressions::Expression>,
-    ) -> Rc<rustyc_hir::expressions::Expression> {
-        let expression_lowerer = ExpressionLowerer::new(expression, Rc::clone(&self.ty_context));
+    ) -> rustyc_diagnostics::Result<Rc<rustyc_hir::expressions::Expression>> {
+        let expression_lowerer = ExpressionLowerer::new(
+            expression,
+            Some(Rc::clone(&self.function)),
+            Rc::clone(&self.resolutions),
+            Rc::clone(&self.function_tys),
+            Rc::clone(&self.local_table),
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
         expression_lowerer.lower()
     }
 
-    fn lower_block(&self, block: Rc<rustyc_ast::Block>) -> Rc<rustyc_hir::Block> {
-        let block_lowerer = BlockLowerer::new(block, Rc::clone(&self.ty_context));
+    fn lower_block(
+        &self,
+        block: Rc<rustyc_ast::Block>,
+    ) -> rustyc_diagnostics::Result<Rc<rustyc_hir::Block>> {
+        let block_lowerer = BlockLowerer::new(
+            block,
+            Rc::clone(&self.function),
+            Rc::clone(&self.resolutions),
+            Rc::clone(&self.function_tys),
+            Rc::clone(&self.local_table),
+            Rc::clone(&self.global_table),
+            Rc::clone(&self.ty_context),
+        );
         block_lowerer.lower()
     }
+
+    fn lower_ty(&self, ty: Rc<rustyc_ast::types::Ty>) -> rustyc_diagnostics::Result<TyId> {
+        let ty_lowerer = TyLowerer::new(ty, Rc::clone(&self.ty_context));
+        ty_lowerer.lower()
+    }
+
+    fn lower_automatic_ty(
+        &self,
+        ty: Rc<rustyc_ast::types::Ty>,
+    ) -> rustyc_diagnostics::Result<(TyId, Option<Rc<rustyc_hir::expressions::Expression>>)> {
+        let length = match ty.get_kind() {
+            rustyc_ast::types::TyKind::Array(array_ty) => array_ty.get_length(),
+            _ => None,
+        };
+
+        let Some(length) = length else {
+            return Ok((self.lower_ty(ty)?, None));
+        };
+
+        let ty_converter = TyConverter::new(Rc::clone(&self.ty_context));
+        let length = ty_converter.convert_size(self.lower_expression(length)?);
+        let ty_lowerer = TyLowerer::new(ty, Rc::clone(&self.ty_context));
+
+        match TyLowerer::evaluate_length(Rc::clone(&length), Rc::clone(&self.ty_context))? {
+            Some(length) => Ok((ty_lowerer.lower_array(Some(length))?, None)),
+            None => Ok((ty_lowerer.lower_array(None)?, Some(length))),
+        }
+    }
 }
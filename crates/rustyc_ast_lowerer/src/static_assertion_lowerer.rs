@@ -0,0 +1,74 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{ConstantEvaluator, FunctionId};
+use rustyc_resolver::Resolutions;
+use rustyc_span::Span;
+use rustyc_ty::{TyContext, TyId};
+
+use crate::{
+    expression_lowerer::ExpressionLowerer, global_table::GlobalTable, local_table::LocalTable,
+};
+
+/// Evaluates a static assertion, which leaves nothing behind in the HIR.
+pub struct StaticAssertionLowerer {
+    static_assertion: Rc<rustyc_ast::StaticAssertion>,
+    span: Span,
+    function: Option<Rc<rustyc_ast::items::FunctionItem>>,
+    resolutions: Rc<Resolutions>,
+    function_tys: Rc<HashMap<FunctionId, TyId>>,
+    local_table: Rc<RefCell<LocalTable>>,
+    global_table: Rc<RefCell<GlobalTable>>,
+    ty_context: Rc<RefCell<TyContext>>,
+}
+
+impl StaticAssertionLowerer {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        static_assertion: Rc<rustyc_ast::StaticAssertion>,
+        span: Span,
+        function: Option<Rc<rustyc_ast::items::FunctionItem>>,
+        resolutions: Rc<Resolutions>,
+        function_tys: Rc<HashMap<FunctionId, TyId>>,
+        local_table: Rc<RefCell<LocalTable>>,
+        global_table: Rc<RefCell<GlobalTable>>,
+        ty_context: Rc<RefCell<TyContext>>,
+    ) -> Self {
+        Self {
+            static_assertion,
+            span,
+            function,
+            resolutions,
+            function_tys,
+            local_table,
+            global_table,
+            ty_context,
+        }
+    }
+
+    pub fn lower(self) -> rustyc_diagnostics::Result<()> {
+        let expression_lowerer = ExpressionLowerer::new(
+            self.static_assertion.get_condition(),
+            self.function,
+            self.resolutions,
+            self.function_tys,
+            self.local_table,
+            self.global_table,
+            Rc::clone(&self.ty_context),
+        );
+        let condition = expression_lowerer.lower()?;
+
+        let constant_evaluator = ConstantEvaluator::new(condition, self.ty_context);
+
+        if constant_evaluator.evaluate_integer()? == 0 {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::StaticAssertionFailed(
+                    self.static_assertion.get_message().to_owned(),
+                ),
+                self.span,
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rustyc_hir::expressions::{CastExpression, Expression, ExpressionKind};
+use rustyc_ty::{Ty, TyContext, TyId, TyMatcher};
+
+pub struct TyConverter {
+    ty_matcher: TyMatcher,
+    ty_context: Rc<RefCell<TyContext>>,
+}
+
+impl TyConverter {
+    pub fn new(ty_context: Rc<RefCell<TyContext>>) -> Self {
+        Self {
+            ty_matcher: TyMatcher::new(Rc::clone(&ty_context)),
+            ty_context,
+        }
+    }
+
+    /// Converts an arithmetic expression, or a pointer to `_Bool`; others are returned as is.
+    pub fn convert(&self, expression: Rc<Expression>, ty: TyId) -> Rc<Expression> {
+        let expression_ty = expression.get_ty();
+
+        let is_convertible = if self.ty_matcher.is_bool(ty) {
+            self.ty_matcher.is_scalar(expression_ty)
+        } else {
+            self.ty_matcher.is_arithmetic(expression_ty) && self.ty_matcher.is_arithmetic(ty)
+        };
+
+        if self.ty_matcher.is_same_unqualified(expression_ty, ty) || !is_convertible {
+            return expression;
+        }
+
+        let span = expression.get_span().clone();
+
+        Rc::new(Expression::new(
+            ExpressionKind::Cast(CastExpression::new(expression)),
+            ty,
+            span,
+        ))
+    }
+
+    pub fn decay(&self, expression: Rc<Expression>) -> Rc<Expression> {
+        let element_ty = match self.ty_context.borrow().get(expression.get_ty()) {
+            Ty::Array(element_ty, _) | Ty::VariableArray(element_ty) => *element_ty,
+            _ => return expression,
+        };
+
+        let ty = self
+            .ty_context
+            .borrow_mut()
+            .register(Ty::Pointer(element_ty));
+        let span = expression.get_span().clone();
+
+        Rc::new(Expression::new(
+            ExpressionKind::Cast(CastExpression::new(expression)),
+            ty,
+            span,
+        ))
+    }
+
+    pub fn convert_size(&self, expression: Rc<Expression>) -> Rc<Expression> {
+        if !self.ty_matcher.is_integer(expression.get_ty()) {
+            return expression;
+        }
+
+        let size_ty = self.ty_context.borrow_mut().register(Ty::UnsignedInt);
+        self.convert(expression, size_ty)
+    }
+
+    /// Applies the usual arithmetic conversions to the operands of a binary expression.
+    pub fn get_common_ty(&self, left: TyId, right: TyId) -> Option<TyId> {
+        if !self.ty_matcher.is_arithmetic(left) || !self.ty_matcher.is_arithmetic(right) {
+            return None;
+        }
+
+        let ty_context = self.ty_context.borrow();
+
+        let common_ty = match (ty_context.get(left), ty_context.get(right)) {
+            (Ty::Double, _) | (_, Ty::Double) => Ty::Double,
+            (Ty::Float, _) | (_, Ty::Float) => Ty::Float,
+            (Ty::UnsignedInt, _) | (_, Ty::UnsignedInt) => Ty::UnsignedInt,
+            _ => Ty::Int,
+        };
+
+        drop(ty_context);
+
+        Some(self.ty_context.borrow_mut().register(common_ty))
+    }
+
+    /// Applies the integer promotions.
+    pub fn promote(&self, expression: Rc<Expression>) -> Rc<Expression> {
+        let ty = expression.get_ty();
+
+        if !self.ty_matcher.is_bool(ty) && !self.ty_matcher.is_character(ty) {
+            return expression;
+        }
+
+        let int_ty = self.ty_context.borrow_mut().register(Ty::Int);
+        self.convert(expression, int_ty)
+    }
+
+    /// Applies the default argument promotions to variadic arguments.
+    pub fn promote_argument(&self, expression: Rc<Expression>) -> Rc<Expression> {
+        if !matches!(self.ty_context.borrow().get(expression.get_ty()), Ty::Float) {
+            return self.promote(expression);
+        }
+
+        let double_ty = self.ty_context.borrow_mut().register(Ty::Double);
+        self.convert(expression, double_ty)
+    }
+}
@@ -0,0 +1,312 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::ConstantEvaluator;
+use rustyc_resolver::Resolutions;
+use rustyc_ty::{
+    FunctionTy, Qualifiers, StructId, StructLayout, StructMemberDeclaration, Ty, TyContext, TyId,
+    TyMatcher,
+};
+
+use crate::{
+    expression_lowerer::ExpressionLowerer, global_table::GlobalTable, local_table::LocalTable,
+};
+
+pub struct TyLowerer {
+    ty: Rc<rustyc_ast::types::Ty>,
+    ty_matcher: TyMatcher,
+    ty_context: Rc<RefCell<TyContext>>,
+}
+
+impl TyLowerer {
+    pub fn new(ty: Rc<rustyc_ast::types::Ty>, ty_context: Rc<RefCell<TyContext>>) -> Self {
+        Self {
+            ty,
+            ty_matcher: TyMatcher::new(Rc::clone(&ty_context)),
+            ty_context,
+        }
+    }
+
+    pub fn lower(self) -> rustyc_diagnostics::Result<TyId> {
+        let ty = match self.ty.get_kind() {
+            rustyc_ast::types::TyKind::Void => Ty::Void,
+            rustyc_ast::types::TyKind::Bool => Ty::Bool,
+            rustyc_ast::types::TyKind::Char => Ty::Char,
+            rustyc_ast::types::TyKind::UnsignedChar => Ty::UnsignedChar,
+            rustyc_ast::types::TyKind::Int => Ty::Int,
+            rustyc_ast::types::TyKind::UnsignedInt => Ty::UnsignedInt,
+            rustyc_ast::types::TyKind::Float => Ty::Float,
+            rustyc_ast::types::TyKind::Double => Ty::Double,
+            rustyc_ast::types::TyKind::Pointer(base) => {
+                Ty::Pointer(self.lower_ty(Rc::clone(base))?)
+            }
+            rustyc_ast::types::TyKind::Array(array_ty) => Ty::Array(
+                self.lower_element_ty(array_ty)?,
+                array_ty
+                    .get_length()
+                    .map(|length| self.lower_constant_length(length))
+                    .transpose()?,
+            ),
+            rustyc_ast::types::TyKind::Function(function_ty) => Ty::Function(FunctionTy::new(
+                self.lower_ty(function_ty.get_return_ty())?,
+                function_ty
+                    .get_parameters()
+                    .map(|parameters| {
+                        parameters
+                            .iter()
+                            .map(|parameter| self.lower_parameter_ty(Rc::clone(parameter)))
+                            .collect::<rustyc_diagnostics::Result<_>>()
+                    })
+                    .transpose()?,
+                function_ty.is_variadic(),
+            )),
+            rustyc_ast::types::TyKind::Struct(struct_ty) => {
+                Ty::Struct(self.lower_struct(struct_ty)?)
+            }
+            rustyc_ast::types::TyKind::VaList => Ty::VaList,
+        };
+
+        let qualifiers = self.ty.get_qualifiers();
+
+        Ok(self.ty_context.borrow_mut().register_qualified(
+            ty,
+            Qualifiers::new(
+                qualifiers.is_const(),
+                qualifiers.is_volatile(),
+                qualifiers.is_restrict(),
+            ),
+        ))
+    }
+
+    pub fn lower_parameter(self) -> rustyc_diagnostics::Result<TyId> {
+        let rustyc_ast::types::TyKind::Array(array_ty) = self.ty.get_kind() else {
+            return self.lower();
+        };
+
+        let element_ty = self.lower_element_ty(array_ty)?;
+
+        Ok(self
+            .ty_context
+            .borrow_mut()
+            .register(Ty::Pointer(element_ty)))
+    }
+
+    pub fn lower_array(self, length: Option<u64>) -> rustyc_diagnostics::Result<TyId> {
+        let rustyc_ast::types::TyKind::Array(array_ty) = self.ty.get_kind() else {
+            return self.lower();
+        };
+
+        let element_ty = self.lower_element_ty(array_ty)?;
+
+        let ty = match length {
+            Some(length) => Ty::Array(element_ty, Some(length)),
+            None => Ty::VariableArray(element_ty),
+        };
+
+        Ok(self.ty_context.borrow_mut().register(ty))
+    }
+
+    pub fn evaluate_length(
+        length: Rc<rustyc_hir::expressions::Expression>,
+        ty_context: Rc<RefCell<TyContext>>,
+    ) -> rustyc_diagnostics::Result<Option<u64>> {
+        let span = length.get_span().clone();
+        let is_signed = TyMatcher::new(Rc::clone(&ty_context)).is_signed_integer(length.get_ty());
+
+        let constant_evaluator = ConstantEvaluator::new(length, ty_context);
+        let Ok(length) = constant_evaluator.evaluate_integer() else {
+            return Ok(None);
+        };
+
+        if length < 0 {
+            let error = if is_signed {
+                rustyc_diagnostics::Error::NegativeArrayLength
+            } else {
+                rustyc_diagnostics::Error::ArrayTooLarge
+            };
+
+            return Err(Diagnostic::new_error(error, span));
+        }
+
+        Ok(Some(length as u64))
+    }
+
+    fn lower_element_ty(
+        &self,
+        array_ty: &rustyc_ast::types::ArrayTy,
+    ) -> rustyc_diagnostics::Result<TyId> {
+        let element_ty = array_ty.get_element_ty();
+        let span = element_ty.get_span().clone();
+
+        let element_ty = self.lower_ty(element_ty)?;
+
+        if self.ty_context.borrow().get_size(element_ty).is_none() {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::IncompleteElementType,
+                span,
+            ));
+        }
+
+        Ok(element_ty)
+    }
+
+    fn lower_constant_length(
+        &self,
+        length: Rc<rustyc_ast::expressions::Expression>,
+    ) -> rustyc_diagnostics::Result<u64> {
+        let span = length.get_span().clone();
+        let non_constant_length = || {
+            Diagnostic::new_error(
+                rustyc_diagnostics::Error::NonConstantArrayLength,
+                span.clone(),
+            )
+        };
+
+        let expression_lowerer = ExpressionLowerer::new(
+            length,
+            None,
+            Rc::new(Resolutions::new()),
+            Rc::new(HashMap::new()),
+            Rc::new(RefCell::new(LocalTable::new())),
+            Rc::new(RefCell::new(GlobalTable::new(Rc::clone(&self.ty_context)))),
+            Rc::clone(&self.ty_context),
+        );
+        let length = expression_lowerer
+            .lower()
+            .map_err(|_| non_constant_length())?;
+
+        Self::evaluate_length(length, Rc::clone(&self.ty_context))?.ok_or_else(non_constant_length)
+    }
+
+    fn lower_struct(
+        &self,
+        struct_ty: &rustyc_ast::types::StructTy,
+    ) -> rustyc_diagnostics::Result<StructId> {
+        let id = StructId::new(struct_ty.get_id().get());
+
+        if self.ty_context.borrow().is_struct_declared(id) {
+            return Ok(id);
+        }
+
+        self.ty_context.borrow_mut().declare_struct(id);
+
+        let Some(members) = struct_ty.get_members() else {
+            return Ok(id);
+        };
+
+        let mut declarations: Vec<StructMemberDeclaration> = Vec::new();
+        let mut names: HashSet<&str> = HashSet::new();
+
+        for member in members.iter() {
+            let ty = self.lower_ty(member.get_ty())?;
+
+            if let Some(name) = member.get_name() {
+                if !names.insert(name) {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::DuplicateMember(name.to_owned()),
+                        member.get_span().clone(),
+                    ));
+                }
+            }
+
+            let ty_context = self.ty_context.borrow();
+            let (Some(size), Some(alignment)) =
+                (ty_context.get_size(ty), ty_context.get_alignment(ty))
+            else {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::IncompleteMemberType,
+                    member.get_span().clone(),
+                ));
+            };
+            drop(ty_context);
+
+            let width = member
+                .get_width()
+                .map(|width| self.lower_bit_field_width(member, ty, width))
+                .transpose()?;
+
+            declarations.push(StructMemberDeclaration::new(
+                member.get_name().map(str::to_owned),
+                ty,
+                size,
+                alignment,
+                width,
+            ));
+        }
+
+        self.ty_context
+            .borrow_mut()
+            .define_struct(id, StructLayout::new(declarations));
+
+        Ok(id)
+    }
+
+    fn lower_bit_field_width(
+        &self,
+        member: &rustyc_ast::types::StructMember,
+        ty: TyId,
+        width: Rc<rustyc_ast::expressions::Expression>,
+    ) -> rustyc_diagnostics::Result<u32> {
+        let Some(ty_width) = self.ty_matcher.get_integer_width(ty) else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::InvalidBitFieldType,
+                member.get_span().clone(),
+            ));
+        };
+
+        let span = width.get_span().clone();
+
+        let expression_lowerer = ExpressionLowerer::new(
+            width,
+            None,
+            Rc::new(Resolutions::new()),
+            Rc::new(HashMap::new()),
+            Rc::new(RefCell::new(LocalTable::new())),
+            Rc::new(RefCell::new(GlobalTable::new(Rc::clone(&self.ty_context)))),
+            Rc::clone(&self.ty_context),
+        );
+        let constant_evaluator =
+            ConstantEvaluator::new(expression_lowerer.lower()?, Rc::clone(&self.ty_context));
+        let width = constant_evaluator.evaluate_integer()?;
+
+        if width < 0 {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::NegativeBitFieldWidth,
+                span,
+            ));
+        }
+
+        if width > i64::from(ty_width) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::BitFieldWidthTooLarge,
+                span,
+            ));
+        }
+
+        if width == 0 && member.get_name().is_some() {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ZeroWidthNamedBitField,
+                span,
+            ));
+        }
+
+        Ok(width as u32)
+    }
+
+    fn lower_ty(&self, ty: Rc<rustyc_ast::types::Ty>) -> rustyc_diagnostics::Result<TyId> {
+        let ty_lowerer = Self::new(ty, Rc::clone(&self.ty_context));
+        ty_lowerer.lower()
+    }
+
+    fn lower_parameter_ty(
+        &self,
+        ty: Rc<rustyc_ast::types::Ty>,
+    ) -> rustyc_diagnostics::Result<TyId> {
+        let ty_lowerer = Self::new(ty, Rc::clone(&self.ty_context));
+        ty_lowerer.lower_parameter()
+    }
+}
@@ -1,15 +1,38 @@
-pub struct Aarch64InstructionEmitter;
+use rustyc_target::Target;
 
-// TODO: This should be moved to a platform abstraction layer that wraps
-// instruction emitters as well as other platform attributes such as the
-// calling convention.
-const FUNCTION_PARAMETER_REGISTERS: [&str; 8] = ["x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7"];
+use crate::{argument_location::ArgumentLocation, register_class::RegisterClass};
+
+pub struct Aarch64InstructionEmitter {
+    target: Target,
+}
+
+// Each register class has its own set of parameter registers, `x0`-`x7` for
+// integers and pointers and `s0`-`s7`/`d0`-`d7` for floating point values.
+const FUNCTION_PARAMETER_REGISTER_COUNT: usize = 8;
+
+// Parameters that don't fit in registers are passed in 8-byte stack slots.
+const FUNCTION_PARAMETER_STACK_SLOT_SIZE: i64 = 8;
+
+// Outside of Apple platforms, variadic functions save the parameter registers
+// in their frame for `va_arg` to find the variadic arguments there, the
+// general ones right below the frame pointer and the 16-byte vector ones below
+// them.
+const GENERAL_REGISTER_SAVE_AREA_SIZE: i64 = 8 * FUNCTION_PARAMETER_REGISTER_COUNT as i64;
+const FLOATING_REGISTER_SAVE_AREA_SIZE: i64 = 16 * FUNCTION_PARAMETER_REGISTER_COUNT as i64;
 
 // TODO: All the formatting in this file can probably be done with an Arena allocator
 // instead of repeatedly allocating Strings in each `format!` invocation.
 impl Aarch64InstructionEmitter {
-    pub fn new() -> Self {
-        Self
+    pub fn new(target: Target) -> Self {
+        Self { target }
+    }
+
+    /// Mach-O prefixes C identifiers with an underscore.
+    pub fn get_symbol(&self, name: &str) -> String {
+        match self.target {
+            Target::Aarch64AppleDarwin => format!("_{name}"),
+            Target::Aarch64UnknownLinuxGnu => name.to_owned(),
+        }
     }
 
     pub fn emit_move_registers(&self, source: &str, destination: &str) {
@@ -24,6 +47,14 @@ impl Aarch64InstructionEmitter {
         Self::emit_instruction(format!("add {destination}, {register_a}, {register_b}").as_str());
     }
 
+    pub fn emit_add_immediate(&self, source: &str, immediate: i64, destination: &str) {
+        Self::emit_instruction(format!("add {destination}, {source}, #{immediate}").as_str());
+    }
+
+    pub fn emit_subtract_immediate(&self, source: &str, immediate: i64, destination: &str) {
+        Self::emit_instruction(format!("sub {destination}, {source}, #{immediate}").as_str());
+    }
+
     pub fn emit_subtract(&self, source_a: &str, source_b: &str, destination: &str) {
         Self::emit_instruction(format!("sub {destination}, {source_a}, {source_b}").as_str());
     }
@@ -36,10 +67,96 @@ impl Aarch64InstructionEmitter {
         Self::emit_instruction(format!("sdiv {destination}, {source_a}, {source_b}").as_str());
     }
 
+    pub fn emit_unsigned_divide(&self, source_a: &str, source_b: &str, destination: &str) {
+        Self::emit_instruction(format!("udiv {destination}, {source_a}, {source_b}").as_str());
+    }
+
+    pub fn emit_and_immediate(&self, source: &str, immediate: i64, destination: &str) {
+        Self::emit_instruction(format!("and {destination}, {source}, #{immediate}").as_str());
+    }
+
+    pub fn emit_sign_extend_byte(&self, source: &str, destination: &str) {
+        Self::emit_instruction(format!("sxtb {destination}, {source}").as_str());
+    }
+
+    pub fn emit_zero_extend_byte(&self, source: &str, destination: &str) {
+        Self::emit_instruction(format!("uxtb {destination}, {source}").as_str());
+    }
+
     pub fn emit_negate(&self, source: &str, destination: &str) {
         Self::emit_instruction(format!("neg {destination}, {source}").as_str());
     }
 
+    pub fn emit_floating_add(&self, source_a: &str, source_b: &str, destination: &str) {
+        Self::emit_instruction(format!("fadd {destination}, {source_a}, {source_b}").as_str());
+    }
+
+    pub fn emit_floating_subtract(&self, source_a: &str, source_b: &str, destination: &str) {
+        Self::emit_instruction(format!("fsub {destination}, {source_a}, {source_b}").as_str());
+    }
+
+    pub fn emit_floating_multiply(&self, source_a: &str, source_b: &str, destination: &str) {
+        Self::emit_instruction(format!("fmul {destination}, {source_a}, {source_b}").as_str());
+    }
+
+    pub fn emit_floating_divide(&self, source_a: &str, source_b: &str, destination: &str) {
+        Self::emit_instruction(format!("fdiv {destination}, {source_a}, {source_b}").as_str());
+    }
+
+    pub fn emit_floating_negate(&self, source: &str, destination: &str) {
+        Self::emit_instruction(format!("fneg {destination}, {source}").as_str());
+    }
+
+    pub fn emit_convert_int_to_floating(&self, source: &str, destination: &str) {
+        Self::emit_instruction(format!("scvtf {destination}, {source}").as_str());
+    }
+
+    pub fn emit_convert_floating_to_int(&self, source: &str, destination: &str) {
+        Self::emit_instruction(format!("fcvtzs {destination}, {source}").as_str());
+    }
+
+    pub fn emit_convert_unsigned_int_to_floating(&self, source: &str, destination: &str) {
+        Self::emit_instruction(format!("ucvtf {destination}, {source}").as_str());
+    }
+
+    pub fn emit_convert_floating_to_unsigned_int(&self, source: &str, destination: &str) {
+        Self::emit_instruction(format!("fcvtzu {destination}, {source}").as_str());
+    }
+
+    pub fn emit_unsigned_bit_field_extract(
+        &self,
+        source: &str,
+        offset: u32,
+        width: u32,
+        destination: &str,
+    ) {
+        Self::emit_instruction(
+            format!("ubfx {destination}, {source}, #{offset}, #{width}").as_str(),
+        );
+    }
+
+    pub fn emit_signed_bit_field_extract(
+        &self,
+        source: &str,
+        offset: u32,
+        width: u32,
+        destination: &str,
+    ) {
+        Self::emit_instruction(
+            format!("sbfx {destination}, {source}, #{offset}, #{width}").as_str(),
+        );
+    }
+
+    pub fn emit_bit_field_insert(&self, source: &str, offset: u32, width: u32, destination: &str) {
+        Self::emit_instruction(
+            format!("bfi {destination}, {source}, #{offset}, #{width}").as_str(),
+        );
+    }
+
+    pub fn emit_convert_floating_precision(&self, source: &str, destination: &str) {
+        Self::emit_instruction(format!("fcvt {destination}, {source}").as_str());
+    }
+
     pub fn emit_push(&self, register: &str) {
         self.emit_push_pair(register, "xzr");
     }
@@ -48,6 +165,15 @@ impl Aarch64InstructionEmitter {
         Self::emit_instruction(format!("stp {register1}, {register2}, [sp, #-0x10]!").as_str());
     }
 
+    /// Pushes a floating point register, which can't be paired with `xzr`.
+    pub fn emit_push_floating(&self, register: &str) {
+        Self::emit_instruction(format!("str {register}, [sp, #-0x10]!").as_str());
+    }
+
+    pub fn emit_pop_floating(&self, register: &str) {
+        Self::emit_instruction(format!("ldr {register}, [sp], #0x10").as_str());
+    }
+
     pub fn emit_pop(&self, register: &str) {
         self.emit_pop_pair(register, "xzr");
     }
@@ -61,7 +187,76 @@ impl Aarch64InstructionEmitter {
     }
 
     pub fn emit_load_offset(&self, source: &str, offset: i64, destination: &str) {
-        Self::emit_instruction(format!("ldr {destination}, [{source}, #{offset}]").as_str());
+        let offset = Self::get_offset_operand(offset);
+        Self::emit_instruction(format!("ldr {destination}, [{source}, {offset}]").as_str());
+    }
+
+    /// Loads a byte, zero-extending it into a 32-bit register.
+    pub fn emit_load_byte(&self, source: &str, destination: &str) {
+        Self::emit_instruction(format!("ldrb {destination}, [{source}]").as_str());
+    }
+
+    pub fn emit_load_byte_offset(&self, source: &str, offset: i64, destination: &str) {
+        let offset = Self::get_offset_operand(offset);
+        Self::emit_instruction(format!("ldrb {destination}, [{source}, {offset}]").as_str());
+    }
+
+    /// Loads a byte, sign-extending it into a 64-bit register.
+    pub fn emit_load_signed_byte(&self, source: &str, destination: &str) {
+        Self::emit_instruction(format!("ldrsb {destination}, [{source}]").as_str());
+    }
+
+    pub fn emit_load_signed_byte_offset(&self, source: &str, offset: i64, destination: &str) {
+        let offset = Self::get_offset_operand(offset);
+        Self::emit_instruction(format!("ldrsb {destination}, [{source}, {offset}]").as_str());
+    }
+
+    pub fn emit_load_signed_word_offset(&self, source: &str, offset: i64, destination: &str) {
+        let offset = Self::get_offset_operand(offset);
+        Self::emit_instruction(format!("ldrsw {destination}, [{source}, {offset}]").as_str());
+    }
+
+    pub fn emit_load_signed_word_indexed(&self, base: &str, index: &str, destination: &str) {
+        Self::emit_instruction(format!("ldrsw {destination}, [{base}, {index}, lsl #2]").as_str());
+    }
+
+    pub fn emit_load_label_address(&self, label: &str, destination: &str) {
+        let (page, page_offset) = self.get_page_operands(label);
+
+        Self::emit_instruction(format!("adrp {destination}, {page}").as_str());
+        Self::emit_instruction(format!("add {destination}, {destination}, {page_offset}").as_str());
+    }
+
+    /// Loads the value stored at a label, using `x9` for its page address.
+    pub fn emit_load_label(&self, label: &str, destination: &str) {
+        let (page, page_offset) = self.get_page_operands(label);
+
+        Self::emit_instruction(format!("adrp x9, {page}").as_str());
+        Self::emit_instruction(format!("ldr {destination}, [x9, {page_offset}]").as_str());
+    }
+
+    /// Loads the address of a symbol through the global offset table.
+    pub fn emit_load_symbol_address(&self, symbol: &str, destination: &str) {
+        let (page, page_offset) = match self.target {
+            Target::Aarch64AppleDarwin => {
+                (format!("{symbol}@GOTPAGE"), format!("{symbol}@GOTPAGEOFF"))
+            }
+            Target::Aarch64UnknownLinuxGnu => {
+                (format!(":got:{symbol}"), format!(":got_lo12:{symbol}"))
+            }
+        };
+
+        Self::emit_instruction(format!("adrp {destination}, {page}").as_str());
+        Self::emit_instruction(
+            format!("ldr {destination}, [{destination}, {page_offset}]").as_str(),
+        );
+    }
+
+    fn get_page_operands(&self, label: &str) -> (String, String) {
+        match self.target {
+            Target::Aarch64AppleDarwin => (format!("{label}@PAGE"), format!("{label}@PAGEOFF")),
+            Target::Aarch64UnknownLinuxGnu => (label.to_owned(), format!(":lo12:{label}")),
+        }
     }
 
     pub fn emit_store(&self, source: &str, destination: &str) {
@@ -69,7 +264,77 @@ impl Aarch64InstructionEmitter {
     }
 
     pub fn emit_store_offset(&self, source: &str, destination: &str, offset: i64) {
-        Self::emit_instruction(format!("str {source}, [{destination}, #{offset}]").as_str());
+        let offset = Self::get_offset_operand(offset);
+        Self::emit_instruction(format!("str {source}, [{destination}, {offset}]").as_str());
+    }
+
+    pub fn emit_store_pair_offset(
+        &self,
+        source1: &str,
+        source2: &str,
+        destination: &str,
+        offset: i64,
+    ) {
+        Self::emit_instruction(
+            format!("stp {source1}, {source2}, [{destination}, #{offset}]").as_str(),
+        );
+    }
+
+    /// Copies `size` bytes between two addresses, using `x9`.
+    pub fn emit_copy(&self, source: &str, destination: &str, size: i64) {
+        let mut offset = 0;
+
+        while offset < size {
+            match size - offset {
+                8.. => {
+                    self.emit_load_offset(source, offset, "x9");
+                    self.emit_store_offset("x9", destination, offset);
+                    offset += 8;
+                }
+                4.. => {
+                    self.emit_load_offset(source, offset, "w9");
+                    self.emit_store_offset("w9", destination, offset);
+                    offset += 4;
+                }
+                _ => {
+                    self.emit_load_byte_offset(source, offset, "w9");
+                    self.emit_store_byte_offset("w9", destination, offset);
+                    offset += 1;
+                }
+            }
+        }
+    }
+
+    /// Clears `size` bytes from an address.
+    pub fn emit_clear(&self, destination: &str, size: i64) {
+        let mut offset = 0;
+
+        while offset < size {
+            match size - offset {
+                8.. => {
+                    self.emit_store_offset("xzr", destination, offset);
+                    offset += 8;
+                }
+                4.. => {
+                    self.emit_store_offset("wzr", destination, offset);
+                    offset += 4;
+                }
+                _ => {
+                    self.emit_store_byte_offset("wzr", destination, offset);
+                    offset += 1;
+                }
+            }
+        }
+    }
+
+    /// Stores the low byte of a 32-bit register.
+    pub fn emit_store_byte(&self, source: &str, destination: &str) {
+        Self::emit_instruction(format!("strb {source}, [{destination}]").as_str());
+    }
+
+    pub fn emit_store_byte_offset(&self, source: &str, destination: &str, offset: i64) {
+        let offset = Self::get_offset_operand(offset);
+        Self::emit_instruction(format!("strb {source}, [{destination}, {offset}]").as_str());
     }
 
     pub fn emit_return(&self) {
@@ -84,12 +349,41 @@ impl Aarch64InstructionEmitter {
         Self::emit_instruction(format!("beq {target}").as_str());
     }
 
+    pub fn emit_branch_not_equals(&self, target: &str) {
+        Self::emit_instruction(format!("bne {target}").as_str());
+    }
+
+    pub fn emit_branch_higher(&self, target: &str) {
+        Self::emit_instruction(format!("bhi {target}").as_str());
+    }
+
+    pub fn emit_branch_greater(&self, target: &str) {
+        Self::emit_instruction(format!("bgt {target}").as_str());
+    }
+
+    pub fn emit_branch_greater_or_equal(&self, target: &str) {
+        Self::emit_instruction(format!("bge {target}").as_str());
+    }
+
+    pub fn emit_branch_register(&self, register: &str) {
+        Self::emit_instruction(format!("br {register}").as_str());
+    }
+
     pub fn emit_branch_link(&self, target: &str) {
         Self::emit_instruction(format!("bl {target}").as_str());
     }
 
+    pub fn emit_branch_link_register(&self, register: &str) {
+        Self::emit_instruction(format!("blr {register}").as_str());
+    }
+
     pub fn emit_conditional_set(&self, condition: &str) {
         self.emit_comparison("x0", "x1");
+        self.emit_set_condition(condition);
+    }
+
+    /// Sets `x0` to whether the condition holds for the current flags.
+    pub fn emit_set_condition(&self, condition: &str) {
         Self::emit_instruction(format!("cset x0, {condition}").as_str());
     }
 
@@ -97,6 +391,15 @@ impl Aarch64InstructionEmitter {
         Self::emit_instruction(format!("cmp {a}, {b}").as_str());
     }
 
+    pub fn emit_floating_conditional_set(&self, a: &str, b: &str, condition: &str) {
+        self.emit_floating_comparison(a, b);
+        self.emit_set_condition(condition);
+    }
+
+    pub fn emit_floating_comparison(&self, a: &str, b: &str) {
+        Self::emit_instruction(format!("fcmp {a}, {b}").as_str());
+    }
+
     pub fn emit_label(&self, label: &str) {
         println!("{label}:");
     }
@@ -109,12 +412,167 @@ impl Aarch64InstructionEmitter {
         println!(".text");
     }
 
+    pub fn emit_read_only_data_section_directive(&self) {
+        match self.target {
+            Target::Aarch64AppleDarwin => println!(".section __TEXT,__const"),
+            Target::Aarch64UnknownLinuxGnu => println!(".section .rodata"),
+        }
+    }
+
+    pub fn emit_data_section_directive(&self) {
+        println!(".data");
+    }
+
+    /// Reserves zero-initialized storage for a symbol.
+    pub fn emit_zero_fill(&self, symbol: &str, size: u64, power_of_two: u32) {
+        match self.target {
+            Target::Aarch64AppleDarwin => {
+                println!(".zerofill __DATA,__bss,{symbol},{size},{power_of_two}");
+            }
+            Target::Aarch64UnknownLinuxGnu => {
+                println!(".bss");
+                self.emit_alignment(power_of_two);
+                self.emit_label(symbol);
+                println!(".zero {size}");
+                self.emit_text_section_directive();
+            }
+        }
+    }
+
+    pub fn emit_alignment(&self, power_of_two: u32) {
+        println!(".p2align {power_of_two}");
+    }
+
+    pub fn emit_relative_word(&self, label: &str, base_label: &str) {
+        Self::emit_instruction(format!(".long {label} - {base_label}").as_str());
+    }
+
+    pub fn emit_zero(&self, size: u64) {
+        Self::emit_instruction(format!(".zero {size}").as_str());
+    }
+
+    pub fn emit_byte(&self, value: u8) {
+        Self::emit_instruction(format!(".byte {value:#x}").as_str());
+    }
+
+    pub fn emit_word(&self, value: u32) {
+        Self::emit_instruction(format!(".long {value:#x}").as_str());
+    }
+
+    pub fn emit_double_word(&self, value: u64) {
+        Self::emit_instruction(format!(".quad {value:#x}").as_str());
+    }
+
+    /// Emits a double word holding the address of a symbol.
+    pub fn emit_symbol_double_word(&self, symbol: &str, offset: i64) {
+        if offset == 0 {
+            Self::emit_instruction(format!(".quad {symbol}").as_str());
+        } else {
+            Self::emit_instruction(format!(".quad {symbol}{offset:+}").as_str());
+        }
+    }
+
     pub fn emit_item_separator(&self) {
         println!();
     }
 
-    pub fn get_function_parameter_register(&self, index: usize) -> &str {
-        FUNCTION_PARAMETER_REGISTERS[index]
+    /// Apple platforms pass variadic arguments on the stack, Linux passes them as named ones.
+    pub fn get_function_argument_locations(
+        &self,
+        register_classes: &[RegisterClass],
+        fixed_argument_count: usize,
+    ) -> (Vec<ArgumentLocation>, i64) {
+        let fixed_argument_count = match self.target {
+            Target::Aarch64AppleDarwin => fixed_argument_count,
+            Target::Aarch64UnknownLinuxGnu => register_classes.len(),
+        };
+        let mut general_register_count = 0;
+        let mut floating_register_count = 0;
+        let mut stack_size = 0;
+
+        let locations = register_classes
+            .iter()
+            .enumerate()
+            .map(|(index, register_class)| {
+                let register_count = if register_class.is_floating() {
+                    &mut floating_register_count
+                } else {
+                    &mut general_register_count
+                };
+
+                if index < fixed_argument_count
+                    && *register_count < FUNCTION_PARAMETER_REGISTER_COUNT
+                {
+                    let register = register_class.get_register(*register_count);
+                    *register_count += 1;
+
+                    ArgumentLocation::Register(register)
+                } else {
+                    let offset = stack_size;
+                    stack_size += FUNCTION_PARAMETER_STACK_SLOT_SIZE;
+
+                    ArgumentLocation::Stack(offset)
+                }
+            })
+            .collect();
+
+        (locations, stack_size)
+    }
+
+    pub fn get_function_parameter_stack_slot_size(&self) -> i64 {
+        FUNCTION_PARAMETER_STACK_SLOT_SIZE
+    }
+
+    pub fn get_function_parameter_register_count(&self) -> usize {
+        FUNCTION_PARAMETER_REGISTER_COUNT
+    }
+
+    /// Returns the size of the area variadic functions save the parameter registers to.
+    pub fn get_register_save_area_size(&self) -> i64 {
+        match self.target {
+            Target::Aarch64AppleDarwin => 0,
+            Target::Aarch64UnknownLinuxGnu => {
+                GENERAL_REGISTER_SAVE_AREA_SIZE + FLOATING_REGISTER_SAVE_AREA_SIZE
+            }
+        }
+    }
+
+    pub fn get_general_register_save_area_size(&self) -> i64 {
+        GENERAL_REGISTER_SAVE_AREA_SIZE
+    }
+
+    /// Saves the parameter registers right below the frame pointer.
+    pub fn emit_save_parameter_registers(&self) {
+        for index in (0..FUNCTION_PARAMETER_REGISTER_COUNT).step_by(2) {
+            self.emit_store_pair_offset(
+                &format!("x{index}"),
+                &format!("x{}", index + 1),
+                "fp",
+                8 * index as i64 - GENERAL_REGISTER_SAVE_AREA_SIZE,
+            );
+        }
+
+        for index in (0..FUNCTION_PARAMETER_REGISTER_COUNT).step_by(2) {
+            self.emit_store_pair_offset(
+                &format!("q{index}"),
+                &format!("q{}", index + 1),
+                "fp",
+                16 * index as i64
+                    - GENERAL_REGISTER_SAVE_AREA_SIZE
+                    - FLOATING_REGISTER_SAVE_AREA_SIZE,
+            );
+        }
+    }
+
+    /// Offsets out of the range of the unscaled immediate are moved to `x17` first.
+    fn get_offset_operand(offset: i64) -> String {
+        if (-256..256).contains(&offset) {
+            return format!("#{offset}");
+        }
+
+        Self::emit_instruction(format!("mov x17, #{offset}").as_str());
+
+        "x17".to_owned()
     }
 
     fn emit_instruction(instruction: &str) {
@@ -0,0 +1,7 @@
+/// Where an argument is passed to a function.
+#[derive(Debug)]
+pub enum ArgumentLocation {
+    Register(String),
+    /// Offset relative to the stack pointer at the time of the call.
+    Stack(i64),
+}
@@ -1,41 +1,78 @@
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{cell::RefCell, rc::Rc};
 
 use rustyc_hir::Block;
+use rustyc_ty::TyContext;
 
 use crate::{
-    label_allocator::LabelAllocator, statement_generator::StatementGenerator,
-    variable_properties::VariableProperties,
+    aarch64_instruction_emitter::Aarch64InstructionEmitter, constant_pool::ConstantPool,
+    function::Function, jump_target_stack::JumpTargetStack, label_allocator::LabelAllocator,
+    statement_generator::StatementGenerator,
 };
 
 pub struct BlockGenerator {
     block: Rc<Block>,
-    local_variables: Rc<HashMap<String, VariableProperties>>,
+    function: Rc<Function>,
     label_allocator: Rc<RefCell<LabelAllocator>>,
+    jump_target_stack: Rc<RefCell<JumpTargetStack>>,
+    constant_pool: Rc<RefCell<ConstantPool>>,
+    ty_context: Rc<RefCell<TyContext>>,
+    instruction_emitter: Aarch64InstructionEmitter,
 }
 
 impl BlockGenerator {
     pub fn new(
         block: Rc<Block>,
-        local_variables: Rc<HashMap<String, VariableProperties>>,
+        function: Rc<Function>,
         label_allocator: Rc<RefCell<LabelAllocator>>,
+        jump_target_stack: Rc<RefCell<JumpTargetStack>>,
+        constant_pool: Rc<RefCell<ConstantPool>>,
+        ty_context: Rc<RefCell<TyContext>>,
     ) -> Self {
+        let instruction_emitter = Aarch64InstructionEmitter::new(function.get_target());
+
         Self {
             block,
-            local_variables,
+            function,
             label_allocator,
+            jump_target_stack,
+            constant_pool,
+            ty_context,
+            instruction_emitter,
         }
     }
 
     pub fn generate(self) -> rustyc_diagnostics::Result<()> {
+        let saved_stack_pointer_count = self
+            .jump_target_stack
+            .borrow()
+            .get_saved_stack_pointer_count();
+        let temporaries_size = self.function.get_temporaries_size();
+
         for statement in self.block.get_statements().iter() {
             let statement_generator = StatementGenerator::new(
                 Rc::clone(statement),
-                Rc::clone(&self.local_variables),
+                Rc::clone(&self.function),
                 Rc::clone(&self.label_allocator),
+                Rc::clone(&self.jump_target_stack),
+                Rc::clone(&self.constant_pool),
+                Rc::clone(&self.ty_context),
             );
             statement_generator.generate()?;
         }
 
+        let saved_stack_pointer = self
+            .jump_target_stack
+            .borrow_mut()
+            .truncate_saved_stack_pointers(saved_stack_pointer_count);
+
+        if let Some(offset) = saved_stack_pointer {
+            self.instruction_emitter
+                .emit_load_offset("fp", offset, "x9");
+            self.instruction_emitter.emit_move_registers("x9", "sp");
+        }
+
+        self.function.set_temporaries_size(temporaries_size);
+
         Ok(())
     }
 }
@@ -1,6 +1,8 @@
-use std::rc::Rc;
+use std::{cell::RefCell, rc::Rc};
 
-use rustyc_hir::Item;
+use rustyc_hir::items::Item;
+use rustyc_target::Target;
+use rustyc_ty::TyContext;
 
 use crate::{
     aarch64_instruction_emitter::Aarch64InstructionEmitter, item_generator::ItemGenerator,
@@ -8,14 +10,18 @@ use crate::{
 
 pub struct CodeGenerator {
     hir: Rc<Vec<Rc<Item>>>,
+    ty_context: Rc<RefCell<TyContext>>,
+    target: Target,
     instruction_emitter: Aarch64InstructionEmitter,
 }
 
 impl CodeGenerator {
-    pub fn new(hir: Rc<Vec<Rc<Item>>>) -> Self {
+    pub fn new(hir: Rc<Vec<Rc<Item>>>, ty_context: Rc<RefCell<TyContext>>, target: Target) -> Self {
         Self {
             hir,
-            instruction_emitter: Aarch64InstructionEmitter::new(),
+            ty_context,
+            target,
+            instruction_emitter: Aarch64InstructionEmitter::new(target),
         }
     }
 
@@ -23,7 +29,8 @@ impl CodeGenerator {
         self.instruction_emitter.emit_text_section_directive();
 
         for item in self.hir.iter() {
-            let item_generator = ItemGenerator::new(Rc::clone(item));
+            let item_generator =
+                ItemGenerator::new(Rc::clone(item), Rc::clone(&self.ty_context), self.target);
             item_generator.generate()?;
         }
 
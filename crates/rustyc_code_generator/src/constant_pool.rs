@@ -0,0 +1,39 @@
+use crate::label_allocator::LabelAllocator;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Constant {
+    Single(u32),
+    Double(u64),
+}
+
+/// Collects the constants of a function that can't be materialized with immediates.
+pub struct ConstantPool {
+    constants: Vec<(Constant, String)>,
+}
+
+impl ConstantPool {
+    pub fn new() -> Self {
+        Self {
+            constants: Vec::new(),
+        }
+    }
+
+    pub fn get_label(
+        &mut self,
+        constant: Constant,
+        label_allocator: &mut LabelAllocator,
+    ) -> String {
+        if let Some((_, label)) = self.constants.iter().find(|(c, _)| *c == constant) {
+            return label.clone();
+        }
+
+        let label = label_allocator.allocate_unique("constant");
+        self.constants.push((constant, label.clone()));
+
+        label
+    }
+
+    pub fn get_constants(&self) -> &[(Constant, String)] {
+        &self.constants
+    }
+}
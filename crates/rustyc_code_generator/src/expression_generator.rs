@@ -1,46 +1,117 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{cell::RefCell, rc::Rc};
 
 use rustyc_diagnostics::Diagnostic;
-use rustyc_hir::{BinaryOperator, Expression, ExpressionKind, UnaryOperator};
+use rustyc_hir::{
+    expressions::{
+        AllocaExpression, AssignmentExpression, BinaryExpression, BinaryOperator, CastExpression,
+        CompoundLiteralExpression, Expression, ExpressionKind, FloatNumberExpression,
+        FunctionCallExpression, FunctionExpression, GlobalExpression, MemberExpression,
+        SizeOfExpression, StatementExpression, UnaryExpression, UnaryOperator, VaArgExpression,
+        VaStartExpression, VariableExpression,
+    },
+    Linkage, LocalId, Res,
+};
+use rustyc_span::Span;
+use rustyc_ty::{StructMember, Ty, TyContext};
 
 use crate::{
-    aarch64_instruction_emitter::Aarch64InstructionEmitter, variable_properties::VariableProperties,
+    aarch64_instruction_emitter::Aarch64InstructionEmitter,
+    argument_location::ArgumentLocation,
+    block_generator::BlockGenerator,
+    constant_pool::{Constant, ConstantPool},
+    function::Function,
+    initializer_generator::InitializerGenerator,
+    jump_target_stack::JumpTargetStack,
+    label_allocator::LabelAllocator,
+    label_collector::LabelCollector,
+    register_class::RegisterClass,
 };
 
+// The offsets of the fields of a `va_list`. On Apple platforms it only has
+// the first one, which points to the next argument on the stack. Elsewhere it
+// also points to the tops of the register save areas, along with the negative
+// offsets from them to the next saved register arguments.
+const VA_LIST_STACK_OFFSET: i64 = 0;
+const VA_LIST_GENERAL_TOP_OFFSET: i64 = 8;
+const VA_LIST_FLOATING_TOP_OFFSET: i64 = 16;
+const VA_LIST_GENERAL_OFFSETS_OFFSET: i64 = 24;
+const VA_LIST_FLOATING_OFFSETS_OFFSET: i64 = 28;
+
 pub struct ExpressionGenerator {
     expression: Rc<Expression>,
-    local_variables: Rc<HashMap<String, VariableProperties>>,
+    function: Rc<Function>,
+    label_allocator: Rc<RefCell<LabelAllocator>>,
+    constant_pool: Rc<RefCell<ConstantPool>>,
+    ty_context: Rc<RefCell<TyContext>>,
     instruction_emitter: Aarch64InstructionEmitter,
 }
 
 impl ExpressionGenerator {
     pub fn new(
         expression: Rc<Expression>,
-        local_variables: Rc<HashMap<String, VariableProperties>>,
+        function: Rc<Function>,
+        label_allocator: Rc<RefCell<LabelAllocator>>,
+        constant_pool: Rc<RefCell<ConstantPool>>,
+        ty_context: Rc<RefCell<TyContext>>,
     ) -> Self {
+        let instruction_emitter = Aarch64InstructionEmitter::new(function.get_target());
+
         Self {
             expression,
-            local_variables,
-            instruction_emitter: Aarch64InstructionEmitter::new(),
+            function,
+            label_allocator,
+            constant_pool,
+            ty_context,
+            instruction_emitter,
         }
     }
 
     pub fn generate(self) -> rustyc_diagnostics::Result<()> {
+        // The value of an array is the address of its first element, and
+        // structures are handled through their addresses too, which assigning
+        // one leaves as its value.
+        let is_copied_object = self.is_copied(&self.expression)
+            && !matches!(self.expression.get_kind(), ExpressionKind::Assignment(_));
+
+        if self.is_array(&self.expression) || is_copied_object {
+            return self.generate_address_of(Rc::clone(&self.expression));
+        }
+
         match self.expression.get_kind() {
-            ExpressionKind::Assignment(left, right) => {
-                self.generate_assignment_expression(Rc::clone(left), Rc::clone(right))?
+            ExpressionKind::Assignment(expression) => {
+                self.generate_assignment_expression(expression)?
+            }
+            ExpressionKind::Binary(expression) => self.generate_binary_expression(expression)?,
+            ExpressionKind::Unary(expression) => self.generate_unary_expression(expression)?,
+            ExpressionKind::Variable(expression) => {
+                self.generate_variable_expression(expression)?
+            }
+            ExpressionKind::Function(expression) => self.generate_function_expression(expression),
+            ExpressionKind::Global(expression) => self.generate_global_expression(expression),
+            ExpressionKind::Member(expression) => self.generate_member_expression(expression)?,
+            ExpressionKind::Number(expression) => {
+                self.generate_number_expression(expression.get_value())
             }
-            ExpressionKind::Binary(operator, left, right) => {
-                self.generate_binary_expression(operator, Rc::clone(left), Rc::clone(right))?
+            ExpressionKind::FloatNumber(expression) => {
+                self.generate_float_number_expression(expression)
             }
-            ExpressionKind::Unary(operator, right) => {
-                self.generate_unary_expression(operator, Rc::clone(right))?
+            ExpressionKind::Cast(expression) => self.generate_cast_expression(expression)?,
+            ExpressionKind::FunctionCall(expression) => {
+                self.generate_function_call_expression(expression)?
             }
-            ExpressionKind::Variable(variable) => self.generate_variable_expression(variable),
-            ExpressionKind::Number(number) => self.generate_number_expression(*number),
-            ExpressionKind::FunctionCall(name, arguments) => {
-                self.generate_function_call_expression(name, arguments)?
+            ExpressionKind::CompoundLiteral(expression) => {
+                self.generate_compound_literal_expression(expression)?
             }
+            ExpressionKind::Statement(expression) => {
+                self.generate_statement_expression(expression)?
+            }
+            ExpressionKind::VaStart(expression) => self.generate_va_start_expression(expression)?,
+            ExpressionKind::VaArg(expression) => self.generate_va_arg_expression(expression)?,
+            // There is nothing to release, the list only points into the
+            // caller's frame.
+            ExpressionKind::VaEnd(_) => {}
+            ExpressionKind::SizeOf(expression) => self.generate_size_of_expression(expression)?,
+            ExpressionKind::Alloca(expression) => self.generate_alloca_expression(expression)?,
         }
 
         Ok(())
@@ -48,74 +119,189 @@ impl ExpressionGenerator {
 
     fn generate_assignment_expression(
         &self,
-        left: Rc<Expression>,
+        expression: &AssignmentExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        let register_class = self.get_register_class(&self.expression);
+
+        if let ExpressionKind::Member(member) = expression.get_left().get_kind() {
+            if member.is_bit_field() {
+                return self.generate_bit_field_assignment(member, expression.get_right());
+            }
+        }
+
+        self.generate_address_of(expression.get_left())?;
+        self.generate_push(RegisterClass::General);
+
+        self.generate_expression(expression.get_right())?;
+
+        self.generate_pop(RegisterClass::General, "x1");
+
+        if self.is_copied(&self.expression) {
+            let size = self.get_size(&self.expression);
+            self.instruction_emitter.emit_copy("x0", "x1", size);
+            self.instruction_emitter.emit_move_registers("x1", "x0");
+        } else if self.is_byte_sized(&self.expression) {
+            self.instruction_emitter.emit_store_byte("w0", "x1");
+        } else {
+            self.instruction_emitter
+                .emit_store(register_class.get_value_register(), "x1");
+        }
+
+        Ok(())
+    }
+
+    fn generate_bit_field_assignment(
+        &self,
+        left: &MemberExpression,
         right: Rc<Expression>,
     ) -> rustyc_diagnostics::Result<()> {
-        self.generate_address_of(left)?;
-        self.instruction_emitter.emit_push("x0");
+        let member = left.get_member();
+
+        self.generate_address_of_member(left)?;
+        self.generate_push(RegisterClass::General);
+
+        self.generate_expression(right)?;
 
-        let right_expression_generator = Self::new(right, Rc::clone(&self.local_variables));
-        right_expression_generator.generate()?;
+        self.generate_pop(RegisterClass::General, "x1");
+
+        let is_unit_byte_sized = self.is_storage_unit_byte_sized(member);
+
+        if is_unit_byte_sized {
+            self.instruction_emitter.emit_load_byte("x1", "w2");
+        } else {
+            self.instruction_emitter.emit_load("x1", "x2");
+        }
+
+        if let Some(bit_field) = member.get_bit_field() {
+            self.instruction_emitter.emit_bit_field_insert(
+                "x0",
+                bit_field.get_offset(),
+                bit_field.get_width(),
+                "x2",
+            );
+        }
 
-        self.instruction_emitter.emit_pop("x1");
+        if is_unit_byte_sized {
+            self.instruction_emitter.emit_store_byte("w2", "x1");
+        } else {
+            self.instruction_emitter.emit_store("x2", "x1");
+        }
 
-        self.instruction_emitter.emit_store("x0", "x1");
+        self.generate_bit_field_extract(member, "x2");
 
         Ok(())
     }
 
     fn generate_binary_expression(
         &self,
-        operator: &BinaryOperator,
-        left: Rc<Expression>,
-        right: Rc<Expression>,
+        expression: &BinaryExpression,
     ) -> rustyc_diagnostics::Result<()> {
-        let right_expression_generator = Self::new(right, Rc::clone(&self.local_variables));
-        right_expression_generator.generate()?;
-        self.instruction_emitter.emit_push("x0");
+        // Both operands have the same type once the usual arithmetic
+        // conversions are applied.
+        let register_class = self.get_register_class(&expression.get_left());
 
-        let left_expression_generator = Self::new(left, Rc::clone(&self.local_variables));
-        left_expression_generator.generate()?;
+        self.generate_expression(expression.get_right())?;
+        self.generate_push(register_class);
 
-        self.instruction_emitter.emit_pop("x1");
+        self.generate_expression(expression.get_left())?;
 
-        match operator {
+        self.generate_pop(register_class, register_class.get_operand_register());
+
+        if register_class.is_floating() {
+            self.generate_floating_binary_operation(
+                expression.get_operator(),
+                register_class.get_value_register(),
+                register_class.get_operand_register(),
+            );
+
+            return Ok(());
+        }
+
+        let is_unsigned = self.is_unsigned(&expression.get_left());
+
+        match expression.get_operator() {
             BinaryOperator::Equal => self.instruction_emitter.emit_conditional_set("eq"),
             BinaryOperator::NotEqual => self.instruction_emitter.emit_conditional_set("ne"),
+            BinaryOperator::LessThan if is_unsigned => {
+                self.instruction_emitter.emit_conditional_set("lo")
+            }
             BinaryOperator::LessThan => self.instruction_emitter.emit_conditional_set("lt"),
+            BinaryOperator::LessThanOrEqual if is_unsigned => {
+                self.instruction_emitter.emit_conditional_set("ls")
+            }
             BinaryOperator::LessThanOrEqual => self.instruction_emitter.emit_conditional_set("le"),
             BinaryOperator::Add => self
                 .instruction_emitter
                 .emit_add_registers("x0", "x1", "x0"),
             BinaryOperator::Subtract => self.instruction_emitter.emit_subtract("x0", "x1", "x0"),
             BinaryOperator::Multiply => self.instruction_emitter.emit_multiply("x0", "x1", "x0"),
+            BinaryOperator::Divide if is_unsigned => self
+                .instruction_emitter
+                .emit_unsigned_divide("x0", "x1", "x0"),
             BinaryOperator::Divide => self.instruction_emitter.emit_divide("x0", "x1", "x0"),
         }
 
         Ok(())
     }
 
+    fn generate_floating_binary_operation(
+        &self,
+        operator: &BinaryOperator,
+        left: &str,
+        right: &str,
+    ) {
+        // The conditions are the ones that are false for unordered operands.
+        match operator {
+            BinaryOperator::Equal => self
+                .instruction_emitter
+                .emit_floating_conditional_set(left, right, "eq"),
+            BinaryOperator::NotEqual => self
+                .instruction_emitter
+                .emit_floating_conditional_set(left, right, "ne"),
+            BinaryOperator::LessThan => self
+                .instruction_emitter
+                .emit_floating_conditional_set(left, right, "mi"),
+            BinaryOperator::LessThanOrEqual => self
+                .instruction_emitter
+                .emit_floating_conditional_set(left, right, "ls"),
+            BinaryOperator::Add => self
+                .instruction_emitter
+                .emit_floating_add(left, right, left),
+            BinaryOperator::Subtract => self
+                .instruction_emitter
+                .emit_floating_subtract(left, right, left),
+            BinaryOperator::Multiply => self
+                .instruction_emitter
+                .emit_floating_multiply(left, right, left),
+            BinaryOperator::Divide => self
+                .instruction_emitter
+                .emit_floating_divide(left, right, left),
+        }
+    }
+
     fn generate_unary_expression(
         &self,
-        operator: &UnaryOperator,
-        right: Rc<Expression>,
+        expression: &UnaryExpression,
     ) -> rustyc_diagnostics::Result<()> {
-        match operator {
-            UnaryOperator::Negate => self.generate_negate(right)?,
-            UnaryOperator::AddressOf => self.generate_address_of(right)?,
-            UnaryOperator::Dereference => self.generate_dereference(right)?,
+        let operand = expression.get_operand();
+
+        match expression.get_operator() {
+            UnaryOperator::Negate => self.generate_negate(operand)?,
+            UnaryOperator::AddressOf => self.generate_address_of(operand)?,
+            UnaryOperator::Dereference => self.generate_dereference(operand)?,
         }
 
         Ok(())
     }
 
-    fn generate_variable_expression(&self, variable: &str) {
-        // TODO: Emit an error if the variable is not found, instead of panicking.
-        self.instruction_emitter.emit_load_offset(
-            "fp",
-            self.local_variables.get(variable).unwrap().get_offset(),
-            "x0",
-        );
+    fn generate_variable_expression(
+        &self,
+        expression: &VariableExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        let offset = self.get_variable_offset(expression, self.expression.get_span())?;
+        self.generate_load("fp", offset);
+
+        Ok(())
     }
 
     fn generate_number_expression(&self, number: u64) {
@@ -123,48 +309,460 @@ impl ExpressionGenerator {
             .emit_move_registers(format!("#{number}").as_str(), "x0");
     }
 
+    fn generate_float_number_expression(&self, expression: &FloatNumberExpression) {
+        let register_class = self.get_register_class(&self.expression);
+
+        let constant = match register_class {
+            RegisterClass::Single => Constant::Single((expression.get_value() as f32).to_bits()),
+            _ => Constant::Double(expression.get_value().to_bits()),
+        };
+
+        let label = self
+            .constant_pool
+            .borrow_mut()
+            .get_label(constant, &mut self.label_allocator.borrow_mut());
+
+        self.instruction_emitter
+            .emit_load_label(&label, register_class.get_value_register());
+    }
+
+    fn generate_cast_expression(
+        &self,
+        expression: &CastExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        let operand = expression.get_operand();
+
+        let source = self.get_register_class(&operand);
+        let destination = self.get_register_class(&self.expression);
+        let is_bool_conversion = self.is_bool(&self.expression) && !self.is_bool(&operand);
+        let is_source_unsigned = self.is_unsigned(&operand);
+        let is_destination_unsigned = self.is_unsigned(&self.expression);
+
+        self.generate_expression(operand)?;
+
+        // Any nonzero scalar converts to 1.
+        if is_bool_conversion {
+            if source.is_floating() {
+                self.instruction_emitter
+                    .emit_floating_comparison(source.get_value_register(), "#0.0");
+            } else {
+                self.instruction_emitter.emit_comparison("x0", "#0");
+            }
+            self.instruction_emitter.emit_set_condition("ne");
+
+            return Ok(());
+        }
+
+        match (source, destination) {
+            (source, destination) if source == destination => {}
+            (RegisterClass::General, destination) if is_source_unsigned => self
+                .instruction_emitter
+                .emit_convert_unsigned_int_to_floating("x0", destination.get_value_register()),
+            (RegisterClass::General, destination) => self
+                .instruction_emitter
+                .emit_convert_int_to_floating("x0", destination.get_value_register()),
+            (source, RegisterClass::General) if is_destination_unsigned => self
+                .instruction_emitter
+                .emit_convert_floating_to_unsigned_int(source.get_value_register(), "x0"),
+            (source, RegisterClass::General) => self
+                .instruction_emitter
+                .emit_convert_floating_to_int(source.get_value_register(), "x0"),
+            (source, destination) => self.instruction_emitter.emit_convert_floating_precision(
+                source.get_value_register(),
+                destination.get_value_register(),
+            ),
+        }
+
+        // A conversion to a character type keeps the low byte.
+        if self.is_signed_char(&self.expression) {
+            self.instruction_emitter.emit_sign_extend_byte("w0", "x0");
+        } else if self.is_unsigned_char(&self.expression) {
+            self.instruction_emitter.emit_zero_extend_byte("w0", "w0");
+        }
+
+        Ok(())
+    }
+
     fn generate_function_call_expression(
         &self,
-        name: &str,
-        arguments: &[Rc<Expression>],
+        expression: &FunctionCallExpression,
     ) -> rustyc_diagnostics::Result<()> {
-        for argument in arguments.iter() {
-            let argument_expression_generator =
-                Self::new(Rc::clone(argument), Rc::clone(&self.local_variables));
-            argument_expression_generator.generate()?;
-            self.instruction_emitter.emit_push("x0");
+        let arguments = expression.get_arguments();
+
+        let register_classes = arguments
+            .iter()
+            .map(|argument| self.get_register_class(argument))
+            .collect::<Vec<_>>();
+
+        for (argument, register_class) in arguments.iter().zip(register_classes.iter()) {
+            self.generate_expression(Rc::clone(argument))?;
+            self.generate_push(*register_class);
         }
 
-        for argument_index in (0..arguments.len()).rev() {
-            self.instruction_emitter.emit_pop(
-                self.instruction_emitter
-                    .get_function_parameter_register(argument_index),
+        // Indirect callees are evaluated after the arguments, into a register
+        // that setting up the arguments leaves alone.
+        let callee = expression.get_callee();
+
+        let direct_callee = match callee.get_kind() {
+            ExpressionKind::Function(function) => Some(function),
+            _ => None,
+        };
+
+        if direct_callee.is_none() {
+            self.generate_expression(Rc::clone(&callee))?;
+            self.instruction_emitter.emit_move_registers("x0", "x16");
+        }
+
+        // The arguments that don't fit in registers are copied to the bottom
+        // of the stack, below the evaluated arguments. The area holding them
+        // keeps the stack pointer 16-byte aligned at the call.
+        // Variadic arguments are always passed on the stack, right after the
+        // named arguments that didn't fit in registers.
+        let fixed_argument_count = expression
+            .get_fixed_argument_count()
+            .unwrap_or(arguments.len());
+        let (locations, stack_size) = self
+            .instruction_emitter
+            .get_function_argument_locations(&register_classes, fixed_argument_count);
+        let stack_arguments_size = Function::align_to(stack_size, 16);
+
+        if stack_arguments_size > 0 {
+            self.instruction_emitter
+                .emit_subtract_immediate("sp", stack_arguments_size, "sp");
+        }
+
+        for (argument_index, location) in locations.into_iter().enumerate() {
+            let pushed_offset =
+                stack_arguments_size + 16 * (arguments.len() - 1 - argument_index) as i64;
+
+            match location {
+                ArgumentLocation::Register(register) => {
+                    self.instruction_emitter
+                        .emit_load_offset("sp", pushed_offset, &register)
+                }
+                ArgumentLocation::Stack(stack_offset) => {
+                    self.instruction_emitter
+                        .emit_load_offset("sp", pushed_offset, "x9");
+                    self.instruction_emitter
+                        .emit_store_offset("x9", "sp", stack_offset);
+                }
+            }
+        }
+
+        match direct_callee {
+            Some(function) => {
+                let function_name = self.instruction_emitter.get_symbol(function.get_name());
+                self.instruction_emitter.emit_branch_link(&function_name);
+            }
+            None => self.instruction_emitter.emit_branch_link_register("x16"),
+        }
+
+        let arguments_size = stack_arguments_size + 16 * arguments.len() as i64;
+
+        if arguments_size > 0 {
+            self.instruction_emitter
+                .emit_add_immediate("sp", arguments_size, "sp");
+        }
+
+        self.function
+            .add_temporaries_size(-16 * arguments.len() as i64);
+
+        Ok(())
+    }
+
+    fn generate_compound_literal_expression(
+        &self,
+        expression: &CompoundLiteralExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        self.generate_address_of_compound_literal(expression)?;
+        self.generate_load("x0", 0);
+
+        Ok(())
+    }
+
+    /// Leaves the value of the last statement in the value register.
+    fn generate_statement_expression(
+        &self,
+        expression: &StatementExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        // Jumping out of the block would leave the evaluated operands on the
+        // stack, so it gets targets of its own.
+        let block_generator = BlockGenerator::new(
+            expression.get_block(),
+            Rc::clone(&self.function),
+            Rc::clone(&self.label_allocator),
+            Rc::new(RefCell::new(JumpTargetStack::new(
+                LabelCollector::new().collect(expression.get_block()),
+            ))),
+            Rc::clone(&self.constant_pool),
+            Rc::clone(&self.ty_context),
+        );
+        block_generator.generate()
+    }
+
+    fn generate_function_expression(&self, expression: &FunctionExpression) {
+        let function_name = self.instruction_emitter.get_symbol(expression.get_name());
+        self.instruction_emitter
+            .emit_load_symbol_address(&function_name, "x0");
+    }
+
+    fn generate_global_expression(&self, expression: &GlobalExpression) {
+        self.generate_address_of_global(expression);
+        self.generate_load("x0", 0);
+    }
+
+    fn generate_member_expression(
+        &self,
+        expression: &MemberExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        self.generate_address_of_member(expression)?;
+
+        let member = expression.get_member();
+
+        if member.get_bit_field().is_none() {
+            self.generate_load("x0", 0);
+
+            return Ok(());
+        }
+
+        if self.is_storage_unit_byte_sized(member) {
+            self.instruction_emitter.emit_load_byte("x0", "w0");
+        } else {
+            self.instruction_emitter.emit_load("x0", "x0");
+        }
+
+        self.generate_bit_field_extract(member, "x0");
+
+        Ok(())
+    }
+
+    fn generate_va_start_expression(
+        &self,
+        expression: &VaStartExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        self.generate_address_of(expression.get_list())?;
+
+        // The variadic arguments start right after the named arguments that
+        // were passed on the stack, above the saved frame pointer and link
+        // register pair.
+        let register_classes = self
+            .function
+            .get_parameter_register_classes(&self.ty_context.borrow());
+        let (locations, named_stack_arguments_size) = self
+            .instruction_emitter
+            .get_function_argument_locations(&register_classes, register_classes.len());
+        let variadic_arguments_offset = 16 + named_stack_arguments_size;
+
+        self.instruction_emitter
+            .emit_add_immediate("fp", variadic_arguments_offset, "x1");
+        self.instruction_emitter
+            .emit_store_offset("x1", "x0", VA_LIST_STACK_OFFSET);
+
+        if !self.is_va_list_structure(&expression.get_list()) {
+            return Ok(());
+        }
+
+        // The offsets count up to 0 from the start of the saved registers
+        // that don't hold named arguments.
+        let (general_register_count, floating_register_count) = register_classes
+            .iter()
+            .zip(locations)
+            .filter(|(_, location)| matches!(location, ArgumentLocation::Register(_)))
+            .fold(
+                (0, 0),
+                |(general, floating), (register_class, _)| match register_class.is_floating() {
+                    true => (general, floating + 1),
+                    false => (general + 1, floating),
+                },
+            );
+        let register_count = self
+            .instruction_emitter
+            .get_function_parameter_register_count() as i64;
+
+        self.instruction_emitter
+            .emit_store_offset("fp", "x0", VA_LIST_GENERAL_TOP_OFFSET);
+        self.instruction_emitter.emit_subtract_immediate(
+            "fp",
+            self.instruction_emitter
+                .get_general_register_save_area_size(),
+            "x1",
+        );
+        self.instruction_emitter
+            .emit_store_offset("x1", "x0", VA_LIST_FLOATING_TOP_OFFSET);
+        self.instruction_emitter
+            .emit_move_signed_immediate_to_register(
+                -8 * (register_count - general_register_count),
+                "x1",
+            );
+        self.instruction_emitter
+            .emit_store_offset("w1", "x0", VA_LIST_GENERAL_OFFSETS_OFFSET);
+        self.instruction_emitter
+            .emit_move_signed_immediate_to_register(
+                -16 * (register_count - floating_register_count),
+                "x1",
             );
+        self.instruction_emitter
+            .emit_store_offset("w1", "x0", VA_LIST_FLOATING_OFFSETS_OFFSET);
+
+        Ok(())
+    }
+
+    fn generate_va_arg_expression(
+        &self,
+        expression: &VaArgExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        self.generate_address_of(expression.get_list())?;
+
+        self.instruction_emitter.emit_move_registers("x0", "x1");
+
+        let register_class = self.get_register_class(&self.expression);
+        let end_label = self
+            .label_allocator
+            .borrow_mut()
+            .allocate_unique("va_arg_end");
+
+        // The argument is taken from the saved registers until they run out,
+        // and from the stack after that.
+        if self.is_va_list_structure(&expression.get_list()) {
+            let stack_label = self
+                .label_allocator
+                .borrow_mut()
+                .allocate_unique("va_arg_stack");
+            let (offsets_offset, top_offset, register_size) = match register_class.is_floating() {
+                true => (
+                    VA_LIST_FLOATING_OFFSETS_OFFSET,
+                    VA_LIST_FLOATING_TOP_OFFSET,
+                    16,
+                ),
+                false => (
+                    VA_LIST_GENERAL_OFFSETS_OFFSET,
+                    VA_LIST_GENERAL_TOP_OFFSET,
+                    8,
+                ),
+            };
+
+            self.instruction_emitter
+                .emit_load_signed_word_offset("x1", offsets_offset, "x2");
+            self.instruction_emitter.emit_comparison("x2", "xzr");
+            self.instruction_emitter
+                .emit_branch_greater_or_equal(&stack_label);
+            self.instruction_emitter
+                .emit_add_immediate("x2", register_size, "x3");
+            self.instruction_emitter
+                .emit_store_offset("w3", "x1", offsets_offset);
+            self.instruction_emitter.emit_comparison("x3", "xzr");
+            self.instruction_emitter.emit_branch_greater(&stack_label);
+            self.instruction_emitter
+                .emit_load_offset("x1", top_offset, "x3");
+            self.instruction_emitter
+                .emit_add_registers("x3", "x2", "x3");
+            self.instruction_emitter
+                .emit_load("x3", register_class.get_value_register());
+            self.instruction_emitter.emit_branch(&end_label);
+            self.instruction_emitter.emit_label(&stack_label);
         }
 
-        // TODO: This logic is only relevant to macOS.
-        // This would need to be abstracted somehow when adding support
-        // for other platforms.
-        let function_name = format!("_{name}");
-        self.instruction_emitter.emit_branch_link(&function_name);
+        self.instruction_emitter
+            .emit_load_offset("x1", VA_LIST_STACK_OFFSET, "x2");
+        self.instruction_emitter
+            .emit_load("x2", register_class.get_value_register());
+        self.instruction_emitter.emit_add_immediate(
+            "x2",
+            self.instruction_emitter
+                .get_function_parameter_stack_slot_size(),
+            "x2",
+        );
+        self.instruction_emitter
+            .emit_store_offset("x2", "x1", VA_LIST_STACK_OFFSET);
+        self.instruction_emitter.emit_label(&end_label);
+
+        Ok(())
+    }
+
+    fn generate_size_of_expression(
+        &self,
+        expression: &SizeOfExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        let operand = expression.get_operand();
+
+        let ExpressionKind::Variable(variable) = operand.get_kind() else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::InvalidExpression,
+                operand.get_span().clone(),
+            ));
+        };
+
+        let offset = self.get_variable_offset(variable, operand.get_span())?;
+        self.instruction_emitter
+            .emit_load_offset("fp", offset + 8, "x0");
+
+        Ok(())
+    }
+
+    fn generate_alloca_expression(
+        &self,
+        expression: &AllocaExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        self.generate_expression(expression.get_size())?;
+
+        self.instruction_emitter.emit_add_immediate("x0", 15, "x0");
+        self.instruction_emitter.emit_and_immediate("x0", -16, "x0");
+        self.instruction_emitter.emit_subtract("sp", "x0", "sp");
+
+        let temporaries_size = self.function.get_temporaries_size();
+
+        if temporaries_size > 0 {
+            self.instruction_emitter
+                .emit_add_registers("sp", "x0", "x1");
+
+            for offset in (0..temporaries_size).step_by(8) {
+                self.instruction_emitter
+                    .emit_load_offset("x1", offset, "x9");
+                self.instruction_emitter
+                    .emit_store_offset("x9", "sp", offset);
+            }
+        }
+
+        self.instruction_emitter
+            .emit_add_immediate("sp", temporaries_size, "x0");
 
         Ok(())
     }
 
     fn generate_negate(&self, right: Rc<Expression>) -> rustyc_diagnostics::Result<()> {
-        let right_expression_generator = Self::new(right, Rc::clone(&self.local_variables));
-        right_expression_generator.generate()?;
+        let register_class = self.get_register_class(&self.expression);
+
+        self.generate_expression(right)?;
+
+        if register_class.is_floating() {
+            let register = register_class.get_value_register();
 
-        self.instruction_emitter.emit_negate("x0", "x0");
+            self.instruction_emitter
+                .emit_floating_negate(register, register);
+        } else {
+            self.instruction_emitter.emit_negate("x0", "x0");
+        }
 
         Ok(())
     }
 
     fn generate_address_of(&self, right: Rc<Expression>) -> rustyc_diagnostics::Result<()> {
         match right.get_kind() {
-            ExpressionKind::Variable(variable) => self.generate_address_of_variable(variable),
-            ExpressionKind::Unary(UnaryOperator::Dereference, right) => {
-                self.generate_address_of_dereference(Rc::clone(right))?
+            ExpressionKind::Variable(expression) => {
+                self.generate_address_of_variable(expression, &right)?
+            }
+            ExpressionKind::Function(expression) => self.generate_function_expression(expression),
+            ExpressionKind::Global(expression) => self.generate_address_of_global(expression),
+            ExpressionKind::CompoundLiteral(expression) => {
+                self.generate_address_of_compound_literal(expression)?
+            }
+            ExpressionKind::Member(expression) if !expression.is_bit_field() => {
+                self.generate_address_of_member(expression)?
+            }
+            ExpressionKind::Unary(expression)
+                if matches!(expression.get_operator(), UnaryOperator::Dereference) =>
+            {
+                self.generate_address_of_dereference(expression.get_operand())?
             }
             _ => {
                 return Err(Diagnostic::new_error(
@@ -178,33 +776,270 @@ impl ExpressionGenerator {
     }
 
     fn generate_dereference(&self, right: Rc<Expression>) -> rustyc_diagnostics::Result<()> {
-        let right_expression_generator = Self::new(right, Rc::clone(&self.local_variables));
-        right_expression_generator.generate()?;
+        self.generate_expression(right)?;
+        self.generate_load("x0", 0);
 
-        self.instruction_emitter.emit_load("x0", "x0");
+        Ok(())
+    }
+
+    fn generate_address_of_variable(
+        &self,
+        expression: &VariableExpression,
+        variable: &Expression,
+    ) -> rustyc_diagnostics::Result<()> {
+        let offset = self.get_variable_offset(expression, variable.get_span())?;
+
+        // Variable length arrays live below the frame, their slot holds their
+        // address.
+        if self.is_variable_array(variable) {
+            self.instruction_emitter
+                .emit_load_offset("fp", offset, "x0");
+
+            return Ok(());
+        }
+
+        self.instruction_emitter
+            .emit_move_signed_immediate_to_register(offset, "x0");
+
+        self.instruction_emitter
+            .emit_add_registers("fp", "x0", "x0");
 
         Ok(())
     }
 
-    fn generate_address_of_variable(&self, variable: &str) {
-        // TODO: Emit an error if the variable is not found, instead of panicking.
+    /// Initializes a compound literal object and leaves its address in `x0`.
+    fn generate_address_of_compound_literal(
+        &self,
+        expression: &CompoundLiteralExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        let offset =
+            self.get_local_offset(expression.get_local_id(), self.expression.get_span())?;
+        let initializer_generator = InitializerGenerator::new(
+            expression.get_initializer(),
+            self.expression.get_ty(),
+            offset,
+            Rc::clone(&self.function),
+            Rc::clone(&self.label_allocator),
+            Rc::clone(&self.constant_pool),
+            Rc::clone(&self.ty_context),
+        );
+        initializer_generator.generate()?;
+
         self.instruction_emitter
-            .emit_move_signed_immediate_to_register(
-                self.local_variables.get(variable).unwrap().get_offset(),
+            .emit_move_signed_immediate_to_register(offset, "x0");
+        self.instruction_emitter
+            .emit_add_registers("fp", "x0", "x0");
+
+        Ok(())
+    }
+
+    fn generate_address_of_global(&self, expression: &GlobalExpression) {
+        let symbol = self.instruction_emitter.get_symbol(expression.get_name());
+
+        // Only variables with external linkage may be defined by another
+        // translation unit, the other ones can be addressed directly.
+        match expression.get_linkage() {
+            Linkage::External => self
+                .instruction_emitter
+                .emit_load_symbol_address(&symbol, "x0"),
+            Linkage::Internal => self
+                .instruction_emitter
+                .emit_load_label_address(&symbol, "x0"),
+        }
+    }
+
+    fn generate_address_of_member(
+        &self,
+        expression: &MemberExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        self.generate_address_of(expression.get_base())?;
+
+        let offset = expression.get_member().get_offset() as i64;
+
+        if offset != 0 {
+            self.instruction_emitter
+                .emit_add_immediate("x0", offset, "x0");
+        }
+
+        Ok(())
+    }
+
+    fn generate_bit_field_extract(&self, member: &StructMember, source: &str) {
+        let Some(bit_field) = member.get_bit_field() else {
+            return;
+        };
+
+        if matches!(self.ty_context.borrow().get(member.get_ty()), Ty::Int) {
+            self.instruction_emitter.emit_signed_bit_field_extract(
+                source,
+                bit_field.get_offset(),
+                bit_field.get_width(),
                 "x0",
             );
+        } else {
+            self.instruction_emitter.emit_unsigned_bit_field_extract(
+                source,
+                bit_field.get_offset(),
+                bit_field.get_width(),
+                "x0",
+            );
+        }
+    }
 
-        self.instruction_emitter
-            .emit_add_registers("fp", "x0", "x0");
+    fn is_storage_unit_byte_sized(&self, member: &StructMember) -> bool {
+        self.ty_context.borrow().get_size(member.get_ty()) == Some(1)
     }
 
     fn generate_address_of_dereference(
         &self,
         right: Rc<Expression>,
     ) -> rustyc_diagnostics::Result<()> {
-        let right_expression_generator = Self::new(right, Rc::clone(&self.local_variables));
-        right_expression_generator.generate()?;
+        self.generate_expression(right)
+    }
 
-        Ok(())
+    fn get_variable_offset(
+        &self,
+        expression: &VariableExpression,
+        span: &Span,
+    ) -> rustyc_diagnostics::Result<i64> {
+        let local_id = match expression.get_res() {
+            Res::Local(local_id) | Res::Parameter(local_id) => local_id,
+            Res::Function(_) | Res::Global(_) => {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::InvalidExpression,
+                    span.clone(),
+                ))
+            }
+        };
+
+        self.get_local_offset(local_id, span)
+    }
+
+    fn get_local_offset(&self, local_id: LocalId, span: &Span) -> rustyc_diagnostics::Result<i64> {
+        self.function
+            .get_local_variables()
+            .get(&local_id)
+            .map(|variable| variable.get_offset())
+            .ok_or_else(|| {
+                Diagnostic::new_error(rustyc_diagnostics::Error::UnallocatedVariable, span.clone())
+            })
+    }
+
+    /// Loads a value of the type of the expression from an address.
+    fn generate_load(&self, address: &str, offset: i64) {
+        let register = self
+            .get_register_class(&self.expression)
+            .get_value_register();
+
+        match (self.is_byte_sized(&self.expression), offset) {
+            (true, 0) if self.is_signed_char(&self.expression) => self
+                .instruction_emitter
+                .emit_load_signed_byte(address, "x0"),
+            (true, offset) if self.is_signed_char(&self.expression) => self
+                .instruction_emitter
+                .emit_load_signed_byte_offset(address, offset, "x0"),
+            (true, 0) => self.instruction_emitter.emit_load_byte(address, "w0"),
+            (true, offset) => self
+                .instruction_emitter
+                .emit_load_byte_offset(address, offset, "w0"),
+            (false, 0) => self.instruction_emitter.emit_load(address, register),
+            (false, offset) => self
+                .instruction_emitter
+                .emit_load_offset(address, offset, register),
+        }
+    }
+
+    fn is_byte_sized(&self, expression: &Expression) -> bool {
+        self.ty_context.borrow().get(expression.get_ty()).get_size() == Some(1)
+    }
+
+    fn is_unsigned(&self, expression: &Expression) -> bool {
+        matches!(
+            self.ty_context.borrow().get(expression.get_ty()),
+            Ty::UnsignedInt | Ty::UnsignedChar
+        )
+    }
+
+    fn is_signed_char(&self, expression: &Expression) -> bool {
+        matches!(self.ty_context.borrow().get(expression.get_ty()), Ty::Char)
+    }
+
+    fn is_unsigned_char(&self, expression: &Expression) -> bool {
+        matches!(
+            self.ty_context.borrow().get(expression.get_ty()),
+            Ty::UnsignedChar
+        )
+    }
+
+    fn is_array(&self, expression: &Expression) -> bool {
+        matches!(
+            self.ty_context.borrow().get(expression.get_ty()),
+            Ty::Array(..) | Ty::VariableArray(_)
+        )
+    }
+
+    fn is_variable_array(&self, expression: &Expression) -> bool {
+        matches!(
+            self.ty_context.borrow().get(expression.get_ty()),
+            Ty::VariableArray(_)
+        )
+    }
+
+    fn is_va_list_structure(&self, expression: &Expression) -> bool {
+        self.function
+            .is_va_list_structure(expression.get_ty(), &self.ty_context.borrow())
+    }
+
+    fn is_copied(&self, expression: &Expression) -> bool {
+        self.function
+            .is_copied(expression.get_ty(), &self.ty_context.borrow())
+    }
+
+    fn get_size(&self, expression: &Expression) -> i64 {
+        self.ty_context
+            .borrow()
+            .get_size(expression.get_ty())
+            .unwrap_or(8) as i64
+    }
+
+    fn is_bool(&self, expression: &Expression) -> bool {
+        matches!(self.ty_context.borrow().get(expression.get_ty()), Ty::Bool)
+    }
+
+    fn generate_push(&self, register_class: RegisterClass) {
+        let register = register_class.get_value_register();
+
+        self.function.add_temporaries_size(16);
+
+        if register_class.is_floating() {
+            self.instruction_emitter.emit_push_floating(register);
+        } else {
+            self.instruction_emitter.emit_push(register);
+        }
+    }
+
+    fn generate_pop(&self, register_class: RegisterClass, register: &str) {
+        self.function.add_temporaries_size(-16);
+
+        if register_class.is_floating() {
+            self.instruction_emitter.emit_pop_floating(register);
+        } else {
+            self.instruction_emitter.emit_pop(register);
+        }
+    }
+
+    fn get_register_class(&self, expression: &Expression) -> RegisterClass {
+        RegisterClass::new(expression.get_ty(), &self.ty_context.borrow())
+    }
+
+    fn generate_expression(&self, expression: Rc<Expression>) -> rustyc_diagnostics::Result<()> {
+        let expression_generator = Self::new(
+            expression,
+            Rc::clone(&self.function),
+            Rc::clone(&self.label_allocator),
+            Rc::clone(&self.constant_pool),
+            Rc::clone(&self.ty_context),
+        );
+        expression_generator.generate()
     }
 }
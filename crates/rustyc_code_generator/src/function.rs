@@ -1,31 +1,60 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{cell::Cell, collections::HashMap, rc::Rc};
 
-use rustyc_hir::FunctionItem;
+use rustyc_hir::{items::FunctionItem, LocalId};
+use rustyc_target::Target;
+use rustyc_ty::{Ty, TyContext, TyId};
 
-use crate::variable_properties::VariableProperties;
+use crate::{
+    aarch64_instruction_emitter::Aarch64InstructionEmitter, register_class::RegisterClass,
+    stack_allocator::StackAllocator, variable_properties::VariableProperties,
+};
 
 pub struct Function {
     item: Rc<FunctionItem>,
+    target: Target,
     stack_size: i64,
-    local_variables: Rc<HashMap<String, VariableProperties>>,
+    register_save_area_size: i64,
+    local_variables: Rc<HashMap<LocalId, VariableProperties>>,
+    temporaries_size: Cell<i64>,
 }
 
 impl Function {
-    pub fn new(item: Rc<FunctionItem>) -> Self {
-        let mut local_variables: HashMap<String, VariableProperties> = HashMap::new();
-        let mut offset: i64 = 0;
-
-        for variable in item.get_local_variables().iter() {
-            offset += 8;
-            local_variables
-                .entry(variable.clone())
-                .or_insert(VariableProperties::new(-offset));
+    pub fn new(item: Rc<FunctionItem>, ty_context: &TyContext, target: Target) -> Self {
+        let mut stack_allocator = StackAllocator::new(&item, ty_context);
+
+        for parameter in item.get_parameters().iter() {
+            stack_allocator.allocate(*parameter);
+        }
+
+        if let Some(body) = item.get_body() {
+            for statement in body.get_statements().iter() {
+                stack_allocator.allocate_statement(statement);
+            }
         }
 
+        let (slots, slots_size) = stack_allocator.finish();
+
+        let register_save_area_size = match item.is_variadic() {
+            true => Aarch64InstructionEmitter::new(target).get_register_save_area_size(),
+            false => 0,
+        };
+
+        // Slots are handed out from the bottom of the frame, so that locals
+        // declared later end up at higher addresses. The register save area
+        // is above them.
+        let stack_size = Self::align_to(slots_size + register_save_area_size, 16);
+        let local_variables = slots
+            .into_iter()
+            .map(|(id, offset)| (id, VariableProperties::new(offset - stack_size)))
+            .collect();
+
         Self {
             item,
-            stack_size: Self::align_to(offset, 16),
+            target,
+            stack_size,
+            register_save_area_size,
             local_variables: Rc::new(local_variables),
+            temporaries_size: Cell::new(0),
         }
     }
 
@@ -33,15 +62,56 @@ impl Function {
         &self.item
     }
 
+    pub fn get_target(&self) -> Target {
+        self.target
+    }
+
     pub fn get_stack_size(&self) -> i64 {
         self.stack_size
     }
 
-    pub fn get_local_variables(&self) -> Rc<HashMap<String, VariableProperties>> {
+    pub fn get_register_save_area_size(&self) -> i64 {
+        self.register_save_area_size
+    }
+
+    /// Checks whether a type is `va_list` on a target where it is a structure.
+    pub fn is_va_list_structure(&self, ty: TyId, ty_context: &TyContext) -> bool {
+        matches!(ty_context.get(ty), Ty::VaList) && self.target.get_va_list_size() > 8
+    }
+
+    /// Checks whether values of a type are copied through their addresses.
+    pub fn is_copied(&self, ty: TyId, ty_context: &TyContext) -> bool {
+        matches!(ty_context.get(ty), Ty::Struct(_)) || self.is_va_list_structure(ty, ty_context)
+    }
+
+    pub fn get_local_variables(&self) -> Rc<HashMap<LocalId, VariableProperties>> {
         Rc::clone(&self.local_variables)
     }
 
-    fn align_to(value: i64, alignment: i64) -> i64 {
+    pub fn get_temporaries_size(&self) -> i64 {
+        self.temporaries_size.get()
+    }
+
+    pub fn set_temporaries_size(&self, size: i64) {
+        self.temporaries_size.set(size);
+    }
+
+    pub fn add_temporaries_size(&self, size: i64) {
+        self.temporaries_size
+            .set(self.temporaries_size.get() + size);
+    }
+
+    pub fn get_parameter_register_classes(&self, ty_context: &TyContext) -> Vec<RegisterClass> {
+        self.item
+            .get_parameters()
+            .iter()
+            .map(|parameter| {
+                RegisterClass::new(self.item.get_local(*parameter).get_ty(), ty_context)
+            })
+            .collect()
+    }
+
+    pub fn align_to(value: i64, alignment: i64) -> i64 {
         (value + alignment - 1) / alignment * alignment
     }
 }
@@ -1,54 +1,87 @@
 use std::{cell::RefCell, rc::Rc};
 
-use rustyc_hir::FunctionItem;
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{items::FunctionItem, Linkage};
+use rustyc_target::Target;
+use rustyc_ty::TyContext;
 
 use crate::{
-    aarch64_instruction_emitter::Aarch64InstructionEmitter, block_generator::BlockGenerator,
-    function::Function, label_allocator::LabelAllocator,
+    aarch64_instruction_emitter::Aarch64InstructionEmitter,
+    argument_location::ArgumentLocation,
+    block_generator::BlockGenerator,
+    constant_pool::{Constant, ConstantPool},
+    function::Function,
+    jump_target_stack::JumpTargetStack,
+    label_allocator::LabelAllocator,
+    label_collector::LabelCollector,
 };
 
 pub struct FunctionGenerator {
-    function: Function,
+    function: Rc<Function>,
     label_allocator: Rc<RefCell<LabelAllocator>>,
+    constant_pool: Rc<RefCell<ConstantPool>>,
+    ty_context: Rc<RefCell<TyContext>>,
     instruction_emitter: Aarch64InstructionEmitter,
 }
 
 impl FunctionGenerator {
-    pub fn new(function: Rc<FunctionItem>) -> Self {
+    pub fn new(
+        function: Rc<FunctionItem>,
+        ty_context: Rc<RefCell<TyContext>>,
+        target: Target,
+    ) -> Self {
         let label_allocator = Rc::new(RefCell::new(LabelAllocator::new(
             function.get_name().to_owned(),
         )));
 
+        let function = Rc::new(Function::new(function, &ty_context.borrow(), target));
+
         Self {
-            function: Function::new(function),
+            function,
             label_allocator,
-            instruction_emitter: Aarch64InstructionEmitter::new(),
+            constant_pool: Rc::new(RefCell::new(ConstantPool::new())),
+            ty_context,
+            instruction_emitter: Aarch64InstructionEmitter::new(target),
         }
     }
 
     pub fn generate(self) -> rustyc_diagnostics::Result<()> {
+        // Prototypes only declare a symbol that is defined elsewhere.
+        let Some(body) = self.function.get_item().get_body() else {
+            return Ok(());
+        };
+
         self.generate_prologue();
 
-        self.generate_push_parameters_to_stack();
+        self.generate_push_parameters_to_stack()?;
+
+        let jump_target_stack =
+            JumpTargetStack::new(LabelCollector::new().collect(Rc::clone(&body)));
 
         let block_generator = BlockGenerator::new(
-            self.function.get_item().get_body(),
-            self.function.get_local_variables(),
+            body,
+            Rc::clone(&self.function),
             Rc::clone(&self.label_allocator),
+            Rc::new(RefCell::new(jump_target_stack)),
+            Rc::clone(&self.constant_pool),
+            Rc::clone(&self.ty_context),
         );
         block_generator.generate()?;
 
         self.generate_epilogue();
 
+        self.generate_constant_pool();
+
         Ok(())
     }
 
     fn generate_prologue(&self) {
-        // TODO: This logic is only relevant to macOS.
-        // This would need to be abstracted somehow when adding support
-        // for other platforms.
-        let function_name = format!("_{}", self.function.get_item().get_name());
-        self.instruction_emitter.emit_global(&function_name);
+        let function_name = self
+            .instruction_emitter
+            .get_symbol(self.function.get_item().get_name());
+        if self.function.get_item().get_linkage() == Linkage::External {
+            self.instruction_emitter.emit_global(&function_name);
+        }
         self.instruction_emitter.emit_label(&function_name);
 
         self.instruction_emitter.emit_push_pair("fp", "lr");
@@ -58,22 +91,79 @@ impl FunctionGenerator {
             self.function.get_stack_size().to_string().as_str(),
             "sp",
         );
+
+        if self.function.get_register_save_area_size() > 0 {
+            self.instruction_emitter.emit_save_parameter_registers();
+        }
     }
 
-    fn generate_push_parameters_to_stack(&self) {
-        for (index, parameter) in self.function.get_item().get_parameters().iter().enumerate() {
-            // TODO: Emit an error if the variable is not found, instead of panicking.
-            self.instruction_emitter.emit_store_offset(
+    fn generate_push_parameters_to_stack(&self) -> rustyc_diagnostics::Result<()> {
+        let item = self.function.get_item();
+
+        let register_classes = self
+            .function
+            .get_parameter_register_classes(&self.ty_context.borrow());
+        let (locations, _) = self
+            .instruction_emitter
+            .get_function_argument_locations(&register_classes, register_classes.len());
+
+        for (parameter, location) in item.get_parameters().iter().zip(locations) {
+            let offset = self
+                .function
+                .get_local_variables()
+                .get(parameter)
+                .map(|variable| variable.get_offset())
+                .ok_or_else(|| {
+                    Diagnostic::new_error(
+                        rustyc_diagnostics::Error::UnallocatedVariable,
+                        item.get_local(*parameter).get_span().clone(),
+                    )
+                })?;
+
+            let ty = item.get_local(*parameter).get_ty();
+
+            // A `va_list` structure is passed as the address of a copy, which
+            // is copied into the slot of the parameter.
+            if self
+                .function
+                .is_va_list_structure(ty, &self.ty_context.borrow())
+            {
+                let source = match location {
+                    ArgumentLocation::Register(register) => register,
+                    ArgumentLocation::Stack(stack_offset) => {
+                        self.instruction_emitter
+                            .emit_load_offset("fp", 16 + stack_offset, "x11");
+                        "x11".to_owned()
+                    }
+                };
+                let size = self.ty_context.borrow().get_size(ty).unwrap_or(8) as i64;
+
+                self.instruction_emitter
+                    .emit_move_signed_immediate_to_register(offset, "x10");
                 self.instruction_emitter
-                    .get_function_parameter_register(index),
-                "fp",
-                self.function
-                    .get_local_variables()
-                    .get(parameter)
-                    .unwrap()
-                    .get_offset(),
-            );
+                    .emit_add_registers("fp", "x10", "x10");
+                self.instruction_emitter.emit_copy(&source, "x10", size);
+
+                continue;
+            }
+
+            match location {
+                ArgumentLocation::Register(register) => self
+                    .instruction_emitter
+                    .emit_store_offset(&register, "fp", offset),
+                ArgumentLocation::Stack(stack_offset) => {
+                    // Stack parameters sit right above the saved frame pointer
+                    // and link register pair. Their slots are copied as a
+                    // whole, whatever their register class.
+                    self.instruction_emitter
+                        .emit_load_offset("fp", 16 + stack_offset, "x9");
+                    self.instruction_emitter
+                        .emit_store_offset("x9", "fp", offset);
+                }
+            }
         }
+
+        Ok(())
     }
 
     fn generate_epilogue(&self) {
@@ -88,4 +178,33 @@ impl FunctionGenerator {
         self.instruction_emitter.emit_pop_pair("fp", "lr");
         self.instruction_emitter.emit_return();
     }
+
+    fn generate_constant_pool(&self) {
+        let constant_pool = self.constant_pool.borrow();
+        let constants = constant_pool.get_constants();
+
+        if constants.is_empty() {
+            return;
+        }
+
+        self.instruction_emitter
+            .emit_read_only_data_section_directive();
+
+        for (constant, label) in constants {
+            match constant {
+                Constant::Single(bits) => {
+                    self.instruction_emitter.emit_alignment(2);
+                    self.instruction_emitter.emit_label(label);
+                    self.instruction_emitter.emit_word(*bits);
+                }
+                Constant::Double(bits) => {
+                    self.instruction_emitter.emit_alignment(3);
+                    self.instruction_emitter.emit_label(label);
+                    self.instruction_emitter.emit_double_word(*bits);
+                }
+            }
+        }
+
+        self.instruction_emitter.emit_text_section_directive();
+    }
 }
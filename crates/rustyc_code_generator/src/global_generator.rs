@@ -0,0 +1,239 @@
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{
+    expressions::{Expression, ExpressionKind},
+    initializers::Initializer,
+    items::GlobalItem,
+    Constant, ConstantEvaluator, Linkage,
+};
+use rustyc_target::Target;
+use rustyc_ty::{BitField, TyContext, TyId};
+
+use crate::{
+    aarch64_instruction_emitter::Aarch64InstructionEmitter, register_class::RegisterClass,
+};
+
+pub struct GlobalGenerator {
+    global: Rc<GlobalItem>,
+    ty_context: Rc<RefCell<TyContext>>,
+    instruction_emitter: Aarch64InstructionEmitter,
+}
+
+impl GlobalGenerator {
+    pub fn new(global: Rc<GlobalItem>, ty_context: Rc<RefCell<TyContext>>, target: Target) -> Self {
+        Self {
+            global,
+            ty_context,
+            instruction_emitter: Aarch64InstructionEmitter::new(target),
+        }
+    }
+
+    pub fn generate(self) -> rustyc_diagnostics::Result<()> {
+        let (size, alignment) = {
+            let ty_context = self.ty_context.borrow();
+            let ty = self.global.get_ty();
+
+            (
+                ty_context.get_size(ty).unwrap_or(8),
+                ty_context.get_alignment(ty).unwrap_or(8),
+            )
+        };
+        let power_of_two = alignment.trailing_zeros();
+
+        // The bytes of the variable and the offsets and sizes of its
+        // initialized scalars. The addresses in it are left for the linker to
+        // fill in.
+        let mut data = vec![0; size as usize];
+        let mut units = BTreeMap::new();
+        let mut addresses = BTreeMap::new();
+
+        if let Some(initializer) = self.global.get_initializer() {
+            self.place_initializer(
+                initializer,
+                self.global.get_ty(),
+                0,
+                &mut data,
+                &mut units,
+                &mut addresses,
+            )?;
+        }
+
+        let symbol = self.instruction_emitter.get_symbol(self.global.get_name());
+
+        if self.global.get_linkage() == Linkage::External {
+            self.instruction_emitter.emit_global(&symbol);
+        }
+
+        if addresses.is_empty() && data.iter().all(|byte| *byte == 0) {
+            self.instruction_emitter
+                .emit_zero_fill(&symbol, size, power_of_two);
+            return Ok(());
+        }
+
+        self.instruction_emitter.emit_data_section_directive();
+        self.instruction_emitter.emit_alignment(power_of_two);
+        self.instruction_emitter.emit_label(&symbol);
+
+        self.emit_data(&data, &units, &addresses);
+
+        self.instruction_emitter.emit_text_section_directive();
+
+        Ok(())
+    }
+
+    fn place_initializer(
+        &self,
+        initializer: Rc<Initializer>,
+        ty: TyId,
+        offset: usize,
+        data: &mut [u8],
+        units: &mut BTreeMap<usize, usize>,
+        addresses: &mut BTreeMap<usize, (String, i64)>,
+    ) -> rustyc_diagnostics::Result<()> {
+        match initializer.as_ref() {
+            Initializer::Expression(expression) => self.place(
+                Rc::clone(expression),
+                ty,
+                offset,
+                None,
+                data,
+                units,
+                addresses,
+            ),
+            Initializer::List(elements) => elements.iter().try_for_each(|element| {
+                self.place(
+                    element.get_expression(),
+                    element.get_ty(),
+                    offset + element.get_offset() as usize,
+                    element.get_bit_field(),
+                    data,
+                    units,
+                    addresses,
+                )
+            }),
+        }
+    }
+
+    /// Overrides what an earlier initializer stored at `offset`.
+    #[allow(clippy::too_many_arguments)]
+    fn place(
+        &self,
+        expression: Rc<Expression>,
+        ty: TyId,
+        offset: usize,
+        bit_field: Option<BitField>,
+        data: &mut [u8],
+        units: &mut BTreeMap<usize, usize>,
+        addresses: &mut BTreeMap<usize, (String, i64)>,
+    ) -> rustyc_diagnostics::Result<()> {
+        // The object of a compound literal is copied from its own initializer.
+        if let ExpressionKind::CompoundLiteral(compound_literal) = expression.get_kind() {
+            if self.ty_context.borrow().get(expression.get_ty()) == self.ty_context.borrow().get(ty)
+            {
+                return self.place_initializer(
+                    compound_literal.get_initializer(),
+                    ty,
+                    offset,
+                    data,
+                    units,
+                    addresses,
+                );
+            }
+        }
+
+        let register_class = RegisterClass::new(ty, &self.ty_context.borrow());
+        let size = self.ty_context.borrow().get_size(ty).unwrap_or(8) as usize;
+
+        let constant_evaluator =
+            ConstantEvaluator::new(Rc::clone(&expression), Rc::clone(&self.ty_context));
+        let constant = constant_evaluator.evaluate()?;
+
+        // Addresses can't be stored in floating variables, and the other
+        // conversions were made explicit by the lowerer.
+        if matches!(constant, Constant::Floating(_)) != register_class.is_floating() {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::NonConstantInitializer,
+                expression.get_span().clone(),
+            ));
+        }
+
+        let bytes = &mut data[offset..offset + size];
+        units.insert(offset, size);
+        addresses.remove(&offset);
+
+        let value = match constant {
+            Constant::Integer(value) => match bit_field {
+                Some(bit_field) => {
+                    let mut unit = [0; 8];
+                    unit[..size].copy_from_slice(bytes);
+
+                    let mask =
+                        (u64::MAX >> (u64::BITS - bit_field.get_width())) << bit_field.get_offset();
+
+                    (u64::from_le_bytes(unit) & !mask)
+                        | ((value as u64) << bit_field.get_offset() & mask)
+                }
+                None => value as u64,
+            },
+            Constant::Floating(value) if register_class == RegisterClass::Single => {
+                (value as f32).to_bits().into()
+            }
+            Constant::Floating(value) => value.to_bits(),
+            Constant::Address(symbol, address_offset) => {
+                addresses.insert(offset, (symbol, address_offset));
+                0
+            }
+        };
+
+        bytes.copy_from_slice(&value.to_le_bytes()[..size]);
+
+        Ok(())
+    }
+
+    fn emit_data(
+        &self,
+        data: &[u8],
+        units: &BTreeMap<usize, usize>,
+        addresses: &BTreeMap<usize, (String, i64)>,
+    ) {
+        let mut offset = 0;
+
+        for (&unit_offset, &size) in units {
+            let bytes = &data[unit_offset..unit_offset + size];
+            let address = addresses.get(&unit_offset);
+
+            if address.is_none() && bytes.iter().all(|byte| *byte == 0) {
+                continue;
+            }
+
+            if unit_offset > offset {
+                self.instruction_emitter
+                    .emit_zero((unit_offset - offset) as u64);
+            }
+
+            let mut value = [0; 8];
+            value[..size].copy_from_slice(bytes);
+            let value = u64::from_le_bytes(value);
+
+            match (address, size) {
+                (Some((symbol, address_offset)), _) => {
+                    self.instruction_emitter.emit_symbol_double_word(
+                        &self.instruction_emitter.get_symbol(symbol),
+                        *address_offset,
+                    )
+                }
+                (None, 1) => self.instruction_emitter.emit_byte(value as u8),
+                (None, 4) => self.instruction_emitter.emit_word(value as u32),
+                (None, _) => self.instruction_emitter.emit_double_word(value),
+            }
+
+            offset = unit_offset + size;
+        }
+
+        if data.len() > offset {
+            self.instruction_emitter
+                .emit_zero((data.len() - offset) as u64);
+        }
+    }
+}
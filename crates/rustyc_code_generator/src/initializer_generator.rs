@@ -0,0 +1,154 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rustyc_hir::{
+    expressions::Expression,
+    initializers::{Initializer, InitializerElement},
+};
+use rustyc_ty::{TyContext, TyId};
+
+use crate::{
+    aarch64_instruction_emitter::Aarch64InstructionEmitter, constant_pool::ConstantPool,
+    expression_generator::ExpressionGenerator, function::Function, label_allocator::LabelAllocator,
+    register_class::RegisterClass,
+};
+
+/// Initializes an automatic object at an offset from the frame pointer.
+pub struct InitializerGenerator {
+    initializer: Rc<Initializer>,
+    ty: TyId,
+    offset: i64,
+    function: Rc<Function>,
+    label_allocator: Rc<RefCell<LabelAllocator>>,
+    constant_pool: Rc<RefCell<ConstantPool>>,
+    ty_context: Rc<RefCell<TyContext>>,
+    instruction_emitter: Aarch64InstructionEmitter,
+}
+
+impl InitializerGenerator {
+    pub fn new(
+        initializer: Rc<Initializer>,
+        ty: TyId,
+        offset: i64,
+        function: Rc<Function>,
+        label_allocator: Rc<RefCell<LabelAllocator>>,
+        constant_pool: Rc<RefCell<ConstantPool>>,
+        ty_context: Rc<RefCell<TyContext>>,
+    ) -> Self {
+        let instruction_emitter = Aarch64InstructionEmitter::new(function.get_target());
+
+        Self {
+            initializer,
+            ty,
+            offset,
+            function,
+            label_allocator,
+            constant_pool,
+            ty_context,
+            instruction_emitter,
+        }
+    }
+
+    pub fn generate(self) -> rustyc_diagnostics::Result<()> {
+        match self.initializer.as_ref() {
+            Initializer::Expression(expression) => {
+                self.generate_store(Rc::clone(expression), self.offset)
+            }
+            // The bytes that no element initializes are zero.
+            Initializer::List(elements) => {
+                let size = self.ty_context.borrow().get_size(self.ty).unwrap_or(0) as i64;
+
+                self.instruction_emitter
+                    .emit_move_signed_immediate_to_register(self.offset, "x1");
+                self.instruction_emitter
+                    .emit_add_registers("fp", "x1", "x1");
+                self.instruction_emitter.emit_clear("x1", size);
+
+                elements
+                    .iter()
+                    .try_for_each(|element| self.generate_element(element))
+            }
+        }
+    }
+
+    fn generate_element(&self, element: &InitializerElement) -> rustyc_diagnostics::Result<()> {
+        let offset = self.offset + element.get_offset() as i64;
+
+        let Some(bit_field) = element.get_bit_field() else {
+            return self.generate_store(element.get_expression(), offset);
+        };
+
+        // The bits of the member are inserted into its storage unit.
+        let is_unit_byte_sized = self.ty_context.borrow().get_size(element.get_ty()) == Some(1);
+
+        self.generate_expression(element.get_expression())?;
+
+        if is_unit_byte_sized {
+            self.instruction_emitter
+                .emit_load_byte_offset("fp", offset, "w2");
+        } else {
+            self.instruction_emitter
+                .emit_load_offset("fp", offset, "x2");
+        }
+
+        self.instruction_emitter.emit_bit_field_insert(
+            "x0",
+            bit_field.get_offset(),
+            bit_field.get_width(),
+            "x2",
+        );
+
+        if is_unit_byte_sized {
+            self.instruction_emitter
+                .emit_store_byte_offset("w2", "fp", offset);
+        } else {
+            self.instruction_emitter
+                .emit_store_offset("x2", "fp", offset);
+        }
+
+        Ok(())
+    }
+
+    fn generate_store(
+        &self,
+        expression: Rc<Expression>,
+        offset: i64,
+    ) -> rustyc_diagnostics::Result<()> {
+        let ty = expression.get_ty();
+        let register_class = RegisterClass::new(ty, &self.ty_context.borrow());
+        let size = self.ty_context.borrow().get_size(ty).unwrap_or(8) as i64;
+
+        let is_copied = self.function.is_copied(ty, &self.ty_context.borrow());
+
+        self.generate_expression(expression)?;
+
+        if is_copied {
+            self.instruction_emitter
+                .emit_move_signed_immediate_to_register(offset, "x1");
+            self.instruction_emitter
+                .emit_add_registers("fp", "x1", "x1");
+            self.instruction_emitter.emit_copy("x0", "x1", size);
+        } else if size == 1 {
+            self.instruction_emitter
+                .emit_store_byte_offset("w0", "fp", offset);
+        } else {
+            self.instruction_emitter.emit_store_offset(
+                register_class.get_value_register(),
+                "fp",
+                offset,
+            );
+        }
+
+        Ok(())
+    }
+
+    fn generate_expression(&self, expression: Rc<Expression>) -> rustyc_diagnostics::Result<()> {
+        let expression_generator = ExpressionGenerator::new(
+            expression,
+            Rc::clone(&self.function),
+            Rc::clone(&self.label_allocator),
+            Rc::clone(&self.constant_pool),
+            Rc::clone(&self.ty_context),
+        );
+        expression_generator.generate()
+    }
+}
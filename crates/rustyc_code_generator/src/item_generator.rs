@@ -1,21 +1,28 @@
-use std::rc::Rc;
+use std::{cell::RefCell, rc::Rc};
 
-use rustyc_hir::{FunctionItem, Item, ItemKind};
+use rustyc_hir::items::{FunctionItem, GlobalItem, Item, ItemKind};
+use rustyc_target::Target;
+use rustyc_ty::TyContext;
 
 use crate::{
     aarch64_instruction_emitter::Aarch64InstructionEmitter, function_generator::FunctionGenerator,
+    global_generator::GlobalGenerator,
 };
 
 pub struct ItemGenerator {
     item: Rc<Item>,
+    ty_context: Rc<RefCell<TyContext>>,
+    target: Target,
     instruction_emitter: Aarch64InstructionEmitter,
 }
 
 impl ItemGenerator {
-    pub fn new(item: Rc<Item>) -> Self {
+    pub fn new(item: Rc<Item>, ty_context: Rc<RefCell<TyContext>>, target: Target) -> Self {
         Self {
             item,
-            instruction_emitter: Aarch64InstructionEmitter::new(),
+            ty_context,
+            target,
+            instruction_emitter: Aarch64InstructionEmitter::new(target),
         }
     }
 
@@ -24,11 +31,17 @@ impl ItemGenerator {
 
         match self.item.get_kind() {
             ItemKind::Function(function) => self.generate_function(Rc::clone(function)),
+            ItemKind::Global(global) => self.generate_global(Rc::clone(global)),
         }
     }
 
     fn generate_function(&self, function: Rc<FunctionItem>) -> rustyc_diagnostics::Result<()> {
-        let generator = FunctionGenerator::new(function);
+        let generator = FunctionGenerator::new(function, Rc::clone(&self.ty_context), self.target);
+        generator.generate()
+    }
+
+    fn generate_global(&self, global: Rc<GlobalItem>) -> rustyc_diagnostics::Result<()> {
+        let generator = GlobalGenerator::new(global, Rc::clone(&self.ty_context), self.target);
         generator.generate()
     }
 }
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+pub struct JumpTarget {
+    break_label: String,
+    continue_label: Option<String>,
+    saved_stack_pointer_count: usize,
+}
+
+impl JumpTarget {
+    pub fn new(break_label: String, continue_label: Option<String>) -> Self {
+        Self {
+            break_label,
+            continue_label,
+            saved_stack_pointer_count: 0,
+        }
+    }
+}
+
+pub struct SwitchTarget {
+    case_labels: HashMap<i64, String>,
+    default_label: Option<String>,
+}
+
+impl SwitchTarget {
+    pub fn new(case_labels: HashMap<i64, String>, default_label: Option<String>) -> Self {
+        Self {
+            case_labels,
+            default_label,
+        }
+    }
+}
+
+/// Tracks the jump targets and the saved stack pointers of variable length arrays.
+pub struct JumpTargetStack {
+    targets: Vec<JumpTarget>,
+    switch_targets: Vec<SwitchTarget>,
+    saved_stack_pointers: Vec<i64>,
+    label_saved_stack_pointer_counts: HashMap<String, usize>,
+}
+
+impl JumpTargetStack {
+    pub fn new(label_saved_stack_pointer_counts: HashMap<String, usize>) -> Self {
+        Self {
+            targets: Vec::new(),
+            switch_targets: Vec::new(),
+            saved_stack_pointers: Vec::new(),
+            label_saved_stack_pointer_counts,
+        }
+    }
+
+    pub fn push(&mut self, mut target: JumpTarget) {
+        target.saved_stack_pointer_count = self.saved_stack_pointers.len();
+        self.targets.push(target);
+    }
+
+    pub fn pop(&mut self) {
+        self.targets.pop();
+    }
+
+    pub fn push_switch(&mut self, target: JumpTarget, switch_target: SwitchTarget) {
+        self.push(target);
+        self.switch_targets.push(switch_target);
+    }
+
+    pub fn pop_switch(&mut self) {
+        self.targets.pop();
+        self.switch_targets.pop();
+    }
+
+    pub fn get_break_label(&self) -> Option<&str> {
+        self.targets
+            .last()
+            .map(|target| target.break_label.as_str())
+    }
+
+    pub fn get_continue_label(&self) -> Option<&str> {
+        self.targets
+            .iter()
+            .rev()
+            .find_map(|target| target.continue_label.as_deref())
+    }
+
+    pub fn get_break_saved_stack_pointer(&self) -> Option<i64> {
+        self.targets
+            .last()
+            .and_then(|target| self.get_saved_stack_pointer(target.saved_stack_pointer_count))
+    }
+
+    pub fn get_continue_saved_stack_pointer(&self) -> Option<i64> {
+        self.targets
+            .iter()
+            .rev()
+            .find(|target| target.continue_label.is_some())
+            .and_then(|target| self.get_saved_stack_pointer(target.saved_stack_pointer_count))
+    }
+
+    pub fn get_goto_saved_stack_pointer(&self, label: &str) -> Option<i64> {
+        self.label_saved_stack_pointer_counts
+            .get(label)
+            .and_then(|count| self.get_saved_stack_pointer(*count))
+    }
+
+    pub fn push_saved_stack_pointer(&mut self, offset: i64) {
+        self.saved_stack_pointers.push(offset);
+    }
+
+    pub fn get_saved_stack_pointer_count(&self) -> usize {
+        self.saved_stack_pointers.len()
+    }
+
+    pub fn truncate_saved_stack_pointers(&mut self, count: usize) -> Option<i64> {
+        let offset = self.get_saved_stack_pointer(count);
+        self.saved_stack_pointers.truncate(count);
+
+        offset
+    }
+
+    fn get_saved_stack_pointer(&self, count: usize) -> Option<i64> {
+        self.saved_stack_pointers.get(count).copied()
+    }
+
+    pub fn get_case_label(&self, value: i64) -> Option<&str> {
+        self.switch_targets
+            .last()
+            .and_then(|target| target.case_labels.get(&value))
+            .map(String::as_str)
+    }
+
+    pub fn get_default_label(&self) -> Option<&str> {
+        self.switch_targets
+            .last()
+            .and_then(|target| target.default_label.as_deref())
+    }
+}
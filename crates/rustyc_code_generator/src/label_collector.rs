@@ -0,0 +1,87 @@
+use std::{collections::HashMap, rc::Rc};
+
+use rustyc_hir::{
+    statements::{Statement, StatementKind},
+    Block,
+};
+
+/// Collects how many variable length arrays are in scope at each label.
+pub struct LabelCollector {
+    saved_stack_pointer_counts: HashMap<String, usize>,
+    variable_array_count: usize,
+}
+
+impl LabelCollector {
+    pub fn new() -> Self {
+        Self {
+            saved_stack_pointer_counts: HashMap::new(),
+            variable_array_count: 0,
+        }
+    }
+
+    pub fn collect(mut self, body: Rc<Block>) -> HashMap<String, usize> {
+        self.collect_block(body);
+        self.saved_stack_pointer_counts
+    }
+
+    fn collect_block(&mut self, block: Rc<Block>) {
+        let variable_array_count = self.variable_array_count;
+
+        for statement in block.get_statements().iter() {
+            self.collect_statement(Rc::clone(statement));
+        }
+
+        self.variable_array_count = variable_array_count;
+    }
+
+    fn collect_statement(&mut self, statement: Rc<Statement>) {
+        match statement.get_kind() {
+            StatementKind::Labeled(statement) => {
+                self.saved_stack_pointer_counts
+                    .insert(statement.get_label().to_owned(), self.variable_array_count);
+                self.collect_statement(statement.get_statement());
+            }
+            StatementKind::If(statement) => {
+                self.collect_statement(statement.get_then_statement());
+                if let Some(statement) = statement.get_else_statement() {
+                    self.collect_statement(statement);
+                }
+            }
+            StatementKind::Loop(statement) => {
+                let variable_array_count = self.variable_array_count;
+
+                if let Some(statement) = statement.get_initialization_statement() {
+                    self.collect_statement(statement);
+                }
+                self.collect_statement(statement.get_then_statement());
+
+                self.variable_array_count = variable_array_count;
+            }
+            StatementKind::DoWhile(statement) => {
+                self.collect_statement(statement.get_then_statement());
+            }
+            StatementKind::Switch(statement) => {
+                self.collect_statement(statement.get_then_statement());
+            }
+            StatementKind::Case(statement) => {
+                self.collect_statement(statement.get_statement());
+            }
+            StatementKind::Default(statement) => {
+                self.collect_statement(statement.get_statement());
+            }
+            StatementKind::Compound(statement) => self.collect_block(statement.get_block()),
+            StatementKind::Declaration(statement) => {
+                self.variable_array_count += statement
+                    .get_declarators()
+                    .iter()
+                    .filter(|declarator| declarator.get_length().is_some())
+                    .count();
+            }
+            StatementKind::Return(_)
+            | StatementKind::Goto(_)
+            | StatementKind::Break
+            | StatementKind::Continue
+            | StatementKind::Expression(_) => {}
+        }
+    }
+}
@@ -1,12 +1,21 @@
 mod aarch64_instruction_emitter;
+mod argument_location;
 mod block_generator;
 mod code_generator;
+mod constant_pool;
 mod expression_generator;
 mod function;
 mod function_generator;
+mod global_generator;
+mod initializer_generator;
 mod item_generator;
+mod jump_target_stack;
 mod label_allocator;
+mod label_collector;
+mod register_class;
+mod stack_allocator;
 mod statement_generator;
+mod switch_case_collector;
 mod variable_properties;
 
 pub use code_generator::CodeGenerator;
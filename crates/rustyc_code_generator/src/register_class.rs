@@ -0,0 +1,49 @@
+use rustyc_ty::{Ty, TyContext, TyId};
+
+/// The kind of register that holds values of a given type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegisterClass {
+    General,
+    Single,
+    Double,
+}
+
+impl RegisterClass {
+    pub fn new(ty: TyId, ty_context: &TyContext) -> Self {
+        match ty_context.get(ty) {
+            Ty::Float => Self::Single,
+            Ty::Double => Self::Double,
+            _ => Self::General,
+        }
+    }
+
+    pub fn is_floating(&self) -> bool {
+        !matches!(self, Self::General)
+    }
+
+    /// Returns the register that expressions of this class are evaluated into.
+    pub fn get_value_register(&self) -> &'static str {
+        match self {
+            Self::General => "x0",
+            Self::Single => "s0",
+            Self::Double => "d0",
+        }
+    }
+
+    /// Returns the register that holds the second operand of binary operations.
+    pub fn get_operand_register(&self) -> &'static str {
+        match self {
+            Self::General => "x1",
+            Self::Single => "s1",
+            Self::Double => "d1",
+        }
+    }
+
+    pub fn get_register(&self, index: usize) -> String {
+        match self {
+            Self::General => format!("x{index}"),
+            Self::Single => format!("s{index}"),
+            Self::Double => format!("d{index}"),
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+use rustyc_hir::{
+    expressions::{Expression, ExpressionKind},
+    initializers::Initializer,
+    items::FunctionItem,
+    statements::{Statement, StatementKind},
+    Block, LocalId,
+};
+use rustyc_ty::{Ty, TyContext};
+
+use crate::function::Function;
+
+/// Assigns stack slots to locals, letting disjoint scopes share slots.
+pub struct StackAllocator<'a> {
+    item: &'a FunctionItem,
+    ty_context: &'a TyContext,
+    slots: HashMap<LocalId, i64>,
+    offset: i64,
+    peak_offset: i64,
+}
+
+impl<'a> StackAllocator<'a> {
+    pub fn new(item: &'a FunctionItem, ty_context: &'a TyContext) -> Self {
+        Self {
+            item,
+            ty_context,
+            slots: HashMap::new(),
+            offset: 0,
+            peak_offset: 0,
+        }
+    }
+
+    /// Returns the slot offsets and the number of bytes the slots occupy.
+    pub fn finish(self) -> (HashMap<LocalId, i64>, i64) {
+        (self.slots, self.peak_offset)
+    }
+
+    pub fn allocate(&mut self, id: LocalId) {
+        let ty = self.item.get_local(id).get_ty();
+
+        let size = match self.ty_context.get(ty) {
+            Ty::VariableArray(_) => 24,
+            _ => self.ty_context.get_size(ty).unwrap_or(8).max(8) as i64,
+        };
+
+        self.slots.insert(id, self.offset);
+        self.offset += Function::align_to(size, 8);
+        self.peak_offset = self.peak_offset.max(self.offset);
+    }
+
+    pub fn allocate_statement(&mut self, statement: &Statement) {
+        match statement.get_kind() {
+            StatementKind::Declaration(statement) => {
+                for declarator in statement.get_declarators().iter() {
+                    if let Some(length) = declarator.get_length() {
+                        self.allocate_expression(&length);
+                    }
+
+                    self.allocate(declarator.get_local_id());
+
+                    if let Some(initializer) = declarator.get_initializer() {
+                        self.allocate_initializer(&initializer);
+                    }
+                }
+            }
+            StatementKind::Compound(statement) => self.allocate_block(&statement.get_block()),
+            StatementKind::If(statement) => {
+                self.allocate_expression(&statement.get_condition_expression());
+                self.allocate_scoped_statement(&statement.get_then_statement());
+                if let Some(statement) = statement.get_else_statement() {
+                    self.allocate_scoped_statement(&statement);
+                }
+            }
+            StatementKind::Loop(statement) => {
+                self.allocate_scoped(|stack_allocator| {
+                    if let Some(statement) = statement.get_initialization_statement() {
+                        stack_allocator.allocate_statement(&statement);
+                    }
+                    if let Some(expression) = statement.get_condition_expression() {
+                        stack_allocator.allocate_expression(&expression);
+                    }
+                    if let Some(expression) = statement.get_incrementation_expression() {
+                        stack_allocator.allocate_expression(&expression);
+                    }
+                    stack_allocator.allocate_scoped_statement(&statement.get_then_statement());
+                });
+            }
+            StatementKind::DoWhile(statement) => {
+                self.allocate_scoped_statement(&statement.get_then_statement());
+                self.allocate_expression(&statement.get_condition_expression());
+            }
+            StatementKind::Switch(statement) => {
+                self.allocate_expression(&statement.get_condition_expression());
+                self.allocate_scoped_statement(&statement.get_then_statement());
+            }
+            StatementKind::Case(statement) => self.allocate_statement(&statement.get_statement()),
+            StatementKind::Default(statement) => {
+                self.allocate_statement(&statement.get_statement())
+            }
+            StatementKind::Labeled(statement) => {
+                self.allocate_statement(&statement.get_statement())
+            }
+            StatementKind::Return(statement) => {
+                if let Some(expression) = statement.get_expression() {
+                    self.allocate_expression(&expression);
+                }
+            }
+            StatementKind::Expression(statement) => {
+                self.allocate_expression(&statement.get_expression())
+            }
+            StatementKind::Goto(_) | StatementKind::Break | StatementKind::Continue => {}
+        }
+    }
+
+    fn allocate_initializer(&mut self, initializer: &Initializer) {
+        match initializer {
+            Initializer::Expression(expression) => self.allocate_expression(expression),
+            Initializer::List(elements) => {
+                for element in elements {
+                    self.allocate_expression(&element.get_expression());
+                }
+            }
+        }
+    }
+
+    fn allocate_expression(&mut self, expression: &Expression) {
+        match expression.get_kind() {
+            ExpressionKind::CompoundLiteral(expression) => {
+                self.allocate_initializer(&expression.get_initializer());
+                self.allocate(expression.get_local_id());
+            }
+            ExpressionKind::Statement(expression) => self.allocate_block(&expression.get_block()),
+            ExpressionKind::Assignment(expression) => {
+                self.allocate_expression(&expression.get_left());
+                self.allocate_expression(&expression.get_right());
+            }
+            ExpressionKind::Binary(expression) => {
+                self.allocate_expression(&expression.get_left());
+                self.allocate_expression(&expression.get_right());
+            }
+            ExpressionKind::Unary(expression) => {
+                self.allocate_expression(&expression.get_operand())
+            }
+            ExpressionKind::Member(expression) => self.allocate_expression(&expression.get_base()),
+            ExpressionKind::Cast(expression) => self.allocate_expression(&expression.get_operand()),
+            ExpressionKind::FunctionCall(expression) => {
+                self.allocate_expression(&expression.get_callee());
+                for argument in expression.get_arguments().iter() {
+                    self.allocate_expression(argument);
+                }
+            }
+            ExpressionKind::VaStart(expression) => {
+                self.allocate_expression(&expression.get_list());
+                self.allocate_expression(&expression.get_last_parameter());
+            }
+            ExpressionKind::VaArg(expression) => self.allocate_expression(&expression.get_list()),
+            ExpressionKind::VaEnd(expression) => self.allocate_expression(&expression.get_list()),
+            ExpressionKind::SizeOf(expression) => {
+                self.allocate_expression(&expression.get_operand())
+            }
+            ExpressionKind::Alloca(expression) => self.allocate_expression(&expression.get_size()),
+            ExpressionKind::Variable(_)
+            | ExpressionKind::Function(_)
+            | ExpressionKind::Global(_)
+            | ExpressionKind::Number(_)
+            | ExpressionKind::FloatNumber(_) => {}
+        }
+    }
+
+    fn allocate_block(&mut self, block: &Block) {
+        self.allocate_scoped(|stack_allocator| {
+            for statement in block.get_statements().iter() {
+                stack_allocator.allocate_statement(statement);
+            }
+        });
+    }
+
+    fn allocate_scoped_statement(&mut self, statement: &Statement) {
+        self.allocate_scoped(|stack_allocator| stack_allocator.allocate_statement(statement));
+    }
+
+    fn allocate_scoped(&mut self, allocate: impl FnOnce(&mut Self)) {
+        let offset = self.offset;
+        allocate(self);
+        self.offset = offset;
+    }
+}
@@ -1,67 +1,93 @@
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use rustyc_hir::{Block, Expression, Statement, StatementKind};
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{
+    expressions::Expression,
+    statements::{
+        CaseStatement, DeclarationStatement, Declarator, DefaultStatement, DoWhileStatement,
+        GotoStatement, IfStatement, LabeledStatement, LoopStatement, ReturnStatement, Statement,
+        StatementKind, SwitchStatement,
+    },
+    Block, ConstantEvaluator,
+};
+use rustyc_ty::{Ty, TyContext};
 
 use crate::{
-    aarch64_instruction_emitter::Aarch64InstructionEmitter, block_generator::BlockGenerator,
-    expression_generator::ExpressionGenerator, label_allocator::LabelAllocator,
-    variable_properties::VariableProperties,
+    aarch64_instruction_emitter::Aarch64InstructionEmitter,
+    block_generator::BlockGenerator,
+    constant_pool::ConstantPool,
+    expression_generator::ExpressionGenerator,
+    function::Function,
+    initializer_generator::InitializerGenerator,
+    jump_target_stack::{JumpTarget, JumpTargetStack, SwitchTarget},
+    label_allocator::LabelAllocator,
+    register_class::RegisterClass,
+    switch_case_collector::SwitchCaseCollector,
 };
 
+/// Switches with at least this many cases are candidates for a jump table.
+const JUMP_TABLE_MIN_CASES: usize = 4;
+/// Minimum case coverage of the value range for emitting a jump table.
+const JUMP_TABLE_MIN_DENSITY_PERCENT: i128 = 40;
+
 pub struct StatementGenerator {
     statement: Rc<Statement>,
-    local_variables: Rc<HashMap<String, VariableProperties>>,
+    function: Rc<Function>,
     label_allocator: Rc<RefCell<LabelAllocator>>,
+    jump_target_stack: Rc<RefCell<JumpTargetStack>>,
+    constant_pool: Rc<RefCell<ConstantPool>>,
+    ty_context: Rc<RefCell<TyContext>>,
     instruction_emitter: Aarch64InstructionEmitter,
 }
 
 impl StatementGenerator {
     pub fn new(
         statement: Rc<Statement>,
-        local_variables: Rc<HashMap<String, VariableProperties>>,
+        function: Rc<Function>,
         label_allocator: Rc<RefCell<LabelAllocator>>,
+        jump_target_stack: Rc<RefCell<JumpTargetStack>>,
+        constant_pool: Rc<RefCell<ConstantPool>>,
+        ty_context: Rc<RefCell<TyContext>>,
     ) -> Self {
+        let instruction_emitter = Aarch64InstructionEmitter::new(function.get_target());
+
         Self {
             statement,
-            local_variables,
+            function,
             label_allocator,
-            instruction_emitter: Aarch64InstructionEmitter::new(),
+            jump_target_stack,
+            constant_pool,
+            ty_context,
+            instruction_emitter,
         }
     }
 
     pub fn generate(self) -> rustyc_diagnostics::Result<()> {
         match self.statement.get_kind() {
-            StatementKind::Return(expression) => self.generate_return(Rc::clone(expression)),
-            StatementKind::If(condition_expression, then_statement, else_statement) => self
-                .generate_if(
-                    Rc::clone(condition_expression),
-                    Rc::clone(then_statement),
-                    else_statement.as_ref().map(|value| Rc::clone(value)),
-                ),
-            StatementKind::Loop(
-                initialization_statement,
-                condition_expression,
-                incrementation_expression,
-                then_statement,
-            ) => self.generate_loop(
-                initialization_statement
-                    .as_ref()
-                    .map(|value| Rc::clone(value)),
-                condition_expression.as_ref().map(|value| Rc::clone(value)),
-                incrementation_expression
-                    .as_ref()
-                    .map(|value| Rc::clone(value)),
-                Rc::clone(then_statement),
-            ),
-            StatementKind::Compound(block) => self.generate_compound(Rc::clone(block)),
-            StatementKind::Expression(expression) => {
-                self.generate_expression(Rc::clone(expression))
+            StatementKind::Return(statement) => self.generate_return(statement),
+            StatementKind::If(statement) => self.generate_if(statement),
+            StatementKind::Loop(statement) => self.generate_loop(statement),
+            StatementKind::DoWhile(statement) => self.generate_do_while(statement),
+            StatementKind::Switch(statement) => self.generate_switch(statement),
+            StatementKind::Case(statement) => self.generate_case(statement),
+            StatementKind::Default(statement) => self.generate_default(statement),
+            StatementKind::Labeled(statement) => self.generate_labeled(statement),
+            StatementKind::Goto(statement) => self.generate_goto(statement),
+            StatementKind::Break => self.generate_break(),
+            StatementKind::Continue => self.generate_continue(),
+            StatementKind::Compound(statement) => self.generate_compound(statement.get_block()),
+            StatementKind::Declaration(statement) => self.generate_declaration(statement),
+            StatementKind::Expression(statement) => {
+                self.generate_expression(statement.get_expression())
             }
         }
     }
 
-    fn generate_return(&self, expression: Rc<Expression>) -> rustyc_diagnostics::Result<()> {
-        self.generate_expression(expression)?;
+    fn generate_return(&self, statement: &ReturnStatement) -> rustyc_diagnostics::Result<()> {
+        statement
+            .get_expression()
+            .map(|expression| self.generate_expression(expression))
+            .transpose()?;
         self.instruction_emitter.emit_branch(
             self.label_allocator
                 .borrow()
@@ -72,35 +98,19 @@ impl StatementGenerator {
         Ok(())
     }
 
-    fn generate_if(
-        &self,
-        condition_expression: Rc<Expression>,
-        then_statement: Rc<Statement>,
-        else_statement: Option<Rc<Statement>>,
-    ) -> rustyc_diagnostics::Result<()> {
+    fn generate_if(&self, statement: &IfStatement) -> rustyc_diagnostics::Result<()> {
         let else_label = self.label_allocator.borrow_mut().allocate_unique("else");
         let end_label = self.label_allocator.borrow_mut().allocate_unique("end");
 
-        self.generate_expression(condition_expression)?;
-        self.instruction_emitter.emit_comparison("x0", "#0");
+        self.generate_condition(statement.get_condition_expression())?;
         self.instruction_emitter.emit_branch_equals(&else_label);
 
-        let then_statement_generator = Self::new(
-            then_statement,
-            Rc::clone(&self.local_variables),
-            Rc::clone(&self.label_allocator),
-        );
-        then_statement_generator.generate()?;
+        self.generate_statement(statement.get_then_statement())?;
         self.instruction_emitter.emit_branch(&end_label);
 
         self.instruction_emitter.emit_label(&else_label);
-        if let Some(statement) = else_statement {
-            let else_statement_generator = Self::new(
-                statement,
-                Rc::clone(&self.local_variables),
-                Rc::clone(&self.label_allocator),
-            );
-            else_statement_generator.generate()?;
+        if let Some(statement) = statement.get_else_statement() {
+            self.generate_statement(statement)?;
         }
 
         self.instruction_emitter.emit_label(&end_label);
@@ -108,41 +118,41 @@ impl StatementGenerator {
         Ok(())
     }
 
-    fn generate_loop(
-        &self,
-        initialization_statement: Option<Rc<Statement>>,
-        condition_expression: Option<Rc<Expression>>,
-        incrementation_expression: Option<Rc<Expression>>,
-        then_statement: Rc<Statement>,
-    ) -> rustyc_diagnostics::Result<()> {
+    fn generate_loop(&self, statement: &LoopStatement) -> rustyc_diagnostics::Result<()> {
         let begin_label = self.label_allocator.borrow_mut().allocate_unique("begin");
+        let continue_label = self
+            .label_allocator
+            .borrow_mut()
+            .allocate_unique("continue");
         let end_label = self.label_allocator.borrow_mut().allocate_unique("end");
 
-        if let Some(statement) = initialization_statement {
-            let initialization_statement_generator = Self::new(
-                statement,
-                Rc::clone(&self.local_variables),
-                Rc::clone(&self.label_allocator),
-            );
-            initialization_statement_generator.generate()?;
+        let saved_stack_pointer_count = self
+            .jump_target_stack
+            .borrow()
+            .get_saved_stack_pointer_count();
+        let temporaries_size = self.function.get_temporaries_size();
+
+        if let Some(initialization_statement) = statement.get_initialization_statement() {
+            self.generate_statement(initialization_statement)?;
         }
 
         self.instruction_emitter.emit_label(&begin_label);
 
-        if let Some(expression) = condition_expression {
-            self.generate_expression(expression)?;
-            self.instruction_emitter.emit_comparison("x0", "#0");
+        if let Some(expression) = statement.get_condition_expression() {
+            self.generate_condition(expression)?;
             self.instruction_emitter.emit_branch_equals(&end_label);
         }
 
-        let then_statement_generator = Self::new(
-            then_statement,
-            Rc::clone(&self.local_variables),
-            Rc::clone(&self.label_allocator),
-        );
-        then_statement_generator.generate()?;
+        self.jump_target_stack.borrow_mut().push(JumpTarget::new(
+            end_label.clone(),
+            Some(continue_label.clone()),
+        ));
+        self.generate_statement(statement.get_then_statement())?;
+        self.jump_target_stack.borrow_mut().pop();
 
-        if let Some(expression) = incrementation_expression {
+        self.instruction_emitter.emit_label(&continue_label);
+
+        if let Some(expression) = statement.get_incrementation_expression() {
             self.generate_expression(expression)?;
         }
 
@@ -150,21 +160,416 @@ impl StatementGenerator {
 
         self.instruction_emitter.emit_label(&end_label);
 
+        // The initialization statement may declare variable length arrays
+        // that live as long as the loop.
+        let saved_stack_pointer = self
+            .jump_target_stack
+            .borrow_mut()
+            .truncate_saved_stack_pointers(saved_stack_pointer_count);
+        self.generate_restore_stack_pointer(saved_stack_pointer);
+        self.function.set_temporaries_size(temporaries_size);
+
+        Ok(())
+    }
+
+    fn generate_do_while(&self, statement: &DoWhileStatement) -> rustyc_diagnostics::Result<()> {
+        let begin_label = self.label_allocator.borrow_mut().allocate_unique("begin");
+        let continue_label = self
+            .label_allocator
+            .borrow_mut()
+            .allocate_unique("continue");
+        let end_label = self.label_allocator.borrow_mut().allocate_unique("end");
+
+        self.instruction_emitter.emit_label(&begin_label);
+
+        self.jump_target_stack.borrow_mut().push(JumpTarget::new(
+            end_label.clone(),
+            Some(continue_label.clone()),
+        ));
+        self.generate_statement(statement.get_then_statement())?;
+        self.jump_target_stack.borrow_mut().pop();
+
+        self.instruction_emitter.emit_label(&continue_label);
+
+        self.generate_condition(statement.get_condition_expression())?;
+        self.instruction_emitter
+            .emit_branch_not_equals(&begin_label);
+
+        self.instruction_emitter.emit_label(&end_label);
+
+        Ok(())
+    }
+
+    fn generate_switch(&self, statement: &SwitchStatement) -> rustyc_diagnostics::Result<()> {
+        let switch_case_collector = SwitchCaseCollector::new(Rc::clone(&self.ty_context))
+            .collect(statement.get_then_statement())?;
+
+        let case_labels: HashMap<i64, String> = switch_case_collector
+            .get_case_values()
+            .iter()
+            .map(|value| {
+                (
+                    *value,
+                    self.label_allocator.borrow_mut().allocate_unique("case"),
+                )
+            })
+            .collect();
+        let default_label = switch_case_collector
+            .has_default()
+            .then(|| self.label_allocator.borrow_mut().allocate_unique("default"));
+        let end_label = self.label_allocator.borrow_mut().allocate_unique("end");
+
+        let fallback_label = default_label.as_deref().unwrap_or(&end_label);
+
+        self.generate_expression(statement.get_condition_expression())?;
+
+        let mut case_values = switch_case_collector.get_case_values().to_vec();
+        case_values.sort_unstable();
+
+        if Self::should_use_jump_table(&case_values) {
+            self.generate_jump_table_dispatch(&case_values, &case_labels, fallback_label);
+        } else {
+            self.generate_compare_chain_dispatch(&case_values, &case_labels, fallback_label);
+        }
+
+        self.jump_target_stack.borrow_mut().push_switch(
+            JumpTarget::new(end_label.clone(), None),
+            SwitchTarget::new(case_labels, default_label),
+        );
+        self.generate_statement(statement.get_then_statement())?;
+        self.jump_target_stack.borrow_mut().pop_switch();
+
+        self.instruction_emitter.emit_label(&end_label);
+
+        Ok(())
+    }
+
+    fn should_use_jump_table(sorted_case_values: &[i64]) -> bool {
+        let (Some(minimum), Some(maximum)) =
+            (sorted_case_values.first(), sorted_case_values.last())
+        else {
+            return false;
+        };
+
+        let range = i128::from(*maximum) - i128::from(*minimum) + 1;
+
+        sorted_case_values.len() >= JUMP_TABLE_MIN_CASES
+            && (sorted_case_values.len() as i128) * 100 >= range * JUMP_TABLE_MIN_DENSITY_PERCENT
+    }
+
+    fn generate_compare_chain_dispatch(
+        &self,
+        case_values: &[i64],
+        case_labels: &HashMap<i64, String>,
+        fallback_label: &str,
+    ) {
+        for value in case_values {
+            self.instruction_emitter
+                .emit_move_signed_immediate_to_register(*value, "x1");
+            self.instruction_emitter.emit_comparison("x0", "x1");
+            self.instruction_emitter
+                .emit_branch_equals(&case_labels[value]);
+        }
+
+        self.instruction_emitter.emit_branch(fallback_label);
+    }
+
+    /// Emits a bounds check and an indirect branch through a table of label offsets.
+    fn generate_jump_table_dispatch(
+        &self,
+        sorted_case_values: &[i64],
+        case_labels: &HashMap<i64, String>,
+        fallback_label: &str,
+    ) {
+        let minimum = sorted_case_values[0];
+        let maximum = sorted_case_values[sorted_case_values.len() - 1];
+
+        let table_label = self
+            .label_allocator
+            .borrow_mut()
+            .allocate_unique("jump_table");
+
+        self.instruction_emitter
+            .emit_move_signed_immediate_to_register(minimum, "x1");
+        self.instruction_emitter.emit_subtract("x0", "x1", "x0");
+        self.instruction_emitter
+            .emit_move_signed_immediate_to_register(maximum.wrapping_sub(minimum), "x1");
+        self.instruction_emitter.emit_comparison("x0", "x1");
+        self.instruction_emitter.emit_branch_higher(fallback_label);
+
+        self.instruction_emitter
+            .emit_load_label_address(&table_label, "x1");
+        self.instruction_emitter
+            .emit_load_signed_word_indexed("x1", "x0", "x2");
+        self.instruction_emitter
+            .emit_add_registers("x1", "x2", "x1");
+        self.instruction_emitter.emit_branch_register("x1");
+
+        self.instruction_emitter
+            .emit_read_only_data_section_directive();
+        self.instruction_emitter.emit_alignment(2);
+        self.instruction_emitter.emit_label(&table_label);
+        for value in minimum..=maximum {
+            let label = case_labels
+                .get(&value)
+                .map(String::as_str)
+                .unwrap_or(fallback_label);
+            self.instruction_emitter
+                .emit_relative_word(label, &table_label);
+        }
+        self.instruction_emitter.emit_text_section_directive();
+    }
+
+    fn generate_case(&self, statement: &CaseStatement) -> rustyc_diagnostics::Result<()> {
+        let expression = statement.get_expression();
+        let constant_evaluator =
+            ConstantEvaluator::new(Rc::clone(&expression), Rc::clone(&self.ty_context));
+        let value = constant_evaluator.evaluate_integer()?;
+
+        let label = self
+            .jump_target_stack
+            .borrow()
+            .get_case_label(value)
+            .map(str::to_owned)
+            .ok_or_else(|| {
+                Diagnostic::new_error(
+                    rustyc_diagnostics::Error::CaseOutsideSwitch,
+                    self.statement.get_span().clone(),
+                )
+            })?;
+
+        self.instruction_emitter.emit_label(&label);
+
+        self.generate_statement(statement.get_statement())
+    }
+
+    fn generate_default(&self, statement: &DefaultStatement) -> rustyc_diagnostics::Result<()> {
+        let label = self
+            .jump_target_stack
+            .borrow()
+            .get_default_label()
+            .map(str::to_owned)
+            .ok_or_else(|| {
+                Diagnostic::new_error(
+                    rustyc_diagnostics::Error::DefaultOutsideSwitch,
+                    self.statement.get_span().clone(),
+                )
+            })?;
+
+        self.instruction_emitter.emit_label(&label);
+
+        self.generate_statement(statement.get_statement())
+    }
+
+    fn generate_labeled(&self, statement: &LabeledStatement) -> rustyc_diagnostics::Result<()> {
+        self.instruction_emitter
+            .emit_label(&self.allocate_user_label(statement.get_label()));
+
+        self.generate_statement(statement.get_statement())
+    }
+
+    fn generate_goto(&self, statement: &GotoStatement) -> rustyc_diagnostics::Result<()> {
+        self.generate_restore_stack_pointer(
+            self.jump_target_stack
+                .borrow()
+                .get_goto_saved_stack_pointer(statement.get_label()),
+        );
+        self.instruction_emitter
+            .emit_branch(&self.allocate_user_label(statement.get_label()));
+
+        Ok(())
+    }
+
+    /// User labels get their own namespace so they cannot clash with internal labels.
+    fn allocate_user_label(&self, label: &str) -> String {
+        self.label_allocator
+            .borrow()
+            .allocate_global(&format!("label.{label}"))
+    }
+
+    fn generate_break(&self) -> rustyc_diagnostics::Result<()> {
+        let jump_target_stack = self.jump_target_stack.borrow();
+        let label = jump_target_stack.get_break_label().ok_or_else(|| {
+            Diagnostic::new_error(
+                rustyc_diagnostics::Error::BreakOutsideLoop,
+                self.statement.get_span().clone(),
+            )
+        })?;
+
+        self.generate_restore_stack_pointer(jump_target_stack.get_break_saved_stack_pointer());
+        self.instruction_emitter.emit_branch(label);
+
+        Ok(())
+    }
+
+    fn generate_continue(&self) -> rustyc_diagnostics::Result<()> {
+        let jump_target_stack = self.jump_target_stack.borrow();
+        let label = jump_target_stack.get_continue_label().ok_or_else(|| {
+            Diagnostic::new_error(
+                rustyc_diagnostics::Error::ContinueOutsideLoop,
+                self.statement.get_span().clone(),
+            )
+        })?;
+
+        self.generate_restore_stack_pointer(jump_target_stack.get_continue_saved_stack_pointer());
+        self.instruction_emitter.emit_branch(label);
+
         Ok(())
     }
 
     fn generate_compound(&self, block: Rc<Block>) -> rustyc_diagnostics::Result<()> {
         let block_generator = BlockGenerator::new(
             block,
-            Rc::clone(&self.local_variables),
+            Rc::clone(&self.function),
             Rc::clone(&self.label_allocator),
+            Rc::clone(&self.jump_target_stack),
+            Rc::clone(&self.constant_pool),
+            Rc::clone(&self.ty_context),
         );
         block_generator.generate()
     }
 
+    fn generate_declaration(
+        &self,
+        statement: &DeclarationStatement,
+    ) -> rustyc_diagnostics::Result<()> {
+        for declarator in statement.get_declarators().iter() {
+            if let Some(length) = declarator.get_length() {
+                self.generate_variable_array(declarator, length)?;
+            }
+
+            if let Some(initializer) = declarator.get_initializer() {
+                let initializer_generator = InitializerGenerator::new(
+                    initializer,
+                    self.function
+                        .get_item()
+                        .get_local(declarator.get_local_id())
+                        .get_ty(),
+                    self.get_declarator_offset(declarator)?,
+                    Rc::clone(&self.function),
+                    Rc::clone(&self.label_allocator),
+                    Rc::clone(&self.constant_pool),
+                    Rc::clone(&self.ty_context),
+                );
+                initializer_generator.generate()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Saves the stack pointer, then allocates the array below it.
+    fn generate_variable_array(
+        &self,
+        declarator: &Declarator,
+        length: Rc<Expression>,
+    ) -> rustyc_diagnostics::Result<()> {
+        let offset = self.get_declarator_offset(declarator)?;
+
+        let element_size = {
+            let ty_context = self.ty_context.borrow();
+            let ty = self
+                .function
+                .get_item()
+                .get_local(declarator.get_local_id())
+                .get_ty();
+
+            match ty_context.get(ty) {
+                Ty::VariableArray(element_ty) => ty_context.get_size(*element_ty),
+                _ => None,
+            }
+            .unwrap_or(1)
+        };
+
+        self.instruction_emitter.emit_move_registers("sp", "x9");
+        self.instruction_emitter
+            .emit_store_offset("x9", "fp", offset + 16);
+
+        self.generate_expression(length)?;
+
+        self.instruction_emitter
+            .emit_move_signed_immediate_to_register(element_size as i64, "x1");
+        self.instruction_emitter.emit_multiply("x0", "x1", "x0");
+        self.instruction_emitter
+            .emit_store_offset("x0", "fp", offset + 8);
+
+        self.instruction_emitter.emit_add_immediate("x0", 15, "x0");
+        self.instruction_emitter.emit_and_immediate("x0", -16, "x0");
+        self.instruction_emitter.emit_subtract("sp", "x0", "sp");
+        self.instruction_emitter.emit_move_registers("sp", "x0");
+        self.instruction_emitter
+            .emit_store_offset("x0", "fp", offset);
+
+        self.jump_target_stack
+            .borrow_mut()
+            .push_saved_stack_pointer(offset + 16);
+        self.function.set_temporaries_size(0);
+
+        Ok(())
+    }
+
+    fn generate_restore_stack_pointer(&self, saved_stack_pointer: Option<i64>) {
+        if let Some(offset) = saved_stack_pointer {
+            self.instruction_emitter
+                .emit_load_offset("fp", offset, "x9");
+            self.instruction_emitter.emit_move_registers("x9", "sp");
+        }
+    }
+
+    fn get_declarator_offset(&self, declarator: &Declarator) -> rustyc_diagnostics::Result<i64> {
+        self.function
+            .get_local_variables()
+            .get(&declarator.get_local_id())
+            .map(|variable| variable.get_offset())
+            .ok_or_else(|| {
+                Diagnostic::new_error(
+                    rustyc_diagnostics::Error::UnallocatedVariable,
+                    self.statement.get_span().clone(),
+                )
+            })
+    }
+
+    fn generate_statement(&self, statement: Rc<Statement>) -> rustyc_diagnostics::Result<()> {
+        let statement_generator = Self::new(
+            statement,
+            Rc::clone(&self.function),
+            Rc::clone(&self.label_allocator),
+            Rc::clone(&self.jump_target_stack),
+            Rc::clone(&self.constant_pool),
+            Rc::clone(&self.ty_context),
+        );
+        statement_generator.generate()
+    }
+
+    /// Evaluates a controlling expression, setting the flags to equal when it is false.
+    fn generate_condition(&self, expression: Rc<Expression>) -> rustyc_diagnostics::Result<()> {
+        let register_class = self.get_register_class(&expression);
+
+        self.generate_expression(expression)?;
+
+        if register_class.is_floating() {
+            self.instruction_emitter
+                .emit_floating_comparison(register_class.get_value_register(), "#0.0");
+        } else {
+            self.instruction_emitter
+                .emit_comparison(register_class.get_value_register(), "#0");
+        }
+
+        Ok(())
+    }
+
+    fn get_register_class(&self, expression: &Expression) -> RegisterClass {
+        RegisterClass::new(expression.get_ty(), &self.ty_context.borrow())
+    }
+
     fn generate_expression(&self, expression: Rc<Expression>) -> rustyc_diagnostics::Result<()> {
-        let expression_generator =
-            ExpressionGenerator::new(expression, Rc::clone(&self.local_variables));
+        let expression_generator = ExpressionGenerator::new(
+            expression,
+            Rc::clone(&self.function),
+            Rc::clone(&self.label_allocator),
+            Rc::clone(&self.constant_pool),
+            Rc::clone(&self.ty_context),
+        );
         expression_generator.generate()
     }
 }
@@ -0,0 +1,84 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rustyc_hir::{
+    statements::{Statement, StatementKind},
+    ConstantEvaluator,
+};
+use rustyc_ty::TyContext;
+
+/// Collects the `case` values and `default` label of a switch body.
+pub struct SwitchCaseCollector {
+    case_values: Vec<i64>,
+    has_default: bool,
+    ty_context: Rc<RefCell<TyContext>>,
+}
+
+impl SwitchCaseCollector {
+    pub fn new(ty_context: Rc<RefCell<TyContext>>) -> Self {
+        Self {
+            case_values: Vec::new(),
+            has_default: false,
+            ty_context,
+        }
+    }
+
+    pub fn collect(mut self, statement: Rc<Statement>) -> rustyc_diagnostics::Result<Self> {
+        self.collect_statement(statement)?;
+        Ok(self)
+    }
+
+    pub fn get_case_values(&self) -> &[i64] {
+        &self.case_values
+    }
+
+    pub fn has_default(&self) -> bool {
+        self.has_default
+    }
+
+    fn collect_statement(&mut self, statement: Rc<Statement>) -> rustyc_diagnostics::Result<()> {
+        match statement.get_kind() {
+            StatementKind::If(statement) => {
+                self.collect_statement(statement.get_then_statement())?;
+                if let Some(statement) = statement.get_else_statement() {
+                    self.collect_statement(statement)?;
+                }
+            }
+            StatementKind::Loop(statement) => {
+                self.collect_statement(statement.get_then_statement())?;
+            }
+            StatementKind::DoWhile(statement) => {
+                self.collect_statement(statement.get_then_statement())?;
+            }
+            StatementKind::Case(statement) => {
+                let expression = statement.get_expression();
+                let constant_evaluator =
+                    ConstantEvaluator::new(Rc::clone(&expression), Rc::clone(&self.ty_context));
+                let value = constant_evaluator.evaluate_integer()?;
+
+                self.case_values.push(value);
+                self.collect_statement(statement.get_statement())?;
+            }
+            StatementKind::Default(statement) => {
+                self.has_default = true;
+                self.collect_statement(statement.get_statement())?;
+            }
+            StatementKind::Labeled(statement) => {
+                self.collect_statement(statement.get_statement())?;
+            }
+            StatementKind::Compound(statement) => {
+                for statement in statement.get_block().get_statements().iter() {
+                    self.collect_statement(Rc::clone(statement))?;
+                }
+            }
+            StatementKind::Switch(_)
+            | StatementKind::Return(_)
+            | StatementKind::Goto(_)
+            | StatementKind::Break
+            | StatementKind::Continue
+            | StatementKind::Declaration(_)
+            | StatementKind::Expression(_) => {}
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use rustyc_span::Span;
 
-use crate::{diagnostic_kind::DiagnosticKind, error};
+use crate::{diagnostic_kind::DiagnosticKind, error, warning};
 
 #[derive(Debug)]
 pub struct Diagnostic {
@@ -16,6 +16,13 @@ impl Diagnostic {
         }
     }
 
+    pub fn new_warning(w: warning::Warning, span: Span) -> Self {
+        Self {
+            kind: DiagnosticKind::Warning(w),
+            span,
+        }
+    }
+
     pub fn get_kind(&self) -> &DiagnosticKind {
         &self.kind
     }
@@ -1,16 +1,18 @@
-use std::error::Error;
+use std::{cell::RefCell, error::Error, rc::Rc};
 
-use crate::{diagnostic_kind::DiagnosticKind, error, Diagnostic};
+use rustyc_span::SourceMap;
+
+use crate::{diagnostic_kind::DiagnosticKind, error, warning, Diagnostic};
 
 pub struct DiagnosticEmitter {
-    source: String,
+    source_map: Rc<RefCell<SourceMap>>,
     error_count: u32,
 }
 
 impl DiagnosticEmitter {
-    pub fn new(source: String) -> Self {
+    pub fn new(source_map: Rc<RefCell<SourceMap>>) -> Self {
         Self {
-            source,
+            source_map,
             error_count: 0,
         }
     }
@@ -22,6 +24,7 @@ impl DiagnosticEmitter {
     pub fn emit(&mut self, diagnostic: Diagnostic) {
         match diagnostic.get_kind() {
             DiagnosticKind::Error(e) => self.emit_error_diagnostic(&diagnostic, e),
+            DiagnosticKind::Warning(w) => self.emit_warning_diagnostic(&diagnostic, w),
         }
     }
 
@@ -32,15 +35,38 @@ impl DiagnosticEmitter {
         }
         eprintln!("{}", error_message);
 
-        eprintln!("{}", self.source);
+        self.emit_source_snippet(diagnostic);
+
+        self.error_count += 1;
+    }
+
+    fn emit_warning_diagnostic(&self, diagnostic: &Diagnostic, w: &warning::Warning) {
+        eprintln!("warning: {}", w);
+
+        self.emit_source_snippet(diagnostic);
+    }
 
+    fn emit_source_snippet(&self, diagnostic: &Diagnostic) {
         let span = diagnostic.get_span();
+        let source_map = self.source_map.borrow();
+
+        let (Some(file), Some((file_name, line_number))) = (
+            source_map.lookup_file(span.get_low()),
+            source_map.lookup_presumed_line(span.get_low()),
+        ) else {
+            return;
+        };
+
+        let line_index = file.get_line_index(span.get_low());
+        let (line_start, line_end) = file.get_line_bounds(line_index);
+        let column = span.get_low() - line_start;
+
+        eprintln!("  --> {}:{}:{}", file_name, line_number, column + 1);
+        eprintln!("{}", file.get_line(line_index));
         eprintln!(
             "{}{}",
-            " ".repeat(span.get_low()),
-            "^".repeat(span.get_high() - span.get_low())
+            " ".repeat(column),
+            "^".repeat(span.get_high().min(line_end) - span.get_low())
         );
-
-        self.error_count += 1;
     }
 }
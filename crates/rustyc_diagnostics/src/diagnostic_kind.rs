@@ -1,6 +1,7 @@
-use crate::error;
+use crate::{error, warning};
 
 #[derive(Debug)]
 pub enum DiagnosticKind {
     Error(error::Error),
+    Warning(warning::Warning),
 }
@@ -7,9 +7,21 @@ pub enum Error {
     #[error("failed parsing number")]
     ParseNumber(#[source] <u64 as FromStr>::Err),
 
+    #[error("failed parsing floating number")]
+    ParseFloatNumber(#[source] <f64 as FromStr>::Err),
+
+    #[error("exponent has no digits")]
+    ExponentWithoutDigits,
+
+    #[error("hexadecimal floating constant requires an exponent")]
+    HexadecimalFloatNumberWithoutExponent,
+
     #[error("unknown token start")]
     UnknownTokenStart,
 
+    #[error("missing terminating `\"` character")]
+    UnterminatedString,
+
     #[error("unexpected token `{0}`, expected: {1}")]
     UnexpectedTokenSingle(TokenKind, TokenCategory),
 
@@ -42,4 +54,346 @@ pub enum Error {
 
     #[error("invalid operands to binary expression")]
     InvalidBinaryExpressionOperands,
+
+    #[error("`break` statement not within a loop or switch")]
+    BreakOutsideLoop,
+
+    #[error("`continue` statement not within a loop")]
+    ContinueOutsideLoop,
+
+    #[error("`case` label not within a switch statement")]
+    CaseOutsideSwitch,
+
+    #[error("`default` label not within a switch statement")]
+    DefaultOutsideSwitch,
+
+    #[error("expression is not a compile-time constant")]
+    NonConstantExpression,
+
+    #[error("expression is not an integer constant expression")]
+    NonIntegerConstantExpression,
+
+    #[error("division by zero in constant expression")]
+    DivisionByZeroInConstantExpression,
+
+    #[error("overflow in constant expression")]
+    ConstantExpressionOverflow,
+
+    #[error("duplicate case value `{0}`")]
+    DuplicateCaseValue(i64),
+
+    #[error("multiple default labels in one switch")]
+    MultipleDefaultLabels,
+
+    #[error("statement expressions are only allowed inside functions")]
+    StatementExpressionOutsideFunction,
+
+    #[error("condition is not a scalar")]
+    InvalidCondition,
+
+    #[error("switch condition is not an integer")]
+    InvalidSwitchCondition,
+
+    #[error("use of undeclared label `{0}`")]
+    UndefinedLabel(String),
+
+    #[error("redefinition of label `{0}`")]
+    DuplicateLabel(String),
+
+    #[error("jump into the scope of a variable length array")]
+    JumpIntoVariableArrayScope,
+
+    #[error("cannot jump out of a statement expression")]
+    JumpOutOfStatementExpression,
+
+    #[error("cannot jump into a statement expression")]
+    JumpIntoStatementExpression,
+
+    #[error("use of undeclared identifier `{0}`")]
+    UndeclaredVariable(String),
+
+    #[error("redefinition of `{0}`")]
+    VariableRedefinition(String),
+
+    #[error("redefinition of function `{0}`")]
+    FunctionRedefinition(String),
+
+    #[error("parameter name omitted")]
+    UnnamedParameter,
+
+    #[error("conflicting types for `{0}`")]
+    ConflictingTypes(String),
+
+    #[error("static declaration of `{0}` follows non-static declaration")]
+    StaticDeclarationFollowsNonStatic(String),
+
+    #[error("non-static declaration of `{0}` follows static declaration")]
+    NonStaticDeclarationFollowsStatic(String),
+
+    #[error("`extern` variable at block scope cannot have an initializer")]
+    ExternLocalInitializer,
+
+    #[error("called object is not a function or function pointer")]
+    NotAFunction,
+
+    #[error("expected {0} arguments, found {1}")]
+    ArgumentCountMismatch(usize, usize),
+
+    #[error("incompatible type for argument {0}")]
+    IncompatibleArgumentType(usize),
+
+    #[error("unresolved name `{0}`")]
+    UnresolvedName(String),
+
+    #[error("variable has no stack slot")]
+    UnallocatedVariable,
+
+    #[error("`va_start` used in a function with fixed arguments")]
+    VaStartOutsideVariadicFunction,
+
+    #[error("second argument to `va_start` is not the last named parameter")]
+    InvalidVaStartParameter,
+
+    #[error("expected a `va_list` variable")]
+    InvalidVaList,
+
+    #[error("initializer element is not a compile-time constant")]
+    NonConstantInitializer,
+
+    #[error("restrict requires a pointer type")]
+    InvalidRestrictQualifier,
+
+    #[error("cannot assign to a const-qualified lvalue")]
+    AssignmentToConst,
+
+    #[error("void function should not return a value")]
+    ReturnValueInVoidFunction,
+
+    #[error("non-void function should return a value")]
+    MissingReturnValue,
+
+    #[error("void value not ignored as it ought to be")]
+    VoidValueUsed,
+
+    #[error("variable has incomplete type `void`")]
+    VoidVariable,
+
+    #[error("excess elements in scalar initializer")]
+    ExcessScalarInitializerElements,
+
+    #[error("static assertion failed: {0:?}")]
+    StaticAssertionFailed(String),
+
+    #[error("`_Generic` selector is not compatible with any association")]
+    GenericSelectionNoMatch,
+
+    #[error("duplicate `default` association in `_Generic`")]
+    DuplicateGenericDefault,
+
+    #[error("`_Generic` specifies two compatible types")]
+    DuplicateGenericAssociation,
+
+    #[error("redefinition of `struct {0}`")]
+    StructRedefinition(String),
+
+    #[error("duplicate member `{0}`")]
+    DuplicateMember(String),
+
+    #[error("member has incomplete type")]
+    IncompleteMemberType,
+
+    #[error("variable has incomplete type")]
+    IncompleteVariable,
+
+    #[error("member reference base type is not a structure")]
+    MemberOfNonStruct,
+
+    #[error("member access into incomplete structure")]
+    IncompleteStructAccess,
+
+    #[error("no member named `{0}` in the structure")]
+    NoSuchMember(String),
+
+    #[error("bit-field has non-integer type")]
+    InvalidBitFieldType,
+
+    #[error("bit-field has negative width")]
+    NegativeBitFieldWidth,
+
+    #[error("width of bit-field exceeds the width of its type")]
+    BitFieldWidthTooLarge,
+
+    #[error("named bit-field has zero width")]
+    ZeroWidthNamedBitField,
+
+    #[error("cannot take the address of a bit-field")]
+    AddressOfBitField,
+
+    #[error("invalid application of `sizeof` to a bit-field")]
+    SizeOfBitField,
+
+    #[error("invalid application of `sizeof` to an incomplete type")]
+    SizeOfIncompleteType,
+
+    #[error("structures can only be used through their members")]
+    StructValueUnsupported,
+
+    #[error("incompatible types when converting to or from a structure")]
+    IncompatibleStructConversion,
+
+    #[error("incompatible function pointer types")]
+    IncompatibleFunctionPointerConversion,
+
+    #[error("array has incomplete element type")]
+    IncompleteElementType,
+
+    #[error("array length is not an integer constant expression")]
+    NonConstantArrayLength,
+
+    #[error("size of array is negative")]
+    NegativeArrayLength,
+
+    #[error("array is too large")]
+    ArrayTooLarge,
+
+    #[error("size of array has non-integer type")]
+    NonIntegerArrayLength,
+
+    #[error("array type is not assignable")]
+    ArrayAssignment,
+
+    #[error("argument of `__builtin_alloca` has non-integer type")]
+    NonIntegerAllocaSize,
+
+    #[error("array initializer must be an initializer list")]
+    ArrayInitializerNotList,
+
+    #[error("initializer-string for char array is too long")]
+    StringInitializerTooLong,
+
+    #[error("excess elements in initializer")]
+    ExcessInitializerElements,
+
+    #[error("array designator index `{0}` exceeds array bounds")]
+    DesignatorIndexOutOfBounds(i64),
+
+    #[error("array designator cannot initialize non-array type")]
+    IndexDesignatorForNonArray,
+
+    #[error("field designator cannot initialize a non-structure type")]
+    MemberDesignatorForNonStruct,
+
+    #[error("designator in initializer for scalar type")]
+    ScalarInitializerDesignator,
+
+    #[error("variable-sized object may not be initialized")]
+    VariableArrayInitializer,
+
+    #[error("invalid preprocessing directive `#{0}`")]
+    InvalidDirective(String),
+
+    #[error("macro name missing")]
+    MacroNameMissing,
+
+    #[error("macro names must be identifiers")]
+    MacroNameNotIdentifier,
+
+    #[error("expected parameter name, found `{0}`")]
+    InvalidMacroParameter(String),
+
+    #[error("duplicate macro parameter `{0}`")]
+    DuplicateMacroParameter(String),
+
+    #[error("missing `)` in macro parameter list")]
+    UnterminatedMacroParameterList,
+
+    #[error("`#` is not followed by a macro parameter")]
+    StringizingWithoutParameter,
+
+    #[error("`##` cannot appear at either end of a macro expansion")]
+    PastingAtReplacementEdge,
+
+    #[error("`{0}` can only appear in the expansion of a variadic macro")]
+    VariadicIdentifierOutsideVariadicMacro(String),
+
+    #[error("`__VA_OPT__` must be followed by a parenthesized replacement")]
+    InvalidVaOpt,
+
+    #[error("`__VA_OPT__` may not appear in a `__VA_OPT__` operand")]
+    NestedVaOpt,
+
+    #[error("unterminated argument list invoking macro `{0}`")]
+    UnterminatedMacroArguments(String),
+
+    #[error("macro `{0}` expects {1} arguments, found {2}")]
+    MacroArgumentCountMismatch(String, usize, usize),
+
+    #[error("pasting `{0}` and `{1}` does not give a valid preprocessing token")]
+    InvalidPasting(String, String),
+
+    #[error("`#include` expects \"FILENAME\" or <FILENAME>")]
+    InvalidIncludeFileName,
+
+    #[error("`{0}` file not found")]
+    IncludeFileNotFound(String),
+
+    #[error("failed reading `{0}`: {1}")]
+    IncludeFileUnreadable(String, String),
+
+    #[error("`#include` nested too deeply")]
+    IncludeNestedTooDeeply,
+
+    #[error("unterminated `#{0}`")]
+    UnterminatedConditional(String),
+
+    #[error("`#elif` without `#if`")]
+    ElifWithoutIf,
+
+    #[error("`#elif` after `#else`")]
+    ElifAfterElse,
+
+    #[error("`#else` without `#if`")]
+    ElseWithoutIf,
+
+    #[error("`#else` after `#else`")]
+    ElseAfterElse,
+
+    #[error("`#endif` without `#if`")]
+    EndifWithoutIf,
+
+    #[error("`#{0}` with no expression")]
+    EmptyCondition(String),
+
+    #[error("token `{0}` is not valid in preprocessor expressions")]
+    InvalidConditionToken(TokenKind),
+
+    #[error("missing `{0}` in preprocessor expression")]
+    MissingConditionToken(TokenKind),
+
+    #[error("expected value in preprocessor expression")]
+    MissingConditionValue,
+
+    #[error("operator `defined` requires an identifier")]
+    DefinedWithoutIdentifier,
+
+    #[error("division by zero in preprocessor expression")]
+    ConditionDivisionByZero,
+
+    #[error("#error {0}")]
+    ErrorDirective(String),
+
+    #[error("unterminated comment")]
+    UnterminatedComment,
+
+    #[error("`#line` expects a line number")]
+    MissingLineNumber,
+
+    #[error("`{0}` after `#line` is not a positive integer")]
+    InvalidLineNumber(String),
+
+    #[error("line number out of range")]
+    LineNumberOutOfRange,
+
+    #[error("invalid filename `{0}`")]
+    InvalidLineFileName(String),
 }
@@ -3,8 +3,10 @@ mod diagnostic_emitter;
 mod diagnostic_kind;
 mod error;
 mod result;
+mod warning;
 
 pub use diagnostic::Diagnostic;
 pub use diagnostic_emitter::DiagnosticEmitter;
 pub use error::Error;
 pub use result::Result;
+pub use warning::Warning;
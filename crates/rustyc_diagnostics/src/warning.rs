@@ -0,0 +1,20 @@
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum Warning {
+    #[error("conversion discards qualifiers from pointer target type")]
+    DiscardedQualifiers,
+
+    #[error("`{0}` macro redefined")]
+    MacroRedefined(String),
+
+    #[error("extra tokens at end of `#{0}` directive")]
+    ExtraDirectiveTokens(String),
+
+    #[error("#warning {0}")]
+    WarningDirective(String),
+
+    #[error("ignoring unknown pragma `{0}`")]
+    UnknownPragma(String),
+
+    #[error("`#pragma once` in main file")]
+    PragmaOnceInMainFile,
+}
@@ -2,52 +2,111 @@ use std::{cell::RefCell, rc::Rc};
 
 use rustyc_ast_lowerer::AstLowerer;
 use rustyc_code_generator::CodeGenerator;
-use rustyc_diagnostics::DiagnosticEmitter;
-use rustyc_lexer::Lexer;
+use rustyc_diagnostics::{Diagnostic, DiagnosticEmitter};
 use rustyc_parser::Parser;
+use rustyc_preprocessor::{Preprocessor, PreprocessorOptions};
+use rustyc_resolver::Resolver;
+use rustyc_span::{SourceFile, SourceMap};
 use rustyc_ty::TyContext;
 use rustyc_type_checker::TypeChecker;
 
 use crate::error;
 
 pub struct Driver {
-    source: String,
+    file: Rc<SourceFile>,
+    preprocessor_options: PreprocessorOptions,
+    source_map: Rc<RefCell<SourceMap>>,
     diagnostic_emitter: DiagnosticEmitter,
     ty_context: Rc<RefCell<TyContext>>,
 }
 
 impl Driver {
-    pub fn new(source: String) -> Self {
-        let diagnostic_emitter = DiagnosticEmitter::new(source.clone());
+    /// Quoted includes are looked for next to the file that `source_name` names.
+    pub fn new(
+        source_name: String,
+        source: String,
+        preprocessor_options: PreprocessorOptions,
+    ) -> Self {
+        let source_map = Rc::new(RefCell::new(SourceMap::new()));
+        let file = source_map.borrow_mut().add_file(source_name, source);
+        let diagnostic_emitter = DiagnosticEmitter::new(Rc::clone(&source_map));
+        let ty_context = Rc::new(RefCell::new(TyContext::new(preprocessor_options.target)));
 
         Self {
-            source,
+            file,
+            preprocessor_options,
+            source_map,
             diagnostic_emitter,
-            ty_context: Rc::new(RefCell::new(TyContext::new())),
+            ty_context,
         }
     }
 
     pub fn run(&mut self) -> error::Result<()> {
-        self.run_passes().map_err(|diagnostic| {
-            self.diagnostic_emitter.emit(diagnostic);
-            error::Error::Aborted(self.diagnostic_emitter.get_error_count())
-        })
+        self.run_passes()
+            .map_err(|diagnostic| self.abort(diagnostic))
+    }
+
+    /// Only preprocesses the source, and prints the result.
+    pub fn preprocess(&mut self) -> error::Result<()> {
+        self.print_preprocessed()
+            .map_err(|diagnostic| self.abort(diagnostic))
+    }
+
+    fn abort(&mut self, diagnostic: Diagnostic) -> error::Error {
+        self.diagnostic_emitter.emit(diagnostic);
+        error::Error::Aborted(self.diagnostic_emitter.get_error_count())
+    }
+
+    fn print_preprocessed(&mut self) -> rustyc_diagnostics::Result<()> {
+        let preprocessor = Preprocessor::new(
+            Rc::clone(&self.file),
+            Rc::clone(&self.source_map),
+            self.preprocessor_options.clone(),
+        );
+        let (text, warnings) = preprocessor.preprocess_to_text()?;
+
+        for warning in warnings {
+            self.diagnostic_emitter.emit(warning);
+        }
+
+        print!("{text}");
+
+        Ok(())
     }
 
     fn run_passes(&mut self) -> rustyc_diagnostics::Result<()> {
-        let lexer = Lexer::new(&self.source)?;
-        let tokens = lexer.lex()?;
+        let preprocessor = Preprocessor::new(
+            Rc::clone(&self.file),
+            Rc::clone(&self.source_map),
+            self.preprocessor_options.clone(),
+        );
+        let (tokens, warnings) = preprocessor.preprocess()?;
+
+        for warning in warnings {
+            self.diagnostic_emitter.emit(warning);
+        }
 
         let parser = Parser::new(tokens);
         let ast = parser.parse()?;
 
-        let ast_lowerer = AstLowerer::new(ast, Rc::clone(&self.ty_context));
-        let hir = ast_lowerer.lower();
+        let resolver = Resolver::new(ast.clone());
+        let resolutions = resolver.resolve()?;
+
+        let ast_lowerer = AstLowerer::new(ast, resolutions, Rc::clone(&self.ty_context));
+        let hir = ast_lowerer.lower()?;
 
         let type_checker = TypeChecker::new(Rc::clone(&hir), Rc::clone(&self.ty_context));
-        type_checker.check()?;
+        let warnings = type_checker.check()?;
+
+        for warning in warnings {
+            self.diagnostic_emitter.emit(warning);
+        }
 
-        let code_generator = CodeGenerator::new(hir);
+        let code_generator = CodeGenerator::new(
+            hir,
+            Rc::clone(&self.ty_context),
+            self.preprocessor_options.target,
+        );
         code_generator.generate()?;
 
         Ok(())
@@ -0,0 +1,8 @@
+/// The value of a constant expression.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Constant {
+    Integer(i64),
+    Floating(f64),
+    /// The address of a global variable or function plus an offset in bytes.
+    Address(String, i64),
+}
@@ -0,0 +1,334 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rustyc_diagnostics::Diagnostic;
+use rustyc_ty::{TyContext, TyId, TyMatcher};
+
+use crate::{
+    expressions::{
+        BinaryExpression, BinaryOperator, CastExpression, Expression, ExpressionKind,
+        UnaryExpression, UnaryOperator,
+    },
+    Constant,
+};
+
+/// Folds constant expressions, such as `case` labels and global initializers.
+pub struct ConstantEvaluator {
+    expression: Rc<Expression>,
+    ty_matcher: TyMatcher,
+    ty_context: Rc<RefCell<TyContext>>,
+}
+
+impl ConstantEvaluator {
+    pub fn new(expression: Rc<Expression>, ty_context: Rc<RefCell<TyContext>>) -> Self {
+        Self {
+            expression,
+            ty_matcher: TyMatcher::new(Rc::clone(&ty_context)),
+            ty_context,
+        }
+    }
+
+    pub fn evaluate(self) -> rustyc_diagnostics::Result<Constant> {
+        match self.expression.get_kind() {
+            ExpressionKind::Number(expression) => {
+                Ok(self.new_integer(expression.get_value() as i64))
+            }
+            ExpressionKind::FloatNumber(expression) => {
+                Ok(self.new_floating(expression.get_value()))
+            }
+            // A function designator decays into the address of the function.
+            ExpressionKind::Function(expression) => {
+                Ok(Constant::Address(expression.get_name().to_owned(), 0))
+            }
+            ExpressionKind::Unary(expression) => self.evaluate_unary(expression),
+            ExpressionKind::Binary(expression) => self.evaluate_binary(expression),
+            ExpressionKind::Cast(expression) => self.evaluate_cast(expression),
+            ExpressionKind::Assignment(_)
+            | ExpressionKind::Variable(_)
+            | ExpressionKind::Global(_)
+            | ExpressionKind::FunctionCall(_)
+            | ExpressionKind::Member(_)
+            | ExpressionKind::CompoundLiteral(_)
+            | ExpressionKind::Statement(_)
+            | ExpressionKind::VaStart(_)
+            | ExpressionKind::VaArg(_)
+            | ExpressionKind::VaEnd(_)
+            | ExpressionKind::SizeOf(_)
+            | ExpressionKind::Alloca(_) => Err(self.new_non_constant_error()),
+        }
+    }
+
+    /// Evaluates an expression that is required to be an integer constant.
+    pub fn evaluate_integer(self) -> rustyc_diagnostics::Result<i64> {
+        let span = self.expression.get_span().clone();
+
+        match self.evaluate()? {
+            Constant::Integer(value) => Ok(value),
+            Constant::Floating(_) | Constant::Address(..) => Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::NonIntegerConstantExpression,
+                span,
+            )),
+        }
+    }
+
+    fn evaluate_unary(&self, expression: &UnaryExpression) -> rustyc_diagnostics::Result<Constant> {
+        match expression.get_operator() {
+            UnaryOperator::Negate => match self.evaluate_expression(expression.get_operand())? {
+                Constant::Integer(value) => {
+                    let (value, is_overflow) = value.overflowing_neg();
+                    self.new_checked_integer(value, is_overflow)
+                }
+                Constant::Floating(value) => Ok(self.new_floating(-value)),
+                Constant::Address(..) => Err(self.new_non_constant_error()),
+            },
+            UnaryOperator::AddressOf => {
+                let (symbol, offset) = self.evaluate_address(&expression.get_operand())?;
+                Ok(Constant::Address(symbol, offset))
+            }
+            UnaryOperator::Dereference => Err(self.new_non_constant_error()),
+        }
+    }
+
+    fn evaluate_address(
+        &self,
+        expression: &Expression,
+    ) -> rustyc_diagnostics::Result<(String, i64)> {
+        match expression.get_kind() {
+            ExpressionKind::Global(expression) => Ok((expression.get_name().to_owned(), 0)),
+            ExpressionKind::Function(expression) => Ok((expression.get_name().to_owned(), 0)),
+            ExpressionKind::Member(expression) if !expression.is_bit_field() => {
+                let (symbol, offset) = self.evaluate_address(&expression.get_base())?;
+                Ok((
+                    symbol,
+                    offset.wrapping_add(expression.get_member().get_offset() as i64),
+                ))
+            }
+            // The operand of `&*p`, as in `&a[1]`, is never dereferenced.
+            ExpressionKind::Unary(unary)
+                if matches!(unary.get_operator(), UnaryOperator::Dereference) =>
+            {
+                match self.evaluate_expression(unary.get_operand())? {
+                    Constant::Address(symbol, offset) => Ok((symbol, offset)),
+                    _ => Err(Self::new_non_constant_operand_error(expression)),
+                }
+            }
+            _ => Err(Self::new_non_constant_operand_error(expression)),
+        }
+    }
+
+    fn evaluate_binary(
+        &self,
+        expression: &BinaryExpression,
+    ) -> rustyc_diagnostics::Result<Constant> {
+        let left = self.evaluate_expression(expression.get_left())?;
+        let right = self.evaluate_expression(expression.get_right())?;
+
+        match (left, right) {
+            (Constant::Integer(left), Constant::Integer(right)) => {
+                self.evaluate_integer_binary(expression, left, right)
+            }
+            (Constant::Floating(left), Constant::Floating(right)) => {
+                Ok(self.evaluate_floating_binary(expression.get_operator(), left, right))
+            }
+            // Pointer arithmetic was already scaled to bytes, and the pointer
+            // is always the left operand.
+            (Constant::Address(symbol, offset), Constant::Integer(right)) => {
+                match expression.get_operator() {
+                    BinaryOperator::Add => {
+                        Ok(Constant::Address(symbol, offset.wrapping_add(right)))
+                    }
+                    BinaryOperator::Subtract => {
+                        Ok(Constant::Address(symbol, offset.wrapping_sub(right)))
+                    }
+                    _ => Err(self.new_non_constant_error()),
+                }
+            }
+            (
+                Constant::Address(left_symbol, left_offset),
+                Constant::Address(right_symbol, right_offset),
+            ) if left_symbol == right_symbol
+                && matches!(expression.get_operator(), BinaryOperator::Subtract) =>
+            {
+                Ok(self.new_integer(left_offset.wrapping_sub(right_offset)))
+            }
+            _ => Err(self.new_non_constant_error()),
+        }
+    }
+
+    fn evaluate_integer_binary(
+        &self,
+        expression: &BinaryExpression,
+        left: i64,
+        right: i64,
+    ) -> rustyc_diagnostics::Result<Constant> {
+        let (value, is_overflow) = match expression.get_operator() {
+            BinaryOperator::Equal => ((left == right) as i64, false),
+            BinaryOperator::NotEqual => ((left != right) as i64, false),
+            BinaryOperator::LessThan => (self.compare_integers(left, right).is_lt() as i64, false),
+            BinaryOperator::LessThanOrEqual => {
+                (self.compare_integers(left, right).is_le() as i64, false)
+            }
+            BinaryOperator::Add => left.overflowing_add(right),
+            BinaryOperator::Subtract => left.overflowing_sub(right),
+            BinaryOperator::Multiply => left.overflowing_mul(right),
+            BinaryOperator::Divide => {
+                if right == 0 {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::DivisionByZeroInConstantExpression,
+                        expression.get_right().get_span().clone(),
+                    ));
+                }
+
+                if self.is_signed(expression.get_left().get_ty()) {
+                    left.overflowing_div(right)
+                } else {
+                    (((left as u64) / (right as u64)) as i64, false)
+                }
+            }
+        };
+
+        self.new_checked_integer(value, is_overflow)
+    }
+
+    fn evaluate_floating_binary(
+        &self,
+        operator: &BinaryOperator,
+        left: f64,
+        right: f64,
+    ) -> Constant {
+        match operator {
+            BinaryOperator::Equal => self.new_integer((left == right) as i64),
+            BinaryOperator::NotEqual => self.new_integer((left != right) as i64),
+            BinaryOperator::LessThan => self.new_integer((left < right) as i64),
+            BinaryOperator::LessThanOrEqual => self.new_integer((left <= right) as i64),
+            BinaryOperator::Add => self.new_floating(left + right),
+            BinaryOperator::Subtract => self.new_floating(left - right),
+            BinaryOperator::Multiply => self.new_floating(left * right),
+            BinaryOperator::Divide => self.new_floating(left / right),
+        }
+    }
+
+    fn evaluate_cast(&self, expression: &CastExpression) -> rustyc_diagnostics::Result<Constant> {
+        let ty = self.expression.get_ty();
+
+        // An array decays into the address of its first element.
+        if self.ty_matcher.is_array(expression.get_operand().get_ty()) {
+            let (symbol, offset) = self.evaluate_address(&expression.get_operand())?;
+            return Ok(Constant::Address(symbol, offset));
+        }
+        let operand = self.evaluate_expression(expression.get_operand())?;
+
+        // Converting to `_Bool` compares against zero rather than truncating,
+        // and the address of an object is never null.
+        if self.ty_matcher.is_bool(ty) {
+            return Ok(Constant::Integer(match operand {
+                Constant::Integer(value) => (value != 0).into(),
+                Constant::Floating(value) => (value != 0.0).into(),
+                Constant::Address(..) => 1,
+            }));
+        }
+
+        match operand {
+            Constant::Integer(value) if self.ty_matcher.is_floating(ty) => Ok(self.new_floating(
+                if self.is_signed(expression.get_operand().get_ty()) {
+                    value as f64
+                } else {
+                    value as u64 as f64
+                },
+            )),
+            Constant::Integer(value) => Ok(self.new_integer(value)),
+            Constant::Floating(value) if self.ty_matcher.is_floating(ty) => {
+                Ok(self.new_floating(value))
+            }
+            // The conversion truncates toward zero.
+            Constant::Floating(value) if self.is_signed(ty) => Ok(self.new_integer(value as i64)),
+            Constant::Floating(value) => Ok(self.new_integer(value as u64 as i64)),
+            Constant::Address(..) => Ok(operand),
+        }
+    }
+
+    fn compare_integers(&self, left: i64, right: i64) -> std::cmp::Ordering {
+        // Both operands have the same type once the usual arithmetic
+        // conversions are applied.
+        let ty = match self.expression.get_kind() {
+            ExpressionKind::Binary(expression) => expression.get_left().get_ty(),
+            _ => self.expression.get_ty(),
+        };
+
+        if self.is_signed(ty) {
+            left.cmp(&right)
+        } else {
+            (left as u64).cmp(&(right as u64))
+        }
+    }
+
+    /// Wraps an integer to the width and signedness of the expression type.
+    fn new_integer(&self, value: i64) -> Constant {
+        let ty = self.expression.get_ty();
+
+        let Some(width) = self.ty_matcher.get_integer_width(ty) else {
+            return Constant::Integer(value);
+        };
+
+        if width >= i64::BITS {
+            return Constant::Integer(value);
+        }
+
+        let shift = i64::BITS - width;
+
+        Constant::Integer(if self.is_signed(ty) {
+            (value << shift) >> shift
+        } else {
+            ((value as u64) << shift >> shift) as i64
+        })
+    }
+
+    /// Unsigned arithmetic wraps around, signed overflow is not constant.
+    fn new_checked_integer(
+        &self,
+        value: i64,
+        is_overflow: bool,
+    ) -> rustyc_diagnostics::Result<Constant> {
+        if is_overflow && self.is_signed(self.expression.get_ty()) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ConstantExpressionOverflow,
+                self.expression.get_span().clone(),
+            ));
+        }
+
+        Ok(self.new_integer(value))
+    }
+
+    fn new_floating(&self, value: f64) -> Constant {
+        if self
+            .ty_matcher
+            .is_single_precision(self.expression.get_ty())
+        {
+            Constant::Floating(value as f32 as f64)
+        } else {
+            Constant::Floating(value)
+        }
+    }
+
+    fn is_signed(&self, ty: TyId) -> bool {
+        self.ty_matcher.is_signed_integer(ty)
+    }
+
+    fn new_non_constant_error(&self) -> Diagnostic {
+        Self::new_non_constant_operand_error(&self.expression)
+    }
+
+    fn new_non_constant_operand_error(expression: &Expression) -> Diagnostic {
+        Diagnostic::new_error(
+            rustyc_diagnostics::Error::NonConstantExpression,
+            expression.get_span().clone(),
+        )
+    }
+
+    fn evaluate_expression(
+        &self,
+        expression: Rc<Expression>,
+    ) -> rustyc_diagnostics::Result<Constant> {
+        let constant_evaluator = Self::new(expression, Rc::clone(&self.ty_context));
+        constant_evaluator.evaluate()
+    }
+}
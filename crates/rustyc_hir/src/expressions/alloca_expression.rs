@@ -0,0 +1,18 @@
+use std::rc::Rc;
+
+use super::Expression;
+
+#[derive(Clone, Debug)]
+pub struct AllocaExpression {
+    size: Rc<Expression>,
+}
+
+impl AllocaExpression {
+    pub fn new(size: Rc<Expression>) -> Self {
+        Self { size }
+    }
+
+    pub fn get_size(&self) -> Rc<Expression> {
+        Rc::clone(&self.size)
+    }
+}
@@ -0,0 +1,19 @@
+use std::rc::Rc;
+
+use super::Expression;
+
+/// Converts its operand to the type of the cast expression itself.
+#[derive(Clone, Debug)]
+pub struct CastExpression {
+    operand: Rc<Expression>,
+}
+
+impl CastExpression {
+    pub fn new(operand: Rc<Expression>) -> Self {
+        Self { operand }
+    }
+
+    pub fn get_operand(&self) -> Rc<Expression> {
+        Rc::clone(&self.operand)
+    }
+}
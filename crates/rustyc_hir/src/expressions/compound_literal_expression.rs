@@ -0,0 +1,27 @@
+use std::rc::Rc;
+
+use crate::{initializers::Initializer, LocalId};
+
+/// A compound literal, whose object is initialized every time it is evaluated.
+#[derive(Clone, Debug)]
+pub struct CompoundLiteralExpression {
+    local_id: LocalId,
+    initializer: Rc<Initializer>,
+}
+
+impl CompoundLiteralExpression {
+    pub fn new(local_id: LocalId, initializer: Rc<Initializer>) -> Self {
+        Self {
+            local_id,
+            initializer,
+        }
+    }
+
+    pub fn get_local_id(&self) -> LocalId {
+        self.local_id
+    }
+
+    pub fn get_initializer(&self) -> Rc<Initializer> {
+        Rc::clone(&self.initializer)
+    }
+}
@@ -1,6 +1,8 @@
 use super::{
-    AssignmentExpression, BinaryExpression, FunctionCallExpression, NumberExpression,
-    UnaryExpression, VariableExpression,
+    AllocaExpression, AssignmentExpression, BinaryExpression, CastExpression,
+    CompoundLiteralExpression, FloatNumberExpression, FunctionCallExpression, FunctionExpression,
+    GlobalExpression, MemberExpression, NumberExpression, SizeOfExpression, StatementExpression,
+    UnaryExpression, VaArgExpression, VaEndExpression, VaStartExpression, VariableExpression,
 };
 
 #[derive(Clone, Debug)]
@@ -8,7 +10,19 @@ pub enum ExpressionKind {
     Assignment(AssignmentExpression),
     Binary(BinaryExpression),
     Unary(UnaryExpression),
+    Cast(CastExpression),
     Variable(VariableExpression),
+    Function(FunctionExpression),
+    Global(GlobalExpression),
     Number(NumberExpression),
+    FloatNumber(FloatNumberExpression),
     FunctionCall(FunctionCallExpression),
+    Member(MemberExpression),
+    SizeOf(SizeOfExpression),
+    CompoundLiteral(CompoundLiteralExpression),
+    Statement(StatementExpression),
+    VaStart(VaStartExpression),
+    VaArg(VaArgExpression),
+    VaEnd(VaEndExpression),
+    Alloca(AllocaExpression),
 }
@@ -0,0 +1,14 @@
+#[derive(Clone, Debug)]
+pub struct FloatNumberExpression {
+    value: f64,
+}
+
+impl FloatNumberExpression {
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    pub fn get_value(&self) -> f64 {
+        self.value
+    }
+}
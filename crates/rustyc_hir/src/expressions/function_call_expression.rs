@@ -4,20 +4,34 @@ use super::Expression;
 
 #[derive(Clone, Debug)]
 pub struct FunctionCallExpression {
-    name: String,
+    callee: Rc<Expression>,
     arguments: Vec<Rc<Expression>>,
+    fixed_argument_count: Option<usize>,
 }
 
 impl FunctionCallExpression {
-    pub fn new(name: String, arguments: Vec<Rc<Expression>>) -> Self {
-        Self { name, arguments }
+    pub fn new(
+        callee: Rc<Expression>,
+        arguments: Vec<Rc<Expression>>,
+        fixed_argument_count: Option<usize>,
+    ) -> Self {
+        Self {
+            callee,
+            arguments,
+            fixed_argument_count,
+        }
     }
 
-    pub fn get_name(&self) -> &str {
-        &self.name
+    pub fn get_callee(&self) -> Rc<Expression> {
+        Rc::clone(&self.callee)
     }
 
     pub fn get_arguments(&self) -> &[Rc<Expression>] {
         &self.arguments
     }
+
+    /// Returns the number of named parameters if the callee is variadic.
+    pub fn get_fixed_argument_count(&self) -> Option<usize> {
+        self.fixed_argument_count
+    }
 }
@@ -0,0 +1,22 @@
+use crate::FunctionId;
+
+/// A function designator, such as the callee of a direct call.
+#[derive(Clone, Debug)]
+pub struct FunctionExpression {
+    function_id: FunctionId,
+    name: String,
+}
+
+impl FunctionExpression {
+    pub fn new(function_id: FunctionId, name: String) -> Self {
+        Self { function_id, name }
+    }
+
+    pub fn get_function_id(&self) -> FunctionId {
+        self.function_id
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{GlobalId, Linkage};
+
+/// A use of a variable with static storage duration.
+#[derive(Clone, Debug)]
+pub struct GlobalExpression {
+    global_id: GlobalId,
+    name: String,
+    linkage: Linkage,
+}
+
+impl GlobalExpression {
+    pub fn new(global_id: GlobalId, name: String, linkage: Linkage) -> Self {
+        Self {
+            global_id,
+            name,
+            linkage,
+        }
+    }
+
+    pub fn get_global_id(&self) -> GlobalId {
+        self.global_id
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_linkage(&self) -> Linkage {
+        self.linkage
+    }
+}
@@ -0,0 +1,29 @@
+use std::rc::Rc;
+
+use rustyc_ty::StructMember;
+
+use super::Expression;
+
+#[derive(Clone, Debug)]
+pub struct MemberExpression {
+    base: Rc<Expression>,
+    member: StructMember,
+}
+
+impl MemberExpression {
+    pub fn new(base: Rc<Expression>, member: StructMember) -> Self {
+        Self { base, member }
+    }
+
+    pub fn get_base(&self) -> Rc<Expression> {
+        Rc::clone(&self.base)
+    }
+
+    pub fn get_member(&self) -> &StructMember {
+        &self.member
+    }
+
+    pub fn is_bit_field(&self) -> bool {
+        self.member.get_bit_field().is_some()
+    }
+}
@@ -1,21 +1,45 @@
+mod alloca_expression;
 mod assignment_expression;
 mod binary_expression;
 mod binary_operator;
+mod cast_expression;
+mod compound_literal_expression;
 mod expression;
 mod expression_kind;
+mod float_number_expression;
 mod function_call_expression;
+mod function_expression;
+mod global_expression;
+mod member_expression;
 mod number_expression;
+mod size_of_expression;
+mod statement_expression;
 mod unary_expression;
 mod unary_operator;
+mod va_arg_expression;
+mod va_end_expression;
+mod va_start_expression;
 mod variable_expression;
 
+pub use alloca_expression::AllocaExpression;
 pub use assignment_expression::AssignmentExpression;
 pub use binary_expression::BinaryExpression;
 pub use binary_operator::BinaryOperator;
+pub use cast_expression::CastExpression;
+pub use compound_literal_expression::CompoundLiteralExpression;
 pub use expression::Expression;
 pub use expression_kind::ExpressionKind;
+pub use float_number_expression::FloatNumberExpression;
 pub use function_call_expression::FunctionCallExpression;
+pub use function_expression::FunctionExpression;
+pub use global_expression::GlobalExpression;
+pub use member_expression::MemberExpression;
 pub use number_expression::NumberExpression;
+pub use size_of_expression::SizeOfExpression;
+pub use statement_expression::StatementExpression;
 pub use unary_expression::UnaryExpression;
 pub use unary_operator::UnaryOperator;
+pub use va_arg_expression::VaArgExpression;
+pub use va_end_expression::VaEndExpression;
+pub use va_start_expression::VaStartExpression;
 pub use variable_expression::VariableExpression;
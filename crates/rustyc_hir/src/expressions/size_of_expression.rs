@@ -0,0 +1,18 @@
+use std::rc::Rc;
+
+use super::Expression;
+
+#[derive(Clone, Debug)]
+pub struct SizeOfExpression {
+    operand: Rc<Expression>,
+}
+
+impl SizeOfExpression {
+    pub fn new(operand: Rc<Expression>) -> Self {
+        Self { operand }
+    }
+
+    pub fn get_operand(&self) -> Rc<Expression> {
+        Rc::clone(&self.operand)
+    }
+}
@@ -0,0 +1,18 @@
+use std::rc::Rc;
+
+use super::Expression;
+
+#[derive(Clone, Debug)]
+pub struct VaArgExpression {
+    list: Rc<Expression>,
+}
+
+impl VaArgExpression {
+    pub fn new(list: Rc<Expression>) -> Self {
+        Self { list }
+    }
+
+    pub fn get_list(&self) -> Rc<Expression> {
+        Rc::clone(&self.list)
+    }
+}
@@ -0,0 +1,26 @@
+use std::rc::Rc;
+
+use super::Expression;
+
+#[derive(Clone, Debug)]
+pub struct VaStartExpression {
+    list: Rc<Expression>,
+    last_parameter: Rc<Expression>,
+}
+
+impl VaStartExpression {
+    pub fn new(list: Rc<Expression>, last_parameter: Rc<Expression>) -> Self {
+        Self {
+            list,
+            last_parameter,
+        }
+    }
+
+    pub fn get_list(&self) -> Rc<Expression> {
+        Rc::clone(&self.list)
+    }
+
+    pub fn get_last_parameter(&self) -> Rc<Expression> {
+        Rc::clone(&self.last_parameter)
+    }
+}
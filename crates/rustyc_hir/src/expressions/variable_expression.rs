@@ -1,14 +1,16 @@
+use crate::Res;
+
 #[derive(Clone, Debug)]
 pub struct VariableExpression {
-    name: String,
+    res: Res,
 }
 
 impl VariableExpression {
-    pub fn new(name: String) -> Self {
-        Self { name }
+    pub fn new(res: Res) -> Self {
+        Self { res }
     }
 
-    pub fn get_name(&self) -> &str {
-        &self.name
+    pub fn get_res(&self) -> Res {
+        self.res
     }
 }
@@ -0,0 +1,13 @@
+use std::rc::Rc;
+
+use crate::expressions::Expression;
+
+use super::InitializerElement;
+
+#[derive(Clone, Debug)]
+pub enum Initializer {
+    /// An expression that has the type of the object it initializes.
+    Expression(Rc<Expression>),
+    /// The initialized subobjects of an aggregate, whose other bytes are zero.
+    List(Vec<InitializerElement>),
+}
@@ -0,0 +1,47 @@
+use std::rc::Rc;
+
+use rustyc_ty::{BitField, TyId};
+
+use crate::expressions::Expression;
+
+/// A subobject of an aggregate and the expression it is initialized with.
+#[derive(Clone, Debug)]
+pub struct InitializerElement {
+    offset: u64,
+    ty: TyId,
+    bit_field: Option<BitField>,
+    expression: Rc<Expression>,
+}
+
+impl InitializerElement {
+    pub fn new(
+        offset: u64,
+        ty: TyId,
+        bit_field: Option<BitField>,
+        expression: Rc<Expression>,
+    ) -> Self {
+        Self {
+            offset,
+            ty,
+            bit_field,
+            expression,
+        }
+    }
+
+    /// Returns the offset of the subobject, or of the storage unit of a bit-field.
+    pub fn get_offset(&self) -> u64 {
+        self.offset
+    }
+
+    pub fn get_ty(&self) -> TyId {
+        self.ty
+    }
+
+    pub fn get_bit_field(&self) -> Option<BitField> {
+        self.bit_field
+    }
+
+    pub fn get_expression(&self) -> Rc<Expression> {
+        Rc::clone(&self.expression)
+    }
+}
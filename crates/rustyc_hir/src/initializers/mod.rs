@@ -0,0 +1,5 @@
+mod initializer;
+mod initializer_element;
+
+pub use initializer::Initializer;
+pub use initializer_element::InitializerElement;
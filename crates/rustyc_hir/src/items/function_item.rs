@@ -1,42 +1,78 @@
 use std::rc::Rc;
 
-use crate::Block;
+use rustyc_ty::TyId;
+
+use crate::{Block, FunctionId, Linkage, Local, LocalId};
 
 pub struct FunctionItem {
+    id: FunctionId,
     name: String,
-    parameters: Vec<String>,
-    body: Rc<Block>,
-    local_variables: Vec<String>,
+    linkage: Linkage,
+    return_ty: TyId,
+    parameters: Vec<LocalId>,
+    is_variadic: bool,
+    body: Option<Rc<Block>>,
+    locals: Vec<Local>,
 }
 
 impl FunctionItem {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
+        id: FunctionId,
         name: String,
-        parameters: Vec<String>,
-        body: Rc<Block>,
-        local_variables: Vec<String>,
+        linkage: Linkage,
+        return_ty: TyId,
+        parameters: Vec<LocalId>,
+        is_variadic: bool,
+        body: Option<Rc<Block>>,
+        locals: Vec<Local>,
     ) -> Self {
         Self {
+            id,
             name,
+            linkage,
+            return_ty,
             parameters,
+            is_variadic,
             body,
-            local_variables,
+            locals,
         }
     }
 
+    pub fn get_id(&self) -> FunctionId {
+        self.id
+    }
+
     pub fn get_name(&self) -> &str {
         &self.name
     }
 
-    pub fn get_parameters(&self) -> &[String] {
+    pub fn get_linkage(&self) -> Linkage {
+        self.linkage
+    }
+
+    pub fn get_return_ty(&self) -> TyId {
+        self.return_ty
+    }
+
+    pub fn get_parameters(&self) -> &[LocalId] {
         &self.parameters
     }
 
-    pub fn get_body(&self) -> Rc<Block> {
-        Rc::clone(&self.body)
+    pub fn is_variadic(&self) -> bool {
+        self.is_variadic
+    }
+
+    /// Returns `None` if the item only declares the function.
+    pub fn get_body(&self) -> Option<Rc<Block>> {
+        self.body.clone()
+    }
+
+    pub fn get_locals(&self) -> &[Local] {
+        &self.locals
     }
 
-    pub fn get_local_variables(&self) -> &[String] {
-        &self.local_variables
+    pub fn get_local(&self, id: LocalId) -> &Local {
+        &self.locals[id.get() as usize]
     }
 }
@@ -0,0 +1,54 @@
+use std::rc::Rc;
+
+use rustyc_ty::TyId;
+
+use crate::{initializers::Initializer, GlobalId, Linkage};
+
+/// A variable with static storage duration, declared at file scope or as a `static` local.
+pub struct GlobalItem {
+    id: GlobalId,
+    name: String,
+    ty: TyId,
+    linkage: Linkage,
+    initializer: Option<Rc<Initializer>>,
+}
+
+impl GlobalItem {
+    pub fn new(
+        id: GlobalId,
+        name: String,
+        ty: TyId,
+        linkage: Linkage,
+        initializer: Option<Rc<Initializer>>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            ty,
+            linkage,
+            initializer,
+        }
+    }
+
+    pub fn get_id(&self) -> GlobalId {
+        self.id
+    }
+
+    /// Returns the symbol name, which is mangled for `static` locals.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_ty(&self) -> TyId {
+        self.ty
+    }
+
+    pub fn get_linkage(&self) -> Linkage {
+        self.linkage
+    }
+
+    /// Returns `None` if the variable is zero-initialized.
+    pub fn get_initializer(&self) -> Option<Rc<Initializer>> {
+        self.initializer.clone()
+    }
+}
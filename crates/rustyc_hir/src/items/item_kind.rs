@@ -1,7 +1,8 @@
 use std::rc::Rc;
 
-use super::FunctionItem;
+use super::{FunctionItem, GlobalItem};
 
 pub enum ItemKind {
     Function(Rc<FunctionItem>),
+    Global(Rc<GlobalItem>),
 }
@@ -1,7 +1,9 @@
 mod function_item;
+mod global_item;
 mod item;
 mod item_kind;
 
 pub use function_item::FunctionItem;
+pub use global_item::GlobalItem;
 pub use item::Item;
 pub use item_kind::ItemKind;
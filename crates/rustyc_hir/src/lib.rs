@@ -1,7 +1,24 @@
 pub mod expressions;
+pub mod initializers;
 pub mod items;
 pub mod statements;
 
 mod block;
+mod constant;
+mod constant_evaluator;
+mod function_id;
+mod global_id;
+mod linkage;
+mod local;
+mod local_id;
+mod res;
 
 pub use block::Block;
+pub use constant::Constant;
+pub use constant_evaluator::ConstantEvaluator;
+pub use function_id::FunctionId;
+pub use global_id::GlobalId;
+pub use linkage::Linkage;
+pub use local::Local;
+pub use local_id::LocalId;
+pub use res::Res;
@@ -0,0 +1,6 @@
+/// Whether a symbol is visible to other translation units.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Linkage {
+    External,
+    Internal,
+}
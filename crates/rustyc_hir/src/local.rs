@@ -0,0 +1,35 @@
+use rustyc_span::Span;
+use rustyc_ty::TyId;
+
+use crate::LocalId;
+
+/// A local variable binding; every declaration, including parameters, gets its own.
+#[derive(Clone, Debug)]
+pub struct Local {
+    id: LocalId,
+    name: String,
+    ty: TyId,
+    span: Span,
+}
+
+impl Local {
+    pub fn new(id: LocalId, name: String, ty: TyId, span: Span) -> Self {
+        Self { id, name, ty, span }
+    }
+
+    pub fn get_id(&self) -> LocalId {
+        self.id
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_ty(&self) -> TyId {
+        self.ty
+    }
+
+    pub fn get_span(&self) -> &Span {
+        &self.span
+    }
+}
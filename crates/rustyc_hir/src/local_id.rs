@@ -0,0 +1,14 @@
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct LocalId {
+    value: u64,
+}
+
+impl LocalId {
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    pub fn get(&self) -> u64 {
+        self.value
+    }
+}
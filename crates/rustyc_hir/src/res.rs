@@ -0,0 +1,10 @@
+use crate::{FunctionId, GlobalId, LocalId};
+
+/// What a name used in the source refers to once it has been resolved.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Res {
+    Local(LocalId),
+    Parameter(LocalId),
+    Function(FunctionId),
+    Global(GlobalId),
+}
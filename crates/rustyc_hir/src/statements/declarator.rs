@@ -0,0 +1,36 @@
+use std::rc::Rc;
+
+use crate::{expressions::Expression, initializers::Initializer, LocalId};
+
+#[derive(Clone, Debug)]
+pub struct Declarator {
+    local_id: LocalId,
+    length: Option<Rc<Expression>>,
+    initializer: Option<Rc<Initializer>>,
+}
+
+impl Declarator {
+    pub fn new(
+        local_id: LocalId,
+        length: Option<Rc<Expression>>,
+        initializer: Option<Rc<Initializer>>,
+    ) -> Self {
+        Self {
+            local_id,
+            length,
+            initializer,
+        }
+    }
+
+    pub fn get_local_id(&self) -> LocalId {
+        self.local_id
+    }
+
+    pub fn get_length(&self) -> Option<Rc<Expression>> {
+        self.length.clone()
+    }
+
+    pub fn get_initializer(&self) -> Option<Rc<Initializer>> {
+        self.initializer.clone()
+    }
+}
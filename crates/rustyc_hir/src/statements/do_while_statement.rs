@@ -0,0 +1,28 @@
+use std::rc::Rc;
+
+use crate::expressions::Expression;
+
+use super::Statement;
+
+#[derive(Clone, Debug)]
+pub struct DoWhileStatement {
+    then_statement: Rc<Statement>,
+    condition_expression: Rc<Expression>,
+}
+
+impl DoWhileStatement {
+    pub fn new(then_statement: Rc<Statement>, condition_expression: Rc<Expression>) -> Self {
+        Self {
+            then_statement,
+            condition_expression,
+        }
+    }
+
+    pub fn get_then_statement(&self) -> Rc<Statement> {
+        Rc::clone(&self.then_statement)
+    }
+
+    pub fn get_condition_expression(&self) -> Rc<Expression> {
+        Rc::clone(&self.condition_expression)
+    }
+}
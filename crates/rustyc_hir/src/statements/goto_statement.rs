@@ -0,0 +1,14 @@
+#[derive(Clone, Debug)]
+pub struct GotoStatement {
+    label: String,
+}
+
+impl GotoStatement {
+    pub fn new(label: String) -> Self {
+        Self { label }
+    }
+
+    pub fn get_label(&self) -> &str {
+        &self.label
+    }
+}
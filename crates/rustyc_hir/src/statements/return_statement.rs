@@ -4,15 +4,16 @@ use crate::expressions::Expression;
 
 #[derive(Clone, Debug)]
 pub struct ReturnStatement {
-    expression: Rc<Expression>,
+    expression: Option<Rc<Expression>>,
 }
 
 impl ReturnStatement {
-    pub fn new(expression: Rc<Expression>) -> Self {
+    pub fn new(expression: Option<Rc<Expression>>) -> Self {
         Self { expression }
     }
 
-    pub fn get_expression(&self) -> Rc<Expression> {
-        Rc::clone(&self.expression)
+    /// Returns `None` for a bare `return;`.
+    pub fn get_expression(&self) -> Option<Rc<Expression>> {
+        self.expression.clone()
     }
 }
@@ -0,0 +1,28 @@
+use std::rc::Rc;
+
+use crate::expressions::Expression;
+
+use super::Statement;
+
+#[derive(Clone, Debug)]
+pub struct SwitchStatement {
+    condition_expression: Rc<Expression>,
+    then_statement: Rc<Statement>,
+}
+
+impl SwitchStatement {
+    pub fn new(condition_expression: Rc<Expression>, then_statement: Rc<Statement>) -> Self {
+        Self {
+            condition_expression,
+            then_statement,
+        }
+    }
+
+    pub fn get_condition_expression(&self) -> Rc<Expression> {
+        Rc::clone(&self.condition_expression)
+    }
+
+    pub fn get_then_statement(&self) -> Rc<Statement> {
+        Rc::clone(&self.then_statement)
+    }
+}
@@ -2,7 +2,7 @@ use std::mem;
 
 use rustyc_diagnostics::Diagnostic;
 use rustyc_span::Span;
-use rustyc_token::{BinaryOperatorToken, DelimiterToken, Token, TokenKind};
+use rustyc_token::{BinaryOperatorToken, DelimiterToken, FloatNumberToken, Token, TokenKind};
 
 use crate::{raw_token_cursor::RawTokenCursor, raw_token_kind::RawTokenKind};
 
@@ -16,12 +16,16 @@ pub struct Lexer<'a> {
 
 impl<'a> Lexer<'a> {
     pub fn new(source: &'a str) -> rustyc_diagnostics::Result<Self> {
+        Self::new_at(source, 0)
+    }
+
+    pub fn new_at(source: &'a str, start_position: usize) -> rustyc_diagnostics::Result<Self> {
         let mut lexer = Self {
             source,
             cursor: RawTokenCursor::new(source),
             token: Token::new_eof(),
-            start_position: 0,
-            position: 0,
+            start_position,
+            position: start_position,
         };
 
         lexer.bump(false)?;
@@ -90,7 +94,12 @@ impl<'a> Lexer<'a> {
                 RawTokenKind::Minus => TokenKind::BinaryOperator(BinaryOperatorToken::Minus),
                 RawTokenKind::Star => TokenKind::BinaryOperator(BinaryOperatorToken::Star),
                 RawTokenKind::Slash => TokenKind::BinaryOperator(BinaryOperatorToken::Slash),
+                RawTokenKind::Percent => TokenKind::BinaryOperator(BinaryOperatorToken::Percent),
+                RawTokenKind::Caret => TokenKind::BinaryOperator(BinaryOperatorToken::Caret),
                 RawTokenKind::And => TokenKind::BinaryOperator(BinaryOperatorToken::And),
+                RawTokenKind::Or => TokenKind::BinaryOperator(BinaryOperatorToken::Or),
+                RawTokenKind::Tilde => TokenKind::Tilde,
+                RawTokenKind::Question => TokenKind::Question,
                 RawTokenKind::OpenParenthesis => {
                     TokenKind::OpenDelimiter(DelimiterToken::Parenthesis)
                 }
@@ -99,10 +108,26 @@ impl<'a> Lexer<'a> {
                 }
                 RawTokenKind::OpenBrace => TokenKind::OpenDelimiter(DelimiterToken::Brace),
                 RawTokenKind::CloseBrace => TokenKind::CloseDelimiter(DelimiterToken::Brace),
+                RawTokenKind::OpenBracket => TokenKind::OpenDelimiter(DelimiterToken::Bracket),
+                RawTokenKind::CloseBracket => TokenKind::CloseDelimiter(DelimiterToken::Bracket),
                 RawTokenKind::Semicolon => TokenKind::Semicolon,
                 RawTokenKind::Comma => TokenKind::Comma,
+                RawTokenKind::Colon => TokenKind::Colon,
+                RawTokenKind::Dot => TokenKind::Dot,
+                RawTokenKind::Hash => TokenKind::Hash,
                 RawTokenKind::Number => self.lex_number(start)?,
+                RawTokenKind::FloatNumber => self.lex_float_number(start)?,
                 RawTokenKind::Identifier => self.lex_identifier(start),
+                RawTokenKind::String { is_terminated } => {
+                    if !is_terminated {
+                        return Err(Diagnostic::new_error(
+                            rustyc_diagnostics::Error::UnterminatedString,
+                            self.span_from(start),
+                        ));
+                    }
+
+                    self.lex_string(start)
+                }
                 RawTokenKind::Whitespace => {
                     preceded_by_whitespace = true;
                     continue;
@@ -124,8 +149,21 @@ impl<'a> Lexer<'a> {
     }
 
     fn lex_number(&self, start: usize) -> rustyc_diagnostics::Result<TokenKind> {
-        let source = self.source_from(start);
-        let value = source.parse().map_err(|e| {
+        // The value of a constant decides its type, so its suffix is only
+        // looked at by the preprocessor.
+        let source = self
+            .source_from(start)
+            .trim_end_matches(['u', 'U', 'l', 'L']);
+
+        let (digits, radix) = match source.strip_prefix(['0']) {
+            Some(hexadecimal_digits) if hexadecimal_digits.starts_with(['x', 'X']) => {
+                (&hexadecimal_digits[1..], 16)
+            }
+            Some(octal_digits) if !octal_digits.is_empty() => (octal_digits, 8),
+            _ => (source, 10),
+        };
+
+        let value = u64::from_str_radix(digits, radix).map_err(|e| {
             Diagnostic::new_error(
                 rustyc_diagnostics::Error::ParseNumber(e),
                 self.span_from(start),
@@ -135,11 +173,119 @@ impl<'a> Lexer<'a> {
         Ok(TokenKind::Number(value))
     }
 
+    fn lex_float_number(&self, start: usize) -> rustyc_diagnostics::Result<TokenKind> {
+        let source = self.source_from(start);
+
+        // `long double` has the same representation as `double`.
+        let (digits, is_single_precision) = match source.strip_suffix(['f', 'F']) {
+            Some(digits) => (digits, true),
+            None => (source.trim_end_matches(['l', 'L']), false),
+        };
+
+        let (mantissa, exponent_marker) = match digits.strip_prefix('0') {
+            Some(hexadecimal_digits) if hexadecimal_digits.starts_with(['x', 'X']) => {
+                (&hexadecimal_digits[1..], ['p', 'P'])
+            }
+            _ => (digits, ['e', 'E']),
+        };
+
+        let (mantissa, exponent) = match mantissa.split_once(exponent_marker) {
+            Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+            None => (mantissa, None),
+        };
+
+        if exponent.is_some_and(|exponent| {
+            !exponent
+                .trim_start_matches(['+', '-'])
+                .starts_with(|c: char| c.is_ascii_digit())
+        }) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ExponentWithoutDigits,
+                self.span_from(start),
+            ));
+        }
+
+        let value = if exponent_marker == ['p', 'P'] {
+            let Some(exponent) = exponent else {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::HexadecimalFloatNumberWithoutExponent,
+                    self.span_from(start),
+                ));
+            };
+
+            self.parse_hexadecimal_float_number(mantissa, exponent)
+        } else {
+            digits.parse().map_err(|e| {
+                Diagnostic::new_error(
+                    rustyc_diagnostics::Error::ParseFloatNumber(e),
+                    self.span_from(start),
+                )
+            })?
+        };
+
+        Ok(TokenKind::FloatNumber(FloatNumberToken::new(
+            value,
+            is_single_precision,
+        )))
+    }
+
+    fn parse_hexadecimal_float_number(&self, mantissa: &str, exponent: &str) -> f64 {
+        let (integer_digits, fraction_digits) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+        let value = integer_digits
+            .chars()
+            .chain(fraction_digits.chars())
+            .fold(0.0, |value, c| {
+                value * 16.0 + f64::from(c.to_digit(16).unwrap())
+            });
+
+        // Each hexadecimal digit of the fraction shifts the value by 4 bits.
+        let exponent =
+            exponent.parse::<i32>().unwrap_or(i32::MAX) - 4 * fraction_digits.len() as i32;
+
+        value * 2.0f64.powi(exponent)
+    }
+
     fn lex_identifier(&self, start: usize) -> TokenKind {
         let source = self.source_from(start);
         TokenKind::Identifier(source.to_owned())
     }
 
+    fn lex_string(&self, start: usize) -> TokenKind {
+        let source = self.source_from(start);
+        let contents = &source[1..(source.len() - 1)];
+
+        let mut value = String::with_capacity(contents.len());
+        let mut chars = contents.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                value.push(c);
+                continue;
+            }
+
+            let Some(escaped) = chars.next() else {
+                break;
+            };
+
+            value.push(match escaped {
+                'n' => '\n',
+                't' => '\t',
+                'r' => '\r',
+                '0' => '\0',
+                'a' => '\x07',
+                'b' => '\x08',
+                'f' => '\x0c',
+                'v' => '\x0b',
+                'e' => '\x1b',
+                // `\\`, `\'`, `\"`, `\?` and unknown escapes stand for themselves.
+                c => c,
+            });
+        }
+
+        TokenKind::String(value)
+    }
+
     fn source_index(&self, position: usize) -> usize {
         position - self.start_position
     }
@@ -263,9 +409,74 @@ mod tests {
                 Span::new(0, 1),
             )
         ],
+        test_lex_single_open_bracket: "[" -> [
+            Token::new(
+                TokenKind::OpenDelimiter(DelimiterToken::Bracket),
+                Span::new(0, 1),
+            )
+        ],
+        test_lex_single_close_bracket: "]" -> [
+            Token::new(
+                TokenKind::CloseDelimiter(DelimiterToken::Bracket),
+                Span::new(0, 1),
+            )
+        ],
+        test_lex_single_hash: "#" -> [
+            Token::new(TokenKind::Hash, Span::new(0, 1))
+        ],
+        test_lex_single_hash_hash: "##" -> [
+            Token::new(TokenKind::HashHash, Span::new(0, 2))
+        ],
+        test_lex_hash_hash_preceded_by_whitespace: "# #" -> [
+            Token::new(TokenKind::Hash, Span::new(0, 1)),
+            Token::new(TokenKind::Hash, Span::new(2, 3))
+        ],
         test_lex_single_semicolon: ";" -> [
             Token::new(TokenKind::Semicolon, Span::new(0, 1))
         ],
+        test_lex_single_colon: ":" -> [
+            Token::new(TokenKind::Colon, Span::new(0, 1))
+        ],
+        test_lex_single_dot: "." -> [
+            Token::new(TokenKind::Dot, Span::new(0, 1))
+        ],
+        test_lex_single_dot_dot_dot: "..." -> [
+            Token::new(TokenKind::DotDotDot, Span::new(0, 3))
+        ],
+        test_lex_dot_dot_dot_preceded_by_whitespace: ". .." -> [
+            Token::new(TokenKind::Dot, Span::new(0, 1)),
+            Token::new(TokenKind::DotDot, Span::new(2, 4))
+        ],
+        test_lex_single_arrow: "->" -> [
+            Token::new(TokenKind::Arrow, Span::new(0, 2))
+        ],
+        test_lex_single_tilde: "~" -> [
+            Token::new(TokenKind::Tilde, Span::new(0, 1))
+        ],
+        test_lex_single_question: "?" -> [
+            Token::new(TokenKind::Question, Span::new(0, 1))
+        ],
+        test_lex_single_percent: "%" -> [
+            Token::new(TokenKind::BinaryOperator(BinaryOperatorToken::Percent), Span::new(0, 1))
+        ],
+        test_lex_single_caret: "^" -> [
+            Token::new(TokenKind::BinaryOperator(BinaryOperatorToken::Caret), Span::new(0, 1))
+        ],
+        test_lex_single_or: "|" -> [
+            Token::new(TokenKind::BinaryOperator(BinaryOperatorToken::Or), Span::new(0, 1))
+        ],
+        test_lex_single_and_and: "&&" -> [
+            Token::new(TokenKind::AndAnd, Span::new(0, 2))
+        ],
+        test_lex_single_or_or: "||" -> [
+            Token::new(TokenKind::OrOr, Span::new(0, 2))
+        ],
+        test_lex_single_shl: "<<" -> [
+            Token::new(TokenKind::BinaryOperator(BinaryOperatorToken::Shl), Span::new(0, 2))
+        ],
+        test_lex_single_shr: ">>" -> [
+            Token::new(TokenKind::BinaryOperator(BinaryOperatorToken::Shr), Span::new(0, 2))
+        ],
         test_lex_single_number_1_digit: "2" -> [
             Token::new(TokenKind::Number(2), Span::new(0, 1))
         ],
@@ -275,6 +486,48 @@ mod tests {
         test_lex_single_number_3_digits: "222" -> [
             Token::new(TokenKind::Number(222), Span::new(0, 3))
         ],
+        test_lex_single_number_hexadecimal: "0x1F" -> [
+            Token::new(TokenKind::Number(31), Span::new(0, 4))
+        ],
+        test_lex_single_number_octal: "017" -> [
+            Token::new(TokenKind::Number(15), Span::new(0, 3))
+        ],
+        test_lex_single_number_zero: "0" -> [
+            Token::new(TokenKind::Number(0), Span::new(0, 1))
+        ],
+        test_lex_single_number_suffixed: "201112UL" -> [
+            Token::new(TokenKind::Number(201112), Span::new(0, 8))
+        ],
+        test_lex_single_float_number_fraction: "1.5" -> [
+            Token::new(TokenKind::FloatNumber(FloatNumberToken::new(1.5, false)), Span::new(0, 3))
+        ],
+        test_lex_single_float_number_leading_dot: ".25" -> [
+            Token::new(TokenKind::FloatNumber(FloatNumberToken::new(0.25, false)), Span::new(0, 3))
+        ],
+        test_lex_single_float_number_exponent: "1e-2" -> [
+            Token::new(TokenKind::FloatNumber(FloatNumberToken::new(0.01, false)), Span::new(0, 4))
+        ],
+        test_lex_single_float_number_single_precision: "2.f" -> [
+            Token::new(TokenKind::FloatNumber(FloatNumberToken::new(2.0, true)), Span::new(0, 3))
+        ],
+        test_lex_single_float_number_exponent_plus: "2E+3" -> [
+            Token::new(TokenKind::FloatNumber(FloatNumberToken::new(2000.0, false)), Span::new(0, 4))
+        ],
+        test_lex_single_float_number_exponent_suffixed: "5e1F" -> [
+            Token::new(TokenKind::FloatNumber(FloatNumberToken::new(50.0, true)), Span::new(0, 4))
+        ],
+        test_lex_single_float_number_long_double: "1.5L" -> [
+            Token::new(TokenKind::FloatNumber(FloatNumberToken::new(1.5, false)), Span::new(0, 4))
+        ],
+        test_lex_single_float_number_hexadecimal: "0x1.8p1" -> [
+            Token::new(TokenKind::FloatNumber(FloatNumberToken::new(3.0, false)), Span::new(0, 7))
+        ],
+        test_lex_single_float_number_hexadecimal_negative_exponent: "0XAp-2f" -> [
+            Token::new(TokenKind::FloatNumber(FloatNumberToken::new(2.5, true)), Span::new(0, 7))
+        ],
+        test_lex_single_float_number_hexadecimal_leading_dot: "0x.4P+0" -> [
+            Token::new(TokenKind::FloatNumber(FloatNumberToken::new(0.25, false)), Span::new(0, 7))
+        ],
         test_lex_single_identifier_letters: "abcd" -> [
             Token::new(TokenKind::Identifier(String::from("abcd")), Span::new(0, 4))
         ],
@@ -284,6 +537,9 @@ mod tests {
         test_lex_single_identifier_letters_digits_underscores: "_a1b2c_3d4" -> [
             Token::new(TokenKind::Identifier(String::from("_a1b2c_3d4")), Span::new(0, 10))
         ],
+        test_lex_single_string: "\"a\\tb\\\"c\"" -> [
+            Token::new(TokenKind::String(String::from("a\tb\"c")), Span::new(0, 9))
+        ],
         test_lex_block_return_0: "{ return 0; }" -> [
             Token::new(TokenKind::OpenDelimiter(DelimiterToken::Brace), Span::new(0, 1)),
             Token::new(
@@ -415,6 +671,40 @@ mod tests {
     // 1. glue - valid + invalid
     // 2. multi-line
 
+    #[test]
+    fn test_lex_unterminated_string() {
+        let lexer = Lexer::new("x = \"abc").expect("lexer should be successfully initialized");
+        assert!(lexer.lex().is_err());
+    }
+
+    #[test]
+    fn test_lex_float_number_exponent_without_digits() {
+        let lexer = Lexer::new("x = 1e+;").expect("lexer should be successfully initialized");
+        assert!(lexer.lex().is_err());
+    }
+
+    #[test]
+    fn test_lex_hexadecimal_float_number_without_exponent() {
+        let lexer = Lexer::new("x = 0x1.8;").expect("lexer should be successfully initialized");
+        assert!(lexer.lex().is_err());
+    }
+
+    #[test]
+    fn test_lex_at_start_position() {
+        let lexer = Lexer::new_at("x = 1", 10).expect("lexer should be successfully initialized");
+        let tokens = lexer
+            .lex()
+            .expect("source should be successfully tokenized");
+        assert_eq!(
+            tokens,
+            vec![
+                Token::new(TokenKind::Identifier(String::from("x")), Span::new(10, 11)),
+                Token::new(TokenKind::Equal, Span::new(12, 13)),
+                Token::new(TokenKind::Number(1), Span::new(14, 15)),
+            ]
+        );
+    }
+
     #[test]
     fn test_lex_invalid() {
         let lexer = Lexer::new("value $= 10").expect("lexer should be successfully initialized");
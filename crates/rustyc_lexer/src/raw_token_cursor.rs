@@ -27,14 +27,12 @@ impl<'a> RawTokenCursor<'a> {
                 self.eat_whitespace();
                 RawTokenKind::Whitespace
             }
-            c if c.is_ascii_digit() => {
-                self.eat_number();
-                RawTokenKind::Number
-            }
+            c if c.is_ascii_digit() => self.eat_number(c),
             c if Self::is_identifier_start(c) => {
                 self.eat_identifier();
                 RawTokenKind::Identifier
             }
+            '"' => self.eat_string(),
             '=' => RawTokenKind::Equal,
             '<' => RawTokenKind::LessThan,
             '>' => RawTokenKind::GreaterThan,
@@ -43,13 +41,24 @@ impl<'a> RawTokenCursor<'a> {
             '-' => RawTokenKind::Minus,
             '*' => RawTokenKind::Star,
             '/' => RawTokenKind::Slash,
+            '%' => RawTokenKind::Percent,
+            '^' => RawTokenKind::Caret,
             '&' => RawTokenKind::And,
+            '|' => RawTokenKind::Or,
+            '~' => RawTokenKind::Tilde,
+            '?' => RawTokenKind::Question,
             '(' => RawTokenKind::OpenParenthesis,
             ')' => RawTokenKind::CloseParenthesis,
             '{' => RawTokenKind::OpenBrace,
             '}' => RawTokenKind::CloseBrace,
+            '[' => RawTokenKind::OpenBracket,
+            ']' => RawTokenKind::CloseBracket,
             ';' => RawTokenKind::Semicolon,
             ',' => RawTokenKind::Comma,
+            ':' => RawTokenKind::Colon,
+            '#' => RawTokenKind::Hash,
+            '.' if self.first().is_ascii_digit() => self.eat_float_number_fraction(),
+            '.' => RawTokenKind::Dot,
             _ => RawTokenKind::Unknown,
         };
 
@@ -91,14 +100,116 @@ impl<'a> RawTokenCursor<'a> {
         self.eat_while(char::is_whitespace);
     }
 
-    fn eat_number(&mut self) {
+    fn eat_number(&mut self, first_digit: char) -> RawTokenKind {
+        if first_digit == '0' && matches!(self.first(), 'x' | 'X') {
+            self.bump();
+            self.eat_while(|c| c.is_ascii_hexdigit());
+
+            return match self.first() {
+                '.' => {
+                    self.bump();
+                    self.eat_hexadecimal_float_number_fraction()
+                }
+                'p' | 'P' => self.eat_float_number_exponent(),
+                _ => self.eat_integer_suffix(),
+            };
+        }
+
+        self.eat_while(|c| c.is_ascii_digit());
+
+        match self.first() {
+            '.' => {
+                self.bump();
+                self.eat_float_number_fraction()
+            }
+            'e' | 'E' => self.eat_float_number_exponent(),
+            _ => self.eat_integer_suffix(),
+        }
+    }
+
+    fn eat_integer_suffix(&mut self) -> RawTokenKind {
+        self.eat_while(|c| matches!(c, 'u' | 'U' | 'l' | 'L'));
+
+        RawTokenKind::Number
+    }
+
+    fn eat_float_number_fraction(&mut self) -> RawTokenKind {
+        self.eat_while(|c| c.is_ascii_digit());
+
+        if matches!(self.first(), 'e' | 'E') {
+            return self.eat_float_number_exponent();
+        }
+
+        self.eat_float_number_suffix()
+    }
+
+    fn eat_hexadecimal_float_number_fraction(&mut self) -> RawTokenKind {
+        self.eat_while(|c| c.is_ascii_hexdigit());
+
+        if matches!(self.first(), 'p' | 'P') {
+            return self.eat_float_number_exponent();
+        }
+
+        RawTokenKind::FloatNumber
+    }
+
+    fn eat_float_number_exponent(&mut self) -> RawTokenKind {
+        self.bump();
+
+        if matches!(self.first(), '+' | '-') {
+            self.bump();
+        }
+
         self.eat_while(|c| c.is_ascii_digit());
+
+        self.eat_float_number_suffix()
+    }
+
+    fn eat_float_number_suffix(&mut self) -> RawTokenKind {
+        if matches!(self.first(), 'f' | 'F' | 'l' | 'L') {
+            self.bump();
+        }
+
+        RawTokenKind::FloatNumber
     }
 
     fn eat_identifier(&mut self) {
         self.eat_while(Self::is_identifier_continuation)
     }
 
+    fn eat_string(&mut self) -> RawTokenKind {
+        loop {
+            match self.first() {
+                '"' => {
+                    self.bump();
+                    return RawTokenKind::String {
+                        is_terminated: true,
+                    };
+                }
+                '\\' => {
+                    self.bump();
+
+                    if self.first() != '\n' {
+                        self.bump();
+                    }
+                }
+                '\n' => {
+                    return RawTokenKind::String {
+                        is_terminated: false,
+                    }
+                }
+                _ if self.is_eof() => {
+                    return RawTokenKind::String {
+                        is_terminated: false,
+                    }
+                }
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+    }
+
     fn eat_while(&mut self, predicate: impl Fn(char) -> bool) {
         while predicate(self.first()) && (!self.is_eof()) {
             self.bump();
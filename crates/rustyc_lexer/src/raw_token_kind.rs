@@ -7,15 +7,27 @@ pub enum RawTokenKind {
     Minus,
     Star,
     Slash,
+    Percent,
+    Caret,
     And,
+    Or,
+    Tilde,
+    Question,
     OpenParenthesis,
     CloseParenthesis,
     OpenBrace,
     CloseBrace,
+    OpenBracket,
+    CloseBracket,
     Semicolon,
     Comma,
+    Colon,
+    Dot,
+    Hash,
     Number,
+    FloatNumber,
     Identifier,
+    String { is_terminated: bool },
     Whitespace,
     Eof,
     Unknown,
@@ -1,22 +1,29 @@
-use std::{mem, rc::Rc};
+use std::{collections::HashMap, mem, rc::Rc};
 
 use rustyc_ast::{
     expressions::{
-        AssignmentExpression, BinaryExpression, BinaryOperator, Expression, ExpressionKind,
-        FunctionCallExpression, NumberExpression, UnaryExpression, UnaryOperator,
+        AllocaExpression, AssignmentExpression, BinaryExpression, BinaryOperator,
+        CompoundLiteralExpression, Expression, ExpressionKind, FloatNumberExpression,
+        FunctionCallExpression, GenericAssociation, GenericSelectionExpression, MemberExpression,
+        NumberExpression, SizeOfExpression, SizeOfOperand, StatementExpression, StringExpression,
+        UnaryExpression, UnaryOperator, VaArgExpression, VaEndExpression, VaStartExpression,
         VariableExpression,
     },
-    items::{FunctionItem, Item, ItemKind},
+    initializers::{DesignatedInitializer, Designator, Initializer},
+    items::{FunctionItem, GlobalItem, Item, ItemKind, Parameter},
     statements::{
-        CompoundStatement, ExpressionStatement, IfStatement, LoopStatement, ReturnStatement,
-        Statement, StatementKind,
+        CaseStatement, CompoundStatement, DeclarationStatement, Declarator, DefaultStatement,
+        DoWhileStatement, ExpressionStatement, GotoStatement, IfStatement, LabeledStatement,
+        LoopStatement, ReturnStatement, Statement, StatementKind, SwitchStatement,
     },
-    Block,
+    types::{ArrayTy, FunctionTy, Qualifiers, StructMember, StructTy, Ty, TyKind},
+    Block, NodeId, StaticAssertion, StorageClass,
 };
 use rustyc_diagnostics::Diagnostic;
 use rustyc_span::Span;
 use rustyc_token::{
-    BinaryOperatorToken, DelimiterToken, Keyword, Token, TokenCategory, TokenCategorySet, TokenKind,
+    BinaryOperatorToken, DelimiterToken, FloatNumberToken, Keyword, Token, TokenCategory,
+    TokenCategorySet, TokenKind,
 };
 
 use crate::token_cursor::TokenCursor;
@@ -26,7 +33,8 @@ pub struct Parser {
     token: Token,
     previous_token: Token,
     expected_tokens: TokenCategorySet,
-    local_variables: Vec<String>,
+    tag_scopes: Vec<HashMap<String, Rc<StructTy>>>,
+    next_node_id: u64,
 }
 
 impl Parser {
@@ -36,7 +44,8 @@ impl Parser {
             token: Token::new_eof(),
             previous_token: Token::new_eof(),
             expected_tokens: TokenCategorySet::new(),
-            local_variables: Vec::new(),
+            tag_scopes: vec![HashMap::new()],
+            next_node_id: 0,
         };
 
         parser.bump();
@@ -48,54 +57,229 @@ impl Parser {
         let mut ast: Vec<Rc<Item>> = Vec::new();
 
         while !self.is_eof() {
-            ast.push(self.parse_item()?);
+            ast.append(&mut self.parse_items()?);
         }
 
         Ok(ast)
     }
 
-    fn parse_item(&mut self) -> rustyc_diagnostics::Result<Rc<Item>> {
+    /// Parses a function, a static assertion or a declaration of global variables.
+    fn parse_items(&mut self) -> rustyc_diagnostics::Result<Vec<Rc<Item>>> {
         let low = self.token.get_span().clone();
 
-        let function = self.parse_function()?;
+        if self.check_keyword(Keyword::StaticAssert) {
+            let static_assertion = self.parse_static_assertion()?;
 
-        Ok(Rc::new(Item::new(
-            ItemKind::Function(function),
-            self.compute_span(&low),
-        )))
+            return Ok(vec![Rc::new(Item::new(
+                ItemKind::StaticAssertion(Rc::new(static_assertion)),
+                self.compute_span(&low),
+            ))]);
+        }
+
+        let storage_class = self.parse_storage_class();
+        let base_ty = self.parse_ty_specifier()?;
+
+        if self.eat_tag_declaration(&base_ty) {
+            return Ok(vec![Rc::new(Item::new(
+                ItemKind::TagDeclaration(base_ty),
+                self.compute_span(&low),
+            ))]);
+        }
+
+        let ty = self.parse_pointer_ty(Rc::clone(&base_ty));
+
+        if self.check_function_name() {
+            let function = self.parse_function(storage_class, ty)?;
+
+            return Ok(vec![Rc::new(Item::new(
+                ItemKind::Function(function),
+                self.compute_span(&low),
+            ))]);
+        }
+
+        let mut items: Vec<Rc<Item>> = Vec::new();
+        let mut ty = ty;
+
+        loop {
+            let low = self.token.get_span().clone();
+
+            let global = self.parse_global(storage_class, ty)?;
+            items.push(Rc::new(Item::new(
+                ItemKind::Global(global),
+                self.compute_span(&low),
+            )));
+
+            if !self.eat_comma() {
+                break;
+            }
+
+            ty = Rc::clone(&base_ty);
+        }
+
+        self.expect_semicolon()?;
+
+        Ok(items)
     }
 
-    fn parse_function(&mut self) -> rustyc_diagnostics::Result<Rc<FunctionItem>> {
+    fn parse_function(
+        &mut self,
+        storage_class: Option<StorageClass>,
+        return_ty: Rc<Ty>,
+    ) -> rustyc_diagnostics::Result<Rc<FunctionItem>> {
+        let id = self.allocate_node_id();
+
         let name = self.expect_identifier()?;
 
         self.expect_open_parenthesis()?;
 
-        let parameters = if self.check_close_parenthesis() {
-            Vec::new()
+        let (parameters, is_variadic) = if self.check_close_parenthesis() {
+            (None, false)
+        } else if self.eat_void_parameter_list() {
+            (Some(Vec::new()), false)
         } else {
-            self.parse_function_parameters()?
+            let (parameters, is_variadic) = self.parse_function_parameters()?;
+            (Some(parameters), is_variadic)
         };
 
         self.expect_close_parenthesis()?;
 
-        let body = self.parse_block()?;
+        let body = if self.eat_semicolon() {
+            None
+        } else {
+            // Only the parameters of a declaration can be left unnamed.
+            if let Some(parameter) = parameters
+                .iter()
+                .flatten()
+                .find(|parameter| parameter.get_name().is_none())
+            {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::UnnamedParameter,
+                    parameter.get_span().clone(),
+                ));
+            }
+
+            Some(self.parse_block()?)
+        };
 
-        let function = Rc::new(FunctionItem::new(
+        Ok(Rc::new(FunctionItem::new(
+            id,
             name,
+            storage_class,
+            return_ty,
             parameters,
+            is_variadic,
             body,
-            self.local_variables.clone(),
-        ));
+        )))
+    }
+
+    fn parse_global(
+        &mut self,
+        storage_class: Option<StorageClass>,
+        base_ty: Rc<Ty>,
+    ) -> rustyc_diagnostics::Result<Rc<GlobalItem>> {
+        let (name, ty) = self.parse_declarator(base_ty)?;
+
+        let initializer = if self.eat_equal() {
+            Some(self.parse_initializer()?)
+        } else {
+            None
+        };
+
+        Ok(Rc::new(GlobalItem::new(
+            self.allocate_node_id(),
+            name,
+            ty,
+            storage_class,
+            initializer,
+        )))
+    }
+
+    fn parse_initializer(&mut self) -> rustyc_diagnostics::Result<Rc<Initializer>> {
+        if self.check_open_brace() {
+            self.parse_braced_initializer()
+        } else {
+            Ok(Rc::new(Initializer::Expression(self.parse_assignment()?)))
+        }
+    }
+
+    // The list may be empty, and may end with a comma.
+    fn parse_braced_initializer(&mut self) -> rustyc_diagnostics::Result<Rc<Initializer>> {
+        self.expect_open_brace()?;
+
+        let mut elements: Vec<DesignatedInitializer> = Vec::new();
+
+        while !self.check_close_brace() {
+            let low = self.token.get_span().clone();
+
+            let designators = self.parse_designators()?;
+            let initializer = self.parse_initializer()?;
+
+            elements.push(DesignatedInitializer::new(
+                designators,
+                initializer,
+                self.compute_span(&low),
+            ));
+
+            if !self.eat_comma() {
+                break;
+            }
+        }
+
+        self.expect_close_brace()?;
+
+        Ok(Rc::new(Initializer::List(elements)))
+    }
+
+    fn parse_designators(&mut self) -> rustyc_diagnostics::Result<Vec<Designator>> {
+        let mut designators: Vec<Designator> = Vec::new();
+
+        loop {
+            if self.eat_open_bracket() {
+                designators.push(Designator::Index(self.parse_assignment()?));
+                self.expect_close_bracket()?;
+            } else if self.eat_dot() {
+                designators.push(Designator::Member(self.expect_identifier()?));
+            } else {
+                break;
+            }
+        }
+
+        if !designators.is_empty() {
+            self.expect_equal()?;
+        }
+
+        Ok(designators)
+    }
+
+    fn parse_static_assertion(&mut self) -> rustyc_diagnostics::Result<StaticAssertion> {
+        self.expect_keyword(Keyword::StaticAssert)?;
+        self.expect_open_parenthesis()?;
+
+        let condition = self.parse_assignment()?;
+        self.expect_comma()?;
+        let message = self.expect_string()?;
 
-        self.local_variables.clear();
+        self.expect_close_parenthesis()?;
+        self.expect_semicolon()?;
 
-        Ok(function)
+        Ok(StaticAssertion::new(condition, message))
+    }
+
+    fn parse_storage_class(&mut self) -> Option<StorageClass> {
+        if self.eat_keyword(Keyword::Static) {
+            Some(StorageClass::Static)
+        } else if self.eat_keyword(Keyword::Extern) {
+            Some(StorageClass::Extern)
+        } else {
+            None
+        }
     }
 
     fn parse_block(&mut self) -> rustyc_diagnostics::Result<Rc<Block>> {
         let low = self.token.get_span().clone();
 
         self.expect_open_brace()?;
+        self.tag_scopes.push(HashMap::new());
 
         let mut statements: Vec<Rc<Statement>> = Vec::new();
 
@@ -104,25 +288,49 @@ impl Parser {
             statements.push(statement);
         }
 
+        self.tag_scopes.pop();
+
         Ok(Rc::new(Block::new(statements, self.compute_span(&low))))
     }
 
     fn parse_statement(&mut self) -> rustyc_diagnostics::Result<Rc<Statement>> {
         let low = self.token.get_span().clone();
 
-        let kind = if self.check_keyword(Keyword::Return) {
-            self.parse_return_statement()?
-        } else if self.check_keyword(Keyword::If) {
-            self.parse_if_statement()?
-        } else if self.check_keyword(Keyword::For) {
-            self.parse_for_statement()?
-        } else if self.check_keyword(Keyword::While) {
-            self.parse_while_statement()?
-        } else if self.check_open_brace() {
-            self.parse_compound_statement()?
-        } else {
-            self.parse_expression_statement()?
-        };
+        let kind =
+            if self.check_storage_class() || self.check_ty_qualifier() || self.check_ty_specifier()
+            {
+                self.parse_declaration_statement()?
+            } else if self.check_keyword(Keyword::Return) {
+                self.parse_return_statement()?
+            } else if self.check_keyword(Keyword::If) {
+                self.parse_if_statement()?
+            } else if self.check_keyword(Keyword::For) {
+                self.parse_for_statement()?
+            } else if self.check_keyword(Keyword::While) {
+                self.parse_while_statement()?
+            } else if self.check_keyword(Keyword::Do) {
+                self.parse_do_while_statement()?
+            } else if self.check_keyword(Keyword::Switch) {
+                self.parse_switch_statement()?
+            } else if self.check_keyword(Keyword::Case) {
+                self.parse_case_statement()?
+            } else if self.check_keyword(Keyword::Default) {
+                self.parse_default_statement()?
+            } else if self.check_keyword(Keyword::Goto) {
+                self.parse_goto_statement()?
+            } else if self.check_keyword(Keyword::StaticAssert) {
+                StatementKind::StaticAssertion(Rc::new(self.parse_static_assertion()?))
+            } else if self.check_keyword(Keyword::Break) {
+                self.parse_break_statement()?
+            } else if self.check_keyword(Keyword::Continue) {
+                self.parse_continue_statement()?
+            } else if self.check_label() {
+                self.parse_labeled_statement()?
+            } else if self.check_open_brace() {
+                self.parse_compound_statement()?
+            } else {
+                self.parse_expression_statement()?
+            };
 
         Ok(Rc::new(Statement::new(kind, self.compute_span(&low))))
     }
@@ -130,7 +338,11 @@ impl Parser {
     fn parse_return_statement(&mut self) -> rustyc_diagnostics::Result<StatementKind> {
         self.expect_keyword(Keyword::Return)?;
 
-        let expression = self.parse_expression()?;
+        let expression = if self.check_semicolon() {
+            None
+        } else {
+            Some(self.parse_expression()?)
+        };
         self.expect_semicolon()?;
 
         Ok(StatementKind::Return(ReturnStatement::new(expression)))
@@ -164,10 +376,13 @@ impl Parser {
         self.expect_open_parenthesis()?;
 
         let low = self.token.get_span().clone();
-        let initialization_statement = Rc::new(Statement::new(
-            self.parse_expression_statement()?,
-            self.compute_span(&low),
-        ));
+        let initialization_kind = if self.check_ty_qualifier() || self.check_ty_specifier() {
+            self.parse_declaration_statement()?
+        } else {
+            self.parse_expression_statement()?
+        };
+        let initialization_statement =
+            Rc::new(Statement::new(initialization_kind, self.compute_span(&low)));
 
         let condition_expression = if self.check_semicolon() {
             None
@@ -198,23 +413,484 @@ impl Parser {
     fn parse_while_statement(&mut self) -> rustyc_diagnostics::Result<StatementKind> {
         self.expect_keyword(Keyword::While)?;
 
-        self.expect_open_parenthesis()?;
-        let condition_expression = self.parse_expression()?;
-        self.expect_close_parenthesis()?;
+        self.expect_open_parenthesis()?;
+        let condition_expression = self.parse_expression()?;
+        self.expect_close_parenthesis()?;
+
+        let then_statement = self.parse_statement()?;
+
+        Ok(StatementKind::Loop(LoopStatement::new(
+            None,
+            Some(condition_expression),
+            None,
+            then_statement,
+        )))
+    }
+
+    fn parse_do_while_statement(&mut self) -> rustyc_diagnostics::Result<StatementKind> {
+        self.expect_keyword(Keyword::Do)?;
+
+        let then_statement = self.parse_statement()?;
+
+        self.expect_keyword(Keyword::While)?;
+
+        self.expect_open_parenthesis()?;
+        let condition_expression = self.parse_expression()?;
+        self.expect_close_parenthesis()?;
+
+        self.expect_semicolon()?;
+
+        Ok(StatementKind::DoWhile(DoWhileStatement::new(
+            then_statement,
+            condition_expression,
+        )))
+    }
+
+    fn parse_switch_statement(&mut self) -> rustyc_diagnostics::Result<StatementKind> {
+        self.expect_keyword(Keyword::Switch)?;
+
+        self.expect_open_parenthesis()?;
+        let condition_expression = self.parse_expression()?;
+        self.expect_close_parenthesis()?;
+
+        let then_statement = self.parse_statement()?;
+
+        Ok(StatementKind::Switch(SwitchStatement::new(
+            condition_expression,
+            then_statement,
+        )))
+    }
+
+    fn parse_case_statement(&mut self) -> rustyc_diagnostics::Result<StatementKind> {
+        self.expect_keyword(Keyword::Case)?;
+
+        let expression = self.parse_expression()?;
+        self.expect_colon()?;
+
+        let statement = self.parse_statement()?;
+
+        Ok(StatementKind::Case(CaseStatement::new(
+            expression, statement,
+        )))
+    }
+
+    fn parse_default_statement(&mut self) -> rustyc_diagnostics::Result<StatementKind> {
+        self.expect_keyword(Keyword::Default)?;
+        self.expect_colon()?;
+
+        let statement = self.parse_statement()?;
+
+        Ok(StatementKind::Default(DefaultStatement::new(statement)))
+    }
+
+    fn parse_goto_statement(&mut self) -> rustyc_diagnostics::Result<StatementKind> {
+        self.expect_keyword(Keyword::Goto)?;
+
+        let label = self.expect_identifier()?;
+        self.expect_semicolon()?;
+
+        Ok(StatementKind::Goto(GotoStatement::new(label)))
+    }
+
+    fn parse_labeled_statement(&mut self) -> rustyc_diagnostics::Result<StatementKind> {
+        let label = self.expect_identifier()?;
+        self.expect_colon()?;
+
+        let statement = self.parse_statement()?;
+
+        Ok(StatementKind::Labeled(LabeledStatement::new(
+            label, statement,
+        )))
+    }
+
+    fn parse_break_statement(&mut self) -> rustyc_diagnostics::Result<StatementKind> {
+        self.expect_keyword(Keyword::Break)?;
+        self.expect_semicolon()?;
+
+        Ok(StatementKind::Break)
+    }
+
+    fn parse_continue_statement(&mut self) -> rustyc_diagnostics::Result<StatementKind> {
+        self.expect_keyword(Keyword::Continue)?;
+        self.expect_semicolon()?;
+
+        Ok(StatementKind::Continue)
+    }
+
+    fn parse_compound_statement(&mut self) -> rustyc_diagnostics::Result<StatementKind> {
+        let block = self.parse_block()?;
+        Ok(StatementKind::Compound(CompoundStatement::new(block)))
+    }
+
+    fn parse_declaration_statement(&mut self) -> rustyc_diagnostics::Result<StatementKind> {
+        let storage_class = self.parse_storage_class();
+        let base_ty = self.parse_ty_specifier()?;
+
+        if self.eat_tag_declaration(&base_ty) {
+            return Ok(StatementKind::TagDeclaration(base_ty));
+        }
+
+        let mut declarators: Vec<Declarator> = Vec::new();
+
+        loop {
+            let low = self.token.get_span().clone();
+
+            let (name, ty) = self.parse_declarator(Rc::clone(&base_ty))?;
+
+            let initializer = if self.eat_equal() {
+                Some(self.parse_initializer()?)
+            } else {
+                None
+            };
+
+            declarators.push(Declarator::new(
+                self.allocate_node_id(),
+                name,
+                ty,
+                storage_class,
+                initializer,
+                self.compute_span(&low),
+            ));
+
+            if !self.eat_comma() {
+                break;
+            }
+        }
+
+        self.expect_semicolon()?;
+
+        Ok(StatementKind::Declaration(DeclarationStatement::new(
+            declarators,
+        )))
+    }
+
+    /// Parses a type specifier along with the qualifiers around it.
+    fn parse_ty_specifier(&mut self) -> rustyc_diagnostics::Result<Rc<Ty>> {
+        let low = self.token.get_span().clone();
+
+        let qualifiers = self.parse_ty_qualifiers(Qualifiers::default());
+
+        let kind = if self.eat_keyword(Keyword::Void) {
+            TyKind::Void
+        } else if self.eat_keyword(Keyword::Bool) {
+            TyKind::Bool
+        } else if self.eat_keyword(Keyword::Char) {
+            TyKind::Char
+        } else if self.eat_keyword(Keyword::Int) {
+            TyKind::Int
+        } else if self.eat_keyword(Keyword::Signed) {
+            if self.eat_keyword(Keyword::Char) {
+                TyKind::Char
+            } else {
+                self.eat_keyword(Keyword::Int);
+                TyKind::Int
+            }
+        } else if self.eat_keyword(Keyword::Unsigned) {
+            if self.eat_keyword(Keyword::Char) {
+                TyKind::UnsignedChar
+            } else {
+                self.eat_keyword(Keyword::Int);
+                TyKind::UnsignedInt
+            }
+        } else if self.check_keyword(Keyword::Struct) {
+            self.parse_struct_specifier()?
+        } else if self.eat_keyword(Keyword::Float) {
+            TyKind::Float
+        } else if self.eat_keyword(Keyword::Double) {
+            TyKind::Double
+        } else if self.eat_keyword(Keyword::BuiltinVaList) {
+            TyKind::VaList
+        } else {
+            return Err(self.unexpected_token());
+        };
+
+        let qualifiers = self.parse_ty_qualifiers(qualifiers);
+
+        // None of the type specifiers designate a pointer type.
+        if qualifiers.is_restrict() {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::InvalidRestrictQualifier,
+                self.compute_span(&low),
+            ));
+        }
+
+        Ok(Rc::new(Ty::new(kind, qualifiers, self.compute_span(&low))))
+    }
+
+    fn parse_struct_specifier(&mut self) -> rustyc_diagnostics::Result<TyKind> {
+        let low = self.token.get_span().clone();
+
+        self.expect_keyword(Keyword::Struct)?;
+
+        let tag = self.eat_identifier();
+
+        if !self.check_open_brace() {
+            let Some(tag) = tag else {
+                return Err(self.unexpected_token());
+            };
+
+            let struct_ty = match self.lookup_tag(&tag) {
+                Some(struct_ty) => struct_ty,
+                None => self.declare_tag(tag),
+            };
+
+            return Ok(TyKind::Struct(struct_ty));
+        }
+
+        // The structure is declared before its members are parsed, so that
+        // they can point to it.
+        let struct_ty = match tag {
+            Some(tag) => match self.tag_scopes.last().unwrap().get(&tag) {
+                Some(struct_ty) if struct_ty.is_defined() => {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::StructRedefinition(tag),
+                        self.compute_span(&low),
+                    ));
+                }
+                Some(struct_ty) => Rc::clone(struct_ty),
+                None => self.declare_tag(tag),
+            },
+            None => Rc::new(StructTy::new(self.allocate_node_id(), None)),
+        };
+
+        self.expect_open_brace()?;
+
+        let mut members: Vec<StructMember> = Vec::new();
+
+        while !self.eat_close_brace() {
+            self.parse_struct_members(&mut members)?;
+        }
+
+        struct_ty.define(members);
+
+        Ok(TyKind::Struct(struct_ty))
+    }
+
+    fn parse_struct_members(
+        &mut self,
+        members: &mut Vec<StructMember>,
+    ) -> rustyc_diagnostics::Result<()> {
+        let low = self.token.get_span().clone();
+
+        let base_ty = self.parse_ty_specifier()?;
+
+        // An unnamed bit-field only pads the members that follow it.
+        if self.eat_colon() {
+            let width = self.parse_assignment()?;
+            self.expect_semicolon()?;
+
+            members.push(StructMember::new(
+                None,
+                base_ty,
+                Some(width),
+                self.compute_span(&low),
+            ));
+
+            return Ok(());
+        }
+
+        loop {
+            let low = self.token.get_span().clone();
+
+            let (name, ty) = self.parse_declarator(Rc::clone(&base_ty))?;
+
+            let width = if self.eat_colon() {
+                Some(self.parse_assignment()?)
+            } else {
+                None
+            };
+
+            members.push(StructMember::new(
+                Some(name),
+                ty,
+                width,
+                self.compute_span(&low),
+            ));
+
+            if !self.eat_comma() {
+                break;
+            }
+        }
+
+        self.expect_semicolon()
+    }
+
+    /// Parses a sequence of type qualifiers, adding them to `qualifiers`.
+    fn parse_ty_qualifiers(&mut self, qualifiers: Qualifiers) -> Qualifiers {
+        let mut is_const = qualifiers.is_const();
+        let mut is_volatile = qualifiers.is_volatile();
+        let mut is_restrict = qualifiers.is_restrict();
+
+        loop {
+            if self.eat_keyword(Keyword::Const) {
+                is_const = true;
+            } else if self.eat_keyword(Keyword::Volatile) {
+                is_volatile = true;
+            } else if self.eat_keyword(Keyword::Restrict) {
+                is_restrict = true;
+            } else {
+                break;
+            }
+        }
+
+        Qualifiers::new(is_const, is_volatile, is_restrict)
+    }
+
+    fn parse_declarator(
+        &mut self,
+        base_ty: Rc<Ty>,
+    ) -> rustyc_diagnostics::Result<(String, Rc<Ty>)> {
+        self.expected_tokens.clear();
+
+        match self.parse_optional_declarator(base_ty)? {
+            (Some(name), ty) => Ok((name, ty)),
+            (None, _) => Err(self.unexpected_token()),
+        }
+    }
+
+    /// Parses a declarator whose name can be omitted.
+    fn parse_optional_declarator(
+        &mut self,
+        base_ty: Rc<Ty>,
+    ) -> rustyc_diagnostics::Result<(Option<String>, Rc<Ty>)> {
+        let ty = self.parse_pointer_ty(base_ty);
+
+        // A parenthesized declarator applies to the type built by the
+        // suffixes that follow it, as in `int (*callback)(int)` or
+        // `int (*table[2])(int)`. It is parsed for `ty` first, which is then
+        // replaced by the type with the suffixes.
+        if self.eat_open_parenthesis() {
+            let (name, inner_ty) = self.parse_optional_declarator(Rc::clone(&ty))?;
+            self.expect_close_parenthesis()?;
+
+            let outer_ty = self.parse_array_ty_suffix(Rc::clone(&ty))?;
+            let outer_ty = self.parse_function_ty_suffix(outer_ty)?;
+
+            return Ok((name, Self::replace_ty(inner_ty, &ty, &outer_ty)));
+        }
+
+        let name = self.eat_identifier();
+        let ty = self.parse_array_ty_suffix(ty)?;
+
+        Ok((name, ty))
+    }
+
+    fn replace_ty(ty: Rc<Ty>, placeholder: &Rc<Ty>, replacement: &Rc<Ty>) -> Rc<Ty> {
+        if Rc::ptr_eq(&ty, placeholder) {
+            return Rc::clone(replacement);
+        }
+
+        let kind = match ty.get_kind() {
+            TyKind::Pointer(pointee_ty) => TyKind::Pointer(Self::replace_ty(
+                Rc::clone(pointee_ty),
+                placeholder,
+                replacement,
+            )),
+            TyKind::Array(array_ty) => TyKind::Array(ArrayTy::new(
+                Self::replace_ty(array_ty.get_element_ty(), placeholder, replacement),
+                array_ty.get_length(),
+            )),
+            TyKind::Function(function_ty) => TyKind::Function(FunctionTy::new(
+                Self::replace_ty(function_ty.get_return_ty(), placeholder, replacement),
+                function_ty.get_parameters().map(<[Rc<Ty>]>::to_vec),
+                function_ty.is_variadic(),
+            )),
+            _ => return ty,
+        };
+
+        Rc::new(Ty::new(kind, ty.get_qualifiers(), ty.get_span().clone()))
+    }
+
+    fn parse_array_ty_suffix(&mut self, element_ty: Rc<Ty>) -> rustyc_diagnostics::Result<Rc<Ty>> {
+        let low = element_ty.get_span().clone();
+
+        let mut lengths: Vec<Option<Rc<Expression>>> = Vec::new();
+        while self.eat_open_bracket() {
+            if self.eat_close_bracket() {
+                lengths.push(None);
+                continue;
+            }
+
+            lengths.push(Some(self.parse_assignment()?));
+            self.expect_close_bracket()?;
+        }
+
+        let mut ty = element_ty;
+        for length in lengths.into_iter().rev() {
+            ty = Rc::new(Ty::new(
+                TyKind::Array(ArrayTy::new(ty, length)),
+                Qualifiers::default(),
+                self.compute_span(&low),
+            ));
+        }
+
+        Ok(ty)
+    }
+
+    fn parse_function_ty_suffix(
+        &mut self,
+        return_ty: Rc<Ty>,
+    ) -> rustyc_diagnostics::Result<Rc<Ty>> {
+        let low = return_ty.get_span().clone();
+
+        if !self.eat_open_parenthesis() {
+            return Ok(return_ty);
+        }
+
+        let (parameters, is_variadic) = if self.check_close_parenthesis() {
+            (None, false)
+        } else if self.eat_void_parameter_list() {
+            (Some(Vec::new()), false)
+        } else {
+            let (parameters, is_variadic) = self.parse_function_ty_parameters()?;
+            (Some(parameters), is_variadic)
+        };
+
+        self.expect_close_parenthesis()?;
+
+        Ok(Rc::new(Ty::new(
+            TyKind::Function(FunctionTy::new(return_ty, parameters, is_variadic)),
+            Qualifiers::default(),
+            self.compute_span(&low),
+        )))
+    }
+
+    fn parse_function_ty_parameters(&mut self) -> rustyc_diagnostics::Result<(Vec<Rc<Ty>>, bool)> {
+        let mut parameters: Vec<Rc<Ty>> = Vec::new();
+        let mut is_variadic = false;
+
+        loop {
+            let base_ty = self.parse_ty_specifier()?;
+            let (_, ty) = self.parse_optional_declarator(base_ty)?;
+            parameters.push(ty);
+
+            if !self.eat_comma() {
+                break;
+            }
 
-        let then_statement = self.parse_statement()?;
+            if self.eat_dot_dot_dot() {
+                is_variadic = true;
+                break;
+            }
+        }
 
-        Ok(StatementKind::Loop(LoopStatement::new(
-            None,
-            Some(condition_expression),
-            None,
-            then_statement,
-        )))
+        Ok((parameters, is_variadic))
     }
 
-    fn parse_compound_statement(&mut self) -> rustyc_diagnostics::Result<StatementKind> {
-        let block = self.parse_block()?;
-        Ok(StatementKind::Compound(CompoundStatement::new(block)))
+    fn parse_pointer_ty(&mut self, base_ty: Rc<Ty>) -> Rc<Ty> {
+        let low = base_ty.get_span().clone();
+
+        let mut ty = base_ty;
+        while self.eat_star() {
+            let qualifiers = self.parse_ty_qualifiers(Qualifiers::default());
+            ty = Rc::new(Ty::new(
+                TyKind::Pointer(ty),
+                qualifiers,
+                self.compute_span(&low),
+            ));
+        }
+
+        ty
     }
 
     fn parse_expression_statement(&mut self) -> rustyc_diagnostics::Result<StatementKind> {
@@ -399,29 +1075,114 @@ impl Parser {
             return Ok(self.new_unary_expression(UnaryOperator::Dereference, operand, &low));
         }
 
-        self.parse_primary()
+        if self.eat_keyword(Keyword::Sizeof) {
+            let operand_low = self.token.get_span().clone();
+
+            let operand = if self.check_parenthesized_ty_name() {
+                self.expect_open_parenthesis()?;
+                let base_ty = self.parse_ty_specifier()?;
+                let ty = self.parse_pointer_ty(base_ty);
+                let ty = self.parse_array_ty_suffix(ty)?;
+                self.expect_close_parenthesis()?;
+
+                // The parenthesized type may start a compound literal, as in
+                // `sizeof (int[]){1, 2}`.
+                if self.check_open_brace() {
+                    let expression = self.parse_compound_literal_initializer(ty, &operand_low)?;
+                    SizeOfOperand::Expression(
+                        self.parse_postfix_operators(expression, &operand_low)?,
+                    )
+                } else {
+                    SizeOfOperand::Ty(ty)
+                }
+            } else {
+                SizeOfOperand::Expression(self.parse_unary()?)
+            };
+
+            return Ok(
+                self.new_expression(ExpressionKind::SizeOf(SizeOfExpression::new(operand)), &low)
+            );
+        }
+
+        self.parse_postfix()
+    }
+
+    fn parse_postfix(&mut self) -> rustyc_diagnostics::Result<Rc<Expression>> {
+        let low = self.token.get_span().clone();
+
+        let expression = self.parse_primary()?;
+        self.parse_postfix_operators(expression, &low)
+    }
+
+    fn parse_postfix_operators(
+        &mut self,
+        mut expression: Rc<Expression>,
+        low: &Span,
+    ) -> rustyc_diagnostics::Result<Rc<Expression>> {
+        loop {
+            if self.eat_open_parenthesis() {
+                expression = self.parse_function_call(expression, low)?;
+            } else if self.eat_open_bracket() {
+                // `a[i]` is the same as `*(a + i)`.
+                let index = self.parse_expression()?;
+                self.expect_close_bracket()?;
+
+                let address =
+                    self.new_binary_expression(BinaryOperator::Add, expression, index, low);
+                expression = self.new_unary_expression(UnaryOperator::Dereference, address, low);
+            } else if self.eat_dot() {
+                let name = self.expect_identifier()?;
+                expression = self.new_member_expression(expression, name, low);
+            } else if self.eat_arrow() {
+                let name = self.expect_identifier()?;
+                let base = self.new_unary_expression(UnaryOperator::Dereference, expression, low);
+                expression = self.new_member_expression(base, name, low);
+            } else {
+                break;
+            }
+        }
+
+        Ok(expression)
     }
 
     fn parse_primary(&mut self) -> rustyc_diagnostics::Result<Rc<Expression>> {
         let low = self.token.get_span().clone();
 
         if self.eat_open_parenthesis() {
+            if self.check_open_brace() {
+                return self.parse_statement_expression(&low);
+            }
+
+            if self.check_ty_specifier() || self.check_ty_qualifier() {
+                return self.parse_compound_literal(&low);
+            }
+
             let expression = self.parse_expression()?;
             self.expect_close_parenthesis()?;
             return Ok(expression);
         }
 
-        if let Some(identifier) = self.eat_identifier() {
-            if self.eat_open_parenthesis() {
-                return self.parse_function_call(identifier, &low);
-            }
+        if self.check_keyword(Keyword::Generic) {
+            return self.parse_generic_selection();
+        }
 
-            // TODO: Currently, variable accesses also add a variable to the local
-            // variables list. This should only be done for variable declarations.
-            if !self.local_variables.contains(&identifier) {
-                self.local_variables.insert(0, identifier.clone());
-            }
+        if self.check_keyword(Keyword::BuiltinVaStart) {
+            return self.parse_va_start();
+        }
+
+        if self.check_keyword(Keyword::BuiltinVaArg) {
+            return self.parse_va_arg();
+        }
+
+        if self.check_keyword(Keyword::BuiltinVaEnd) {
+            return self.parse_va_end();
+        }
+
+        if self.check_keyword(Keyword::BuiltinAlloca) {
+            return self.parse_alloca();
+        }
 
+        if let Some(identifier) = self.eat_identifier() {
             return Ok(self.new_variable_expression(identifier, &low));
         }
 
@@ -429,31 +1190,206 @@ impl Parser {
             return Ok(self.new_number_expression(number, &low));
         }
 
+        if let Some(number) = self.eat_float_number() {
+            return Ok(self.new_expression(
+                ExpressionKind::FloatNumber(FloatNumberExpression::new(
+                    number.get_value(),
+                    number.is_single_precision(),
+                )),
+                &low,
+            ));
+        }
+
+        if let Some(mut value) = self.eat_string() {
+            while let Some(next_value) = self.eat_string() {
+                value.push_str(&next_value);
+            }
+
+            let id = self.allocate_node_id();
+
+            return Ok(self.new_expression(
+                ExpressionKind::String(StringExpression::new(id, value)),
+                &low,
+            ));
+        }
+
         Err(Diagnostic::new_error(
             rustyc_diagnostics::Error::ExpressionExpected,
             self.token.get_span().clone(),
         ))
     }
 
-    fn parse_function_parameters(&mut self) -> rustyc_diagnostics::Result<Vec<String>> {
-        let mut parameters: Vec<String> = Vec::new();
+    /// Parses a compound literal after its opening parenthesis.
+    fn parse_compound_literal(&mut self, low: &Span) -> rustyc_diagnostics::Result<Rc<Expression>> {
+        let base_ty = self.parse_ty_specifier()?;
+        let ty = self.parse_pointer_ty(base_ty);
+        let ty = self.parse_array_ty_suffix(ty)?;
+
+        self.expect_close_parenthesis()?;
+
+        self.parse_compound_literal_initializer(ty, low)
+    }
+
+    /// Parses the initializer of a compound literal after its type.
+    fn parse_compound_literal_initializer(
+        &mut self,
+        ty: Rc<Ty>,
+        low: &Span,
+    ) -> rustyc_diagnostics::Result<Rc<Expression>> {
+        let initializer = self.parse_braced_initializer()?;
+
+        let id = self.allocate_node_id();
+
+        Ok(self.new_expression(
+            ExpressionKind::CompoundLiteral(CompoundLiteralExpression::new(id, ty, initializer)),
+            low,
+        ))
+    }
+
+    /// Parses a statement expression after its opening parenthesis.
+    fn parse_statement_expression(
+        &mut self,
+        low: &Span,
+    ) -> rustyc_diagnostics::Result<Rc<Expression>> {
+        let block = self.parse_block()?;
+
+        self.expect_close_parenthesis()?;
+
+        Ok(self.new_expression(
+            ExpressionKind::Statement(StatementExpression::new(block)),
+            low,
+        ))
+    }
+
+    fn parse_generic_selection(&mut self) -> rustyc_diagnostics::Result<Rc<Expression>> {
+        let low = self.token.get_span().clone();
+
+        self.expect_keyword(Keyword::Generic)?;
+        self.expect_open_parenthesis()?;
+
+        let controlling_expression = self.parse_assignment()?;
+
+        let mut associations: Vec<GenericAssociation> = Vec::new();
+
+        while self.eat_comma() {
+            let ty = if self.eat_keyword(Keyword::Default) {
+                None
+            } else {
+                let base_ty = self.parse_ty_specifier()?;
+                Some(self.parse_pointer_ty(base_ty))
+            };
+
+            self.expect_colon()?;
+
+            let expression = self.parse_assignment()?;
+            associations.push(GenericAssociation::new(ty, expression));
+        }
+
+        self.expect_close_parenthesis()?;
+
+        Ok(self.new_expression(
+            ExpressionKind::GenericSelection(GenericSelectionExpression::new(
+                controlling_expression,
+                associations,
+            )),
+            &low,
+        ))
+    }
+
+    fn parse_va_start(&mut self) -> rustyc_diagnostics::Result<Rc<Expression>> {
+        let low = self.token.get_span().clone();
+
+        self.expect_keyword(Keyword::BuiltinVaStart)?;
+        self.expect_open_parenthesis()?;
+
+        let list = self.parse_assignment()?;
+        self.expect_comma()?;
+        let last_parameter = self.parse_assignment()?;
+
+        self.expect_close_parenthesis()?;
+
+        Ok(self.new_expression(
+            ExpressionKind::VaStart(VaStartExpression::new(list, last_parameter)),
+            &low,
+        ))
+    }
+
+    fn parse_va_arg(&mut self) -> rustyc_diagnostics::Result<Rc<Expression>> {
+        let low = self.token.get_span().clone();
+
+        self.expect_keyword(Keyword::BuiltinVaArg)?;
+        self.expect_open_parenthesis()?;
+
+        let list = self.parse_assignment()?;
+        self.expect_comma()?;
+        let base_ty = self.parse_ty_specifier()?;
+        let ty = self.parse_pointer_ty(base_ty);
+
+        self.expect_close_parenthesis()?;
+
+        Ok(self.new_expression(ExpressionKind::VaArg(VaArgExpression::new(list, ty)), &low))
+    }
+
+    fn parse_va_end(&mut self) -> rustyc_diagnostics::Result<Rc<Expression>> {
+        let low = self.token.get_span().clone();
+
+        self.expect_keyword(Keyword::BuiltinVaEnd)?;
+        self.expect_open_parenthesis()?;
+
+        let list = self.parse_assignment()?;
+
+        self.expect_close_parenthesis()?;
+
+        Ok(self.new_expression(ExpressionKind::VaEnd(VaEndExpression::new(list)), &low))
+    }
+
+    fn parse_alloca(&mut self) -> rustyc_diagnostics::Result<Rc<Expression>> {
+        let low = self.token.get_span().clone();
+
+        self.expect_keyword(Keyword::BuiltinAlloca)?;
+        self.expect_open_parenthesis()?;
+
+        let size = self.parse_assignment()?;
+
+        self.expect_close_parenthesis()?;
+
+        Ok(self.new_expression(ExpressionKind::Alloca(AllocaExpression::new(size)), &low))
+    }
+
+    fn parse_function_parameters(&mut self) -> rustyc_diagnostics::Result<(Vec<Parameter>, bool)> {
+        let mut parameters: Vec<Parameter> = Vec::new();
+        let mut is_variadic = false;
 
         loop {
-            let parameter = self.expect_identifier()?;
-            self.local_variables.push(parameter.clone());
-            parameters.push(parameter);
+            let low = self.token.get_span().clone();
+
+            let base_ty = self.parse_ty_specifier()?;
+            let (name, ty) = self.parse_optional_declarator(base_ty)?;
+
+            parameters.push(Parameter::new(
+                self.allocate_node_id(),
+                name,
+                ty,
+                self.compute_span(&low),
+            ));
 
             if !self.eat_comma() {
                 break;
             }
+
+            // An ellipsis can only follow the last named parameter.
+            if self.eat_dot_dot_dot() {
+                is_variadic = true;
+                break;
+            }
         }
 
-        Ok(parameters)
+        Ok((parameters, is_variadic))
     }
 
     fn parse_function_call(
         &mut self,
-        name: String,
+        callee: Rc<Expression>,
         low: &Span,
     ) -> rustyc_diagnostics::Result<Rc<Expression>> {
         let arguments = if self.check_close_parenthesis() {
@@ -464,7 +1400,7 @@ impl Parser {
 
         self.expect_close_parenthesis()?;
 
-        Ok(self.new_function_call_expression(name, arguments, low))
+        Ok(self.new_function_call_expression(callee, arguments, low))
     }
 
     fn parse_function_call_arguments(&mut self) -> rustyc_diagnostics::Result<Vec<Rc<Expression>>> {
@@ -518,8 +1454,24 @@ impl Parser {
         )
     }
 
-    fn new_variable_expression(&self, name: String, low: &Span) -> Rc<Expression> {
-        self.new_expression(ExpressionKind::Variable(VariableExpression::new(name)), low)
+    fn new_member_expression(
+        &self,
+        base: Rc<Expression>,
+        name: String,
+        low: &Span,
+    ) -> Rc<Expression> {
+        self.new_expression(
+            ExpressionKind::Member(MemberExpression::new(base, name)),
+            low,
+        )
+    }
+
+    fn new_variable_expression(&mut self, name: String, low: &Span) -> Rc<Expression> {
+        let id = self.allocate_node_id();
+        self.new_expression(
+            ExpressionKind::Variable(VariableExpression::new(id, name)),
+            low,
+        )
     }
 
     fn new_number_expression(&self, value: u64, low: &Span) -> Rc<Expression> {
@@ -528,12 +1480,12 @@ impl Parser {
 
     fn new_function_call_expression(
         &self,
-        name: String,
+        callee: Rc<Expression>,
         arguments: Vec<Rc<Expression>>,
         low: &Span,
     ) -> Rc<Expression> {
         self.new_expression(
-            ExpressionKind::FunctionCall(FunctionCallExpression::new(name, arguments)),
+            ExpressionKind::FunctionCall(FunctionCallExpression::new(callee, arguments)),
             low,
         )
     }
@@ -542,6 +1494,31 @@ impl Parser {
         Rc::new(Expression::new(kind, self.compute_span(low)))
     }
 
+    fn declare_tag(&mut self, tag: String) -> Rc<StructTy> {
+        let struct_ty = Rc::new(StructTy::new(self.allocate_node_id(), Some(tag.clone())));
+
+        self.tag_scopes
+            .last_mut()
+            .unwrap()
+            .insert(tag, Rc::clone(&struct_ty));
+
+        struct_ty
+    }
+
+    fn lookup_tag(&self, tag: &str) -> Option<Rc<StructTy>> {
+        self.tag_scopes
+            .iter()
+            .rev()
+            .find_map(|tag_scope| tag_scope.get(tag))
+            .cloned()
+    }
+
+    fn allocate_node_id(&mut self) -> NodeId {
+        let id = NodeId::new(self.next_node_id);
+        self.next_node_id += 1;
+        id
+    }
+
     fn expect_open_parenthesis(&mut self) -> rustyc_diagnostics::Result<()> {
         self.expected_tokens.clear();
 
@@ -562,6 +1539,16 @@ impl Parser {
         }
     }
 
+    fn expect_close_bracket(&mut self) -> rustyc_diagnostics::Result<()> {
+        self.expected_tokens.clear();
+
+        if self.eat_close_bracket() {
+            Ok(())
+        } else {
+            Err(self.unexpected_token())
+        }
+    }
+
     fn expect_open_brace(&mut self) -> rustyc_diagnostics::Result<()> {
         self.expected_tokens.clear();
 
@@ -572,6 +1559,26 @@ impl Parser {
         }
     }
 
+    fn expect_close_brace(&mut self) -> rustyc_diagnostics::Result<()> {
+        self.expected_tokens.clear();
+
+        if self.eat_close_brace() {
+            Ok(())
+        } else {
+            Err(self.unexpected_token())
+        }
+    }
+
+    fn expect_comma(&mut self) -> rustyc_diagnostics::Result<()> {
+        self.expected_tokens.clear();
+
+        if self.eat_comma() {
+            Ok(())
+        } else {
+            Err(self.unexpected_token())
+        }
+    }
+
     fn expect_semicolon(&mut self) -> rustyc_diagnostics::Result<()> {
         self.expected_tokens.clear();
 
@@ -582,6 +1589,26 @@ impl Parser {
         }
     }
 
+    fn expect_colon(&mut self) -> rustyc_diagnostics::Result<()> {
+        self.expected_tokens.clear();
+
+        if self.eat_colon() {
+            Ok(())
+        } else {
+            Err(self.unexpected_token())
+        }
+    }
+
+    fn expect_equal(&mut self) -> rustyc_diagnostics::Result<()> {
+        self.expected_tokens.clear();
+
+        if self.eat_equal() {
+            Ok(())
+        } else {
+            Err(self.unexpected_token())
+        }
+    }
+
     fn expect_keyword(&mut self, keyword: Keyword) -> rustyc_diagnostics::Result<()> {
         self.expected_tokens.clear();
 
@@ -602,6 +1629,21 @@ impl Parser {
         }
     }
 
+    /// Expects a string literal, concatenating any adjacent ones.
+    fn expect_string(&mut self) -> rustyc_diagnostics::Result<String> {
+        self.expected_tokens.clear();
+
+        let Some(mut value) = self.eat_string() else {
+            return Err(self.unexpected_token());
+        };
+
+        while let Some(next_value) = self.eat_string() {
+            value.push_str(&next_value);
+        }
+
+        Ok(value)
+    }
+
     fn unexpected_token(&self) -> Diagnostic {
         let error = if self.expected_tokens.len() > 1 {
             rustyc_diagnostics::Error::UnexpectedTokenMultiple(
@@ -682,14 +1724,38 @@ impl Parser {
         self.eat_close_delimiter(DelimiterToken::Brace)
     }
 
+    fn eat_open_bracket(&mut self) -> bool {
+        self.eat_open_delimiter(DelimiterToken::Bracket)
+    }
+
+    fn eat_close_bracket(&mut self) -> bool {
+        self.eat_close_delimiter(DelimiterToken::Bracket)
+    }
+
     fn eat_semicolon(&mut self) -> bool {
         self.eat(TokenKind::Semicolon)
     }
 
+    fn eat_dot_dot_dot(&mut self) -> bool {
+        self.eat(TokenKind::DotDotDot)
+    }
+
+    fn eat_dot(&mut self) -> bool {
+        self.eat(TokenKind::Dot)
+    }
+
+    fn eat_arrow(&mut self) -> bool {
+        self.eat(TokenKind::Arrow)
+    }
+
     fn eat_comma(&mut self) -> bool {
         self.eat(TokenKind::Comma)
     }
 
+    fn eat_colon(&mut self) -> bool {
+        self.eat(TokenKind::Colon)
+    }
+
     fn eat_identifier(&mut self) -> Option<String> {
         let kind = self.token.get_kind().clone();
 
@@ -703,6 +1769,19 @@ impl Parser {
         }
     }
 
+    fn eat_string(&mut self) -> Option<String> {
+        let kind = self.token.get_kind().clone();
+
+        self.expected_tokens.insert(TokenCategory::String);
+
+        if let TokenKind::String(value) = kind {
+            self.bump();
+            Some(value)
+        } else {
+            None
+        }
+    }
+
     fn eat_number(&mut self) -> Option<u64> {
         let kind = self.token.get_kind().clone();
 
@@ -714,6 +1793,32 @@ impl Parser {
         }
     }
 
+    fn eat_float_number(&mut self) -> Option<FloatNumberToken> {
+        if let TokenKind::FloatNumber(number) = *self.token.get_kind() {
+            self.bump();
+            Some(number)
+        } else {
+            None
+        }
+    }
+
+    /// Eats the `void` of a `(void)` parameter list.
+    fn eat_void_parameter_list(&mut self) -> bool {
+        let is_void_parameter_list = self.check_keyword(Keyword::Void)
+            && *self.cursor.peek().get_kind()
+                == TokenKind::CloseDelimiter(DelimiterToken::Parenthesis);
+
+        if is_void_parameter_list {
+            self.eat_keyword(Keyword::Void);
+        }
+
+        is_void_parameter_list
+    }
+
+    fn eat_tag_declaration(&mut self, base_ty: &Ty) -> bool {
+        matches!(base_ty.get_kind(), TyKind::Struct(_)) && self.eat_semicolon()
+    }
+
     fn eat_binary_operator(&mut self, token: BinaryOperatorToken) -> bool {
         self.eat(TokenKind::BinaryOperator(token))
     }
@@ -748,10 +1853,74 @@ impl Parser {
         self.check_open_delimiter(DelimiterToken::Brace)
     }
 
+    fn check_close_brace(&mut self) -> bool {
+        self.check_close_delimiter(DelimiterToken::Brace)
+    }
+
     fn check_close_parenthesis(&mut self) -> bool {
         self.check_close_delimiter(DelimiterToken::Parenthesis)
     }
 
+    fn check_label(&self) -> bool {
+        matches!(self.token.get_kind(), TokenKind::Identifier(_))
+            && *self.cursor.peek().get_kind() == TokenKind::Colon
+    }
+
+    /// Checks whether the current token names a function being declared or defined.
+    fn check_function_name(&self) -> bool {
+        matches!(self.token.get_kind(), TokenKind::Identifier(_))
+            && *self.cursor.peek().get_kind()
+                == TokenKind::OpenDelimiter(DelimiterToken::Parenthesis)
+    }
+
+    fn check_storage_class(&mut self) -> bool {
+        self.check_keyword(Keyword::Static) || self.check_keyword(Keyword::Extern)
+    }
+
+    fn check_ty_qualifier(&mut self) -> bool {
+        self.check_keyword(Keyword::Const)
+            || self.check_keyword(Keyword::Volatile)
+            || self.check_keyword(Keyword::Restrict)
+    }
+
+    fn check_ty_specifier(&mut self) -> bool {
+        self.check_keyword(Keyword::Void)
+            || self.check_keyword(Keyword::Bool)
+            || self.check_keyword(Keyword::Char)
+            || self.check_keyword(Keyword::Int)
+            || self.check_keyword(Keyword::Signed)
+            || self.check_keyword(Keyword::Unsigned)
+            || self.check_keyword(Keyword::Struct)
+            || self.check_keyword(Keyword::Float)
+            || self.check_keyword(Keyword::Double)
+            || self.check_keyword(Keyword::BuiltinVaList)
+    }
+
+    fn check_parenthesized_ty_name(&self) -> bool {
+        const TY_NAME_KEYWORDS: [Keyword; 13] = [
+            Keyword::Const,
+            Keyword::Volatile,
+            Keyword::Restrict,
+            Keyword::Void,
+            Keyword::Bool,
+            Keyword::Char,
+            Keyword::Int,
+            Keyword::Signed,
+            Keyword::Unsigned,
+            Keyword::Struct,
+            Keyword::Float,
+            Keyword::Double,
+            Keyword::BuiltinVaList,
+        ];
+
+        let next_token = self.cursor.peek();
+
+        *self.token.get_kind() == TokenKind::OpenDelimiter(DelimiterToken::Parenthesis)
+            && TY_NAME_KEYWORDS
+                .iter()
+                .any(|keyword| next_token.is_keyword(keyword))
+    }
+
     fn check_semicolon(&mut self) -> bool {
         self.check(TokenKind::Semicolon)
     }
@@ -18,4 +18,10 @@ impl TokenCursor {
                 token.clone()
             })
     }
+
+    pub fn peek(&self) -> Token {
+        self.tokens
+            .get(self.index)
+            .map_or(Token::new_eof(), Token::clone)
+    }
 }
@@ -0,0 +1,5 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BuiltinMacro {
+    File,
+    Line,
+}
@@ -0,0 +1,348 @@
+use std::{cmp::Ordering, iter::Peekable, vec};
+
+use rustyc_diagnostics::Diagnostic;
+use rustyc_token::{BinaryOperatorToken, DelimiterToken, TokenKind};
+
+use crate::preprocessing_token::PreprocessingToken;
+
+/// A preprocessor value, computed in `intmax_t` or `uintmax_t`.
+#[derive(Clone, Copy)]
+enum Value {
+    Signed(i64),
+    Unsigned(u64),
+}
+
+impl Value {
+    fn from_bool(value: bool) -> Self {
+        Self::Signed(i64::from(value))
+    }
+
+    fn is_true(self) -> bool {
+        self.as_unsigned() != 0
+    }
+
+    fn is_unsigned(self) -> bool {
+        matches!(self, Self::Unsigned(_))
+    }
+
+    fn as_unsigned(self) -> u64 {
+        match self {
+            Self::Signed(value) => value as u64,
+            Self::Unsigned(value) => value,
+        }
+    }
+
+    fn apply(
+        self,
+        other: Self,
+        signed: impl FnOnce(i64, i64) -> i64,
+        unsigned: impl FnOnce(u64, u64) -> u64,
+    ) -> Self {
+        match (self, other) {
+            (Self::Signed(left), Self::Signed(right)) => Self::Signed(signed(left, right)),
+            _ => Self::Unsigned(unsigned(self.as_unsigned(), other.as_unsigned())),
+        }
+    }
+
+    fn compare(self, other: Self) -> Ordering {
+        match (self, other) {
+            (Self::Signed(left), Self::Signed(right)) => left.cmp(&right),
+            _ => self.as_unsigned().cmp(&other.as_unsigned()),
+        }
+    }
+
+    fn shift_left(self, count: Self) -> Self {
+        let count = match count {
+            Self::Signed(count) if count < 0 => {
+                return self.shift_right(Self::Unsigned(count.unsigned_abs()));
+            }
+            count => u32::try_from(count.as_unsigned()).unwrap_or(u32::MAX),
+        };
+
+        match self {
+            Self::Signed(value) => Self::Signed(value.checked_shl(count).unwrap_or(0)),
+            Self::Unsigned(value) => Self::Unsigned(value.checked_shl(count).unwrap_or(0)),
+        }
+    }
+
+    fn shift_right(self, count: Self) -> Self {
+        let count = match count {
+            Self::Signed(count) if count < 0 => {
+                return self.shift_left(Self::Unsigned(count.unsigned_abs()));
+            }
+            count => u32::try_from(count.as_unsigned()).unwrap_or(u32::MAX),
+        };
+
+        match self {
+            Self::Signed(value) => {
+                Self::Signed(
+                    value
+                        .checked_shr(count)
+                        .unwrap_or(if value < 0 { -1 } else { 0 }),
+                )
+            }
+            Self::Unsigned(value) => Self::Unsigned(value.checked_shr(count).unwrap_or(0)),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum BinaryOperator {
+    Multiply,
+    Divide,
+    Remainder,
+    Add,
+    Subtract,
+    ShiftLeft,
+    ShiftRight,
+    LessThan,
+    GreaterThan,
+    LessEqual,
+    GreaterEqual,
+    Equal,
+    NotEqual,
+    BitwiseAnd,
+    BitwiseXor,
+    BitwiseOr,
+    LogicalAnd,
+    LogicalOr,
+}
+
+impl BinaryOperator {
+    fn from_kind(kind: &TokenKind) -> Option<(Self, u8)> {
+        let operator = match kind {
+            TokenKind::BinaryOperator(BinaryOperatorToken::Star) => (Self::Multiply, 10),
+            TokenKind::BinaryOperator(BinaryOperatorToken::Slash) => (Self::Divide, 10),
+            TokenKind::BinaryOperator(BinaryOperatorToken::Percent) => (Self::Remainder, 10),
+            TokenKind::BinaryOperator(BinaryOperatorToken::Plus) => (Self::Add, 9),
+            TokenKind::BinaryOperator(BinaryOperatorToken::Minus) => (Self::Subtract, 9),
+            TokenKind::BinaryOperator(BinaryOperatorToken::Shl) => (Self::ShiftLeft, 8),
+            TokenKind::BinaryOperator(BinaryOperatorToken::Shr) => (Self::ShiftRight, 8),
+            TokenKind::LessThan => (Self::LessThan, 7),
+            TokenKind::GreaterThan => (Self::GreaterThan, 7),
+            TokenKind::LessEqual => (Self::LessEqual, 7),
+            TokenKind::GreaterEqual => (Self::GreaterEqual, 7),
+            TokenKind::EqualEqual => (Self::Equal, 6),
+            TokenKind::NotEqual => (Self::NotEqual, 6),
+            TokenKind::BinaryOperator(BinaryOperatorToken::And) => (Self::BitwiseAnd, 5),
+            TokenKind::BinaryOperator(BinaryOperatorToken::Caret) => (Self::BitwiseXor, 4),
+            TokenKind::BinaryOperator(BinaryOperatorToken::Or) => (Self::BitwiseOr, 3),
+            TokenKind::AndAnd => (Self::LogicalAnd, 2),
+            TokenKind::OrOr => (Self::LogicalOr, 1),
+            _ => return None,
+        };
+
+        Some(operator)
+    }
+}
+
+pub struct ConditionEvaluator<'a> {
+    directive: &'a PreprocessingToken,
+    tokens: Peekable<vec::IntoIter<PreprocessingToken>>,
+}
+
+impl<'a> ConditionEvaluator<'a> {
+    pub fn new(directive: &'a PreprocessingToken, tokens: Vec<PreprocessingToken>) -> Self {
+        Self {
+            directive,
+            tokens: tokens.into_iter().peekable(),
+        }
+    }
+
+    pub fn evaluate(mut self) -> rustyc_diagnostics::Result<bool> {
+        if self.tokens.peek().is_none() {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::EmptyCondition(self.directive.get_spelling().to_owned()),
+                self.directive.get_span().clone(),
+            ));
+        }
+
+        let value = self.evaluate_conditional(true)?;
+
+        if let Some(token) = self.tokens.next() {
+            return Err(Self::invalid_token(&token));
+        }
+
+        Ok(value.is_true())
+    }
+
+    fn evaluate_conditional(&mut self, is_evaluated: bool) -> rustyc_diagnostics::Result<Value> {
+        let condition = self.evaluate_binary(1, is_evaluated)?;
+
+        if !self.eat(&TokenKind::Question) {
+            return Ok(condition);
+        }
+
+        let then_value = self.evaluate_conditional(is_evaluated && condition.is_true())?;
+        self.expect(TokenKind::Colon)?;
+        let else_value = self.evaluate_conditional(is_evaluated && !condition.is_true())?;
+
+        let value = if condition.is_true() {
+            then_value
+        } else {
+            else_value
+        };
+
+        // The result has the type that both operands are converted to.
+        match then_value.is_unsigned() || else_value.is_unsigned() {
+            true => Ok(Value::Unsigned(value.as_unsigned())),
+            false => Ok(value),
+        }
+    }
+
+    fn evaluate_binary(
+        &mut self,
+        minimum_precedence: u8,
+        is_evaluated: bool,
+    ) -> rustyc_diagnostics::Result<Value> {
+        let mut left = self.evaluate_unary(is_evaluated)?;
+
+        while let Some((operator, precedence)) = self
+            .tokens
+            .peek()
+            .and_then(|token| BinaryOperator::from_kind(token.get_kind()))
+        {
+            if precedence < minimum_precedence {
+                break;
+            }
+
+            let Some(operator_token) = self.tokens.next() else {
+                break;
+            };
+
+            // The right operand of `&&` and `||` isn't evaluated when the left
+            // one decides the result.
+            let is_right_evaluated = match operator {
+                BinaryOperator::LogicalAnd => is_evaluated && left.is_true(),
+                BinaryOperator::LogicalOr => is_evaluated && !left.is_true(),
+                _ => is_evaluated,
+            };
+
+            let right = self.evaluate_binary(precedence + 1, is_right_evaluated)?;
+
+            left = Self::apply_binary(operator, &operator_token, left, right, is_evaluated)?;
+        }
+
+        Ok(left)
+    }
+
+    fn apply_binary(
+        operator: BinaryOperator,
+        operator_token: &PreprocessingToken,
+        left: Value,
+        right: Value,
+        is_evaluated: bool,
+    ) -> rustyc_diagnostics::Result<Value> {
+        if matches!(operator, BinaryOperator::Divide | BinaryOperator::Remainder)
+            && !right.is_true()
+        {
+            if !is_evaluated {
+                return Ok(Value::Signed(0));
+            }
+
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ConditionDivisionByZero,
+                operator_token.get_span().clone(),
+            ));
+        }
+
+        let value = match operator {
+            BinaryOperator::Multiply => left.apply(right, i64::wrapping_mul, u64::wrapping_mul),
+            BinaryOperator::Divide => left.apply(right, i64::wrapping_div, u64::wrapping_div),
+            BinaryOperator::Remainder => left.apply(right, i64::wrapping_rem, u64::wrapping_rem),
+            BinaryOperator::Add => left.apply(right, i64::wrapping_add, u64::wrapping_add),
+            BinaryOperator::Subtract => left.apply(right, i64::wrapping_sub, u64::wrapping_sub),
+            BinaryOperator::ShiftLeft => left.shift_left(right),
+            BinaryOperator::ShiftRight => left.shift_right(right),
+            BinaryOperator::LessThan => Value::from_bool(left.compare(right).is_lt()),
+            BinaryOperator::GreaterThan => Value::from_bool(left.compare(right).is_gt()),
+            BinaryOperator::LessEqual => Value::from_bool(left.compare(right).is_le()),
+            BinaryOperator::GreaterEqual => Value::from_bool(left.compare(right).is_ge()),
+            BinaryOperator::Equal => Value::from_bool(left.compare(right).is_eq()),
+            BinaryOperator::NotEqual => Value::from_bool(left.compare(right).is_ne()),
+            BinaryOperator::BitwiseAnd => left.apply(right, |l, r| l & r, |l, r| l & r),
+            BinaryOperator::BitwiseXor => left.apply(right, |l, r| l ^ r, |l, r| l ^ r),
+            BinaryOperator::BitwiseOr => left.apply(right, |l, r| l | r, |l, r| l | r),
+            BinaryOperator::LogicalAnd => Value::from_bool(left.is_true() && right.is_true()),
+            BinaryOperator::LogicalOr => Value::from_bool(left.is_true() || right.is_true()),
+        };
+
+        Ok(value)
+    }
+
+    fn evaluate_unary(&mut self, is_evaluated: bool) -> rustyc_diagnostics::Result<Value> {
+        let Some(token) = self.tokens.next() else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::MissingConditionValue,
+                self.directive.get_span().clone(),
+            ));
+        };
+
+        let value = match token.get_kind() {
+            TokenKind::BinaryOperator(BinaryOperatorToken::Plus) => {
+                self.evaluate_unary(is_evaluated)?
+            }
+            TokenKind::BinaryOperator(BinaryOperatorToken::Minus) => {
+                match self.evaluate_unary(is_evaluated)? {
+                    Value::Signed(value) => Value::Signed(value.wrapping_neg()),
+                    Value::Unsigned(value) => Value::Unsigned(value.wrapping_neg()),
+                }
+            }
+            TokenKind::Tilde => match self.evaluate_unary(is_evaluated)? {
+                Value::Signed(value) => Value::Signed(!value),
+                Value::Unsigned(value) => Value::Unsigned(!value),
+            },
+            TokenKind::Not => Value::from_bool(!self.evaluate_unary(is_evaluated)?.is_true()),
+            TokenKind::OpenDelimiter(DelimiterToken::Parenthesis) => {
+                let value = self.evaluate_conditional(is_evaluated)?;
+                self.expect(TokenKind::CloseDelimiter(DelimiterToken::Parenthesis))?;
+
+                value
+            }
+            TokenKind::Number(value) => {
+                // Constants that don't fit in `intmax_t` are unsigned, like
+                // the ones with a `u` suffix.
+                if token.get_spelling().contains(['u', 'U']) || i64::try_from(*value).is_err() {
+                    Value::Unsigned(*value)
+                } else {
+                    Value::Signed(*value as i64)
+                }
+            }
+            TokenKind::Identifier(_) => Value::Signed(0),
+            _ => return Err(Self::invalid_token(&token)),
+        };
+
+        Ok(value)
+    }
+
+    fn eat(&mut self, kind: &TokenKind) -> bool {
+        self.tokens
+            .next_if(|token| token.get_kind() == kind)
+            .is_some()
+    }
+
+    fn expect(&mut self, kind: TokenKind) -> rustyc_diagnostics::Result<()> {
+        if self.eat(&kind) {
+            return Ok(());
+        }
+
+        let span = self
+            .tokens
+            .peek()
+            .unwrap_or(self.directive)
+            .get_span()
+            .clone();
+
+        Err(Diagnostic::new_error(
+            rustyc_diagnostics::Error::MissingConditionToken(kind),
+            span,
+        ))
+    }
+
+    fn invalid_token(token: &PreprocessingToken) -> Diagnostic {
+        Diagnostic::new_error(
+            rustyc_diagnostics::Error::InvalidConditionToken(token.get_kind().clone()),
+            token.get_span().clone(),
+        )
+    }
+}
@@ -0,0 +1,110 @@
+use rustyc_diagnostics::Diagnostic;
+use rustyc_span::Span;
+
+struct Conditional {
+    directive: String,
+    span: Span,
+    is_enclosing_group_active: bool,
+    is_group_active: bool,
+    was_any_group_active: bool,
+    has_else: bool,
+}
+
+pub struct ConditionalStack {
+    conditionals: Vec<Conditional>,
+}
+
+impl ConditionalStack {
+    pub fn new() -> Self {
+        Self {
+            conditionals: Vec::new(),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.conditionals
+            .last()
+            .is_none_or(|conditional| conditional.is_group_active)
+    }
+
+    pub fn push(&mut self, directive: &str, span: Span, condition: bool) {
+        let is_enclosing_group_active = self.is_active();
+        let is_group_active = is_enclosing_group_active && condition;
+
+        self.conditionals.push(Conditional {
+            directive: directive.to_owned(),
+            span,
+            is_enclosing_group_active,
+            is_group_active,
+            was_any_group_active: is_group_active,
+            has_else: false,
+        });
+    }
+
+    pub fn enter_elif(
+        &mut self,
+        span: Span,
+        condition: impl FnOnce() -> rustyc_diagnostics::Result<bool>,
+    ) -> rustyc_diagnostics::Result<()> {
+        let Some(conditional) = self.conditionals.last_mut() else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ElifWithoutIf,
+                span,
+            ));
+        };
+
+        if conditional.has_else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ElifAfterElse,
+                span,
+            ));
+        }
+
+        conditional.is_group_active = conditional.is_enclosing_group_active
+            && !conditional.was_any_group_active
+            && condition()?;
+        conditional.was_any_group_active |= conditional.is_group_active;
+
+        Ok(())
+    }
+
+    pub fn enter_else(&mut self, span: Span) -> rustyc_diagnostics::Result<()> {
+        let Some(conditional) = self.conditionals.last_mut() else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ElseWithoutIf,
+                span,
+            ));
+        };
+
+        if conditional.has_else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ElseAfterElse,
+                span,
+            ));
+        }
+
+        conditional.has_else = true;
+        conditional.is_group_active =
+            conditional.is_enclosing_group_active && !conditional.was_any_group_active;
+        conditional.was_any_group_active |= conditional.is_group_active;
+
+        Ok(())
+    }
+
+    pub fn pop(&mut self, span: Span) -> rustyc_diagnostics::Result<()> {
+        self.conditionals
+            .pop()
+            .map(|_| ())
+            .ok_or_else(|| Diagnostic::new_error(rustyc_diagnostics::Error::EndifWithoutIf, span))
+    }
+
+    pub fn check_terminated(&self) -> rustyc_diagnostics::Result<()> {
+        match self.conditionals.last() {
+            Some(conditional) => Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::UnterminatedConditional(conditional.directive.clone()),
+                conditional.span.clone(),
+            )),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+mod builtin_macro;
+mod condition_evaluator;
+mod conditional_stack;
+mod logical_line;
+mod macro_argument;
+mod macro_definition;
+mod macro_expander;
+mod macro_option;
+mod preprocessed_text_writer;
+mod preprocessing_token;
+mod preprocessor;
+mod preprocessor_options;
+
+pub use macro_option::MacroOption;
+pub use preprocessor::Preprocessor;
+pub use preprocessor_options::PreprocessorOptions;
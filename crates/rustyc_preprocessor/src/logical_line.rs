@@ -0,0 +1,172 @@
+use rustyc_diagnostics::Diagnostic;
+use rustyc_lexer::Lexer;
+use rustyc_span::{SourceFile, Span};
+use rustyc_token::Token;
+
+use crate::preprocessing_token::PreprocessingToken;
+
+/// Physical lines joined at trailing backslashes, with comments replaced by spaces.
+pub struct LogicalLine {
+    text: String,
+    positions: Vec<usize>,
+}
+
+impl LogicalLine {
+    pub fn read(file: &SourceFile, line_index: usize) -> rustyc_diagnostics::Result<(Self, usize)> {
+        let mut line = Self {
+            text: String::new(),
+            positions: Vec::new(),
+        };
+        let mut line_index = line_index;
+        let mut comment_start = None;
+
+        loop {
+            let (characters, next_line_index) = Self::splice_lines(file, line_index);
+            line_index = next_line_index;
+            comment_start = line.append_without_comments(&characters, comment_start);
+
+            match comment_start {
+                Some(start) if line_index == file.get_line_count() => {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::UnterminatedComment,
+                        Span::new(start, start + 2),
+                    ));
+                }
+                Some(_) => {}
+                None => {
+                    let (_, end) = file.get_line_bounds(line_index - 1);
+                    line.positions.push(end);
+
+                    return Ok((line, line_index));
+                }
+            }
+        }
+    }
+
+    fn splice_lines(file: &SourceFile, line_index: usize) -> (Vec<(usize, char)>, usize) {
+        let mut characters = Vec::new();
+        let mut line_index = line_index;
+
+        loop {
+            let (start, _) = file.get_line_bounds(line_index);
+            let line = file.get_line(line_index).trim_end_matches('\r');
+            line_index += 1;
+
+            let (line, is_spliced) = match line.strip_suffix('\\') {
+                Some(line) if line_index < file.get_line_count() => (line, true),
+                _ => (line, false),
+            };
+
+            characters.extend(
+                line.char_indices()
+                    .map(|(offset, character)| (start + offset, character)),
+            );
+
+            if !is_spliced {
+                return (characters, line_index);
+            }
+        }
+    }
+
+    fn append_without_comments(
+        &mut self,
+        characters: &[(usize, char)],
+        comment_start: Option<usize>,
+    ) -> Option<usize> {
+        let mut comment_start = comment_start;
+        let mut quote = None;
+        let mut index = 0;
+
+        while let Some(&(position, character)) = characters.get(index) {
+            let next_character = characters.get(index + 1).map(|(_, character)| *character);
+            index += 1;
+
+            if comment_start.is_some() {
+                if (character, next_character) == ('*', Some('/')) {
+                    comment_start = None;
+                    index += 1;
+                }
+
+                continue;
+            }
+
+            // Comments don't start inside character constants and string
+            // literals, which end with the line if they aren't terminated.
+            if let Some(quote_character) = quote {
+                self.push(position, character);
+
+                if character == '\\' {
+                    if let Some(&(position, character)) = characters.get(index) {
+                        self.push(position, character);
+                        index += 1;
+                    }
+                } else if character == quote_character {
+                    quote = None;
+                }
+
+                continue;
+            }
+
+            match (character, next_character) {
+                ('/', Some('*')) => {
+                    self.push(position, ' ');
+                    comment_start = Some(position);
+                    index += 1;
+                }
+                ('/', Some('/')) => {
+                    self.push(position, ' ');
+                    break;
+                }
+                _ => {
+                    if character == '"' || character == '\'' {
+                        quote = Some(character);
+                    }
+
+                    self.push(position, character);
+                }
+            }
+        }
+
+        comment_start
+    }
+
+    fn push(&mut self, position: usize, character: char) {
+        self.text.push(character);
+        self.positions
+            .extend((0..character.len_utf8()).map(|offset| position + offset));
+    }
+
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn get_span(&self, start: usize, end: usize) -> Span {
+        match start == end {
+            true => Span::new(self.positions[start], self.positions[start]),
+            false => Span::new(self.positions[start], self.positions[end - 1] + 1),
+        }
+    }
+
+    pub fn lex(&self) -> rustyc_diagnostics::Result<Vec<PreprocessingToken>> {
+        let start_position = self.positions[0];
+        let lexer = Lexer::new_at(&self.text, start_position)?;
+
+        let mut tokens = Vec::<PreprocessingToken>::new();
+        let mut previous_end = 0;
+
+        for token in lexer.lex()? {
+            let start = token.get_span().get_low() - start_position;
+            let end = token.get_span().get_high() - start_position;
+
+            tokens.push(PreprocessingToken::new(
+                Token::new(token.get_kind().clone(), self.get_span(start, end)),
+                self.text[start..end].to_owned(),
+                previous_end != start,
+            ));
+
+            previous_end = end;
+        }
+
+        Ok(tokens)
+    }
+}
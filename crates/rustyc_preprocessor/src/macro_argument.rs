@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+
+use rustyc_span::SourceMap;
+
+use crate::{
+    macro_definition::MacroDefinition, macro_expander::MacroExpander,
+    preprocessing_token::PreprocessingToken,
+};
+
+/// An argument of a function-like macro invocation, expanded at most once.
+pub struct MacroArgument {
+    tokens: Vec<PreprocessingToken>,
+    expanded_tokens: Option<Vec<PreprocessingToken>>,
+}
+
+impl MacroArgument {
+    pub fn new(tokens: Vec<PreprocessingToken>) -> Self {
+        Self {
+            tokens,
+            expanded_tokens: None,
+        }
+    }
+
+    pub fn get_tokens(&self) -> &[PreprocessingToken] {
+        &self.tokens
+    }
+
+    pub fn get_expanded_tokens(
+        &mut self,
+        macros: &HashMap<String, MacroDefinition>,
+        source_map: &SourceMap,
+    ) -> rustyc_diagnostics::Result<&[PreprocessingToken]> {
+        if self.expanded_tokens.is_none() {
+            let macro_expander = MacroExpander::new(self.tokens.clone(), macros, source_map);
+            self.expanded_tokens = Some(macro_expander.expand()?);
+        }
+
+        Ok(self.expanded_tokens.as_deref().unwrap_or_default())
+    }
+}
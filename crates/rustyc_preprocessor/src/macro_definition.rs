@@ -0,0 +1,85 @@
+use crate::{builtin_macro::BuiltinMacro, preprocessing_token::PreprocessingToken};
+
+pub const VARIADIC_PARAMETER: &str = "__VA_ARGS__";
+
+pub const VA_OPT: &str = "__VA_OPT__";
+
+#[derive(Debug)]
+pub struct MacroDefinition {
+    parameters: Option<Vec<String>>,
+    is_variadic: bool,
+    replacement: Vec<PreprocessingToken>,
+    builtin: Option<BuiltinMacro>,
+}
+
+impl MacroDefinition {
+    pub fn new_object_like(replacement: Vec<PreprocessingToken>) -> Self {
+        Self {
+            parameters: None,
+            is_variadic: false,
+            replacement,
+            builtin: None,
+        }
+    }
+
+    pub fn new_builtin(builtin: BuiltinMacro) -> Self {
+        Self {
+            builtin: Some(builtin),
+            ..Self::new_object_like(Vec::new())
+        }
+    }
+
+    pub fn new_function_like(
+        parameters: Vec<String>,
+        is_variadic: bool,
+        replacement: Vec<PreprocessingToken>,
+    ) -> Self {
+        Self {
+            parameters: Some(parameters),
+            is_variadic,
+            replacement,
+            builtin: None,
+        }
+    }
+
+    pub fn get_parameters(&self) -> Option<&[String]> {
+        self.parameters.as_deref()
+    }
+
+    pub fn get_parameter_index(&self, name: &str) -> Option<usize> {
+        self.get_parameters()?
+            .iter()
+            .position(|parameter| parameter == name)
+    }
+
+    pub fn is_function_like(&self) -> bool {
+        self.parameters.is_some()
+    }
+
+    pub fn is_variadic(&self) -> bool {
+        self.is_variadic
+    }
+
+    pub fn get_builtin(&self) -> Option<BuiltinMacro> {
+        self.builtin
+    }
+
+    pub fn get_replacement(&self) -> &[PreprocessingToken] {
+        &self.replacement
+    }
+
+    pub fn is_identical(&self, other: &Self) -> bool {
+        self.parameters == other.parameters
+            && self.is_variadic == other.is_variadic
+            && self.builtin == other.builtin
+            && self.replacement.len() == other.replacement.len()
+            && self
+                .replacement
+                .iter()
+                .zip(other.replacement.iter())
+                .all(|(token, other_token)| {
+                    token.get_spelling() == other_token.get_spelling()
+                        && token.has_leading_space() == other_token.has_leading_space()
+                })
+    }
+}
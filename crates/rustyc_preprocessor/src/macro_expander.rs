@@ -0,0 +1,468 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    mem,
+};
+
+use rustyc_diagnostics::Diagnostic;
+use rustyc_lexer::Lexer;
+use rustyc_span::SourceMap;
+use rustyc_token::{DelimiterToken, Token, TokenKind};
+
+use crate::{
+    builtin_macro::BuiltinMacro,
+    macro_argument::MacroArgument,
+    macro_definition::{MacroDefinition, VARIADIC_PARAMETER, VA_OPT},
+    preprocessing_token::PreprocessingToken,
+};
+
+/// A token of a substituted replacement list, or `None` for a placemarker.
+type SubstitutedToken = Option<PreprocessingToken>;
+
+/// The hide set of a token keeps a macro from expanding within its own expansion.
+pub struct MacroExpander<'a> {
+    tokens: Vec<PreprocessingToken>,
+    macros: &'a HashMap<String, MacroDefinition>,
+    source_map: &'a SourceMap,
+}
+
+impl<'a> MacroExpander<'a> {
+    pub fn new(
+        tokens: Vec<PreprocessingToken>,
+        macros: &'a HashMap<String, MacroDefinition>,
+        source_map: &'a SourceMap,
+    ) -> Self {
+        Self {
+            tokens,
+            macros,
+            source_map,
+        }
+    }
+
+    pub fn expand(mut self) -> rustyc_diagnostics::Result<Vec<PreprocessingToken>> {
+        let mut input = VecDeque::from(mem::take(&mut self.tokens));
+        let mut output = Vec::new();
+
+        while let Some(token) = input.pop_front() {
+            let invoked_macro = token
+                .get_identifier()
+                .filter(|name| !token.is_hidden(name))
+                .and_then(|name| self.macros.get_key_value(name));
+
+            let Some((name, definition)) = invoked_macro else {
+                output.push(token);
+                continue;
+            };
+
+            if let Some(builtin) = definition.get_builtin() {
+                output.push(self.expand_builtin(builtin, &token));
+                continue;
+            }
+
+            let (mut arguments, mut hide_set) = if definition.is_function_like() {
+                // The name of a function-like macro only invokes it when an
+                // argument list follows.
+                if !matches!(
+                    input.front().map(PreprocessingToken::get_kind),
+                    Some(TokenKind::OpenDelimiter(DelimiterToken::Parenthesis))
+                ) {
+                    output.push(token);
+                    continue;
+                }
+
+                let (arguments, close_parenthesis) =
+                    Self::collect_arguments(&token, name, definition, &mut input)?;
+
+                // Only the macros that both the name and the closing
+                // parenthesis are hidden from stay hidden, since the
+                // arguments may come from outside of their expansion.
+                let hide_set = token
+                    .get_hide_set()
+                    .intersection(close_parenthesis.get_hide_set())
+                    .cloned()
+                    .collect::<HashSet<_>>();
+
+                (arguments, hide_set)
+            } else {
+                (Vec::new(), token.get_hide_set().clone())
+            };
+
+            hide_set.insert(name.clone());
+
+            let mut substitution = Vec::new();
+            self.substitute(
+                &token,
+                definition,
+                definition.get_replacement(),
+                &mut arguments,
+                &mut substitution,
+            )?;
+
+            let expansion = substitution
+                .into_iter()
+                .flatten()
+                .enumerate()
+                .map(|(index, replacement_token)| {
+                    let replacement_token = replacement_token.with_hide_set(&hide_set);
+
+                    match index {
+                        0 => replacement_token.with_leading_space(token.has_leading_space()),
+                        _ => replacement_token,
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            for replacement_token in expansion.into_iter().rev() {
+                input.push_front(replacement_token);
+            }
+        }
+
+        Ok(output)
+    }
+
+    fn expand_builtin(
+        &self,
+        builtin: BuiltinMacro,
+        invocation: &PreprocessingToken,
+    ) -> PreprocessingToken {
+        let (file_name, line_number) = self
+            .source_map
+            .lookup_presumed_line(invocation.get_span().get_low())
+            .unwrap_or_default();
+
+        let (kind, spelling) = match builtin {
+            BuiltinMacro::File => (
+                TokenKind::String(file_name.clone()),
+                Self::quote(&file_name),
+            ),
+            BuiltinMacro::Line => (
+                TokenKind::Number(line_number as u64),
+                line_number.to_string(),
+            ),
+        };
+
+        PreprocessingToken::new(
+            Token::new(kind, invocation.get_span().clone()),
+            spelling,
+            invocation.has_leading_space(),
+        )
+    }
+
+    fn collect_arguments(
+        invocation: &PreprocessingToken,
+        name: &str,
+        definition: &MacroDefinition,
+        input: &mut VecDeque<PreprocessingToken>,
+    ) -> rustyc_diagnostics::Result<(Vec<MacroArgument>, PreprocessingToken)> {
+        let parameter_count = definition.get_parameters().unwrap_or_default().len();
+
+        // Skip the opening parenthesis.
+        input.pop_front();
+
+        let mut arguments = vec![Vec::new()];
+        let mut depth = 0;
+
+        let close_parenthesis = loop {
+            let Some(token) = input.pop_front() else {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::UnterminatedMacroArguments(name.to_owned()),
+                    invocation.get_span().clone(),
+                ));
+            };
+
+            match token.get_kind() {
+                TokenKind::OpenDelimiter(DelimiterToken::Parenthesis) => depth += 1,
+                TokenKind::CloseDelimiter(DelimiterToken::Parenthesis) if depth == 0 => {
+                    break token;
+                }
+                TokenKind::CloseDelimiter(DelimiterToken::Parenthesis) => depth -= 1,
+                // The variable arguments include the commas between them.
+                TokenKind::Comma
+                    if depth == 0
+                        && !(definition.is_variadic() && arguments.len() == parameter_count) =>
+                {
+                    arguments.push(Vec::new());
+                    continue;
+                }
+                _ => {}
+            }
+
+            if let Some(argument) = arguments.last_mut() {
+                argument.push(token);
+            }
+        };
+
+        // Empty parentheses pass no arguments to a macro without parameters,
+        // and the variable arguments may be left out entirely.
+        if parameter_count == 0 && arguments.len() == 1 && arguments[0].is_empty() {
+            arguments.clear();
+        } else if definition.is_variadic() && arguments.len() + 1 == parameter_count {
+            arguments.push(Vec::new());
+        }
+
+        if arguments.len() != parameter_count {
+            let expected_count = parameter_count - usize::from(definition.is_variadic());
+
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::MacroArgumentCountMismatch(
+                    name.to_owned(),
+                    expected_count,
+                    arguments.len(),
+                ),
+                invocation.get_span().clone(),
+            ));
+        }
+
+        Ok((
+            arguments.into_iter().map(MacroArgument::new).collect(),
+            close_parenthesis,
+        ))
+    }
+
+    fn substitute(
+        &self,
+        invocation: &PreprocessingToken,
+        definition: &MacroDefinition,
+        tokens: &[PreprocessingToken],
+        arguments: &mut [MacroArgument],
+        output: &mut Vec<SubstitutedToken>,
+    ) -> rustyc_diagnostics::Result<()> {
+        let mut index = 0;
+
+        while let Some(token) = tokens.get(index) {
+            let has_operand = index + 1 < tokens.len();
+
+            match token.get_kind() {
+                TokenKind::Hash if definition.is_function_like() && has_operand => {
+                    let operand_end = Self::get_operand_end(definition, tokens, index + 1);
+                    let operand = self.substitute_operand(
+                        invocation,
+                        definition,
+                        &tokens[(index + 1)..operand_end],
+                        arguments,
+                        false,
+                    )?;
+
+                    output.push(Some(Self::stringize(
+                        &token.expanded_from(invocation),
+                        &operand,
+                    )));
+                    index = operand_end;
+                }
+                TokenKind::HashHash if has_operand => {
+                    let operand_end = Self::get_operand_end(definition, tokens, index + 1);
+                    let operand = self.substitute_operand(
+                        invocation,
+                        definition,
+                        &tokens[(index + 1)..operand_end],
+                        arguments,
+                        false,
+                    )?;
+
+                    // Like gcc, `, ## __VA_ARGS__` removes the comma when
+                    // there are no variable arguments, rather than pasting.
+                    let is_comma_before_variable_arguments = definition.is_variadic()
+                        && tokens[index + 1].get_identifier() == Some(VARIADIC_PARAMETER)
+                        && matches!(
+                            output.last(),
+                            Some(Some(comma)) if matches!(comma.get_kind(), TokenKind::Comma)
+                        );
+
+                    if !is_comma_before_variable_arguments {
+                        Self::paste(output, operand)?;
+                    } else if operand.iter().all(Option::is_none) {
+                        output.pop();
+                    } else {
+                        output.extend(operand);
+                    }
+
+                    index = operand_end;
+                }
+                _ => {
+                    let operand_end = Self::get_operand_end(definition, tokens, index);
+
+                    // The operands of `##` aren't macro expanded.
+                    let is_pasted = matches!(
+                        tokens.get(operand_end).map(PreprocessingToken::get_kind),
+                        Some(TokenKind::HashHash)
+                    );
+
+                    output.extend(self.substitute_operand(
+                        invocation,
+                        definition,
+                        &tokens[index..operand_end],
+                        arguments,
+                        !is_pasted,
+                    )?);
+                    index = operand_end;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn substitute_operand(
+        &self,
+        invocation: &PreprocessingToken,
+        definition: &MacroDefinition,
+        operand: &[PreprocessingToken],
+        arguments: &mut [MacroArgument],
+        is_expanded: bool,
+    ) -> rustyc_diagnostics::Result<Vec<SubstitutedToken>> {
+        let token = &operand[0];
+
+        let substitution = match token.get_identifier() {
+            Some(VA_OPT) if definition.is_variadic() => {
+                let mut substitution = Vec::new();
+
+                let has_variable_arguments = match arguments.last_mut() {
+                    Some(argument) => !argument
+                        .get_expanded_tokens(self.macros, self.source_map)?
+                        .is_empty(),
+                    None => false,
+                };
+
+                // Skip the parentheses around the tokens.
+                if has_variable_arguments {
+                    self.substitute(
+                        invocation,
+                        definition,
+                        &operand[2..(operand.len() - 1)],
+                        arguments,
+                        &mut substitution,
+                    )?;
+                }
+
+                substitution
+            }
+            Some(name) => match definition.get_parameter_index(name) {
+                Some(index) if is_expanded => arguments[index]
+                    .get_expanded_tokens(self.macros, self.source_map)?
+                    .iter()
+                    .cloned()
+                    .map(Some)
+                    .collect(),
+                Some(index) => arguments[index]
+                    .get_tokens()
+                    .iter()
+                    .cloned()
+                    .map(Some)
+                    .collect(),
+                None => vec![Some(token.expanded_from(invocation))],
+            },
+            None => vec![Some(token.expanded_from(invocation))],
+        };
+
+        if substitution.is_empty() {
+            return Ok(vec![None]);
+        }
+
+        // The substitution takes the place of the operand.
+        Ok(substitution
+            .into_iter()
+            .enumerate()
+            .map(|(index, substituted_token)| match index {
+                0 => substituted_token
+                    .map(|first| first.with_leading_space(token.has_leading_space())),
+                _ => substituted_token,
+            })
+            .collect())
+    }
+
+    fn get_operand_end(
+        definition: &MacroDefinition,
+        tokens: &[PreprocessingToken],
+        index: usize,
+    ) -> usize {
+        if !definition.is_variadic() || tokens[index].get_identifier() != Some(VA_OPT) {
+            return index + 1;
+        }
+
+        let mut depth = 0;
+
+        for (offset, token) in tokens[(index + 1)..].iter().enumerate() {
+            match token.get_kind() {
+                TokenKind::OpenDelimiter(DelimiterToken::Parenthesis) => depth += 1,
+                TokenKind::CloseDelimiter(DelimiterToken::Parenthesis) => {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        return index + offset + 2;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        tokens.len()
+    }
+
+    fn stringize(hash: &PreprocessingToken, operand: &[SubstitutedToken]) -> PreprocessingToken {
+        let value = PreprocessingToken::spell(operand.iter().flatten());
+        let spelling = Self::quote(&value);
+
+        PreprocessingToken::new(
+            Token::new(TokenKind::String(value), hash.get_span().clone()),
+            spelling,
+            hash.has_leading_space(),
+        )
+    }
+
+    pub fn quote(value: &str) -> String {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+
+    fn paste(
+        output: &mut Vec<SubstitutedToken>,
+        operand: Vec<SubstitutedToken>,
+    ) -> rustyc_diagnostics::Result<()> {
+        let mut operand = operand.into_iter();
+
+        let pasted = match (output.pop().flatten(), operand.next().flatten()) {
+            (Some(left), Some(right)) => Some(Self::paste_tokens(&left, &right)?),
+            (left, None) => left,
+            (None, right) => right,
+        };
+
+        output.push(pasted);
+        output.extend(operand);
+
+        Ok(())
+    }
+
+    fn paste_tokens(
+        left: &PreprocessingToken,
+        right: &PreprocessingToken,
+    ) -> rustyc_diagnostics::Result<PreprocessingToken> {
+        let spelling = format!("{}{}", left.get_spelling(), right.get_spelling());
+
+        let token = Lexer::new(&spelling)
+            .and_then(Lexer::lex)
+            .ok()
+            .filter(|tokens| tokens.len() == 1)
+            .and_then(|tokens| tokens.into_iter().next());
+
+        let Some(token) = token else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::InvalidPasting(
+                    left.get_spelling().to_owned(),
+                    right.get_spelling().to_owned(),
+                ),
+                left.get_span().clone(),
+            ));
+        };
+
+        let hide_set = left
+            .get_hide_set()
+            .intersection(right.get_hide_set())
+            .cloned()
+            .collect();
+
+        Ok(PreprocessingToken::new(
+            Token::new(token.get_kind().clone(), left.get_span().clone()),
+            spelling,
+            left.has_leading_space(),
+        )
+        .with_hide_set(&hide_set))
+    }
+}
@@ -0,0 +1,17 @@
+#[derive(Clone, Debug)]
+pub enum MacroOption {
+    Define(String),
+    Undefine(String),
+}
+
+impl MacroOption {
+    pub fn to_directive(&self) -> String {
+        match self {
+            Self::Define(definition) => match definition.split_once('=') {
+                Some((name, value)) => format!("#define {name} {value}"),
+                None => format!("#define {definition} 1"),
+            },
+            Self::Undefine(name) => format!("#undef {name}"),
+        }
+    }
+}
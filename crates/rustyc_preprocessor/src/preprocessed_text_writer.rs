@@ -0,0 +1,170 @@
+use std::rc::Rc;
+
+use rustyc_span::{SourceFile, SourceMap};
+
+use crate::{macro_expander::MacroExpander, preprocessing_token::PreprocessingToken};
+
+/// Gaps shorter than this are written as empty lines rather than a line marker.
+const MAX_EMPTY_LINES: usize = 8;
+
+/// The multi-character punctuators of C, including the digraphs.
+const PUNCTUATORS: [&str; 29] = [
+    "->", "++", "--", "<<", ">>", "<=", ">=", "==", "!=", "&&", "||", "...", "*=", "/=", "%=",
+    "+=", "-=", "<<=", ">>=", "&=", "^=", "|=", "##", "<:", ":>", "<%", "%>", "%:", "%:%:",
+];
+
+/// Writes preprocessed tokens as text, with gcc-style line markers.
+pub struct PreprocessedTextWriter<'a> {
+    source_map: &'a SourceMap,
+    text: String,
+    /// The file that the last token was written from.
+    file: Option<Rc<SourceFile>>,
+    /// The position of the last written token that didn't come from a macro expansion.
+    position: usize,
+    /// The presumed file name and line number of the current line.
+    presumed_line: (String, usize),
+    last_spelling: Option<String>,
+}
+
+impl<'a> PreprocessedTextWriter<'a> {
+    pub fn new(source_map: &'a SourceMap) -> Self {
+        Self {
+            source_map,
+            text: String::new(),
+            file: None,
+            position: 0,
+            presumed_line: (String::new(), 0),
+            last_spelling: None,
+        }
+    }
+
+    pub fn write(mut self, tokens: &[PreprocessingToken]) -> String {
+        for token in tokens {
+            self.write_token(token);
+        }
+
+        if !self.text.is_empty() {
+            self.text.push('\n');
+        }
+
+        self.text
+    }
+
+    fn write_token(&mut self, token: &PreprocessingToken) {
+        let position = token.get_span().get_low();
+        let file = self.source_map.lookup_file(position);
+
+        // Tokens that macros are replaced by have the positions of their
+        // invocations, so only tokens that come after the ones before them
+        // in the same file can start new lines.
+        let is_in_same_file = match (&file, &self.file) {
+            (Some(file), Some(last_file)) => Rc::ptr_eq(file, last_file),
+            (None, None) => true,
+            _ => false,
+        };
+
+        if !is_in_same_file || position > self.position {
+            self.position = position;
+            self.file = file.clone();
+
+            if let Some(presumed_line) = self.source_map.lookup_presumed_line(position) {
+                if self.move_to_line(presumed_line, is_in_same_file) {
+                    let column = file.map_or(0, |file| {
+                        position - file.get_line_bounds(file.get_line_index(position)).0
+                    });
+
+                    self.text.push_str(&" ".repeat(column));
+                    self.push_spelling(token.get_spelling());
+
+                    return;
+                }
+            }
+        }
+
+        if token.has_leading_space() || self.would_paste(token.get_spelling()) {
+            self.text.push(' ');
+        }
+
+        self.push_spelling(token.get_spelling());
+    }
+
+    /// Starts the presumed line of a token, returning whether it was a new one.
+    fn move_to_line(&mut self, presumed_line: (String, usize), is_in_same_file: bool) -> bool {
+        let is_first_line = self.last_spelling.is_none();
+
+        // A line can only be presumed to go back because of a `#line`
+        // directive, which needs a line marker.
+        let line_offset =
+            (!is_first_line && is_in_same_file && presumed_line.0 == self.presumed_line.0)
+                .then(|| presumed_line.1.checked_sub(self.presumed_line.1))
+                .flatten();
+
+        match line_offset {
+            Some(0) => return false,
+            Some(line_offset) if line_offset <= MAX_EMPTY_LINES => {
+                self.text.push_str(&"\n".repeat(line_offset));
+            }
+            _ => {
+                if !is_first_line {
+                    self.text.push('\n');
+                }
+
+                self.text.push_str(&format!(
+                    "# {} {}\n",
+                    presumed_line.1,
+                    MacroExpander::quote(&presumed_line.0)
+                ));
+            }
+        }
+
+        self.presumed_line = presumed_line;
+
+        true
+    }
+
+    /// Returns whether a token written right after the last one would lex differently.
+    fn would_paste(&self, spelling: &str) -> bool {
+        let Some(last_spelling) = &self.last_spelling else {
+            return false;
+        };
+
+        let (Some(last_character), Some(first_character)) =
+            (last_spelling.chars().last(), spelling.chars().next())
+        else {
+            return false;
+        };
+
+        let is_word_character = |c: char| c.is_ascii_alphanumeric() || c == '_';
+        // A preprocessing number goes on with letters, digits, dots, and
+        // signs after an exponent.
+        let is_number = last_spelling
+            .strip_prefix('.')
+            .unwrap_or(last_spelling)
+            .starts_with(|c: char| c.is_ascii_digit());
+
+        match (last_character, first_character) {
+            _ if is_word_character(last_character) => {
+                is_word_character(first_character)
+                    || first_character == '"'
+                    || first_character == '\''
+                    || (is_number && first_character == '.')
+                    || (is_number
+                        && matches!(last_character, 'e' | 'E' | 'p' | 'P')
+                        && matches!(first_character, '+' | '-'))
+            }
+            ('.', '.' | '0'..='9') | ('/', '/' | '*') => true,
+            _ => {
+                let text = format!("{last_spelling}{spelling}");
+
+                PUNCTUATORS.iter().any(|punctuator| {
+                    punctuator.len() > last_spelling.len() && text.starts_with(punctuator)
+                })
+            }
+        }
+    }
+
+    fn push_spelling(&mut self, spelling: &str) {
+        self.text.push_str(spelling);
+        self.last_spelling = Some(spelling.to_owned());
+    }
+}
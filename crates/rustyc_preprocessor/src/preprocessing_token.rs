@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+
+use rustyc_span::Span;
+use rustyc_token::{Token, TokenKind};
+
+#[derive(Clone, Debug)]
+pub struct PreprocessingToken {
+    token: Token,
+    spelling: String,
+    has_leading_space: bool,
+    hide_set: HashSet<String>,
+}
+
+impl PreprocessingToken {
+    pub fn new(token: Token, spelling: String, has_leading_space: bool) -> Self {
+        Self {
+            token,
+            spelling,
+            has_leading_space,
+            hide_set: HashSet::new(),
+        }
+    }
+
+    pub fn get_kind(&self) -> &TokenKind {
+        self.token.get_kind()
+    }
+
+    pub fn get_span(&self) -> &Span {
+        self.token.get_span()
+    }
+
+    pub fn get_identifier(&self) -> Option<&str> {
+        self.token.get_identifier()
+    }
+
+    pub fn get_spelling(&self) -> &str {
+        &self.spelling
+    }
+
+    pub fn spell<'a>(tokens: impl IntoIterator<Item = &'a Self>) -> String {
+        let mut spelling = String::new();
+
+        for (index, token) in tokens.into_iter().enumerate() {
+            if index != 0 && token.has_leading_space {
+                spelling.push(' ');
+            }
+
+            spelling.push_str(&token.spelling);
+        }
+
+        spelling
+    }
+
+    pub fn has_leading_space(&self) -> bool {
+        self.has_leading_space
+    }
+
+    pub fn is_hidden(&self, name: &str) -> bool {
+        self.hide_set.contains(name)
+    }
+
+    pub fn with_leading_space(self, has_leading_space: bool) -> Self {
+        Self {
+            has_leading_space,
+            ..self
+        }
+    }
+
+    pub fn expanded_from(&self, invocation: &Self) -> Self {
+        Self {
+            token: Token::new(self.get_kind().clone(), invocation.get_span().clone()),
+            ..self.clone()
+        }
+    }
+
+    pub fn with_hide_set(mut self, hide_set: &HashSet<String>) -> Self {
+        self.hide_set.extend(hide_set.iter().cloned());
+        self
+    }
+
+    pub fn get_hide_set(&self) -> &HashSet<String> {
+        &self.hide_set
+    }
+
+    pub fn into_token(self) -> Token {
+        self.token
+    }
+}
@@ -0,0 +1,896 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fs, iter, mem,
+    path::{Path, PathBuf},
+    ptr,
+    rc::Rc,
+};
+
+use chrono::Local;
+use rustyc_diagnostics::Diagnostic;
+use rustyc_span::{SourceFile, SourceMap, Span};
+use rustyc_token::{DelimiterToken, Token, TokenKind};
+
+use crate::{
+    builtin_macro::BuiltinMacro,
+    condition_evaluator::ConditionEvaluator,
+    conditional_stack::ConditionalStack,
+    logical_line::LogicalLine,
+    macro_definition::{MacroDefinition, VARIADIC_PARAMETER, VA_OPT},
+    macro_expander::MacroExpander,
+    preprocessed_text_writer::PreprocessedTextWriter,
+    preprocessing_token::PreprocessingToken,
+    PreprocessorOptions,
+};
+
+/// Stops files that include themselves.
+const MAX_INCLUDE_DEPTH: usize = 200;
+
+const BUILTIN_NAME: &str = "<built-in>";
+
+const COMMAND_LINE_NAME: &str = "<command-line>";
+
+const BUILTIN_HEADERS: [(&str, &str); 2] = [
+    ("stdarg.h", include_str!("../include/stdarg.h")),
+    ("stdbool.h", include_str!("../include/stdbool.h")),
+];
+
+const MAX_LINE_NUMBER: u64 = 2147483647;
+
+pub struct Preprocessor {
+    file: Rc<SourceFile>,
+    source_map: Rc<RefCell<SourceMap>>,
+    options: PreprocessorOptions,
+    macros: HashMap<String, MacroDefinition>,
+    include_depth: usize,
+    once_files: HashSet<PathBuf>,
+    tokens: Vec<PreprocessingToken>,
+    warnings: Vec<Diagnostic>,
+}
+
+impl Preprocessor {
+    pub fn new(
+        file: Rc<SourceFile>,
+        source_map: Rc<RefCell<SourceMap>>,
+        options: PreprocessorOptions,
+    ) -> Self {
+        Self {
+            file,
+            source_map,
+            options,
+            macros: HashMap::new(),
+            include_depth: 0,
+            once_files: HashSet::new(),
+            tokens: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn preprocess(mut self) -> rustyc_diagnostics::Result<(Vec<Token>, Vec<Diagnostic>)> {
+        self.preprocess_files()?;
+
+        let tokens = self
+            .tokens
+            .into_iter()
+            .map(PreprocessingToken::into_token)
+            .collect();
+
+        Ok((tokens, self.warnings))
+    }
+
+    /// Returns the preprocessed file as text, along with the warnings.
+    pub fn preprocess_to_text(mut self) -> rustyc_diagnostics::Result<(String, Vec<Diagnostic>)> {
+        self.preprocess_files()?;
+
+        let text = PreprocessedTextWriter::new(&self.source_map.borrow()).write(&self.tokens);
+
+        Ok((text, self.warnings))
+    }
+
+    /// Defines the predefined and command-line macros, then preprocesses the file.
+    fn preprocess_files(&mut self) -> rustyc_diagnostics::Result<()> {
+        self.macros.insert(
+            "__FILE__".to_owned(),
+            MacroDefinition::new_builtin(BuiltinMacro::File),
+        );
+        self.macros.insert(
+            "__LINE__".to_owned(),
+            MacroDefinition::new_builtin(BuiltinMacro::Line),
+        );
+
+        let builtin_file = self
+            .source_map
+            .borrow_mut()
+            .add_file(BUILTIN_NAME.to_owned(), self.get_predefinitions());
+        self.preprocess_file(builtin_file)?;
+
+        let command_line_file = self.source_map.borrow_mut().add_file(
+            COMMAND_LINE_NAME.to_owned(),
+            self.options
+                .macro_options
+                .iter()
+                .map(|macro_option| format!("{}\n", macro_option.to_directive()))
+                .collect(),
+        );
+        self.preprocess_file(command_line_file)?;
+
+        self.preprocess_file(Rc::clone(&self.file))
+    }
+
+    fn get_predefinitions(&self) -> String {
+        let now = Local::now();
+        let date = now.format("\"%b %e %Y\"").to_string();
+        let time = now.format("\"%H:%M:%S\"").to_string();
+
+        [
+            ("__STDC__", "1"),
+            ("__STDC_HOSTED__", "1"),
+            ("__STDC_VERSION__", "201112L"),
+            ("__DATE__", &date),
+            ("__TIME__", &time),
+        ]
+        .into_iter()
+        .chain(self.options.target.get_predefined_macros().iter().copied())
+        .map(|(name, value)| format!("#define {name} {value}\n"))
+        .collect()
+    }
+
+    fn preprocess_file(&mut self, file: Rc<SourceFile>) -> rustyc_diagnostics::Result<()> {
+        let mut conditionals = ConditionalStack::new();
+        // Macro invocations can span several lines, so the lines between
+        // directives are expanded together.
+        let mut text = Vec::new();
+        let mut line_index = 0;
+
+        while line_index < file.get_line_count() {
+            let (line, next_line_index) = LogicalLine::read(&file, line_index)?;
+            line_index = next_line_index;
+
+            // Skipped lines don't need to consist of valid tokens, so only
+            // the names of their directives are looked at.
+            if !conditionals.is_active() {
+                if let Some((name, span)) = Self::get_skipped_directive_name(&line) {
+                    self.preprocess_skipped_directive(&line, &name, span, &mut conditionals)?;
+                }
+
+                continue;
+            }
+
+            let tokens = line.lex()?;
+
+            if matches!(
+                tokens.first().map(PreprocessingToken::get_kind),
+                Some(TokenKind::Hash)
+            ) {
+                self.expand_text(&mut text)?;
+                self.preprocess_directive(&file, tokens, line_index, &mut conditionals)?;
+            } else {
+                text.extend(tokens);
+            }
+        }
+
+        self.expand_text(&mut text)?;
+
+        conditionals.check_terminated()
+    }
+
+    fn expand_text(
+        &mut self,
+        text: &mut Vec<PreprocessingToken>,
+    ) -> rustyc_diagnostics::Result<()> {
+        let source_map = self.source_map.borrow();
+        let macro_expander = MacroExpander::new(mem::take(text), &self.macros, &source_map);
+
+        self.tokens.extend(macro_expander.expand()?);
+
+        Ok(())
+    }
+
+    fn preprocess_directive(
+        &mut self,
+        file: &SourceFile,
+        tokens: Vec<PreprocessingToken>,
+        next_line_index: usize,
+        conditionals: &mut ConditionalStack,
+    ) -> rustyc_diagnostics::Result<()> {
+        let mut tokens = tokens.into_iter().skip(1);
+
+        // A `#` on its own is a null directive, which does nothing.
+        let Some(directive) = tokens.next() else {
+            return Ok(());
+        };
+
+        let arguments = tokens.collect::<Vec<_>>();
+
+        // A line marker, which is how preprocessed text tells where its lines
+        // come from, works like `#line` but may end with flags, which are
+        // ignored.
+        if let TokenKind::Number(_) = directive.get_kind() {
+            let arguments = iter::once(directive.clone())
+                .chain(arguments)
+                .take(2)
+                .collect();
+
+            return self.preprocess_line(file, &directive, arguments, next_line_index);
+        }
+
+        let Some(name) = directive.get_identifier() else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::InvalidDirective(directive.get_kind().to_string()),
+                directive.get_span().clone(),
+            ));
+        };
+
+        match name {
+            "include" => self.preprocess_include(file, &directive, arguments),
+            "define" => self.preprocess_define(&directive, arguments),
+            "undef" => {
+                let macro_name = Self::expect_macro_name(&directive, &arguments)?;
+                self.check_no_extra_tokens(&directive, &arguments[1..]);
+                self.macros.remove(&macro_name);
+
+                Ok(())
+            }
+            "ifdef" | "ifndef" => {
+                let macro_name = Self::expect_macro_name(&directive, &arguments)?;
+                self.check_no_extra_tokens(&directive, &arguments[1..]);
+
+                let is_defined = self.macros.contains_key(&macro_name);
+                conditionals.push(
+                    name,
+                    directive.get_span().clone(),
+                    (name == "ifdef") == is_defined,
+                );
+
+                Ok(())
+            }
+            "if" => {
+                let condition = self.evaluate_condition(&directive, arguments)?;
+                conditionals.push(name, directive.get_span().clone(), condition);
+
+                Ok(())
+            }
+            "elif" => conditionals.enter_elif(directive.get_span().clone(), || {
+                self.evaluate_condition(&directive, arguments)
+            }),
+            "else" => {
+                self.check_no_extra_tokens(&directive, &arguments);
+                conditionals.enter_else(directive.get_span().clone())
+            }
+            "endif" => {
+                self.check_no_extra_tokens(&directive, &arguments);
+                conditionals.pop(directive.get_span().clone())
+            }
+            "line" => self.preprocess_line(file, &directive, arguments, next_line_index),
+            "error" => Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ErrorDirective(PreprocessingToken::spell(&arguments)),
+                directive.get_span().clone(),
+            )),
+            "warning" => {
+                self.warnings.push(Diagnostic::new_warning(
+                    rustyc_diagnostics::Warning::WarningDirective(PreprocessingToken::spell(
+                        &arguments,
+                    )),
+                    directive.get_span().clone(),
+                ));
+
+                Ok(())
+            }
+            "pragma" => {
+                self.preprocess_pragma(file, &directive, &arguments);
+                Ok(())
+            }
+            _ => Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::InvalidDirective(name.to_owned()),
+                directive.get_span().clone(),
+            )),
+        }
+    }
+
+    fn preprocess_skipped_directive(
+        &mut self,
+        line: &LogicalLine,
+        name: &str,
+        span: Span,
+        conditionals: &mut ConditionalStack,
+    ) -> rustyc_diagnostics::Result<()> {
+        match name {
+            "if" | "ifdef" | "ifndef" => conditionals.push(name, span, false),
+            "elif" => conditionals.enter_elif(span, || {
+                let mut tokens = line.lex()?.into_iter().skip(1);
+
+                match tokens.next() {
+                    Some(directive) => self.evaluate_condition(&directive, tokens.collect()),
+                    None => Ok(false),
+                }
+            })?,
+            "else" => conditionals.enter_else(span)?,
+            "endif" => conditionals.pop(span)?,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn evaluate_condition(
+        &self,
+        directive: &PreprocessingToken,
+        arguments: Vec<PreprocessingToken>,
+    ) -> rustyc_diagnostics::Result<bool> {
+        let mut tokens = Vec::new();
+        let mut arguments = arguments.into_iter().peekable();
+
+        while let Some(token) = arguments.next() {
+            if token.get_identifier() != Some("defined") {
+                tokens.push(token);
+                continue;
+            }
+
+            let is_parenthesized = arguments
+                .next_if(|argument| {
+                    matches!(
+                        argument.get_kind(),
+                        TokenKind::OpenDelimiter(DelimiterToken::Parenthesis)
+                    )
+                })
+                .is_some();
+
+            let Some(name) = arguments.next_if(|argument| argument.get_identifier().is_some())
+            else {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::DefinedWithoutIdentifier,
+                    token.get_span().clone(),
+                ));
+            };
+
+            if is_parenthesized
+                && arguments
+                    .next_if(|argument| {
+                        matches!(
+                            argument.get_kind(),
+                            TokenKind::CloseDelimiter(DelimiterToken::Parenthesis)
+                        )
+                    })
+                    .is_none()
+            {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::MissingConditionToken(TokenKind::CloseDelimiter(
+                        DelimiterToken::Parenthesis,
+                    )),
+                    name.get_span().clone(),
+                ));
+            }
+
+            let value = u64::from(
+                name.get_identifier()
+                    .is_some_and(|name| self.macros.contains_key(name)),
+            );
+
+            tokens.push(PreprocessingToken::new(
+                Token::new(TokenKind::Number(value), token.get_span().clone()),
+                value.to_string(),
+                token.has_leading_space(),
+            ));
+        }
+
+        let tokens =
+            MacroExpander::new(tokens, &self.macros, &self.source_map.borrow()).expand()?;
+
+        ConditionEvaluator::new(directive, tokens).evaluate()
+    }
+
+    fn preprocess_include(
+        &mut self,
+        file: &SourceFile,
+        directive: &PreprocessingToken,
+        arguments: Vec<PreprocessingToken>,
+    ) -> rustyc_diagnostics::Result<()> {
+        // The header name may be the result of expanding macros, in which
+        // case it is only recognized once they are.
+        let header_name = match Self::get_header_name(&arguments) {
+            Some(header_name) => Some(header_name),
+            None => Self::get_header_name(
+                &MacroExpander::new(arguments, &self.macros, &self.source_map.borrow()).expand()?,
+            ),
+        };
+
+        let Some((name, is_angled)) = header_name else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::InvalidIncludeFileName,
+                directive.get_span().clone(),
+            ));
+        };
+
+        if self.include_depth == MAX_INCLUDE_DEPTH {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::IncludeNestedTooDeeply,
+                directive.get_span().clone(),
+            ));
+        }
+
+        let (file_name, source) = match self.find_include_file(file, &name, is_angled) {
+            Some(path) => {
+                if fs::canonicalize(&path).is_ok_and(|path| self.once_files.contains(&path)) {
+                    return Ok(());
+                }
+
+                let source = fs::read_to_string(&path).map_err(|e| {
+                    Diagnostic::new_error(
+                        rustyc_diagnostics::Error::IncludeFileUnreadable(name, e.to_string()),
+                        directive.get_span().clone(),
+                    )
+                })?;
+
+                (path.display().to_string(), source)
+            }
+            // The headers that come with the compiler can be replaced by
+            // ones in the include directories.
+            None => match BUILTIN_HEADERS
+                .iter()
+                .find(|(header_name, _)| *header_name == name)
+            {
+                Some((header_name, source)) => (header_name.to_string(), source.to_string()),
+                None => {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::IncludeFileNotFound(name),
+                        directive.get_span().clone(),
+                    ));
+                }
+            },
+        };
+
+        let included_file = self.source_map.borrow_mut().add_file(file_name, source);
+
+        self.include_depth += 1;
+        self.preprocess_file(included_file)?;
+        self.include_depth -= 1;
+
+        Ok(())
+    }
+
+    fn get_header_name(arguments: &[PreprocessingToken]) -> Option<(String, bool)> {
+        match arguments.first()?.get_kind() {
+            TokenKind::String(name) => Some((name.clone(), false)),
+            TokenKind::LessThan => {
+                let length = arguments
+                    .iter()
+                    .position(|token| matches!(token.get_kind(), TokenKind::GreaterThan))?;
+
+                let name = arguments[1..length]
+                    .iter()
+                    .enumerate()
+                    .map(
+                        |(index, token)| match index != 0 && token.has_leading_space() {
+                            true => format!(" {}", token.get_kind()),
+                            false => token.get_kind().to_string(),
+                        },
+                    )
+                    .collect();
+
+                Some((name, true))
+            }
+            _ => None,
+        }
+    }
+
+    fn find_include_file(&self, file: &SourceFile, name: &str, is_angled: bool) -> Option<PathBuf> {
+        let including_directory = (!is_angled).then(|| {
+            Path::new(file.get_name())
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default()
+        });
+
+        including_directory
+            .into_iter()
+            .chain(self.options.include_directories.iter().cloned())
+            .map(|directory| directory.join(name))
+            .find(|path| path.is_file())
+    }
+
+    fn preprocess_line(
+        &mut self,
+        file: &SourceFile,
+        directive: &PreprocessingToken,
+        arguments: Vec<PreprocessingToken>,
+        next_line_index: usize,
+    ) -> rustyc_diagnostics::Result<()> {
+        // The line number and file name may be the result of expanding
+        // macros.
+        let arguments =
+            MacroExpander::new(arguments, &self.macros, &self.source_map.borrow()).expand()?;
+
+        let Some(line_number_token) = arguments.first() else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::MissingLineNumber,
+                directive.get_span().clone(),
+            ));
+        };
+
+        // The line number is a digit sequence, which is decimal even if it
+        // starts with a zero.
+        let spelling = line_number_token.get_spelling();
+        if !matches!(line_number_token.get_kind(), TokenKind::Number(_))
+            || !spelling.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::InvalidLineNumber(spelling.to_owned()),
+                line_number_token.get_span().clone(),
+            ));
+        }
+
+        let line_number = spelling
+            .parse::<u64>()
+            .ok()
+            .filter(|line_number| (1..=MAX_LINE_NUMBER).contains(line_number))
+            .ok_or_else(|| {
+                Diagnostic::new_error(
+                    rustyc_diagnostics::Error::LineNumberOutOfRange,
+                    line_number_token.get_span().clone(),
+                )
+            })?;
+
+        let file_name = match arguments.get(1) {
+            Some(token) => match token.get_kind() {
+                TokenKind::String(file_name) => file_name.clone(),
+                _ => {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::InvalidLineFileName(
+                            token.get_spelling().to_owned(),
+                        ),
+                        token.get_span().clone(),
+                    ));
+                }
+            },
+            None => self
+                .source_map
+                .borrow()
+                .lookup_presumed_line(directive.get_span().get_low())
+                .map_or_else(|| file.get_name().to_owned(), |(file_name, _)| file_name),
+        };
+
+        self.check_no_extra_tokens(directive, arguments.get(2..).unwrap_or_default());
+
+        let position = match next_line_index < file.get_line_count() {
+            true => file.get_line_bounds(next_line_index).0,
+            false => file.get_end_position(),
+        };
+
+        self.source_map
+            .borrow_mut()
+            .add_line_directive(position, line_number as usize, file_name);
+
+        Ok(())
+    }
+
+    fn preprocess_pragma(
+        &mut self,
+        file: &SourceFile,
+        directive: &PreprocessingToken,
+        arguments: &[PreprocessingToken],
+    ) {
+        let Some(pragma) = arguments.first() else {
+            return;
+        };
+
+        if pragma.get_identifier() != Some("once") {
+            if self.options.warns_unknown_pragmas {
+                self.warnings.push(Diagnostic::new_warning(
+                    rustyc_diagnostics::Warning::UnknownPragma(PreprocessingToken::spell(
+                        arguments,
+                    )),
+                    pragma.get_span().clone(),
+                ));
+            }
+
+            return;
+        }
+
+        self.check_no_extra_tokens(directive, &arguments[1..]);
+
+        if ptr::eq(file, self.file.as_ref()) {
+            self.warnings.push(Diagnostic::new_warning(
+                rustyc_diagnostics::Warning::PragmaOnceInMainFile,
+                pragma.get_span().clone(),
+            ));
+
+            return;
+        }
+
+        if let Ok(path) = fs::canonicalize(file.get_name()) {
+            self.once_files.insert(path);
+        }
+    }
+
+    fn preprocess_define(
+        &mut self,
+        directive: &PreprocessingToken,
+        arguments: Vec<PreprocessingToken>,
+    ) -> rustyc_diagnostics::Result<()> {
+        let name = Self::expect_macro_name(directive, &arguments)?;
+        let mut tokens = arguments.into_iter().skip(1).peekable();
+
+        // A parenthesis right after the name starts the parameters of a
+        // function-like macro.
+        let definition = match tokens.next_if(|token| {
+            matches!(
+                token.get_kind(),
+                TokenKind::OpenDelimiter(DelimiterToken::Parenthesis)
+            ) && !token.has_leading_space()
+        }) {
+            Some(open_parenthesis) => {
+                let (parameters, is_variadic) =
+                    Self::parse_macro_parameters(&open_parenthesis, &mut tokens)?;
+                let replacement = Self::get_macro_replacement(tokens);
+
+                let definition =
+                    MacroDefinition::new_function_like(parameters, is_variadic, replacement);
+                Self::check_macro_replacement(&definition)?;
+
+                definition
+            }
+            None => {
+                let definition =
+                    MacroDefinition::new_object_like(Self::get_macro_replacement(tokens));
+                Self::check_macro_replacement(&definition)?;
+
+                definition
+            }
+        };
+
+        if let Some(previous_definition) = self.macros.get(&name) {
+            if !previous_definition.is_identical(&definition) {
+                self.warnings.push(Diagnostic::new_warning(
+                    rustyc_diagnostics::Warning::MacroRedefined(name.clone()),
+                    directive.get_span().clone(),
+                ));
+            }
+        }
+
+        self.macros.insert(name, definition);
+
+        Ok(())
+    }
+
+    fn parse_macro_parameters(
+        open_parenthesis: &PreprocessingToken,
+        tokens: &mut impl Iterator<Item = PreprocessingToken>,
+    ) -> rustyc_diagnostics::Result<(Vec<String>, bool)> {
+        let mut parameters = Vec::<String>::new();
+
+        // A macro may have no parameters at all.
+        let mut token = tokens.next();
+        if let Some(TokenKind::CloseDelimiter(DelimiterToken::Parenthesis)) =
+            token.as_ref().map(PreprocessingToken::get_kind)
+        {
+            return Ok((parameters, false));
+        }
+
+        loop {
+            let Some(parameter) = token else {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::UnterminatedMacroParameterList,
+                    open_parenthesis.get_span().clone(),
+                ));
+            };
+
+            let is_variadic = match parameter.get_kind() {
+                TokenKind::DotDotDot => {
+                    parameters.push(VARIADIC_PARAMETER.to_owned());
+                    true
+                }
+                TokenKind::Identifier(name) if name == VARIADIC_PARAMETER || name == VA_OPT => {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::VariadicIdentifierOutsideVariadicMacro(
+                            name.clone(),
+                        ),
+                        parameter.get_span().clone(),
+                    ));
+                }
+                TokenKind::Identifier(name) if parameters.contains(name) => {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::DuplicateMacroParameter(name.clone()),
+                        parameter.get_span().clone(),
+                    ));
+                }
+                TokenKind::Identifier(name) => {
+                    parameters.push(name.clone());
+                    false
+                }
+                kind => {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::InvalidMacroParameter(kind.to_string()),
+                        parameter.get_span().clone(),
+                    ));
+                }
+            };
+
+            // Only a comma may come between the parameters, and nothing may
+            // come after the variable arguments.
+            match tokens.next() {
+                Some(separator)
+                    if matches!(
+                        separator.get_kind(),
+                        TokenKind::CloseDelimiter(DelimiterToken::Parenthesis)
+                    ) =>
+                {
+                    return Ok((parameters, is_variadic));
+                }
+                Some(separator)
+                    if matches!(separator.get_kind(), TokenKind::Comma) && !is_variadic =>
+                {
+                    token = tokens.next();
+                }
+                Some(separator) => {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::UnterminatedMacroParameterList,
+                        separator.get_span().clone(),
+                    ));
+                }
+                None => token = None,
+            }
+        }
+    }
+
+    fn get_macro_replacement(
+        tokens: impl Iterator<Item = PreprocessingToken>,
+    ) -> Vec<PreprocessingToken> {
+        // The whitespace between the name or parameters and the replacement
+        // list isn't part of it.
+        tokens
+            .enumerate()
+            .map(|(index, token)| match index {
+                0 => token.with_leading_space(false),
+                _ => token,
+            })
+            .collect()
+    }
+
+    fn check_macro_replacement(definition: &MacroDefinition) -> rustyc_diagnostics::Result<()> {
+        let replacement = definition.get_replacement();
+
+        let edges = [replacement.first(), replacement.last()];
+        if let Some(hash_hash) = edges
+            .into_iter()
+            .flatten()
+            .find(|token| matches!(token.get_kind(), TokenKind::HashHash))
+        {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::PastingAtReplacementEdge,
+                hash_hash.get_span().clone(),
+            ));
+        }
+
+        // The index of the closing parenthesis of the `__VA_OPT__` that the
+        // tokens are in, if any, along with the parentheses left to close.
+        let mut va_opt_depth = None::<usize>;
+
+        for (index, token) in replacement.iter().enumerate() {
+            let next_token = replacement.get(index + 1);
+
+            match token.get_kind() {
+                TokenKind::Hash if definition.is_function_like() => {
+                    let is_operand = next_token
+                        .and_then(PreprocessingToken::get_identifier)
+                        .is_some_and(|name| {
+                            definition.get_parameter_index(name).is_some()
+                                || (name == VA_OPT && definition.is_variadic())
+                        });
+
+                    if !is_operand {
+                        return Err(Diagnostic::new_error(
+                            rustyc_diagnostics::Error::StringizingWithoutParameter,
+                            token.get_span().clone(),
+                        ));
+                    }
+                }
+                TokenKind::Identifier(name)
+                    if (name == VARIADIC_PARAMETER || name == VA_OPT)
+                        && !definition.is_variadic() =>
+                {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::VariadicIdentifierOutsideVariadicMacro(
+                            name.clone(),
+                        ),
+                        token.get_span().clone(),
+                    ));
+                }
+                TokenKind::Identifier(name) if name == VA_OPT => {
+                    if va_opt_depth.is_some() {
+                        return Err(Diagnostic::new_error(
+                            rustyc_diagnostics::Error::NestedVaOpt,
+                            token.get_span().clone(),
+                        ));
+                    }
+
+                    if !matches!(
+                        next_token.map(PreprocessingToken::get_kind),
+                        Some(TokenKind::OpenDelimiter(DelimiterToken::Parenthesis))
+                    ) {
+                        return Err(Diagnostic::new_error(
+                            rustyc_diagnostics::Error::InvalidVaOpt,
+                            token.get_span().clone(),
+                        ));
+                    }
+
+                    va_opt_depth = Some(0);
+                }
+                TokenKind::OpenDelimiter(DelimiterToken::Parenthesis) => {
+                    if let Some(depth) = &mut va_opt_depth {
+                        *depth += 1;
+                    }
+                }
+                TokenKind::CloseDelimiter(DelimiterToken::Parenthesis) => {
+                    if let Some(depth) = &mut va_opt_depth {
+                        *depth -= 1;
+
+                        if *depth == 0 {
+                            va_opt_depth = None;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        match va_opt_depth {
+            Some(_) => Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::InvalidVaOpt,
+                replacement
+                    .iter()
+                    .rev()
+                    .find(|token| token.get_identifier() == Some(VA_OPT))
+                    .map_or_else(Span::new_dummy, |token| token.get_span().clone()),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn expect_macro_name(
+        directive: &PreprocessingToken,
+        arguments: &[PreprocessingToken],
+    ) -> rustyc_diagnostics::Result<String> {
+        let Some(token) = arguments.first() else {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::MacroNameMissing,
+                directive.get_span().clone(),
+            ));
+        };
+
+        token.get_identifier().map(str::to_owned).ok_or_else(|| {
+            Diagnostic::new_error(
+                rustyc_diagnostics::Error::MacroNameNotIdentifier,
+                token.get_span().clone(),
+            )
+        })
+    }
+
+    fn check_no_extra_tokens(
+        &mut self,
+        directive: &PreprocessingToken,
+        extra_tokens: &[PreprocessingToken],
+    ) {
+        if let Some(token) = extra_tokens.first() {
+            self.warnings.push(Diagnostic::new_warning(
+                rustyc_diagnostics::Warning::ExtraDirectiveTokens(directive.get_kind().to_string()),
+                token.get_span().clone(),
+            ));
+        }
+    }
+
+    fn get_skipped_directive_name(line: &LogicalLine) -> Option<(String, Span)> {
+        let text = line.get_text();
+
+        let directive = text.trim_start().strip_prefix('#')?.trim_start();
+        let name_start = text.len() - directive.len();
+        let name = directive
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+            .collect::<String>();
+
+        let span = line.get_span(name_start, name_start + name.len());
+
+        Some((name, span))
+    }
+}
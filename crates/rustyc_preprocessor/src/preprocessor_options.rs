@@ -0,0 +1,13 @@
+use std::path::PathBuf;
+
+use rustyc_target::Target;
+
+use crate::MacroOption;
+
+#[derive(Clone, Debug, Default)]
+pub struct PreprocessorOptions {
+    pub include_directories: Vec<PathBuf>,
+    pub macro_options: Vec<MacroOption>,
+    pub target: Target,
+    pub warns_unknown_pragmas: bool,
+}
@@ -0,0 +1,53 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rustyc_ast::{statements::Statement, Block};
+
+use crate::{
+    function_table::FunctionTable, resolutions::Resolutions, scope_stack::ScopeStack,
+    statement_resolver::StatementResolver,
+};
+
+pub struct BlockResolver {
+    block: Rc<Block>,
+    scope_stack: Rc<RefCell<ScopeStack>>,
+    function_table: Rc<RefCell<FunctionTable>>,
+    resolutions: Rc<RefCell<Resolutions>>,
+}
+
+impl BlockResolver {
+    pub fn new(
+        block: Rc<Block>,
+        scope_stack: Rc<RefCell<ScopeStack>>,
+        function_table: Rc<RefCell<FunctionTable>>,
+        resolutions: Rc<RefCell<Resolutions>>,
+    ) -> Self {
+        Self {
+            block,
+            scope_stack,
+            function_table,
+            resolutions,
+        }
+    }
+
+    pub fn resolve(self) -> rustyc_diagnostics::Result<()> {
+        self.scope_stack.borrow_mut().enter_scope();
+
+        for statement in self.block.get_statements().iter() {
+            self.resolve_statement(Rc::clone(statement))?;
+        }
+
+        self.scope_stack.borrow_mut().exit_scope();
+
+        Ok(())
+    }
+
+    fn resolve_statement(&self, statement: Rc<Statement>) -> rustyc_diagnostics::Result<()> {
+        let statement_resolver = StatementResolver::new(
+            statement,
+            Rc::clone(&self.scope_stack),
+            Rc::clone(&self.function_table),
+            Rc::clone(&self.resolutions),
+        );
+        statement_resolver.resolve()
+    }
+}
@@ -0,0 +1,190 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rustyc_ast::{
+    expressions::{
+        CompoundLiteralExpression, Expression, ExpressionKind, FunctionCallExpression,
+        SizeOfOperand, VariableExpression,
+    },
+    types::TyKind,
+};
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::Res;
+
+use crate::{
+    block_resolver::BlockResolver, function_table::FunctionTable,
+    initializer_resolver::InitializerResolver, resolutions::Resolutions, scope_stack::ScopeStack,
+};
+
+pub struct ExpressionResolver {
+    expression: Rc<Expression>,
+    scope_stack: Rc<RefCell<ScopeStack>>,
+    function_table: Rc<RefCell<FunctionTable>>,
+    resolutions: Rc<RefCell<Resolutions>>,
+}
+
+impl ExpressionResolver {
+    pub fn new(
+        expression: Rc<Expression>,
+        scope_stack: Rc<RefCell<ScopeStack>>,
+        function_table: Rc<RefCell<FunctionTable>>,
+        resolutions: Rc<RefCell<Resolutions>>,
+    ) -> Self {
+        Self {
+            expression,
+            scope_stack,
+            function_table,
+            resolutions,
+        }
+    }
+
+    pub fn resolve(self) -> rustyc_diagnostics::Result<()> {
+        match self.expression.get_kind() {
+            ExpressionKind::Assignment(expression) => {
+                self.resolve_expression(expression.get_left())?;
+                self.resolve_expression(expression.get_right())
+            }
+            ExpressionKind::Binary(expression) => {
+                self.resolve_expression(expression.get_left())?;
+                self.resolve_expression(expression.get_right())
+            }
+            ExpressionKind::Unary(expression) => self.resolve_expression(expression.get_operand()),
+            ExpressionKind::Variable(expression) => self.resolve_variable(expression),
+            ExpressionKind::Number(_) | ExpressionKind::FloatNumber(_) => Ok(()),
+            ExpressionKind::String(expression) => {
+                let res = self.scope_stack.borrow_mut().declare_anonymous_global();
+                self.resolutions
+                    .borrow_mut()
+                    .insert(expression.get_id(), res);
+
+                Ok(())
+            }
+            ExpressionKind::FunctionCall(expression) => self.resolve_function_call(expression),
+            ExpressionKind::Member(expression) => self.resolve_expression(expression.get_base()),
+            ExpressionKind::SizeOf(expression) => match expression.get_operand() {
+                SizeOfOperand::Ty(ty) => match ty.get_kind() {
+                    TyKind::Array(array_ty) => array_ty
+                        .get_length()
+                        .map_or(Ok(()), |length| self.resolve_expression(length)),
+                    _ => Ok(()),
+                },
+                SizeOfOperand::Expression(operand) => self.resolve_expression(Rc::clone(operand)),
+            },
+            ExpressionKind::CompoundLiteral(expression) => {
+                self.resolve_compound_literal(expression)
+            }
+            ExpressionKind::Statement(expression) => {
+                let block_resolver = BlockResolver::new(
+                    expression.get_block(),
+                    Rc::clone(&self.scope_stack),
+                    Rc::clone(&self.function_table),
+                    Rc::clone(&self.resolutions),
+                );
+                block_resolver.resolve()
+            }
+            ExpressionKind::GenericSelection(expression) => {
+                self.resolve_expression(expression.get_controlling_expression())?;
+
+                for association in expression.get_associations().iter() {
+                    self.resolve_expression(association.get_expression())?;
+                }
+
+                Ok(())
+            }
+            ExpressionKind::VaStart(expression) => {
+                self.resolve_expression(expression.get_list())?;
+                self.resolve_expression(expression.get_last_parameter())
+            }
+            ExpressionKind::VaArg(expression) => self.resolve_expression(expression.get_list()),
+            ExpressionKind::VaEnd(expression) => self.resolve_expression(expression.get_list()),
+            ExpressionKind::Alloca(expression) => self.resolve_expression(expression.get_size()),
+        }
+    }
+
+    fn resolve_variable(&self, expression: &VariableExpression) -> rustyc_diagnostics::Result<()> {
+        let res = self
+            .scope_stack
+            .borrow()
+            .resolve(expression.get_name())
+            .or_else(|| {
+                self.function_table
+                    .borrow()
+                    .get(expression.get_name())
+                    .map(Res::Function)
+            })
+            .ok_or_else(|| {
+                Diagnostic::new_error(
+                    rustyc_diagnostics::Error::UndeclaredVariable(expression.get_name().to_owned()),
+                    self.expression.get_span().clone(),
+                )
+            })?;
+
+        self.resolutions
+            .borrow_mut()
+            .insert(expression.get_id(), res);
+
+        Ok(())
+    }
+
+    fn resolve_function_call(
+        &self,
+        expression: &FunctionCallExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        let callee = expression.get_callee();
+
+        match callee.get_kind() {
+            ExpressionKind::Variable(variable) if !self.is_declared(variable.get_name()) => {
+                let function_id = self.function_table.borrow_mut().declare(
+                    variable.get_name(),
+                    None,
+                    callee.get_span().clone(),
+                )?;
+
+                let mut resolutions = self.resolutions.borrow_mut();
+                resolutions.insert(variable.get_id(), Res::Function(function_id));
+                resolutions.insert_implicit_function(function_id);
+            }
+            _ => self.resolve_expression(callee)?,
+        }
+
+        for argument in expression.get_arguments().iter() {
+            self.resolve_expression(Rc::clone(argument))?;
+        }
+
+        Ok(())
+    }
+
+    fn resolve_compound_literal(
+        &self,
+        expression: &CompoundLiteralExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        let initializer_resolver = InitializerResolver::new(
+            expression.get_initializer(),
+            Rc::clone(&self.scope_stack),
+            Rc::clone(&self.function_table),
+            Rc::clone(&self.resolutions),
+        );
+        initializer_resolver.resolve()?;
+
+        let res = self.scope_stack.borrow_mut().declare_anonymous_local();
+        self.resolutions
+            .borrow_mut()
+            .insert(expression.get_id(), res);
+
+        Ok(())
+    }
+
+    fn is_declared(&self, name: &str) -> bool {
+        self.scope_stack.borrow().resolve(name).is_some()
+            || self.function_table.borrow().get(name).is_some()
+    }
+
+    fn resolve_expression(&self, expression: Rc<Expression>) -> rustyc_diagnostics::Result<()> {
+        let expression_resolver = Self::new(
+            expression,
+            Rc::clone(&self.scope_stack),
+            Rc::clone(&self.function_table),
+            Rc::clone(&self.resolutions),
+        );
+        expression_resolver.resolve()
+    }
+}
@@ -0,0 +1,70 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rustyc_ast::items::{FunctionItem, Parameter};
+
+use crate::{
+    function_table::FunctionTable, global_table::GlobalTable, resolutions::Resolutions,
+    scope_stack::ScopeStack, statement_resolver::StatementResolver,
+};
+
+pub struct FunctionResolver {
+    function: Rc<FunctionItem>,
+    scope_stack: Rc<RefCell<ScopeStack>>,
+    function_table: Rc<RefCell<FunctionTable>>,
+    resolutions: Rc<RefCell<Resolutions>>,
+}
+
+impl FunctionResolver {
+    pub fn new(
+        function: Rc<FunctionItem>,
+        function_table: Rc<RefCell<FunctionTable>>,
+        global_table: Rc<RefCell<GlobalTable>>,
+        resolutions: Rc<RefCell<Resolutions>>,
+    ) -> Self {
+        Self {
+            function,
+            scope_stack: Rc::new(RefCell::new(ScopeStack::new(global_table))),
+            function_table,
+            resolutions,
+        }
+    }
+
+    pub fn resolve(self) -> rustyc_diagnostics::Result<()> {
+        for parameter in self.function.get_parameters().iter() {
+            self.resolve_parameter(parameter)?;
+        }
+
+        let Some(body) = self.function.get_body() else {
+            return Ok(());
+        };
+
+        // The outermost block of the body shares the scope of the parameters.
+        for statement in body.get_statements().iter() {
+            let statement_resolver = StatementResolver::new(
+                Rc::clone(statement),
+                Rc::clone(&self.scope_stack),
+                Rc::clone(&self.function_table),
+                Rc::clone(&self.resolutions),
+            );
+            statement_resolver.resolve()?;
+        }
+
+        Ok(())
+    }
+
+    fn resolve_parameter(&self, parameter: &Parameter) -> rustyc_diagnostics::Result<()> {
+        let res = match parameter.get_name() {
+            Some(name) => self
+                .scope_stack
+                .borrow_mut()
+                .declare_parameter(name, parameter.get_span().clone())?,
+            None => self.scope_stack.borrow_mut().declare_anonymous_parameter(),
+        };
+
+        self.resolutions
+            .borrow_mut()
+            .insert(parameter.get_id(), res);
+
+        Ok(())
+    }
+}
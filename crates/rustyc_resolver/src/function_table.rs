@@ -0,0 +1,67 @@
+use std::collections::{HashMap, HashSet};
+
+use rustyc_ast::StorageClass;
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::FunctionId;
+use rustyc_span::Span;
+
+/// Every function name declared or called so far in the translation unit.
+#[derive(Default)]
+pub struct FunctionTable {
+    functions: HashMap<String, (FunctionId, bool)>,
+    definitions: HashSet<FunctionId>,
+}
+
+impl FunctionTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn define(
+        &mut self,
+        name: &str,
+        storage_class: Option<StorageClass>,
+        span: Span,
+    ) -> rustyc_diagnostics::Result<FunctionId> {
+        let id = self.declare(name, storage_class, span.clone())?;
+
+        if !self.definitions.insert(id) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::FunctionRedefinition(name.to_owned()),
+                span,
+            ));
+        }
+
+        Ok(id)
+    }
+
+    pub fn get(&self, name: &str) -> Option<FunctionId> {
+        self.functions.get(name).map(|(id, _)| *id)
+    }
+
+    /// Declares a function, which is `static` if its first declaration is.
+    pub fn declare(
+        &mut self,
+        name: &str,
+        storage_class: Option<StorageClass>,
+        span: Span,
+    ) -> rustyc_diagnostics::Result<FunctionId> {
+        let is_static = storage_class == Some(StorageClass::Static);
+
+        if let Some((id, was_static)) = self.functions.get(name) {
+            if is_static && !was_static {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::StaticDeclarationFollowsNonStatic(name.to_owned()),
+                    span,
+                ));
+            }
+
+            return Ok(*id);
+        }
+
+        let id = FunctionId::new(self.functions.len() as u64);
+        self.functions.insert(name.to_owned(), (id, is_static));
+
+        Ok(id)
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::{HashMap, HashSet};
+
+use rustyc_ast::StorageClass;
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::GlobalId;
+use rustyc_span::Span;
+
+/// The variables with static storage duration of the translation unit.
+#[derive(Default)]
+pub struct GlobalTable {
+    globals: HashMap<String, (GlobalId, bool)>,
+    definitions: HashSet<GlobalId>,
+    global_count: u64,
+}
+
+impl GlobalTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares a file scope variable; only `extern` declarations can follow a `static` one.
+    pub fn declare(
+        &mut self,
+        name: &str,
+        storage_class: Option<StorageClass>,
+        span: Span,
+    ) -> rustyc_diagnostics::Result<GlobalId> {
+        let is_static = storage_class == Some(StorageClass::Static);
+
+        let Some((id, was_static)) = self.globals.get(name).copied() else {
+            let id = self.allocate();
+            self.globals.insert(name.to_owned(), (id, is_static));
+
+            return Ok(id);
+        };
+
+        if is_static && !was_static {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::StaticDeclarationFollowsNonStatic(name.to_owned()),
+                span,
+            ));
+        }
+
+        if storage_class.is_none() && was_static {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::NonStaticDeclarationFollowsStatic(name.to_owned()),
+                span,
+            ));
+        }
+
+        Ok(id)
+    }
+
+    /// Declares a file scope variable along with its single initializer.
+    pub fn define(
+        &mut self,
+        name: &str,
+        storage_class: Option<StorageClass>,
+        span: Span,
+    ) -> rustyc_diagnostics::Result<GlobalId> {
+        let id = self.declare(name, storage_class, span.clone())?;
+
+        if !self.definitions.insert(id) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::VariableRedefinition(name.to_owned()),
+                span,
+            ));
+        }
+
+        Ok(id)
+    }
+
+    pub fn get(&self, name: &str) -> Option<GlobalId> {
+        self.globals.get(name).map(|(id, _)| *id)
+    }
+
+    /// Allocates a variable that can't be looked up by name.
+    pub fn allocate(&mut self) -> GlobalId {
+        let id = GlobalId::new(self.global_count);
+        self.global_count += 1;
+
+        id
+    }
+}
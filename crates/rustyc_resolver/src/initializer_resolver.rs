@@ -0,0 +1,71 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rustyc_ast::initializers::{Designator, Initializer};
+
+use crate::{
+    expression_resolver::ExpressionResolver, function_table::FunctionTable,
+    resolutions::Resolutions, scope_stack::ScopeStack,
+};
+
+pub struct InitializerResolver {
+    initializer: Rc<Initializer>,
+    scope_stack: Rc<RefCell<ScopeStack>>,
+    function_table: Rc<RefCell<FunctionTable>>,
+    resolutions: Rc<RefCell<Resolutions>>,
+}
+
+impl InitializerResolver {
+    pub fn new(
+        initializer: Rc<Initializer>,
+        scope_stack: Rc<RefCell<ScopeStack>>,
+        function_table: Rc<RefCell<FunctionTable>>,
+        resolutions: Rc<RefCell<Resolutions>>,
+    ) -> Self {
+        Self {
+            initializer,
+            scope_stack,
+            function_table,
+            resolutions,
+        }
+    }
+
+    pub fn resolve(self) -> rustyc_diagnostics::Result<()> {
+        let elements = match self.initializer.as_ref() {
+            Initializer::Expression(expression) => {
+                return self.resolve_expression(Rc::clone(expression))
+            }
+            Initializer::List(elements) => elements,
+        };
+
+        for element in elements.iter() {
+            for designator in element.get_designators().iter() {
+                if let Designator::Index(index) = designator {
+                    self.resolve_expression(Rc::clone(index))?;
+                }
+            }
+
+            let initializer_resolver = Self::new(
+                element.get_initializer(),
+                Rc::clone(&self.scope_stack),
+                Rc::clone(&self.function_table),
+                Rc::clone(&self.resolutions),
+            );
+            initializer_resolver.resolve()?;
+        }
+
+        Ok(())
+    }
+
+    fn resolve_expression(
+        &self,
+        expression: Rc<rustyc_ast::expressions::Expression>,
+    ) -> rustyc_diagnostics::Result<()> {
+        let expression_resolver = ExpressionResolver::new(
+            expression,
+            Rc::clone(&self.scope_stack),
+            Rc::clone(&self.function_table),
+            Rc::clone(&self.resolutions),
+        );
+        expression_resolver.resolve()
+    }
+}
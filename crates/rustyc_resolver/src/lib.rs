@@ -0,0 +1,13 @@
+mod block_resolver;
+mod expression_resolver;
+mod function_resolver;
+mod function_table;
+mod global_table;
+mod initializer_resolver;
+mod resolutions;
+mod resolver;
+mod scope_stack;
+mod statement_resolver;
+
+pub use resolutions::Resolutions;
+pub use resolver::Resolver;
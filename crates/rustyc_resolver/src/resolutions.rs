@@ -0,0 +1,34 @@
+use std::collections::{HashMap, HashSet};
+
+use rustyc_ast::NodeId;
+use rustyc_hir::{FunctionId, Res};
+
+/// Maps every name-bearing AST node to the binding its name refers to.
+#[derive(Default)]
+pub struct Resolutions {
+    bindings: HashMap<NodeId, Res>,
+    implicit_functions: HashSet<FunctionId>,
+}
+
+impl Resolutions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, id: NodeId, res: Res) {
+        self.bindings.insert(id, res);
+    }
+
+    pub fn get(&self, id: NodeId) -> Option<Res> {
+        self.bindings.get(&id).copied()
+    }
+
+    /// Records a call to an undeclared function, which implicitly returns `int`.
+    pub fn insert_implicit_function(&mut self, id: FunctionId) {
+        self.implicit_functions.insert(id);
+    }
+
+    pub fn is_implicit_function(&self, id: FunctionId) -> bool {
+        self.implicit_functions.contains(&id)
+    }
+}
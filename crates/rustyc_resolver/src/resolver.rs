@@ -0,0 +1,130 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rustyc_ast::{
+    expressions::Expression,
+    items::{FunctionItem, GlobalItem, Item, ItemKind},
+};
+use rustyc_hir::Res;
+
+use crate::{
+    expression_resolver::ExpressionResolver, function_resolver::FunctionResolver,
+    function_table::FunctionTable, global_table::GlobalTable,
+    initializer_resolver::InitializerResolver, resolutions::Resolutions, scope_stack::ScopeStack,
+};
+
+pub struct Resolver {
+    ast: Vec<Rc<Item>>,
+    function_table: Rc<RefCell<FunctionTable>>,
+    global_table: Rc<RefCell<GlobalTable>>,
+    resolutions: Rc<RefCell<Resolutions>>,
+}
+
+impl Resolver {
+    pub fn new(ast: Vec<Rc<Item>>) -> Self {
+        Self {
+            ast,
+            function_table: Rc::new(RefCell::new(FunctionTable::new())),
+            global_table: Rc::new(RefCell::new(GlobalTable::new())),
+            resolutions: Rc::new(RefCell::new(Resolutions::new())),
+        }
+    }
+
+    pub fn resolve(self) -> rustyc_diagnostics::Result<Rc<Resolutions>> {
+        for item in self.ast.iter() {
+            self.resolve_item(item)?;
+        }
+
+        Ok(Rc::new(self.resolutions.take()))
+    }
+
+    fn resolve_item(&self, item: &Item) -> rustyc_diagnostics::Result<()> {
+        match item.get_kind() {
+            ItemKind::Function(function) => {
+                self.declare_function(function, item)?;
+
+                let function_resolver = FunctionResolver::new(
+                    Rc::clone(function),
+                    Rc::clone(&self.function_table),
+                    Rc::clone(&self.global_table),
+                    Rc::clone(&self.resolutions),
+                );
+                function_resolver.resolve()
+            }
+            ItemKind::Global(global) => self.resolve_global(global, item),
+            ItemKind::StaticAssertion(static_assertion) => {
+                self.resolve_file_scope_expression(static_assertion.get_condition())
+            }
+            ItemKind::TagDeclaration(_) => Ok(()),
+        }
+    }
+
+    // A function is visible from its own body, so that it can call itself.
+    fn declare_function(
+        &self,
+        function: &FunctionItem,
+        item: &Item,
+    ) -> rustyc_diagnostics::Result<()> {
+        let mut function_table = self.function_table.borrow_mut();
+        let name = function.get_name();
+        let storage_class = function.get_storage_class();
+        let span = item.get_span().clone();
+
+        let function_id = if function.get_body().is_some() {
+            function_table.define(name, storage_class, span)?
+        } else {
+            function_table.declare(name, storage_class, span)?
+        };
+
+        self.resolutions
+            .borrow_mut()
+            .insert(function.get_id(), Res::Function(function_id));
+
+        Ok(())
+    }
+
+    fn resolve_global(&self, global: &GlobalItem, item: &Item) -> rustyc_diagnostics::Result<()> {
+        let initializer = global.get_initializer();
+        let name = global.get_name();
+        let storage_class = global.get_storage_class();
+        let span = item.get_span().clone();
+
+        let global_id = if initializer.is_some() {
+            self.global_table
+                .borrow_mut()
+                .define(name, storage_class, span)?
+        } else {
+            self.global_table
+                .borrow_mut()
+                .declare(name, storage_class, span)?
+        };
+
+        self.resolutions
+            .borrow_mut()
+            .insert(global.get_id(), Res::Global(global_id));
+
+        let Some(initializer) = initializer else {
+            return Ok(());
+        };
+
+        let initializer_resolver = InitializerResolver::new(
+            initializer,
+            Rc::new(RefCell::new(ScopeStack::new(Rc::clone(&self.global_table)))),
+            Rc::clone(&self.function_table),
+            Rc::clone(&self.resolutions),
+        );
+        initializer_resolver.resolve()
+    }
+
+    fn resolve_file_scope_expression(
+        &self,
+        expression: Rc<Expression>,
+    ) -> rustyc_diagnostics::Result<()> {
+        let expression_resolver = ExpressionResolver::new(
+            expression,
+            Rc::new(RefCell::new(ScopeStack::new(Rc::clone(&self.global_table)))),
+            Rc::clone(&self.function_table),
+            Rc::clone(&self.resolutions),
+        );
+        expression_resolver.resolve()
+    }
+}
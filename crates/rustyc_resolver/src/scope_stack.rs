@@ -0,0 +1,119 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use rustyc_ast::StorageClass;
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{LocalId, Res};
+use rustyc_span::Span;
+
+use crate::global_table::GlobalTable;
+
+/// Tracks the lexical scopes of the function being resolved.
+pub struct ScopeStack {
+    scopes: Vec<HashMap<String, Res>>,
+    local_count: u64,
+    global_table: Rc<RefCell<GlobalTable>>,
+}
+
+impl ScopeStack {
+    pub fn new(global_table: Rc<RefCell<GlobalTable>>) -> Self {
+        Self {
+            scopes: vec![HashMap::new()],
+            local_count: 0,
+            global_table,
+        }
+    }
+
+    pub fn enter_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    pub fn exit_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    pub fn declare_parameter(&mut self, name: &str, span: Span) -> rustyc_diagnostics::Result<Res> {
+        self.declare(name, Res::Parameter, span)
+    }
+
+    pub fn declare_local(&mut self, name: &str, span: Span) -> rustyc_diagnostics::Result<Res> {
+        self.declare(name, Res::Local, span)
+    }
+
+    pub fn declare_anonymous_parameter(&mut self) -> Res {
+        let res = Res::Parameter(LocalId::new(self.local_count));
+        self.local_count += 1;
+        res
+    }
+
+    /// Declares a local that has no name, such as the object of a compound literal.
+    pub fn declare_anonymous_local(&mut self) -> Res {
+        let res = Res::Local(LocalId::new(self.local_count));
+        self.local_count += 1;
+        res
+    }
+
+    /// Declares a variable that has no name, such as a string literal.
+    pub fn declare_anonymous_global(&mut self) -> Res {
+        Res::Global(self.global_table.borrow_mut().allocate())
+    }
+
+    /// Declares a `static` local, which gets a variable of its own.
+    pub fn declare_static_local(
+        &mut self,
+        name: &str,
+        span: Span,
+    ) -> rustyc_diagnostics::Result<Res> {
+        let res = Res::Global(self.global_table.borrow_mut().allocate());
+        self.insert(name, res, span)
+    }
+
+    /// Declares an `extern` local, which refers to the file scope variable.
+    pub fn declare_extern_local(
+        &mut self,
+        name: &str,
+        span: Span,
+    ) -> rustyc_diagnostics::Result<Res> {
+        let id = self.global_table.borrow_mut().declare(
+            name,
+            Some(StorageClass::Extern),
+            span.clone(),
+        )?;
+        let res = Res::Global(id);
+        self.insert(name, res, span)
+    }
+
+    pub fn resolve(&self, name: &str) -> Option<Res> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name).copied())
+            .or_else(|| self.global_table.borrow().get(name).map(Res::Global))
+    }
+
+    fn declare(
+        &mut self,
+        name: &str,
+        new_res: fn(LocalId) -> Res,
+        span: Span,
+    ) -> rustyc_diagnostics::Result<Res> {
+        let res = self.insert(name, new_res(LocalId::new(self.local_count)), span)?;
+        self.local_count += 1;
+
+        Ok(res)
+    }
+
+    fn insert(&mut self, name: &str, res: Res, span: Span) -> rustyc_diagnostics::Result<Res> {
+        let scope = self.scopes.last_mut().unwrap();
+
+        if scope.contains_key(name) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::VariableRedefinition(name.to_owned()),
+                span,
+            ));
+        }
+
+        scope.insert(name.to_owned(), res);
+
+        Ok(res)
+    }
+}
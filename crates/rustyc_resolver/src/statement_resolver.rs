@@ -0,0 +1,194 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rustyc_ast::{
+    expressions::Expression,
+    statements::{Declarator, LoopStatement, Statement, StatementKind},
+    types::TyKind,
+    Block, StorageClass,
+};
+
+use rustyc_diagnostics::Diagnostic;
+
+use crate::{
+    block_resolver::BlockResolver, expression_resolver::ExpressionResolver,
+    function_table::FunctionTable, initializer_resolver::InitializerResolver,
+    resolutions::Resolutions, scope_stack::ScopeStack,
+};
+
+pub struct StatementResolver {
+    statement: Rc<Statement>,
+    scope_stack: Rc<RefCell<ScopeStack>>,
+    function_table: Rc<RefCell<FunctionTable>>,
+    resolutions: Rc<RefCell<Resolutions>>,
+}
+
+impl StatementResolver {
+    pub fn new(
+        statement: Rc<Statement>,
+        scope_stack: Rc<RefCell<ScopeStack>>,
+        function_table: Rc<RefCell<FunctionTable>>,
+        resolutions: Rc<RefCell<Resolutions>>,
+    ) -> Self {
+        Self {
+            statement,
+            scope_stack,
+            function_table,
+            resolutions,
+        }
+    }
+
+    pub fn resolve(self) -> rustyc_diagnostics::Result<()> {
+        match self.statement.get_kind() {
+            StatementKind::Return(statement) => statement
+                .get_expression()
+                .map_or(Ok(()), |expression| self.resolve_expression(expression)),
+            StatementKind::If(statement) => {
+                self.resolve_expression(statement.get_condition_expression())?;
+                self.resolve_statement(statement.get_then_statement())?;
+
+                if let Some(else_statement) = statement.get_else_statement() {
+                    self.resolve_statement(else_statement)?;
+                }
+
+                Ok(())
+            }
+            StatementKind::Loop(statement) => self.resolve_loop(statement),
+            StatementKind::DoWhile(statement) => {
+                self.resolve_statement(statement.get_then_statement())?;
+                self.resolve_expression(statement.get_condition_expression())
+            }
+            StatementKind::Switch(statement) => {
+                self.resolve_expression(statement.get_condition_expression())?;
+                self.resolve_statement(statement.get_then_statement())
+            }
+            StatementKind::Case(statement) => {
+                self.resolve_expression(statement.get_expression())?;
+                self.resolve_statement(statement.get_statement())
+            }
+            StatementKind::Default(statement) => self.resolve_statement(statement.get_statement()),
+            StatementKind::Labeled(statement) => self.resolve_statement(statement.get_statement()),
+            StatementKind::Goto(_)
+            | StatementKind::Break
+            | StatementKind::Continue
+            | StatementKind::TagDeclaration(_) => Ok(()),
+            StatementKind::Compound(statement) => self.resolve_block(statement.get_block()),
+            StatementKind::Declaration(statement) => {
+                for declarator in statement.get_declarators().iter() {
+                    self.resolve_declarator(declarator)?;
+                }
+
+                Ok(())
+            }
+            StatementKind::Expression(statement) => {
+                self.resolve_expression(statement.get_expression())
+            }
+            StatementKind::StaticAssertion(static_assertion) => {
+                self.resolve_expression(static_assertion.get_condition())
+            }
+        }
+    }
+
+    fn resolve_loop(&self, statement: &LoopStatement) -> rustyc_diagnostics::Result<()> {
+        // A declaration in the initialization statement is scoped to the loop.
+        self.scope_stack.borrow_mut().enter_scope();
+
+        if let Some(initialization_statement) = statement.get_initialization_statement() {
+            self.resolve_statement(initialization_statement)?;
+        }
+
+        if let Some(condition_expression) = statement.get_condition_expression() {
+            self.resolve_expression(condition_expression)?;
+        }
+
+        if let Some(incrementation_expression) = statement.get_incrementation_expression() {
+            self.resolve_expression(incrementation_expression)?;
+        }
+
+        self.resolve_statement(statement.get_then_statement())?;
+
+        self.scope_stack.borrow_mut().exit_scope();
+
+        Ok(())
+    }
+
+    fn resolve_declarator(&self, declarator: &Declarator) -> rustyc_diagnostics::Result<()> {
+        // The scope of a declaration begins right after its declarator, so the
+        // binding is already visible inside of its own initializer.
+        let name = declarator.get_name();
+        let span = declarator.get_span().clone();
+
+        // The length of a variable length array is part of the declarator, so
+        // it can't refer to the variable being declared. Only the outermost
+        // length may be variable.
+        if let TyKind::Array(array_ty) = declarator.get_ty().get_kind() {
+            if let Some(length) = array_ty.get_length() {
+                self.resolve_expression(length)?;
+            }
+        }
+
+        if declarator.get_storage_class() == Some(StorageClass::Extern)
+            && declarator.get_initializer().is_some()
+        {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ExternLocalInitializer,
+                span,
+            ));
+        }
+
+        let res = {
+            let mut scope_stack = self.scope_stack.borrow_mut();
+
+            match declarator.get_storage_class() {
+                Some(StorageClass::Static) => scope_stack.declare_static_local(name, span)?,
+                Some(StorageClass::Extern) => scope_stack.declare_extern_local(name, span)?,
+                None => scope_stack.declare_local(name, span)?,
+            }
+        };
+
+        self.resolutions
+            .borrow_mut()
+            .insert(declarator.get_id(), res);
+
+        if let Some(initializer) = declarator.get_initializer() {
+            let initializer_resolver = InitializerResolver::new(
+                initializer,
+                Rc::clone(&self.scope_stack),
+                Rc::clone(&self.function_table),
+                Rc::clone(&self.resolutions),
+            );
+            initializer_resolver.resolve()?;
+        }
+
+        Ok(())
+    }
+
+    fn resolve_statement(&self, statement: Rc<Statement>) -> rustyc_diagnostics::Result<()> {
+        let statement_resolver = Self::new(
+            statement,
+            Rc::clone(&self.scope_stack),
+            Rc::clone(&self.function_table),
+            Rc::clone(&self.resolutions),
+        );
+        statement_resolver.resolve()
+    }
+
+    fn resolve_expression(&self, expression: Rc<Expression>) -> rustyc_diagnostics::Result<()> {
+        let expression_resolver = ExpressionResolver::new(
+            expression,
+            Rc::clone(&self.scope_stack),
+            Rc::clone(&self.function_table),
+            Rc::clone(&self.resolutions),
+        );
+        expression_resolver.resolve()
+    }
+
+    fn resolve_block(&self, block: Rc<Block>) -> rustyc_diagnostics::Result<()> {
+        let block_resolver = BlockResolver::new(
+            block,
+            Rc::clone(&self.scope_stack),
+            Rc::clone(&self.function_table),
+            Rc::clone(&self.resolutions),
+        );
+        block_resolver.resolve()
+    }
+}
@@ -1,3 +1,7 @@
+mod source_file;
+mod source_map;
 mod span;
 
+pub use source_file::SourceFile;
+pub use source_map::SourceMap;
 pub use span::Span;
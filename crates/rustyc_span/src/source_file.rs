@@ -0,0 +1,74 @@
+#[derive(Debug)]
+pub struct SourceFile {
+    name: String,
+    source: String,
+    start_position: usize,
+    line_starts: Vec<usize>,
+}
+
+impl SourceFile {
+    pub fn new(name: String, source: String, start_position: usize) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+
+        Self {
+            name,
+            source,
+            start_position,
+            line_starts,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn get_start_position(&self) -> usize {
+        self.start_position
+    }
+
+    pub fn get_end_position(&self) -> usize {
+        self.start_position + self.source.len()
+    }
+
+    pub fn contains(&self, position: usize) -> bool {
+        (self.start_position..=self.get_end_position()).contains(&position)
+    }
+
+    pub fn get_line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    pub fn get_line_index(&self, position: usize) -> usize {
+        let offset = position - self.start_position;
+
+        match self.line_starts.binary_search(&offset) {
+            Ok(index) => index,
+            Err(index) => index - 1,
+        }
+    }
+
+    pub fn get_line_bounds(&self, index: usize) -> (usize, usize) {
+        let start = self.line_starts[index];
+        let end = self
+            .line_starts
+            .get(index + 1)
+            .map_or(self.source.len(), |next_start| next_start - 1);
+
+        (self.start_position + start, self.start_position + end)
+    }
+
+    pub fn get_line(&self, index: usize) -> &str {
+        let (start, end) = self.get_line_bounds(index);
+        self.get_text(start, end)
+    }
+
+    pub fn get_text(&self, start: usize, end: usize) -> &str {
+        &self.source[(start - self.start_position)..(end - self.start_position)]
+    }
+}
@@ -0,0 +1,75 @@
+use std::rc::Rc;
+
+use crate::SourceFile;
+
+#[derive(Debug)]
+struct LineDirective {
+    position: usize,
+    line_number: usize,
+    file_name: String,
+}
+
+#[derive(Debug, Default)]
+pub struct SourceMap {
+    files: Vec<Rc<SourceFile>>,
+    line_directives: Vec<LineDirective>,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_file(&mut self, name: String, source: String) -> Rc<SourceFile> {
+        // Files are one position apart, so that the end of a file isn't the
+        // start of the next one.
+        let start_position = self
+            .files
+            .last()
+            .map_or(0, |file| file.get_end_position() + 1);
+
+        let file = Rc::new(SourceFile::new(name, source, start_position));
+        self.files.push(Rc::clone(&file));
+
+        file
+    }
+
+    pub fn lookup_file(&self, position: usize) -> Option<Rc<SourceFile>> {
+        self.files
+            .iter()
+            .find(|file| file.contains(position))
+            .cloned()
+    }
+
+    pub fn add_line_directive(&mut self, position: usize, line_number: usize, file_name: String) {
+        self.line_directives.push(LineDirective {
+            position,
+            line_number,
+            file_name,
+        });
+    }
+
+    pub fn lookup_presumed_line(&self, position: usize) -> Option<(String, usize)> {
+        let file = self.lookup_file(position)?;
+        let line_index = file.get_line_index(position);
+
+        let line_directive = self
+            .line_directives
+            .iter()
+            .filter(|line_directive| {
+                file.contains(line_directive.position) && line_directive.position <= position
+            })
+            .max_by_key(|line_directive| line_directive.position);
+
+        let presumed_line = match line_directive {
+            Some(line_directive) => (
+                line_directive.file_name.clone(),
+                line_directive.line_number + line_index
+                    - file.get_line_index(line_directive.position),
+            ),
+            None => (file.get_name().to_owned(), line_index + 1),
+        };
+
+        Some(presumed_line)
+    }
+}
@@ -0,0 +1,3 @@
+mod target;
+
+pub use target::{Target, UnknownTargetError};
@@ -0,0 +1,72 @@
+use std::{fmt, str::FromStr};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Target {
+    #[default]
+    Aarch64AppleDarwin,
+    Aarch64UnknownLinuxGnu,
+}
+
+impl Target {
+    pub const ALL: [Self; 2] = [Self::Aarch64AppleDarwin, Self::Aarch64UnknownLinuxGnu];
+
+    pub fn get_triple(&self) -> &'static str {
+        match self {
+            Self::Aarch64AppleDarwin => "aarch64-apple-darwin",
+            Self::Aarch64UnknownLinuxGnu => "aarch64-unknown-linux-gnu",
+        }
+    }
+
+    /// Returns the size of `va_list`, which is a plain pointer only on Apple platforms.
+    pub fn get_va_list_size(&self) -> u64 {
+        match self {
+            Self::Aarch64AppleDarwin => 8,
+            Self::Aarch64UnknownLinuxGnu => 32,
+        }
+    }
+
+    pub fn get_predefined_macros(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::Aarch64AppleDarwin => &[
+                ("__aarch64__", "1"),
+                ("__arm64__", "1"),
+                ("__LP64__", "1"),
+                ("_LP64", "1"),
+                ("__APPLE__", "1"),
+                ("__MACH__", "1"),
+            ],
+            Self::Aarch64UnknownLinuxGnu => &[
+                ("__aarch64__", "1"),
+                ("__LP64__", "1"),
+                ("_LP64", "1"),
+                ("__linux__", "1"),
+                ("__linux", "1"),
+                ("__gnu_linux__", "1"),
+                ("__unix__", "1"),
+                ("__unix", "1"),
+                ("__ELF__", "1"),
+            ],
+        }
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_triple())
+    }
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+#[error("unknown target `{0}`")]
+pub struct UnknownTargetError(String);
+
+impl FromStr for Target {
+    type Err = UnknownTargetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|target| target.get_triple() == s)
+            .ok_or_else(|| UnknownTargetError(s.to_owned()))
+    }
+}
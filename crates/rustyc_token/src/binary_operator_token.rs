@@ -4,5 +4,10 @@ pub enum BinaryOperatorToken {
     Minus,
     Star,
     Slash,
+    Percent,
+    Caret,
     And,
+    Or,
+    Shl,
+    Shr,
 }
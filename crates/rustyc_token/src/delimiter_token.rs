@@ -2,4 +2,5 @@
 pub enum DelimiterToken {
     Parenthesis,
     Brace,
+    Bracket,
 }
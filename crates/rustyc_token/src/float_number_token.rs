@@ -0,0 +1,38 @@
+use std::fmt;
+
+/// A floating literal, kept as its bit pattern so that tokens can be hashed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FloatNumberToken {
+    bits: u64,
+    is_single_precision: bool,
+}
+
+impl FloatNumberToken {
+    pub fn new(value: f64, is_single_precision: bool) -> Self {
+        Self {
+            bits: value.to_bits(),
+            is_single_precision,
+        }
+    }
+
+    pub fn get_value(&self) -> f64 {
+        f64::from_bits(self.bits)
+    }
+
+    /// Returns whether the literal has an `f` suffix.
+    pub fn is_single_precision(&self) -> bool {
+        self.is_single_precision
+    }
+}
+
+impl fmt::Display for FloatNumberToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.get_value())?;
+
+        if self.is_single_precision {
+            write!(f, "f")?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,10 +1,86 @@
-use enum_display::EnumDisplay;
+use std::fmt;
 
-#[derive(Clone, Debug, EnumDisplay, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Keyword {
     Return,
     If,
     Else,
     For,
     While,
+    Do,
+    Break,
+    Continue,
+    Switch,
+    Case,
+    Default,
+    Goto,
+    Static,
+    Extern,
+    Const,
+    Volatile,
+    Restrict,
+    Void,
+    Bool,
+    Char,
+    Int,
+    Float,
+    Double,
+    Signed,
+    Unsigned,
+    Struct,
+    Sizeof,
+    StaticAssert,
+    Generic,
+    BuiltinVaList,
+    BuiltinVaStart,
+    BuiltinVaArg,
+    BuiltinVaEnd,
+    BuiltinAlloca,
+}
+
+impl Keyword {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Return => "return",
+            Self::If => "if",
+            Self::Else => "else",
+            Self::For => "for",
+            Self::While => "while",
+            Self::Do => "do",
+            Self::Break => "break",
+            Self::Continue => "continue",
+            Self::Switch => "switch",
+            Self::Case => "case",
+            Self::Default => "default",
+            Self::Goto => "goto",
+            Self::Static => "static",
+            Self::Extern => "extern",
+            Self::Const => "const",
+            Self::Volatile => "volatile",
+            Self::Restrict => "restrict",
+            Self::Void => "void",
+            Self::Bool => "_Bool",
+            Self::Char => "char",
+            Self::Int => "int",
+            Self::Float => "float",
+            Self::Double => "double",
+            Self::Signed => "signed",
+            Self::Unsigned => "unsigned",
+            Self::Struct => "struct",
+            Self::Sizeof => "sizeof",
+            Self::StaticAssert => "_Static_assert",
+            Self::Generic => "_Generic",
+            Self::BuiltinVaList => "__builtin_va_list",
+            Self::BuiltinVaStart => "__builtin_va_start",
+            Self::BuiltinVaArg => "__builtin_va_arg",
+            Self::BuiltinVaEnd => "__builtin_va_end",
+            Self::BuiltinAlloca => "__builtin_alloca",
+        }
+    }
+}
+
+impl fmt::Display for Keyword {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
@@ -1,5 +1,6 @@
 mod binary_operator_token;
 mod delimiter_token;
+mod float_number_token;
 mod keyword;
 mod token;
 mod token_category;
@@ -8,6 +9,7 @@ mod token_kind;
 
 pub use binary_operator_token::BinaryOperatorToken;
 pub use delimiter_token::DelimiterToken;
+pub use float_number_token::FloatNumberToken;
 pub use keyword::Keyword;
 pub use token::Token;
 pub use token_category::TokenCategory;
@@ -1,6 +1,6 @@
 use rustyc_span::Span;
 
-use crate::{Keyword, TokenKind};
+use crate::{BinaryOperatorToken, Keyword, TokenKind};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Token {
@@ -33,10 +33,36 @@ impl Token {
             },
             TokenKind::LessThan => match joint.kind {
                 TokenKind::Equal => TokenKind::LessEqual,
+                TokenKind::LessThan => TokenKind::BinaryOperator(BinaryOperatorToken::Shl),
                 _ => return None,
             },
             TokenKind::GreaterThan => match joint.kind {
                 TokenKind::Equal => TokenKind::GreaterEqual,
+                TokenKind::GreaterThan => TokenKind::BinaryOperator(BinaryOperatorToken::Shr),
+                _ => return None,
+            },
+            TokenKind::Dot => match joint.kind {
+                TokenKind::Dot => TokenKind::DotDot,
+                _ => return None,
+            },
+            TokenKind::DotDot => match joint.kind {
+                TokenKind::Dot => TokenKind::DotDotDot,
+                _ => return None,
+            },
+            TokenKind::BinaryOperator(BinaryOperatorToken::Minus) => match joint.kind {
+                TokenKind::GreaterThan => TokenKind::Arrow,
+                _ => return None,
+            },
+            TokenKind::Hash => match joint.kind {
+                TokenKind::Hash => TokenKind::HashHash,
+                _ => return None,
+            },
+            TokenKind::BinaryOperator(BinaryOperatorToken::And) => match joint.kind {
+                TokenKind::BinaryOperator(BinaryOperatorToken::And) => TokenKind::AndAnd,
+                _ => return None,
+            },
+            TokenKind::BinaryOperator(BinaryOperatorToken::Or) => match joint.kind {
+                TokenKind::BinaryOperator(BinaryOperatorToken::Or) => TokenKind::OrOr,
                 _ => return None,
             },
             TokenKind::Not => match joint.kind {
@@ -51,7 +77,7 @@ impl Token {
 
     pub fn is_keyword(&self, keyword: &Keyword) -> bool {
         self.get_identifier()
-            .is_some_and(|identifier| keyword.to_string().to_lowercase() == identifier)
+            .is_some_and(|identifier| keyword.as_str() == identifier)
     }
 
     pub fn get_identifier(&self) -> Option<&str> {
@@ -7,16 +7,16 @@ pub enum TokenCategory {
     Token(TokenKind),
     Keyword(Keyword),
     Identifier,
+    String,
 }
 
 impl fmt::Display for TokenCategory {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             TokenCategory::Token(kind) => write!(f, "`{kind}`"),
-            TokenCategory::Keyword(keyword) => {
-                write!(f, "`{}`", keyword.to_string().to_lowercase())
-            }
+            TokenCategory::Keyword(keyword) => write!(f, "`{keyword}`"),
             TokenCategory::Identifier => write!(f, "identifier"),
+            TokenCategory::String => write!(f, "string literal"),
         }
     }
 }
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::{BinaryOperatorToken, DelimiterToken};
+use crate::{BinaryOperatorToken, DelimiterToken, FloatNumberToken};
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum TokenKind {
@@ -12,10 +12,23 @@ pub enum TokenKind {
     GreaterEqual,
     Not,
     NotEqual,
+    Tilde,
+    AndAnd,
+    OrOr,
+    Question,
     Semicolon,
     Comma,
+    Colon,
+    Dot,
+    DotDot,
+    DotDotDot,
+    Arrow,
+    Hash,
+    HashHash,
     Number(u64),
+    FloatNumber(FloatNumberToken),
     Identifier(String),
+    String(String),
     BinaryOperator(BinaryOperatorToken),
     OpenDelimiter(DelimiterToken),
     CloseDelimiter(DelimiterToken),
@@ -33,19 +46,39 @@ impl fmt::Display for TokenKind {
             Self::GreaterEqual => write!(f, ">="),
             Self::Not => write!(f, "!"),
             Self::NotEqual => write!(f, "!="),
+            Self::Tilde => write!(f, "~"),
+            Self::AndAnd => write!(f, "&&"),
+            Self::OrOr => write!(f, "||"),
+            Self::Question => write!(f, "?"),
             Self::Semicolon => write!(f, ";"),
             Self::Comma => write!(f, ","),
+            Self::Colon => write!(f, ":"),
+            Self::Dot => write!(f, "."),
+            Self::DotDot => write!(f, ".."),
+            Self::DotDotDot => write!(f, "..."),
+            Self::Arrow => write!(f, "->"),
+            Self::Hash => write!(f, "#"),
+            Self::HashHash => write!(f, "##"),
             Self::Number(value) => write!(f, "{}", value),
+            Self::FloatNumber(value) => write!(f, "{}", value),
             Self::Identifier(name) => write!(f, "{}", name),
+            Self::String(value) => write!(f, "{:?}", value),
             Self::BinaryOperator(BinaryOperatorToken::Plus) => write!(f, "+"),
             Self::BinaryOperator(BinaryOperatorToken::Minus) => write!(f, "-"),
             Self::BinaryOperator(BinaryOperatorToken::Star) => write!(f, "*"),
             Self::BinaryOperator(BinaryOperatorToken::Slash) => write!(f, "/"),
+            Self::BinaryOperator(BinaryOperatorToken::Percent) => write!(f, "%"),
+            Self::BinaryOperator(BinaryOperatorToken::Caret) => write!(f, "^"),
             Self::BinaryOperator(BinaryOperatorToken::And) => write!(f, "&"),
+            Self::BinaryOperator(BinaryOperatorToken::Or) => write!(f, "|"),
+            Self::BinaryOperator(BinaryOperatorToken::Shl) => write!(f, "<<"),
+            Self::BinaryOperator(BinaryOperatorToken::Shr) => write!(f, ">>"),
             Self::OpenDelimiter(DelimiterToken::Parenthesis) => write!(f, "("),
             Self::CloseDelimiter(DelimiterToken::Parenthesis) => write!(f, ")"),
             Self::OpenDelimiter(DelimiterToken::Brace) => write!(f, "{{"),
             Self::CloseDelimiter(DelimiterToken::Brace) => write!(f, "}}"),
+            Self::OpenDelimiter(DelimiterToken::Bracket) => write!(f, "["),
+            Self::CloseDelimiter(DelimiterToken::Bracket) => write!(f, "]"),
             Self::Eof => write!(f, "<eof>"),
         }
     }
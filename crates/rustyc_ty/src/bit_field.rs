@@ -0,0 +1,20 @@
+/// The position of a bit-field within the storage unit that holds it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BitField {
+    offset: u32,
+    width: u32,
+}
+
+impl BitField {
+    pub fn new(offset: u32, width: u32) -> Self {
+        Self { offset, width }
+    }
+
+    pub fn get_offset(&self) -> u32 {
+        self.offset
+    }
+
+    pub fn get_width(&self) -> u32 {
+        self.width
+    }
+}
@@ -0,0 +1,31 @@
+use crate::TyId;
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct FunctionTy {
+    return_ty: TyId,
+    parameters: Option<Vec<TyId>>,
+    is_variadic: bool,
+}
+
+impl FunctionTy {
+    pub fn new(return_ty: TyId, parameters: Option<Vec<TyId>>, is_variadic: bool) -> Self {
+        Self {
+            return_ty,
+            parameters,
+            is_variadic,
+        }
+    }
+
+    pub fn get_return_ty(&self) -> TyId {
+        self.return_ty
+    }
+
+    /// Returns `None` for functions declared without a prototype.
+    pub fn get_parameters(&self) -> Option<&[TyId]> {
+        self.parameters.as_deref()
+    }
+
+    pub fn is_variadic(&self) -> bool {
+        self.is_variadic
+    }
+}
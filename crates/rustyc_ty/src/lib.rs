@@ -1,8 +1,18 @@
+mod bit_field;
+mod function_ty;
+mod qualifiers;
+mod struct_id;
+mod struct_layout;
 mod ty;
 mod ty_context;
 mod ty_id;
 mod ty_matcher;
 
+pub use bit_field::BitField;
+pub use function_ty::FunctionTy;
+pub use qualifiers::Qualifiers;
+pub use struct_id::StructId;
+pub use struct_layout::{StructLayout, StructMember, StructMemberDeclaration};
 pub use ty::Ty;
 pub use ty_context::TyContext;
 pub use ty_id::TyId;
@@ -0,0 +1,44 @@
+/// The qualifiers of a type.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Qualifiers {
+    is_const: bool,
+    is_volatile: bool,
+    is_restrict: bool,
+}
+
+impl Qualifiers {
+    pub fn new(is_const: bool, is_volatile: bool, is_restrict: bool) -> Self {
+        Self {
+            is_const,
+            is_volatile,
+            is_restrict,
+        }
+    }
+
+    pub fn is_const(&self) -> bool {
+        self.is_const
+    }
+
+    pub fn is_volatile(&self) -> bool {
+        self.is_volatile
+    }
+
+    pub fn is_restrict(&self) -> bool {
+        self.is_restrict
+    }
+
+    pub fn union(&self, other: Qualifiers) -> Qualifiers {
+        Self::new(
+            self.is_const || other.is_const,
+            self.is_volatile || other.is_volatile,
+            self.is_restrict || other.is_restrict,
+        )
+    }
+
+    /// Checks whether every qualifier of `other` is also in `self`.
+    pub fn contains(&self, other: Qualifiers) -> bool {
+        (self.is_const || !other.is_const)
+            && (self.is_volatile || !other.is_volatile)
+            && (self.is_restrict || !other.is_restrict)
+    }
+}
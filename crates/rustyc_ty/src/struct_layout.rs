@@ -0,0 +1,143 @@
+use crate::{BitField, TyId};
+
+pub struct StructLayout {
+    members: Vec<StructMember>,
+    size: u64,
+    alignment: u64,
+}
+
+impl StructLayout {
+    /// Lays out the members of a structure as the AAPCS64 does.
+    pub fn new(declarations: Vec<StructMemberDeclaration>) -> Self {
+        let mut members: Vec<StructMember> = Vec::new();
+        let mut bit_offset: u64 = 0;
+        let mut alignment: u64 = 1;
+
+        for declaration in declarations {
+            let unit_bits = declaration.size * 8;
+
+            let Some(width) = declaration.width else {
+                bit_offset = bit_offset.next_multiple_of(declaration.alignment * 8);
+
+                members.push(StructMember {
+                    name: declaration.name,
+                    ty: declaration.ty,
+                    offset: bit_offset / 8,
+                    bit_field: None,
+                });
+
+                bit_offset += unit_bits;
+                alignment = alignment.max(declaration.alignment);
+                continue;
+            };
+
+            let width = u64::from(width);
+
+            // A zero-width bit-field only moves the following bit-fields to the
+            // next storage unit.
+            if width == 0 {
+                bit_offset = bit_offset.next_multiple_of(declaration.alignment * 8);
+                continue;
+            }
+
+            if bit_offset / unit_bits != (bit_offset + width - 1) / unit_bits {
+                bit_offset = bit_offset.next_multiple_of(unit_bits);
+            }
+
+            // Unnamed bit-fields don't affect the alignment of the structure.
+            if let Some(name) = declaration.name {
+                let unit_offset = bit_offset / unit_bits * unit_bits;
+
+                members.push(StructMember {
+                    name: Some(name),
+                    ty: declaration.ty,
+                    offset: unit_offset / 8,
+                    bit_field: Some(BitField::new(
+                        (bit_offset - unit_offset) as u32,
+                        width as u32,
+                    )),
+                });
+
+                alignment = alignment.max(declaration.alignment);
+            }
+
+            bit_offset += width;
+        }
+
+        Self {
+            members,
+            size: bit_offset.div_ceil(8).next_multiple_of(alignment),
+            alignment,
+        }
+    }
+
+    pub fn get_members(&self) -> &[StructMember] {
+        &self.members
+    }
+
+    pub fn get_member(&self, name: &str) -> Option<&StructMember> {
+        self.members
+            .iter()
+            .find(|member| member.get_name() == Some(name))
+    }
+
+    pub fn get_size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn get_alignment(&self) -> u64 {
+        self.alignment
+    }
+}
+
+pub struct StructMemberDeclaration {
+    name: Option<String>,
+    ty: TyId,
+    size: u64,
+    alignment: u64,
+    width: Option<u32>,
+}
+
+impl StructMemberDeclaration {
+    pub fn new(
+        name: Option<String>,
+        ty: TyId,
+        size: u64,
+        alignment: u64,
+        width: Option<u32>,
+    ) -> Self {
+        Self {
+            name,
+            ty,
+            size,
+            alignment,
+            width,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StructMember {
+    name: Option<String>,
+    ty: TyId,
+    offset: u64,
+    bit_field: Option<BitField>,
+}
+
+impl StructMember {
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn get_ty(&self) -> TyId {
+        self.ty
+    }
+
+    pub fn get_offset(&self) -> u64 {
+        self.offset
+    }
+
+    pub fn get_bit_field(&self) -> Option<BitField> {
+        self.bit_field
+    }
+}
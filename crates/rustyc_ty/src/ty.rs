@@ -1,7 +1,35 @@
-use crate::TyId;
+use crate::{FunctionTy, StructId, TyId};
 
-#[derive(Eq, Hash, PartialEq)]
+#[derive(Clone, Eq, Hash, PartialEq)]
 pub enum Ty {
+    Void,
+    Bool,
+    Char,
+    UnsignedChar,
     Int,
+    UnsignedInt,
+    Float,
+    Double,
     Pointer(TyId),
+    Array(TyId, Option<u64>),
+    VariableArray(TyId),
+    Function(FunctionTy),
+    Struct(StructId),
+    VaList,
+}
+
+impl Ty {
+    pub fn get_size(&self) -> Option<u64> {
+        match self {
+            Self::Bool | Self::Char | Self::UnsignedChar => Some(1),
+            Self::Float => Some(4),
+            Self::Int | Self::UnsignedInt | Self::Double | Self::Pointer(_) => Some(8),
+            Self::Void
+            | Self::VaList
+            | Self::Array(..)
+            | Self::VariableArray(_)
+            | Self::Function(_)
+            | Self::Struct(_) => None,
+        }
+    }
 }
@@ -1,35 +1,113 @@
 use std::collections::HashMap;
 
-use crate::{ty::Ty, TyId};
+use rustyc_target::Target;
+
+use crate::{ty::Ty, FunctionTy, Qualifiers, StructId, StructLayout, TyId};
 
 pub struct TyContext {
-    types: HashMap<TyId, Ty>,
+    types: HashMap<TyId, (Ty, Qualifiers)>,
+    structs: HashMap<StructId, Option<StructLayout>>,
     next_id: TyId,
+    target: Target,
 }
 
 impl TyContext {
-    pub fn new() -> Self {
+    pub fn new(target: Target) -> Self {
         Self {
             types: HashMap::new(),
+            structs: HashMap::new(),
             next_id: TyId::new(0),
+            target,
         }
     }
 
+    /// Returns the type without its qualifiers.
     pub fn get(&self, id: TyId) -> &Ty {
-        self.types.get(&id).unwrap()
+        &self.types.get(&id).unwrap().0
+    }
+
+    pub fn get_qualifiers(&self, id: TyId) -> Qualifiers {
+        self.types.get(&id).unwrap().1
+    }
+
+    pub fn get_size(&self, id: TyId) -> Option<u64> {
+        match self.get(id) {
+            Ty::Array(element_ty, length) => Some(self.get_size(*element_ty)? * (*length)?),
+            Ty::Struct(struct_id) => self
+                .get_struct_layout(*struct_id)
+                .map(StructLayout::get_size),
+            Ty::VaList => Some(self.target.get_va_list_size()),
+            ty => ty.get_size(),
+        }
+    }
+
+    pub fn get_alignment(&self, id: TyId) -> Option<u64> {
+        match self.get(id) {
+            Ty::Array(element_ty, _) | Ty::VariableArray(element_ty) => {
+                self.get_alignment(*element_ty)
+            }
+            Ty::Struct(struct_id) => self
+                .get_struct_layout(*struct_id)
+                .map(StructLayout::get_alignment),
+            Ty::VaList => Some(8),
+            ty => ty.get_size(),
+        }
+    }
+
+    pub fn get_struct_layout(&self, id: StructId) -> Option<&StructLayout> {
+        self.structs.get(&id).and_then(Option::as_ref)
+    }
+
+    pub fn is_struct_declared(&self, id: StructId) -> bool {
+        self.structs.contains_key(&id)
+    }
+
+    pub fn declare_struct(&mut self, id: StructId) {
+        self.structs.entry(id).or_insert(None);
+    }
+
+    pub fn define_struct(&mut self, id: StructId, layout: StructLayout) {
+        self.structs.insert(id, Some(layout));
+    }
+
+    /// Returns the function type called through a function designator or pointer.
+    pub fn get_callee_function_ty(&self, id: TyId) -> Option<&FunctionTy> {
+        match self.get(id) {
+            Ty::Function(function_ty) => Some(function_ty),
+            Ty::Pointer(base) => match self.get(*base) {
+                Ty::Function(function_ty) => Some(function_ty),
+                _ => None,
+            },
+            _ => None,
+        }
     }
 
     pub fn register(&mut self, ty: Ty) -> TyId {
-        if let Some((id, _)) = self.types.iter().find(|(_, v)| **v == ty) {
+        self.register_qualified(ty, Qualifiers::default())
+    }
+
+    pub fn register_qualified(&mut self, ty: Ty, qualifiers: Qualifiers) -> TyId {
+        if let Some((id, _)) = self
+            .types
+            .iter()
+            .find(|(_, (v, q))| *v == ty && *q == qualifiers)
+        {
             *id
         } else {
             let id = self.next_id();
-            self.types.insert(id, ty);
+            self.types.insert(id, (ty, qualifiers));
 
             id
         }
     }
 
+    pub fn qualify(&mut self, id: TyId, qualifiers: Qualifiers) -> TyId {
+        let ty = self.get(id).clone();
+        let qualifiers = self.get_qualifiers(id).union(qualifiers);
+
+        self.register_qualified(ty, qualifiers)
+    }
+
     fn next_id(&mut self) -> TyId {
         let id = self.next_id;
         self.next_id = TyId::new(id.get() + 1);
@@ -37,3 +115,9 @@ impl TyContext {
         id
     }
 }
+
+impl Default for TyContext {
+    fn default() -> Self {
+        Self::new(Target::default())
+    }
+}
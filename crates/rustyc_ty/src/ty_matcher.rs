@@ -11,11 +11,181 @@ impl TyMatcher {
         Self { ty_context }
     }
 
+    pub fn is_void(&self, ty: TyId) -> bool {
+        matches!(self.ty_context.borrow().get(ty), Ty::Void)
+    }
+
+    pub fn is_void_pointer(&self, ty: TyId) -> bool {
+        match self.ty_context.borrow().get(ty) {
+            Ty::Pointer(base) => self.is_void(*base),
+            _ => false,
+        }
+    }
+
     pub fn is_int(&self, ty: TyId) -> bool {
         matches!(self.ty_context.borrow().get(ty), Ty::Int)
     }
 
+    pub fn is_bool(&self, ty: TyId) -> bool {
+        matches!(self.ty_context.borrow().get(ty), Ty::Bool)
+    }
+
+    pub fn is_integer(&self, ty: TyId) -> bool {
+        matches!(
+            self.ty_context.borrow().get(ty),
+            Ty::Int | Ty::UnsignedInt | Ty::Bool | Ty::Char | Ty::UnsignedChar
+        )
+    }
+
+    /// Returns the width in bits of the values of an integer type.
+    pub fn get_integer_width(&self, ty: TyId) -> Option<u32> {
+        if self.is_bool(ty) {
+            return Some(1);
+        }
+
+        if !self.is_integer(ty) {
+            return None;
+        }
+
+        let size = self.ty_context.borrow().get_size(ty)?;
+        Some(size as u32 * u8::BITS)
+    }
+
+    pub fn is_signed_integer(&self, ty: TyId) -> bool {
+        matches!(self.ty_context.borrow().get(ty), Ty::Int | Ty::Char)
+    }
+
+    pub fn is_character(&self, ty: TyId) -> bool {
+        matches!(
+            self.ty_context.borrow().get(ty),
+            Ty::Char | Ty::UnsignedChar
+        )
+    }
+
+    pub fn is_floating(&self, ty: TyId) -> bool {
+        matches!(self.ty_context.borrow().get(ty), Ty::Float | Ty::Double)
+    }
+
+    pub fn is_single_precision(&self, ty: TyId) -> bool {
+        matches!(self.ty_context.borrow().get(ty), Ty::Float)
+    }
+
+    pub fn is_arithmetic(&self, ty: TyId) -> bool {
+        self.is_integer(ty) || self.is_floating(ty)
+    }
+
+    /// Checks whether a type can be compared against zero.
+    pub fn is_scalar(&self, ty: TyId) -> bool {
+        self.is_arithmetic(ty) || self.is_pointer(ty)
+    }
+
     pub fn is_pointer(&self, ty: TyId) -> bool {
         matches!(self.ty_context.borrow().get(ty), Ty::Pointer(_))
     }
+
+    pub fn is_array(&self, ty: TyId) -> bool {
+        matches!(
+            self.ty_context.borrow().get(ty),
+            Ty::Array(..) | Ty::VariableArray(_)
+        )
+    }
+
+    pub fn is_variable_array(&self, ty: TyId) -> bool {
+        matches!(self.ty_context.borrow().get(ty), Ty::VariableArray(_))
+    }
+
+    pub fn is_function(&self, ty: TyId) -> bool {
+        matches!(self.ty_context.borrow().get(ty), Ty::Function(_))
+    }
+
+    pub fn is_function_pointer(&self, ty: TyId) -> bool {
+        match self.ty_context.borrow().get(ty) {
+            Ty::Pointer(base) => self.is_function(*base),
+            _ => false,
+        }
+    }
+
+    /// Checks whether functions called through `left` and `right` have compatible types.
+    pub fn is_compatible_callee(&self, left: TyId, right: TyId) -> bool {
+        let ty_context = self.ty_context.borrow();
+
+        let (Some(left), Some(right)) = (
+            ty_context.get_callee_function_ty(left),
+            ty_context.get_callee_function_ty(right),
+        ) else {
+            return true;
+        };
+
+        if !self.is_same_unqualified(left.get_return_ty(), right.get_return_ty()) {
+            return false;
+        }
+
+        match (left.get_parameters(), right.get_parameters()) {
+            (Some(left_parameters), Some(right_parameters)) => {
+                left.is_variadic() == right.is_variadic()
+                    && left_parameters.len() == right_parameters.len()
+                    && left_parameters
+                        .iter()
+                        .zip(right_parameters)
+                        .all(|(left, right)| self.is_same_unqualified(*left, *right))
+            }
+            _ => true,
+        }
+    }
+
+    /// Checks whether two declarations of the same name have compatible types.
+    pub fn is_compatible(&self, left: TyId, right: TyId) -> bool {
+        if left == right {
+            return true;
+        }
+
+        if self.is_function(left) && self.is_function(right) {
+            return self.is_compatible_callee(left, right);
+        }
+
+        let ty_context = self.ty_context.borrow();
+
+        match (ty_context.get(left), ty_context.get(right)) {
+            (
+                Ty::Array(left_element_ty, left_length),
+                Ty::Array(right_element_ty, right_length),
+            ) => {
+                left_element_ty == right_element_ty
+                    && (left_length.is_none()
+                        || right_length.is_none()
+                        || left_length == right_length)
+            }
+            _ => false,
+        }
+    }
+
+    pub fn is_struct(&self, ty: TyId) -> bool {
+        matches!(self.ty_context.borrow().get(ty), Ty::Struct(_))
+    }
+
+    pub fn is_const(&self, ty: TyId) -> bool {
+        self.ty_context.borrow().get_qualifiers(ty).is_const()
+    }
+
+    /// Checks whether two types are the same ignoring top-level qualifiers.
+    pub fn is_same_unqualified(&self, left: TyId, right: TyId) -> bool {
+        let ty_context = self.ty_context.borrow();
+        ty_context.get(left) == ty_context.get(right)
+    }
+
+    /// Checks whether converting the pointer `from` to `to` drops pointee qualifiers.
+    pub fn discards_qualifiers(&self, from: TyId, to: TyId) -> bool {
+        let ty_context = self.ty_context.borrow();
+
+        match (ty_context.get(from), ty_context.get(to)) {
+            (Ty::Pointer(from_base), Ty::Pointer(to_base)) => !ty_context
+                .get_qualifiers(*to_base)
+                .contains(ty_context.get_qualifiers(*from_base)),
+            _ => false,
+        }
+    }
+
+    pub fn is_va_list(&self, ty: TyId) -> bool {
+        matches!(self.ty_context.borrow().get(ty), Ty::VaList)
+    }
 }
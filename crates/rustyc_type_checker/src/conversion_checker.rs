@@ -0,0 +1,76 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::expressions::Expression;
+use rustyc_ty::{TyContext, TyId, TyMatcher};
+
+/// Checks implicit conversions, warning about suspicious ones.
+pub struct ConversionChecker {
+    expression: Rc<Expression>,
+    ty: TyId,
+    warnings: Rc<RefCell<Vec<Diagnostic>>>,
+    ty_matcher: TyMatcher,
+}
+
+impl ConversionChecker {
+    pub fn new(
+        expression: Rc<Expression>,
+        ty: TyId,
+        warnings: Rc<RefCell<Vec<Diagnostic>>>,
+        ty_context: Rc<RefCell<TyContext>>,
+    ) -> Self {
+        Self {
+            expression,
+            ty,
+            warnings,
+            ty_matcher: TyMatcher::new(ty_context),
+        }
+    }
+
+    pub fn check(self) -> rustyc_diagnostics::Result<()> {
+        if self.ty_matcher.is_void(self.expression.get_ty()) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::VoidValueUsed,
+                self.expression.get_span().clone(),
+            ));
+        }
+
+        // Structures are only copied into structures of the same type.
+        if self.ty_matcher.is_struct(self.expression.get_ty()) || self.ty_matcher.is_struct(self.ty)
+        {
+            if !self
+                .ty_matcher
+                .is_same_unqualified(self.expression.get_ty(), self.ty)
+            {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::IncompatibleStructConversion,
+                    self.expression.get_span().clone(),
+                ));
+            }
+
+            return Ok(());
+        }
+
+        if !self
+            .ty_matcher
+            .is_compatible_callee(self.expression.get_ty(), self.ty)
+        {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::IncompatibleFunctionPointerConversion,
+                self.expression.get_span().clone(),
+            ));
+        }
+
+        if self
+            .ty_matcher
+            .discards_qualifiers(self.expression.get_ty(), self.ty)
+        {
+            self.warnings.borrow_mut().push(Diagnostic::new_warning(
+                rustyc_diagnostics::Warning::DiscardedQualifiers,
+                self.expression.get_span().clone(),
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -1,19 +1,44 @@
 use std::{cell::RefCell, rc::Rc};
 
 use rustyc_diagnostics::Diagnostic;
-use rustyc_hir::{BinaryOperator, Expression, ExpressionKind};
-use rustyc_ty::{TyContext, TyMatcher};
+use rustyc_hir::{
+    expressions::{
+        AllocaExpression, AssignmentExpression, BinaryExpression, BinaryOperator,
+        CompoundLiteralExpression, Expression, ExpressionKind, FunctionCallExpression,
+        StatementExpression, UnaryExpression, UnaryOperator, VaStartExpression,
+    },
+    items::FunctionItem,
+    Constant, ConstantEvaluator, Res,
+};
+use rustyc_ty::{Ty, TyContext, TyId, TyMatcher};
+
+use crate::{
+    block_checker::BlockChecker, conversion_checker::ConversionChecker,
+    initializer_checker::InitializerChecker, jump_context::JumpContext,
+};
 
 pub struct ExpressionChecker {
     expression: Rc<Expression>,
+    function: Rc<FunctionItem>,
+    jump_context: JumpContext,
+    warnings: Rc<RefCell<Vec<Diagnostic>>>,
     ty_matcher: TyMatcher,
     ty_context: Rc<RefCell<TyContext>>,
 }
 
 impl ExpressionChecker {
-    pub fn new(expression: Rc<Expression>, ty_context: Rc<RefCell<TyContext>>) -> Self {
+    pub fn new(
+        expression: Rc<Expression>,
+        function: Rc<FunctionItem>,
+        jump_context: JumpContext,
+        warnings: Rc<RefCell<Vec<Diagnostic>>>,
+        ty_context: Rc<RefCell<TyContext>>,
+    ) -> Self {
         Self {
             expression,
+            function,
+            jump_context,
+            warnings,
             ty_matcher: TyMatcher::new(Rc::clone(&ty_context)),
             ty_context,
         }
@@ -21,59 +46,354 @@ impl ExpressionChecker {
 
     pub fn check(self) -> rustyc_diagnostics::Result<()> {
         match self.expression.get_kind() {
-            ExpressionKind::Assignment(left, right) => {
-                self.check_assignment(Rc::clone(left), Rc::clone(right))
-            }
-            ExpressionKind::Binary(operator, left, right) => {
-                self.check_binary(operator, Rc::clone(left), Rc::clone(right))
-            }
-            ExpressionKind::Unary(_, right) => self.check_expression(Rc::clone(right)),
-            ExpressionKind::FunctionCall(_, arguments) => self.check_function_call(arguments),
+            ExpressionKind::Assignment(expression) => self.check_assignment(expression),
+            ExpressionKind::Binary(expression) => self.check_binary(expression),
+            ExpressionKind::Unary(expression) => self.check_unary(expression),
+            ExpressionKind::Cast(expression) => self.check_expression(expression.get_operand()),
+            ExpressionKind::FunctionCall(expression) => self.check_function_call(expression),
+            ExpressionKind::Member(expression) => self.check_expression(expression.get_base()),
+            ExpressionKind::CompoundLiteral(expression) => self.check_compound_literal(expression),
+            ExpressionKind::Statement(expression) => self.check_statement_expression(expression),
+            ExpressionKind::VaStart(expression) => self.check_va_start(expression),
+            ExpressionKind::VaArg(expression) => self.check_va_list(expression.get_list()),
+            ExpressionKind::VaEnd(expression) => self.check_va_list(expression.get_list()),
+            ExpressionKind::SizeOf(expression) => self.check_expression(expression.get_operand()),
+            ExpressionKind::Alloca(expression) => self.check_alloca(expression),
             _ => Ok(()),
         }
     }
 
     fn check_assignment(
         &self,
-        left: Rc<Expression>,
-        right: Rc<Expression>,
+        expression: &AssignmentExpression,
     ) -> rustyc_diagnostics::Result<()> {
-        self.check_expression(left)?;
-        self.check_expression(right)?;
+        let left = expression.get_left();
 
-        Ok(())
+        self.check_expression(Rc::clone(&left))?;
+        self.check_expression(expression.get_right())?;
+
+        if self.ty_matcher.is_array(left.get_ty()) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ArrayAssignment,
+                left.get_span().clone(),
+            ));
+        }
+
+        if self.ty_matcher.is_const(left.get_ty()) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::AssignmentToConst,
+                left.get_span().clone(),
+            ));
+        }
+
+        self.check_conversion(expression.get_right(), left.get_ty())
     }
 
-    fn check_binary(
-        &self,
-        operator: &BinaryOperator,
-        left: Rc<Expression>,
-        right: Rc<Expression>,
-    ) -> rustyc_diagnostics::Result<()> {
+    fn check_binary(&self, expression: &BinaryExpression) -> rustyc_diagnostics::Result<()> {
+        let left = expression.get_left();
+        let right = expression.get_right();
+
         self.check_expression(Rc::clone(&left))?;
         self.check_expression(Rc::clone(&right))?;
 
-        match operator {
+        for operand in [&left, &right] {
+            if self.ty_matcher.is_void(operand.get_ty()) {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::VoidValueUsed,
+                    operand.get_span().clone(),
+                ));
+            }
+
+            self.check_not_struct(operand)?;
+        }
+
+        match expression.get_operator() {
             BinaryOperator::Add => self.check_add(&left, &right),
             BinaryOperator::Subtract => self.check_subtract(&left, &right),
             _ => Ok(()),
         }
     }
 
-    fn check_function_call(&self, arguments: &[Rc<Expression>]) -> rustyc_diagnostics::Result<()> {
+    fn check_unary(&self, expression: &UnaryExpression) -> rustyc_diagnostics::Result<()> {
+        let operand = expression.get_operand();
+
+        self.check_expression(Rc::clone(&operand))?;
+
+        match expression.get_operator() {
+            UnaryOperator::Negate => self.check_not_struct(&operand),
+            // A bit-field doesn't start at a byte boundary, so it has no
+            // address.
+            UnaryOperator::AddressOf => match operand.get_kind() {
+                ExpressionKind::Member(member) if member.is_bit_field() => {
+                    Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::AddressOfBitField,
+                        self.expression.get_span().clone(),
+                    ))
+                }
+                _ => Ok(()),
+            },
+            UnaryOperator::Dereference => Ok(()),
+        }
+    }
+
+    fn check_function_call(
+        &self,
+        expression: &FunctionCallExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        let callee = expression.get_callee();
+        let arguments = expression.get_arguments();
+
+        self.check_expression(Rc::clone(&callee))?;
+
         for argument in arguments.iter() {
             self.check_expression(Rc::clone(argument))?;
         }
 
+        let function_ty = self
+            .ty_context
+            .borrow()
+            .get_callee_function_ty(callee.get_ty())
+            .cloned()
+            .ok_or_else(|| {
+                Diagnostic::new_error(
+                    rustyc_diagnostics::Error::NotAFunction,
+                    callee.get_span().clone(),
+                )
+            })?;
+
+        // Calls to functions without a prototype can't be checked, other than
+        // for values that can't be passed at all.
+        let Some(parameters) = function_ty.get_parameters() else {
+            return arguments
+                .iter()
+                .try_for_each(|argument| self.check_not_struct(argument));
+        };
+
+        if arguments.len() < parameters.len()
+            || (arguments.len() > parameters.len() && !function_ty.is_variadic())
+        {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ArgumentCountMismatch(parameters.len(), arguments.len()),
+                self.expression.get_span().clone(),
+            ));
+        }
+
+        for (index, (parameter_ty, argument)) in parameters.iter().zip(arguments).enumerate() {
+            self.check_not_struct(argument)?;
+
+            if !self.is_compatible_argument(*parameter_ty, argument) {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::IncompatibleArgumentType(index + 1),
+                    argument.get_span().clone(),
+                ));
+            }
+
+            self.check_conversion(Rc::clone(argument), *parameter_ty)?;
+        }
+
+        arguments
+            .iter()
+            .skip(parameters.len())
+            .try_for_each(|argument| self.check_not_struct(argument))
+    }
+
+    fn is_compatible_argument(&self, parameter_ty: TyId, argument: &Rc<Expression>) -> bool {
+        let argument_ty = argument.get_ty();
+
+        if self
+            .ty_matcher
+            .is_same_unqualified(parameter_ty, argument_ty)
+        {
+            return true;
+        }
+
+        if self.ty_matcher.is_function_pointer(parameter_ty) {
+            // Function designators decay to pointers to their function.
+            let ty_context = self.ty_context.borrow();
+
+            return match (
+                ty_context.get_callee_function_ty(parameter_ty),
+                ty_context.get_callee_function_ty(argument_ty),
+            ) {
+                (Some(parameter), Some(argument)) => {
+                    parameter == argument
+                        || (parameter.get_return_ty() == argument.get_return_ty()
+                            && (parameter.get_parameters().is_none()
+                                || argument.get_parameters().is_none()))
+                }
+                _ => self.is_null_pointer_constant(argument),
+            };
+        }
+
+        if self.ty_matcher.is_pointer(parameter_ty) {
+            // Qualifiers can be added to the pointed-to type, and dropping
+            // them is only warned about.
+            let ty_context = self.ty_context.borrow();
+
+            if let (Ty::Pointer(parameter_base), Ty::Pointer(argument_base)) =
+                (ty_context.get(parameter_ty), ty_context.get(argument_ty))
+            {
+                // `void *` converts to and from any other object pointer.
+                return match (
+                    ty_context.get(*parameter_base),
+                    ty_context.get(*argument_base),
+                ) {
+                    (Ty::Void, Ty::Function(_)) | (Ty::Function(_), Ty::Void) => false,
+                    (Ty::Void, _) | (_, Ty::Void) => true,
+                    (parameter_base, argument_base) => parameter_base == argument_base,
+                };
+            }
+
+            drop(ty_context);
+
+            return self.is_null_pointer_constant(argument);
+        }
+
+        false
+    }
+
+    fn check_compound_literal(
+        &self,
+        expression: &CompoundLiteralExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        let initializer_checker = InitializerChecker::new(
+            expression.get_initializer(),
+            self.expression.get_ty(),
+            Rc::clone(&self.function),
+            self.jump_context.clone(),
+            Rc::clone(&self.warnings),
+            Rc::clone(&self.ty_context),
+        );
+        initializer_checker.check()
+    }
+
+    fn check_alloca(&self, expression: &AllocaExpression) -> rustyc_diagnostics::Result<()> {
+        let size = expression.get_size();
+
+        self.check_expression(Rc::clone(&size))?;
+
+        if !self.ty_matcher.is_integer(size.get_ty()) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::NonIntegerAllocaSize,
+                size.get_span().clone(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn check_statement_expression(
+        &self,
+        expression: &StatementExpression,
+    ) -> rustyc_diagnostics::Result<()> {
+        let block = expression.get_block();
+
+        // The block can't be jumped out of, since that would skip popping
+        // the operands that are being evaluated around it.
+        let block_checker = BlockChecker::new(
+            block,
+            Rc::clone(&self.function),
+            self.jump_context.enter_statement_expression(),
+            Rc::clone(&self.warnings),
+            Rc::clone(&self.ty_context),
+        );
+        block_checker.check()
+    }
+
+    fn check_conversion(
+        &self,
+        expression: Rc<Expression>,
+        ty: TyId,
+    ) -> rustyc_diagnostics::Result<()> {
+        let conversion_checker = ConversionChecker::new(
+            expression,
+            ty,
+            Rc::clone(&self.warnings),
+            Rc::clone(&self.ty_context),
+        );
+        conversion_checker.check()
+    }
+
+    fn check_not_struct(&self, expression: &Expression) -> rustyc_diagnostics::Result<()> {
+        if self.ty_matcher.is_struct(expression.get_ty()) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::StructValueUnsupported,
+                expression.get_span().clone(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn is_null_pointer_constant(&self, expression: &Rc<Expression>) -> bool {
+        if !self.ty_matcher.is_integer(expression.get_ty()) {
+            return false;
+        }
+
+        let constant_evaluator =
+            ConstantEvaluator::new(Rc::clone(expression), Rc::clone(&self.ty_context));
+        matches!(constant_evaluator.evaluate(), Ok(Constant::Integer(0)))
+    }
+
+    fn check_va_start(&self, expression: &VaStartExpression) -> rustyc_diagnostics::Result<()> {
+        if !self.function.is_variadic() {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::VaStartOutsideVariadicFunction,
+                self.expression.get_span().clone(),
+            ));
+        }
+
+        self.check_va_list(expression.get_list())?;
+
+        let last_parameter = expression.get_last_parameter();
+
+        let is_last_parameter = match last_parameter.get_kind() {
+            ExpressionKind::Variable(variable) => {
+                Some(&variable.get_res())
+                    == self
+                        .function
+                        .get_parameters()
+                        .last()
+                        .map(|id| Res::Parameter(*id))
+                        .as_ref()
+            }
+            _ => false,
+        };
+
+        if !is_last_parameter {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::InvalidVaStartParameter,
+                last_parameter.get_span().clone(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn check_va_list(&self, list: Rc<Expression>) -> rustyc_diagnostics::Result<()> {
+        self.check_expression(Rc::clone(&list))?;
+
+        // The builtins update the list in place, so it has to be a variable.
+        if !self.ty_matcher.is_va_list(list.get_ty())
+            || !matches!(list.get_kind(), ExpressionKind::Variable(_))
+        {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::InvalidVaList,
+                list.get_span().clone(),
+            ));
+        }
+
         Ok(())
     }
 
     fn check_add(&self, left: &Expression, right: &Expression) -> rustyc_diagnostics::Result<()> {
-        if self.ty_matcher.is_int(left.get_ty()) && self.ty_matcher.is_int(right.get_ty()) {
+        if self.ty_matcher.is_arithmetic(left.get_ty())
+            && self.ty_matcher.is_arithmetic(right.get_ty())
+        {
             return Ok(());
         }
 
-        if self.ty_matcher.is_pointer(left.get_ty()) && self.ty_matcher.is_int(right.get_ty()) {
+        if self.ty_matcher.is_pointer(left.get_ty()) && self.ty_matcher.is_integer(right.get_ty()) {
             return Ok(());
         }
 
@@ -88,11 +408,13 @@ impl ExpressionChecker {
         left: &Expression,
         right: &Expression,
     ) -> rustyc_diagnostics::Result<()> {
-        if self.ty_matcher.is_int(left.get_ty()) && self.ty_matcher.is_int(right.get_ty()) {
+        if self.ty_matcher.is_arithmetic(left.get_ty())
+            && self.ty_matcher.is_arithmetic(right.get_ty())
+        {
             return Ok(());
         }
 
-        if self.ty_matcher.is_pointer(left.get_ty()) && self.ty_matcher.is_int(right.get_ty()) {
+        if self.ty_matcher.is_pointer(left.get_ty()) && self.ty_matcher.is_integer(right.get_ty()) {
             return Ok(());
         }
 
@@ -107,7 +429,13 @@ impl ExpressionChecker {
     }
 
     fn check_expression(&self, expression: Rc<Expression>) -> rustyc_diagnostics::Result<()> {
-        let expression_checker = Self::new(expression, Rc::clone(&self.ty_context));
+        let expression_checker = Self::new(
+            expression,
+            Rc::clone(&self.function),
+            self.jump_context.clone(),
+            Rc::clone(&self.warnings),
+            Rc::clone(&self.ty_context),
+        );
         expression_checker.check()
     }
 }
@@ -1,26 +1,83 @@
 use std::{cell::RefCell, rc::Rc};
 
-use rustyc_hir::FunctionItem;
-use rustyc_ty::TyContext;
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::items::FunctionItem;
+use rustyc_ty::{TyContext, TyMatcher};
 
-use crate::block_checker::BlockChecker;
+use crate::{
+    block_checker::BlockChecker, jump_context::JumpContext, label_resolver::LabelResolver,
+};
 
 pub struct FunctionChecker {
     function: Rc<FunctionItem>,
+    warnings: Rc<RefCell<Vec<Diagnostic>>>,
     ty_context: Rc<RefCell<TyContext>>,
 }
 
 impl FunctionChecker {
-    pub fn new(function: Rc<FunctionItem>, ty_context: Rc<RefCell<TyContext>>) -> Self {
+    pub fn new(
+        function: Rc<FunctionItem>,
+        warnings: Rc<RefCell<Vec<Diagnostic>>>,
+        ty_context: Rc<RefCell<TyContext>>,
+    ) -> Self {
         Self {
             function,
+            warnings,
             ty_context,
         }
     }
 
     pub fn check(self) -> rustyc_diagnostics::Result<()> {
-        let block_checker =
-            BlockChecker::new(self.function.get_body(), Rc::clone(&self.ty_context));
+        let ty_matcher = TyMatcher::new(Rc::clone(&self.ty_context));
+        for local in self.function.get_locals() {
+            if ty_matcher.is_void(local.get_ty()) {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::VoidVariable,
+                    local.get_span().clone(),
+                ));
+            }
+
+            // Variable length arrays are the only objects whose size is
+            // computed at run time.
+            if (ty_matcher.is_struct(local.get_ty())
+                || ty_matcher.is_array(local.get_ty())
+                    && !ty_matcher.is_variable_array(local.get_ty()))
+                && self.ty_context.borrow().get_size(local.get_ty()).is_none()
+            {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::IncompleteVariable,
+                    local.get_span().clone(),
+                ));
+            }
+        }
+
+        // Structures are only ever accessed in memory, never passed in
+        // registers.
+        for id in self.function.get_parameters() {
+            let parameter = self.function.get_local(*id);
+
+            if ty_matcher.is_struct(parameter.get_ty()) {
+                return Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::StructValueUnsupported,
+                    parameter.get_span().clone(),
+                ));
+            }
+        }
+
+        let Some(body) = self.function.get_body() else {
+            return Ok(());
+        };
+
+        let label_resolver = LabelResolver::new(Rc::clone(&body));
+        label_resolver.resolve()?;
+
+        let block_checker = BlockChecker::new(
+            body,
+            Rc::clone(&self.function),
+            JumpContext::new(),
+            Rc::clone(&self.warnings),
+            Rc::clone(&self.ty_context),
+        );
         block_checker.check()
     }
 }
@@ -0,0 +1,73 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{expressions::Expression, initializers::Initializer, items::FunctionItem};
+use rustyc_ty::{TyContext, TyId};
+
+use crate::{
+    conversion_checker::ConversionChecker, expression_checker::ExpressionChecker,
+    jump_context::JumpContext,
+};
+
+pub struct InitializerChecker {
+    initializer: Rc<Initializer>,
+    ty: TyId,
+    function: Rc<FunctionItem>,
+    jump_context: JumpContext,
+    warnings: Rc<RefCell<Vec<Diagnostic>>>,
+    ty_context: Rc<RefCell<TyContext>>,
+}
+
+impl InitializerChecker {
+    pub fn new(
+        initializer: Rc<Initializer>,
+        ty: TyId,
+        function: Rc<FunctionItem>,
+        jump_context: JumpContext,
+        warnings: Rc<RefCell<Vec<Diagnostic>>>,
+        ty_context: Rc<RefCell<TyContext>>,
+    ) -> Self {
+        Self {
+            initializer,
+            ty,
+            function,
+            jump_context,
+            warnings,
+            ty_context,
+        }
+    }
+
+    pub fn check(self) -> rustyc_diagnostics::Result<()> {
+        match self.initializer.as_ref() {
+            Initializer::Expression(expression) => {
+                self.check_element(Rc::clone(expression), self.ty)
+            }
+            Initializer::List(elements) => elements.iter().try_for_each(|element| {
+                self.check_element(element.get_expression(), element.get_ty())
+            }),
+        }
+    }
+
+    fn check_element(
+        &self,
+        expression: Rc<Expression>,
+        ty: TyId,
+    ) -> rustyc_diagnostics::Result<()> {
+        let expression_checker = ExpressionChecker::new(
+            Rc::clone(&expression),
+            Rc::clone(&self.function),
+            self.jump_context.clone(),
+            Rc::clone(&self.warnings),
+            Rc::clone(&self.ty_context),
+        );
+        expression_checker.check()?;
+
+        let conversion_checker = ConversionChecker::new(
+            expression,
+            ty,
+            Rc::clone(&self.warnings),
+            Rc::clone(&self.ty_context),
+        );
+        conversion_checker.check()
+    }
+}
@@ -1,28 +1,100 @@
 use std::{cell::RefCell, rc::Rc};
 
-use rustyc_hir::{FunctionItem, Item, ItemKind};
-use rustyc_ty::TyContext;
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{
+    expressions::Expression,
+    initializers::Initializer,
+    items::{FunctionItem, GlobalItem, Item, ItemKind},
+};
+use rustyc_ty::{TyContext, TyId, TyMatcher};
 
-use crate::function_checker::FunctionChecker;
+use crate::{conversion_checker::ConversionChecker, function_checker::FunctionChecker};
 
 pub struct ItemChecker {
     item: Rc<Item>,
+    warnings: Rc<RefCell<Vec<Diagnostic>>>,
     ty_context: Rc<RefCell<TyContext>>,
 }
 
 impl ItemChecker {
-    pub fn new(item: Rc<Item>, ty_context: Rc<RefCell<TyContext>>) -> Self {
-        Self { item, ty_context }
+    pub fn new(
+        item: Rc<Item>,
+        warnings: Rc<RefCell<Vec<Diagnostic>>>,
+        ty_context: Rc<RefCell<TyContext>>,
+    ) -> Self {
+        Self {
+            item,
+            warnings,
+            ty_context,
+        }
     }
 
     pub fn check(self) -> rustyc_diagnostics::Result<()> {
         match self.item.get_kind() {
             ItemKind::Function(function) => self.check_function(Rc::clone(function)),
+            ItemKind::Global(global) => self.check_global(Rc::clone(global)),
+        }
+    }
+
+    fn check_global(&self, global: Rc<GlobalItem>) -> rustyc_diagnostics::Result<()> {
+        // Initializers are required to be constant, which is checked when
+        // they are evaluated.
+        let ty_matcher = TyMatcher::new(Rc::clone(&self.ty_context));
+
+        if ty_matcher.is_void(global.get_ty()) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::VoidVariable,
+                self.item.get_span().clone(),
+            ));
+        }
+
+        if (ty_matcher.is_struct(global.get_ty()) || ty_matcher.is_array(global.get_ty()))
+            && self.ty_context.borrow().get_size(global.get_ty()).is_none()
+        {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::IncompleteVariable,
+                self.item.get_span().clone(),
+            ));
+        }
+
+        match global.get_initializer().as_deref() {
+            Some(Initializer::Expression(expression)) => {
+                self.check_conversion(Rc::clone(expression), global.get_ty())
+            }
+            Some(Initializer::List(elements)) => elements.iter().try_for_each(|element| {
+                self.check_conversion(element.get_expression(), element.get_ty())
+            }),
+            None => Ok(()),
         }
     }
 
     fn check_function(&self, function: Rc<FunctionItem>) -> rustyc_diagnostics::Result<()> {
-        let function_checker = FunctionChecker::new(function, Rc::clone(&self.ty_context));
+        if TyMatcher::new(Rc::clone(&self.ty_context)).is_struct(function.get_return_ty()) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::StructValueUnsupported,
+                self.item.get_span().clone(),
+            ));
+        }
+
+        let function_checker = FunctionChecker::new(
+            function,
+            Rc::clone(&self.warnings),
+            Rc::clone(&self.ty_context),
+        );
         function_checker.check()
     }
+
+    fn check_conversion(
+        &self,
+        expression: Rc<Expression>,
+        ty: TyId,
+    ) -> rustyc_diagnostics::Result<()> {
+        let conversion_checker = ConversionChecker::new(
+            expression,
+            ty,
+            Rc::clone(&self.warnings),
+            Rc::clone(&self.ty_context),
+        );
+        conversion_checker.check()
+    }
 }
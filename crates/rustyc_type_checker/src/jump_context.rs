@@ -0,0 +1,72 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::switch_labels::SwitchLabels;
+
+#[derive(Clone, Default)]
+pub struct JumpContext {
+    can_break: bool,
+    can_continue: bool,
+    // Whether the statement expression being checked is inside of the loop
+    // or switch a `break` or `continue` would jump to.
+    breaks_out_of_statement_expression: bool,
+    continues_out_of_statement_expression: bool,
+    switch_labels: Option<Rc<RefCell<SwitchLabels>>>,
+}
+
+impl JumpContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn enter_loop(&self) -> Self {
+        Self {
+            can_break: true,
+            can_continue: true,
+            breaks_out_of_statement_expression: false,
+            continues_out_of_statement_expression: false,
+            switch_labels: self.switch_labels.clone(),
+        }
+    }
+
+    pub fn enter_switch(&self, switch_labels: Rc<RefCell<SwitchLabels>>) -> Self {
+        Self {
+            can_break: true,
+            can_continue: self.can_continue,
+            breaks_out_of_statement_expression: false,
+            continues_out_of_statement_expression: self.continues_out_of_statement_expression,
+            switch_labels: Some(switch_labels),
+        }
+    }
+
+    pub fn enter_statement_expression(&self) -> Self {
+        Self {
+            can_break: false,
+            can_continue: false,
+            breaks_out_of_statement_expression: self.can_break
+                || self.breaks_out_of_statement_expression,
+            continues_out_of_statement_expression: self.can_continue
+                || self.continues_out_of_statement_expression,
+            switch_labels: None,
+        }
+    }
+
+    pub fn can_break(&self) -> bool {
+        self.can_break
+    }
+
+    pub fn can_continue(&self) -> bool {
+        self.can_continue
+    }
+
+    pub fn breaks_out_of_statement_expression(&self) -> bool {
+        self.breaks_out_of_statement_expression
+    }
+
+    pub fn continues_out_of_statement_expression(&self) -> bool {
+        self.continues_out_of_statement_expression
+    }
+
+    pub fn get_switch_labels(&self) -> Option<Rc<RefCell<SwitchLabels>>> {
+        self.switch_labels.clone()
+    }
+}
@@ -0,0 +1,273 @@
+use std::{collections::HashMap, rc::Rc};
+
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{
+    expressions::{Expression, ExpressionKind},
+    initializers::Initializer,
+    statements::{Statement, StatementKind},
+    Block, LocalId,
+};
+
+/// The variable length arrays and statement expressions around a label or `goto`.
+#[derive(Clone)]
+struct Position {
+    variable_arrays: Vec<LocalId>,
+    statement_expressions: Vec<usize>,
+}
+
+pub struct LabelResolver {
+    body: Rc<Block>,
+    labels: HashMap<String, Position>,
+    gotos: Vec<(Rc<Statement>, Position)>,
+    position: Position,
+    statement_expression_count: usize,
+    switch_variable_array_counts: Vec<usize>,
+}
+
+impl LabelResolver {
+    pub fn new(body: Rc<Block>) -> Self {
+        Self {
+            body,
+            labels: HashMap::new(),
+            gotos: Vec::new(),
+            position: Position {
+                variable_arrays: Vec::new(),
+                statement_expressions: Vec::new(),
+            },
+            statement_expression_count: 0,
+            switch_variable_array_counts: Vec::new(),
+        }
+    }
+
+    pub fn resolve(mut self) -> rustyc_diagnostics::Result<()> {
+        self.collect_block(Rc::clone(&self.body))?;
+
+        for (statement, position) in self.gotos.iter() {
+            if let StatementKind::Goto(goto_statement) = statement.get_kind() {
+                let label_position =
+                    self.labels.get(goto_statement.get_label()).ok_or_else(|| {
+                        Diagnostic::new_error(
+                            rustyc_diagnostics::Error::UndefinedLabel(
+                                goto_statement.get_label().to_owned(),
+                            ),
+                            statement.get_span().clone(),
+                        )
+                    })?;
+
+                if position.statement_expressions != label_position.statement_expressions {
+                    let error = if position
+                        .statement_expressions
+                        .starts_with(&label_position.statement_expressions)
+                    {
+                        rustyc_diagnostics::Error::JumpOutOfStatementExpression
+                    } else {
+                        rustyc_diagnostics::Error::JumpIntoStatementExpression
+                    };
+
+                    return Err(Diagnostic::new_error(error, statement.get_span().clone()));
+                }
+
+                if !position
+                    .variable_arrays
+                    .starts_with(&label_position.variable_arrays)
+                {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::JumpIntoVariableArrayScope,
+                        statement.get_span().clone(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn collect_block(&mut self, block: Rc<Block>) -> rustyc_diagnostics::Result<()> {
+        let variable_array_count = self.position.variable_arrays.len();
+
+        for statement in block.get_statements().iter() {
+            self.collect_statement(Rc::clone(statement))?;
+        }
+
+        self.position.variable_arrays.truncate(variable_array_count);
+
+        Ok(())
+    }
+
+    fn check_switch_label(&self, statement: &Statement) -> rustyc_diagnostics::Result<()> {
+        match self.switch_variable_array_counts.last() {
+            Some(count) if self.position.variable_arrays.len() > *count => {
+                Err(Diagnostic::new_error(
+                    rustyc_diagnostics::Error::JumpIntoVariableArrayScope,
+                    statement.get_span().clone(),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn collect_statement(&mut self, statement: Rc<Statement>) -> rustyc_diagnostics::Result<()> {
+        match statement.get_kind() {
+            StatementKind::Labeled(labeled_statement) => {
+                if self
+                    .labels
+                    .insert(
+                        labeled_statement.get_label().to_owned(),
+                        self.position.clone(),
+                    )
+                    .is_some()
+                {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::DuplicateLabel(
+                            labeled_statement.get_label().to_owned(),
+                        ),
+                        statement.get_span().clone(),
+                    ));
+                }
+
+                self.collect_statement(labeled_statement.get_statement())?;
+            }
+            StatementKind::Goto(_) => self
+                .gotos
+                .push((Rc::clone(&statement), self.position.clone())),
+            StatementKind::If(statement) => {
+                self.collect_expression(&statement.get_condition_expression())?;
+                self.collect_statement(statement.get_then_statement())?;
+                if let Some(statement) = statement.get_else_statement() {
+                    self.collect_statement(statement)?;
+                }
+            }
+            StatementKind::Loop(statement) => {
+                let variable_array_count = self.position.variable_arrays.len();
+
+                if let Some(statement) = statement.get_initialization_statement() {
+                    self.collect_statement(statement)?;
+                }
+                if let Some(expression) = statement.get_condition_expression() {
+                    self.collect_expression(&expression)?;
+                }
+                if let Some(expression) = statement.get_incrementation_expression() {
+                    self.collect_expression(&expression)?;
+                }
+                self.collect_statement(statement.get_then_statement())?;
+
+                self.position.variable_arrays.truncate(variable_array_count);
+            }
+            StatementKind::DoWhile(statement) => {
+                self.collect_statement(statement.get_then_statement())?;
+                self.collect_expression(&statement.get_condition_expression())?;
+            }
+            StatementKind::Switch(statement) => {
+                self.collect_expression(&statement.get_condition_expression())?;
+                self.switch_variable_array_counts
+                    .push(self.position.variable_arrays.len());
+                self.collect_statement(statement.get_then_statement())?;
+                self.switch_variable_array_counts.pop();
+            }
+            StatementKind::Case(case_statement) => {
+                self.check_switch_label(&statement)?;
+                self.collect_statement(case_statement.get_statement())?;
+            }
+            StatementKind::Default(default_statement) => {
+                self.check_switch_label(&statement)?;
+                self.collect_statement(default_statement.get_statement())?;
+            }
+            StatementKind::Compound(statement) => self.collect_block(statement.get_block())?,
+            StatementKind::Declaration(statement) => {
+                for declarator in statement.get_declarators().iter() {
+                    if let Some(length) = declarator.get_length() {
+                        self.collect_expression(&length)?;
+                        self.position
+                            .variable_arrays
+                            .push(declarator.get_local_id());
+                    }
+
+                    if let Some(initializer) = declarator.get_initializer() {
+                        self.collect_initializer(&initializer)?;
+                    }
+                }
+            }
+            StatementKind::Return(statement) => {
+                if let Some(expression) = statement.get_expression() {
+                    self.collect_expression(&expression)?;
+                }
+            }
+            StatementKind::Expression(statement) => {
+                self.collect_expression(&statement.get_expression())?
+            }
+            StatementKind::Break | StatementKind::Continue => {}
+        }
+
+        Ok(())
+    }
+
+    fn collect_initializer(&mut self, initializer: &Initializer) -> rustyc_diagnostics::Result<()> {
+        match initializer {
+            Initializer::Expression(expression) => self.collect_expression(expression),
+            Initializer::List(elements) => {
+                for element in elements {
+                    self.collect_expression(&element.get_expression())?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    // Labels are visible from the whole function, including the statement
+    // expressions in it.
+    fn collect_expression(&mut self, expression: &Expression) -> rustyc_diagnostics::Result<()> {
+        match expression.get_kind() {
+            ExpressionKind::Statement(expression) => {
+                self.position
+                    .statement_expressions
+                    .push(self.statement_expression_count);
+                self.statement_expression_count += 1;
+
+                self.collect_block(expression.get_block())?;
+
+                self.position.statement_expressions.pop();
+
+                Ok(())
+            }
+            ExpressionKind::CompoundLiteral(expression) => {
+                self.collect_initializer(&expression.get_initializer())
+            }
+            ExpressionKind::Assignment(expression) => {
+                self.collect_expression(&expression.get_left())?;
+                self.collect_expression(&expression.get_right())
+            }
+            ExpressionKind::Binary(expression) => {
+                self.collect_expression(&expression.get_left())?;
+                self.collect_expression(&expression.get_right())
+            }
+            ExpressionKind::Unary(expression) => self.collect_expression(&expression.get_operand()),
+            ExpressionKind::Member(expression) => self.collect_expression(&expression.get_base()),
+            ExpressionKind::Cast(expression) => self.collect_expression(&expression.get_operand()),
+            ExpressionKind::FunctionCall(expression) => {
+                self.collect_expression(&expression.get_callee())?;
+
+                for argument in expression.get_arguments().iter() {
+                    self.collect_expression(argument)?;
+                }
+
+                Ok(())
+            }
+            ExpressionKind::VaStart(expression) => {
+                self.collect_expression(&expression.get_list())?;
+                self.collect_expression(&expression.get_last_parameter())
+            }
+            ExpressionKind::VaArg(expression) => self.collect_expression(&expression.get_list()),
+            ExpressionKind::VaEnd(expression) => self.collect_expression(&expression.get_list()),
+            ExpressionKind::SizeOf(expression) => {
+                self.collect_expression(&expression.get_operand())
+            }
+            ExpressionKind::Alloca(expression) => self.collect_expression(&expression.get_size()),
+            ExpressionKind::Variable(_)
+            | ExpressionKind::Function(_)
+            | ExpressionKind::Global(_)
+            | ExpressionKind::Number(_)
+            | ExpressionKind::FloatNumber(_) => Ok(()),
+        }
+    }
+}
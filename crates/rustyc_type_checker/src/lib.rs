@@ -1,8 +1,13 @@
 mod block_checker;
+mod conversion_checker;
 mod expression_checker;
 mod function_checker;
+mod initializer_checker;
 mod item_checker;
+mod jump_context;
+mod label_resolver;
 mod statement_checker;
+mod switch_labels;
 mod type_checker;
 
 pub use type_checker::TypeChecker;
@@ -1,100 +1,330 @@
 use std::{cell::RefCell, rc::Rc};
 
-use rustyc_hir::{Block, Expression, Statement, StatementKind};
-use rustyc_ty::TyContext;
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::{
+    expressions::Expression,
+    items::FunctionItem,
+    statements::{
+        CaseStatement, DeclarationStatement, DefaultStatement, DoWhileStatement, IfStatement,
+        LoopStatement, ReturnStatement, Statement, StatementKind, SwitchStatement,
+    },
+    Block, ConstantEvaluator,
+};
+use rustyc_ty::{TyContext, TyId, TyMatcher};
 
-use crate::{block_checker::BlockChecker, expression_checker::ExpressionChecker};
+use crate::{
+    block_checker::BlockChecker, conversion_checker::ConversionChecker,
+    expression_checker::ExpressionChecker, initializer_checker::InitializerChecker,
+    jump_context::JumpContext, switch_labels::SwitchLabels,
+};
 
 pub struct StatementChecker {
     statement: Rc<Statement>,
+    function: Rc<FunctionItem>,
+    jump_context: JumpContext,
+    warnings: Rc<RefCell<Vec<Diagnostic>>>,
+    ty_matcher: TyMatcher,
     ty_context: Rc<RefCell<TyContext>>,
 }
 
 impl StatementChecker {
-    pub fn new(statement: Rc<Statement>, ty_context: Rc<RefCell<TyContext>>) -> Self {
+    pub fn new(
+        statement: Rc<Statement>,
+        function: Rc<FunctionItem>,
+        jump_context: JumpContext,
+        warnings: Rc<RefCell<Vec<Diagnostic>>>,
+        ty_context: Rc<RefCell<TyContext>>,
+    ) -> Self {
         Self {
             statement,
+            function,
+            jump_context,
+            warnings,
+            ty_matcher: TyMatcher::new(Rc::clone(&ty_context)),
             ty_context,
         }
     }
 
     pub fn check(self) -> rustyc_diagnostics::Result<()> {
         match self.statement.get_kind() {
-            StatementKind::Return(expression) => self.check_expression(Rc::clone(expression)),
-            StatementKind::If(condition_expression, then_statement, else_statement) => self
-                .check_if(
-                    Rc::clone(condition_expression),
-                    Rc::clone(then_statement),
-                    else_statement.as_ref().map(|value| Rc::clone(value)),
-                ),
-            StatementKind::Loop(
-                initialization_statement,
-                condition_expression,
-                incrementation_expression,
-                then_statement,
-            ) => self.check_loop(
-                initialization_statement
-                    .as_ref()
-                    .map(|value| Rc::clone(value)),
-                condition_expression.as_ref().map(|value| Rc::clone(value)),
-                incrementation_expression
-                    .as_ref()
-                    .map(|value| Rc::clone(value)),
-                Rc::clone(then_statement),
-            ),
-            StatementKind::Compound(block) => self.check_block(Rc::clone(block)),
-            StatementKind::Expression(expression) => self.check_expression(Rc::clone(expression)),
+            StatementKind::Return(statement) => self.check_return(statement),
+            StatementKind::If(statement) => self.check_if(statement),
+            StatementKind::Loop(statement) => self.check_loop(statement),
+            StatementKind::DoWhile(statement) => self.check_do_while(statement),
+            StatementKind::Switch(statement) => self.check_switch(statement),
+            StatementKind::Case(statement) => self.check_case(statement),
+            StatementKind::Default(statement) => self.check_default(statement),
+            StatementKind::Labeled(statement) => {
+                self.check_statement(statement.get_statement(), self.jump_context.clone())
+            }
+            StatementKind::Goto(_) => Ok(()),
+            StatementKind::Break => self.check_break(),
+            StatementKind::Continue => self.check_continue(),
+            StatementKind::Compound(statement) => self.check_block(statement.get_block()),
+            StatementKind::Declaration(statement) => self.check_declaration(statement),
+            StatementKind::Expression(statement) => {
+                self.check_expression(statement.get_expression())
+            }
         }
     }
 
-    fn check_if(
-        &self,
-        condition_expression: Rc<Expression>,
-        then_statement: Rc<Statement>,
-        else_statement: Option<Rc<Statement>>,
-    ) -> rustyc_diagnostics::Result<()> {
-        self.check_expression(condition_expression)?;
-        self.check_statement(then_statement)?;
-        else_statement
-            .map(|statement| self.check_statement(statement))
+    fn check_return(&self, statement: &ReturnStatement) -> rustyc_diagnostics::Result<()> {
+        let return_ty = self.function.get_return_ty();
+
+        let Some(expression) = statement.get_expression() else {
+            if self.ty_matcher.is_void(return_ty) {
+                return Ok(());
+            }
+
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::MissingReturnValue,
+                self.statement.get_span().clone(),
+            ));
+        };
+
+        self.check_expression(Rc::clone(&expression))?;
+
+        if self.ty_matcher.is_void(return_ty) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ReturnValueInVoidFunction,
+                expression.get_span().clone(),
+            ));
+        }
+
+        self.check_conversion(expression, return_ty)
+    }
+
+    fn check_if(&self, statement: &IfStatement) -> rustyc_diagnostics::Result<()> {
+        self.check_condition(statement.get_condition_expression())?;
+        self.check_statement(statement.get_then_statement(), self.jump_context.clone())?;
+        statement
+            .get_else_statement()
+            .map(|statement| self.check_statement(statement, self.jump_context.clone()))
             .transpose()?;
 
         Ok(())
     }
 
-    fn check_loop(
-        &self,
-        initialization_statement: Option<Rc<Statement>>,
-        condition_expression: Option<Rc<Expression>>,
-        incrementation_expression: Option<Rc<Expression>>,
-        then_statement: Rc<Statement>,
-    ) -> rustyc_diagnostics::Result<()> {
-        initialization_statement
-            .map(|statement| self.check_statement(statement))
+    fn check_loop(&self, statement: &LoopStatement) -> rustyc_diagnostics::Result<()> {
+        statement
+            .get_initialization_statement()
+            .map(|statement| self.check_statement(statement, self.jump_context.clone()))
             .transpose()?;
-        condition_expression
-            .map(|expression| self.check_expression(expression))
+        statement
+            .get_condition_expression()
+            .map(|expression| self.check_condition(expression))
             .transpose()?;
-        incrementation_expression
+        statement
+            .get_incrementation_expression()
             .map(|expression| self.check_expression(expression))
             .transpose()?;
-        self.check_statement(then_statement)?;
+        self.check_statement(
+            statement.get_then_statement(),
+            self.jump_context.enter_loop(),
+        )?;
+
+        Ok(())
+    }
+
+    fn check_do_while(&self, statement: &DoWhileStatement) -> rustyc_diagnostics::Result<()> {
+        self.check_statement(
+            statement.get_then_statement(),
+            self.jump_context.enter_loop(),
+        )?;
+        self.check_condition(statement.get_condition_expression())?;
+
+        Ok(())
+    }
+
+    /// Checks that a controlling expression can be compared against zero.
+    fn check_condition(&self, expression: Rc<Expression>) -> rustyc_diagnostics::Result<()> {
+        self.check_expression(Rc::clone(&expression))?;
+
+        if !self.ty_matcher.is_scalar(expression.get_ty()) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::InvalidCondition,
+                expression.get_span().clone(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn check_switch(&self, statement: &SwitchStatement) -> rustyc_diagnostics::Result<()> {
+        let condition_expression = statement.get_condition_expression();
+        self.check_expression(Rc::clone(&condition_expression))?;
+
+        if !self.ty_matcher.is_integer(condition_expression.get_ty()) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::InvalidSwitchCondition,
+                condition_expression.get_span().clone(),
+            ));
+        }
+
+        let switch_labels = Rc::new(RefCell::new(SwitchLabels::new()));
+        self.check_statement(
+            statement.get_then_statement(),
+            self.jump_context.enter_switch(switch_labels),
+        )?;
+
+        Ok(())
+    }
+
+    fn check_case(&self, statement: &CaseStatement) -> rustyc_diagnostics::Result<()> {
+        let switch_labels = self.jump_context.get_switch_labels().ok_or_else(|| {
+            Diagnostic::new_error(
+                rustyc_diagnostics::Error::CaseOutsideSwitch,
+                self.statement.get_span().clone(),
+            )
+        })?;
+
+        let expression = statement.get_expression();
+        self.check_expression(Rc::clone(&expression))?;
+
+        let constant_evaluator =
+            ConstantEvaluator::new(Rc::clone(&expression), Rc::clone(&self.ty_context));
+        let value = constant_evaluator.evaluate_integer()?;
+
+        if !switch_labels.borrow_mut().insert_case(value) {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::DuplicateCaseValue(value),
+                expression.get_span().clone(),
+            ));
+        }
+
+        self.check_statement(statement.get_statement(), self.jump_context.clone())
+    }
+
+    fn check_default(&self, statement: &DefaultStatement) -> rustyc_diagnostics::Result<()> {
+        let switch_labels = self.jump_context.get_switch_labels().ok_or_else(|| {
+            Diagnostic::new_error(
+                rustyc_diagnostics::Error::DefaultOutsideSwitch,
+                self.statement.get_span().clone(),
+            )
+        })?;
+
+        if !switch_labels.borrow_mut().insert_default() {
+            return Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::MultipleDefaultLabels,
+                self.statement.get_span().clone(),
+            ));
+        }
+
+        self.check_statement(statement.get_statement(), self.jump_context.clone())
+    }
+
+    fn check_break(&self) -> rustyc_diagnostics::Result<()> {
+        if self.jump_context.can_break() {
+            Ok(())
+        } else if self.jump_context.breaks_out_of_statement_expression() {
+            Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::JumpOutOfStatementExpression,
+                self.statement.get_span().clone(),
+            ))
+        } else {
+            Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::BreakOutsideLoop,
+                self.statement.get_span().clone(),
+            ))
+        }
+    }
+
+    fn check_continue(&self) -> rustyc_diagnostics::Result<()> {
+        if self.jump_context.can_continue() {
+            Ok(())
+        } else if self.jump_context.continues_out_of_statement_expression() {
+            Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::JumpOutOfStatementExpression,
+                self.statement.get_span().clone(),
+            ))
+        } else {
+            Err(Diagnostic::new_error(
+                rustyc_diagnostics::Error::ContinueOutsideLoop,
+                self.statement.get_span().clone(),
+            ))
+        }
+    }
+
+    fn check_declaration(
+        &self,
+        statement: &DeclarationStatement,
+    ) -> rustyc_diagnostics::Result<()> {
+        for declarator in statement.get_declarators().iter() {
+            if let Some(length) = declarator.get_length() {
+                self.check_expression(Rc::clone(&length))?;
+
+                if !self.ty_matcher.is_integer(length.get_ty()) {
+                    return Err(Diagnostic::new_error(
+                        rustyc_diagnostics::Error::NonIntegerArrayLength,
+                        length.get_span().clone(),
+                    ));
+                }
+            }
+
+            if let Some(initializer) = declarator.get_initializer() {
+                let initializer_checker = InitializerChecker::new(
+                    initializer,
+                    self.function.get_local(declarator.get_local_id()).get_ty(),
+                    Rc::clone(&self.function),
+                    self.jump_context.clone(),
+                    Rc::clone(&self.warnings),
+                    Rc::clone(&self.ty_context),
+                );
+                initializer_checker.check()?;
+            }
+        }
 
         Ok(())
     }
 
-    fn check_statement(&self, statement: Rc<Statement>) -> rustyc_diagnostics::Result<()> {
-        let statement_checker = Self::new(statement, Rc::clone(&self.ty_context));
+    fn check_statement(
+        &self,
+        statement: Rc<Statement>,
+        jump_context: JumpContext,
+    ) -> rustyc_diagnostics::Result<()> {
+        let statement_checker = Self::new(
+            statement,
+            Rc::clone(&self.function),
+            jump_context,
+            Rc::clone(&self.warnings),
+            Rc::clone(&self.ty_context),
+        );
         statement_checker.check()
     }
 
     fn check_expression(&self, expression: Rc<Expression>) -> rustyc_diagnostics::Result<()> {
-        let expression_checker = ExpressionChecker::new(expression, Rc::clone(&self.ty_context));
+        let expression_checker = ExpressionChecker::new(
+            expression,
+            Rc::clone(&self.function),
+            self.jump_context.clone(),
+            Rc::clone(&self.warnings),
+            Rc::clone(&self.ty_context),
+        );
         expression_checker.check()
     }
 
+    fn check_conversion(
+        &self,
+        expression: Rc<Expression>,
+        ty: TyId,
+    ) -> rustyc_diagnostics::Result<()> {
+        let conversion_checker = ConversionChecker::new(
+            expression,
+            ty,
+            Rc::clone(&self.warnings),
+            Rc::clone(&self.ty_context),
+        );
+        conversion_checker.check()
+    }
+
     fn check_block(&self, block: Rc<Block>) -> rustyc_diagnostics::Result<()> {
-        let block_checker = BlockChecker::new(block, Rc::clone(&self.ty_context));
+        let block_checker = BlockChecker::new(
+            block,
+            Rc::clone(&self.function),
+            self.jump_context.clone(),
+            Rc::clone(&self.warnings),
+            Rc::clone(&self.ty_context),
+        );
         block_checker.check()
     }
 }
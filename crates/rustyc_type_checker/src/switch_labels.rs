@@ -0,0 +1,23 @@
+use std::collections::HashSet;
+
+#[derive(Default)]
+pub struct SwitchLabels {
+    case_values: HashSet<i64>,
+    has_default: bool,
+}
+
+impl SwitchLabels {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `false` if the switch already has a case with this value.
+    pub fn insert_case(&mut self, value: i64) -> bool {
+        self.case_values.insert(value)
+    }
+
+    /// Returns `false` if the switch already has a default label.
+    pub fn insert_default(&mut self) -> bool {
+        !std::mem::replace(&mut self.has_default, true)
+    }
+}
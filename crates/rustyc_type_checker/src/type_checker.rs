@@ -1,6 +1,7 @@
 use std::{cell::RefCell, rc::Rc};
 
-use rustyc_hir::Item;
+use rustyc_diagnostics::Diagnostic;
+use rustyc_hir::items::Item;
 use rustyc_ty::TyContext;
 
 use crate::item_checker::ItemChecker;
@@ -15,12 +16,19 @@ impl TypeChecker {
         Self { hir, ty_context }
     }
 
-    pub fn check(self) -> rustyc_diagnostics::Result<()> {
+    /// Checks the items, returning the warnings that were reported.
+    pub fn check(self) -> rustyc_diagnostics::Result<Vec<Diagnostic>> {
+        let warnings = Rc::new(RefCell::new(Vec::new()));
+
         for item in self.hir.iter() {
-            let item_checker = ItemChecker::new(Rc::clone(item), Rc::clone(&self.ty_context));
+            let item_checker = ItemChecker::new(
+                Rc::clone(item),
+                Rc::clone(&warnings),
+                Rc::clone(&self.ty_context),
+            );
             item_checker.check()?;
         }
 
-        Ok(())
+        Ok(warnings.take())
     }
 }
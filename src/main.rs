@@ -1,17 +1,99 @@
-use clap::Parser;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, ValueEnum};
 use rustyc_driver::Driver;
+use rustyc_preprocessor::{MacroOption, PreprocessorOptions};
+use rustyc_target::Target;
+
+/// The name of a source given on the command line.
+const SOURCE_NAME: &str = "<source>";
 
 #[derive(Parser)]
 #[command(author = "ydolev", version = "0.1.0", about = "A minimalist C compiler written in Rust", long_about = None)]
 struct Cli {
-    expression: String,
+    /// Adds a directory to search for included headers
+    #[arg(short = 'I', value_name = "DIRECTORY")]
+    include_directories: Vec<PathBuf>,
+    /// Defines a macro as VALUE, or as 1 if no value is given
+    #[arg(short = 'D', value_name = "NAME[=VALUE]")]
+    macro_definitions: Vec<String>,
+    /// Removes the definition of a macro
+    #[arg(short = 'U', value_name = "NAME")]
+    macro_removals: Vec<String>,
+    /// Selects the target to compile for
+    #[arg(long, value_name = "TRIPLE", default_value_t = Target::default())]
+    target: Target,
+    /// Enables an optional warning
+    #[arg(short = 'W', value_name = "WARNING")]
+    warnings: Vec<WarningOption>,
+    /// Only preprocesses the input, and prints the result
+    #[arg(short = 'E')]
+    preprocess_only: bool,
+    /// A source file, or the source itself if there is no such file
+    input: String,
+}
+
+#[derive(Clone, PartialEq, ValueEnum)]
+enum WarningOption {
+    /// Warns about pragmas that are ignored because they aren't known
+    UnknownPragmas,
 }
 
 fn main() -> anyhow::Result<()> {
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches)?;
+
+    let macro_options = get_macro_options(&matches, cli.macro_definitions, cli.macro_removals);
+
+    let preprocessor_options = PreprocessorOptions {
+        include_directories: cli.include_directories,
+        macro_options,
+        target: cli.target,
+        warns_unknown_pragmas: cli.warnings.contains(&WarningOption::UnknownPragmas),
+    };
+
+    let (source_name, source) = match Path::new(&cli.input).is_file() {
+        true => {
+            let source = fs::read_to_string(&cli.input)
+                .with_context(|| format!("couldn't read `{}`", cli.input))?;
+            (cli.input, source)
+        }
+        false => (SOURCE_NAME.to_owned(), cli.input),
+    };
 
-    let mut driver = Driver::new(cli.expression);
-    driver.run()?;
+    let mut driver = Driver::new(source_name, source, preprocessor_options);
+
+    match cli.preprocess_only {
+        true => driver.preprocess()?,
+        false => driver.run()?,
+    }
 
     Ok(())
 }
+
+fn get_macro_options(
+    matches: &ArgMatches,
+    macro_definitions: Vec<String>,
+    macro_removals: Vec<String>,
+) -> Vec<MacroOption> {
+    let get_indices = |id| matches.indices_of(id).into_iter().flatten();
+
+    let definitions = get_indices("macro_definitions")
+        .zip(macro_definitions)
+        .map(|(index, definition)| (index, MacroOption::Define(definition)));
+    let removals = get_indices("macro_removals")
+        .zip(macro_removals)
+        .map(|(index, name)| (index, MacroOption::Undefine(name)));
+
+    let mut macro_options = definitions.chain(removals).collect::<Vec<_>>();
+    macro_options.sort_by_key(|(index, _)| *index);
+
+    macro_options
+        .into_iter()
+        .map(|(_, macro_option)| macro_option)
+        .collect()
+}
@@ -0,0 +1,9 @@
+#ifndef GUARDED_H
+#define GUARDED_H
+
+/* Headers are shared, so they have comments like any other source. */
+#define FIVE 5 // the value that guarded.h provides
+
+int guarded_add(int x, int y) { return x + y; }
+
+#endif
@@ -0,0 +1,3 @@
+#include "guarded.h"
+
+#define SEVEN (FIVE + 2)
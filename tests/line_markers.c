@@ -0,0 +1,8 @@
+#include "include/nested.h"
+
+#define ADD(a, b) guarded_add(a, \
+                              b)
+
+int main() {
+    return ADD(SEVEN, FIVE) - __LINE__ + 7;
+}
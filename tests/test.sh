@@ -25,9 +25,9 @@ fail() {
 
 assert() {
     local expected="$1"
-    local input="$2"
+    local input="${*:2}"
 
-    $RUSTYC_PATH "$input" > test.s
+    $RUSTYC_PATH "${@:2}" > test.s
     local rustyc_status="$?"
     if [[ 0 != $rustyc_status ]]; then
         print_red "rustyc failed with status $rustyc_status"
@@ -48,106 +48,729 @@ assert() {
     fi
 }
 
+assert_error() {
+    assert_diagnostic 1 "error: $1" "${@:2}"
+}
+
+assert_warning() {
+    assert_diagnostic 0 "warning: $1" "${@:2}"
+}
+
+assert_diagnostic() {
+    local expected_status="$1"
+    local expected="$2"$'\n'"  --> $3"
+    local input="${*:4}"
+
+    local actual
+    actual="$($RUSTYC_PATH "${@:4}" 2>&1 >/dev/null)"
+    local rustyc_status="$?"
+    actual="$(head -2 <<<"$actual")"
+
+    if [[ "$rustyc_status" != "$expected_status" ]]; then
+        print_red "$input => expected status $expected_status, got $rustyc_status"
+        fail
+    elif [[ "$actual" == "$expected" ]]; then
+        echo "$input => $2"
+    else
+        print_red "$input => expected $expected, got $actual"
+        fail
+    fi
+}
+
+assert_preprocessed() {
+    local expected="$1"
+    local input="${*:2}"
+
+    local actual="$($RUSTYC_PATH -E "${@:2}" | grep -v '^#')"
+
+    if [[ "$actual" == "$expected" ]]; then
+        echo "$input => $actual"
+    else
+        print_red "$input => expected $expected, got $actual"
+        fail
+    fi
+}
+
+assert_assembled() {
+    local target="$1"
+    local expected="$2"
+    local input="${*:3}"
+
+    $RUSTYC_PATH --target "$target" "${@:3}" > test.s
+    local rustyc_status="$?"
+    if [[ 0 != $rustyc_status ]]; then
+        print_red "rustyc failed with status $rustyc_status"
+        echo
+        fail
+        return
+    fi
+
+    if ! clang --target="$target" -c -o test.o test.s; then
+        print_red "$input => failed to assemble for $target"
+        fail
+    elif grep -qxF -- "$expected" test.s; then
+        echo "$input => $expected"
+    else
+        print_red "$input => expected a line $expected for $target"
+        fail
+    fi
+}
+
 pushd "$PROJECT_DIRECTORY"
 cargo build
 popd >/dev/null
 
 pushd "$TESTS_DIRECTORY" >/dev/null
 
-assert 0 "main() { return 0; }"
-assert 42 "main() { return 42; }"
-assert 5 "main() { return 3 + 2; }"
-assert 21 "main() { return 5+20-4; }"
-assert 107 "main() {    return     111 +    5                              -              9;      }"
-assert 56 "main() { return 8* 7; }"
-assert 47 "main() { return 5 + 6 * 7; }"
-assert 100 "main() {   return 200 /2; }"
-assert 15 "main() { return 5* (9 -6); }"
-assert 4 "main() { return (3+5)/2; }"
-assert 78 "main() { return (34 + 5) * 2; }"
-assert 10 "main() { return -10+20; }"
-assert 10 "main() { return - -10; }"
-assert 10 "main() { return - - +10; }"
-assert 8 "main() { return -(5 + 3 * 44) + 145; }"
-
-assert 0 "main() { return 0 == 1; }"
-assert 1 "main() { return 42==42; }"
-
-assert 1 "main() { return 0!=1; }"
-assert 0 "main() { return 42!=42; }"
-
-assert 1 "main() { return 0<1; }"
-assert 0 "main() { return 1<1; }"
-assert 0 "main() { return 2           < 1; }"
-assert 1 "main() { return 0<=1; }"
-assert 1 "main() { return 1<=1; }"
-assert 0 "main() { return 2<=1; }"
-
-assert 1 "main() { return 1>0; }"
-assert 0 "main() { return 1 >  1; }"
-assert 0 "main() { return 1>2; }"
-assert 1 "main() { return 1>=0; }"
-assert 1 "main() { return 1>=1; }"
-assert 0 "main() { return 1>=2; }"
-
-assert 3 "main() { a=3; return a; }"
-assert 8 "main() { a=3; z=5; return a+z; }"
-assert 6 "main() { a=b=3; return a+b; }"
-assert 3 "main() { foo=3; return foo; }"
-assert 8 "main() { foo123=3; _bar=5; return foo123+_bar; }"
-
-assert 1 "main() { return 1; 2; 3; }"
-assert 2 "main() { 1; return 2; 3; }"
-assert 3 "main() { 1; 2; return 3; }"
-assert 2 "main() { return 2; 5 + 6; 9 - 3; }"
-assert 11 "main() { 2; return 5 + 6; 9 - 3; }"
-assert 6 "main() { 2; 5 + 6; return 9 - 3; }"
-
-assert 3 "main() { {1; {2;} return 3; } }"
-
-assert 5 "main() { ; ;; return 5; }"
-
-assert 3 "main() { if (0) return 2; return 3; }"
-assert 3 "main() { if (1-1) return 2; return 3; }"
-assert 2 "main() { if (1) return 2; return 3; }"
-assert 2 "main() { if (2-1) return 2; return 3; }"
-assert 4 "main() { if (0) { 1; 2; return 3; } else { return 4; } }"
-assert 3 "main() { if (1) { 1; 2; return 3; } else { return 4; } }"
-assert 2 "main() { i = 0; if (1) i = 2; else i = 5; return i; }"
-assert 5 "main() { i = 0; if (0) { i = 2; } else { i = 5; } return i; }"
-
-assert 55 "main() { i=0; j=0; for (i=0; i<=10; i=i+1) j=i+j; return j; }"
-assert 3 "main() { for (;;) {return 3;} return 5; }"
-
-assert 10 "main() { i = 0; while (i < 10) { i = i + 1; } return i; }"
-assert 100 "main() { i = 0; j = 0; while (i < 10) { j = j + 10; i = i + 1; } return j; }"
-
-assert 3 "main() { return ret3(); }"
-assert 5 "main() { return ret5(); }"
-
-assert 8 "main() { return add(3, 5); }"
-assert 2 "main() { return sub(5, 3); }"
-assert 21 "main() { return add6(1, 2, 3, 4, 5, 6); }"
-assert 10 "main() { return sub8(100, 50, 25, 5, 4, 3, 2, 1); }"
-
-assert 32 "main() { return ret32(); } ret32() { return 32; }"
-assert 35 "main() { ret20_result = test_ret20(); return test_ret5() + test_ret10() + ret20_result; } test_ret5() { a = 2; b = 3; return a + b; } test_ret10() { return 10; } test_ret20() { a = 100; b = 80; return (a - b); }"
-
-assert 7 "main() { return add2(3,4); } add2(x,y) { return x+y; }"
-assert 1 "main() { return sub2(4,3); } sub2(x,y) { return x-y; }"
-assert 36 "main() { return add8(1, 2, 3, 4, 5, 6, 7, 8); } add8(a, b, c, d, e, f, g, h) { return a + b + c + d + e + f + g + h; }"
-assert 55 "main() { return fib(9); } fib(x) { if (x<=1) return 1; return fib(x-1) + fib(x-2); }"
-
-assert 3 "main() { x=3; return *&x; }"
-assert 3 "main() { x=3; y=&x; z=&y; return **z; }"
-assert 5 "main() { x=3; y=5; return *(&x+1); }"
-assert 3 "main() { x=3; y=5; return *(&y-1); }"
-assert 5 "main() { x=3; y=5; return *(&x-(-1)); }"
-assert 5 "main() { x=3; y=&x; *y=5; return x; }"
-assert 7 "main() { x=3; y=5; *(&x+1)=7; return y; }"
-assert 7 "main() { x=3; y=5; *(&y-2+1)=7; return x; }"
-assert 5 "main() { x=3; return (&x+2)-&x+3; }"
-
+assert 0 "int main() { return 0; }"
+assert 42 "int main() { return 42; }"
+assert 5 "int main() { return 3 + 2; }"
+assert 21 "int main() { return 5+20-4; }"
+assert 107 "int main() {    return     111 +    5                              -              9;      }"
+assert 56 "int main() { return 8* 7; }"
+assert 47 "int main() { return 5 + 6 * 7; }"
+assert 100 "int main() {   return 200 /2; }"
+assert 15 "int main() { return 5* (9 -6); }"
+assert 4 "int main() { return (3+5)/2; }"
+assert 78 "int main() { return (34 + 5) * 2; }"
+assert 10 "int main() { return -10+20; }"
+assert 10 "int main() { return - -10; }"
+assert 10 "int main() { return - - +10; }"
+assert 8 "int main() { return -(5 + 3 * 44) + 145; }"
+
+assert 0 "int main() { return 0 == 1; }"
+assert 1 "int main() { return 42==42; }"
+
+assert 1 "int main() { return 0!=1; }"
+assert 0 "int main() { return 42!=42; }"
+
+assert 1 "int main() { return 0<1; }"
+assert 0 "int main() { return 1<1; }"
+assert 0 "int main() { return 2           < 1; }"
+assert 1 "int main() { return 0<=1; }"
+assert 1 "int main() { return 1<=1; }"
+assert 0 "int main() { return 2<=1; }"
+
+assert 1 "int main() { return 1>0; }"
+assert 0 "int main() { return 1 >  1; }"
+assert 0 "int main() { return 1>2; }"
+assert 1 "int main() { return 1>=0; }"
+assert 1 "int main() { return 1>=1; }"
+assert 0 "int main() { return 1>=2; }"
+
+assert 3 "int main() { int a; a=3; return a; }"
+assert 8 "int main() { int a, z; a=3; z=5; return a+z; }"
+assert 6 "int main() { int a, b; a=b=3; return a+b; }"
+assert 3 "int main() { int foo; foo=3; return foo; }"
+assert 8 "int main() { int foo123, _bar; foo123=3; _bar=5; return foo123+_bar; }"
+assert 7 "int main() { int x = 3, y = x + 1; return x + y; }"
+assert 1 "int main() { int x = 1; { int x = 2; } return x; }"
+assert 2 "int main() { int x = 1; { int x = 2; return x; } }"
+assert 2 "int f(int a) { { int a = 2; return a; } } int main() { return f(1); }"
+assert 7 "int main() { int r = 0; { int x = 3; r = r + x; } { int x = 4; r = r + x; } return r; }"
+assert 6 "int main() { int x = 2; { int y = x + 1; int x = y * 2; return x; } }"
+assert 10 "int main() { int j = 0; for (int i = 0; i < 5; i = i + 1) j = j + i; return j; }"
+assert 10 "int main() { int i = 10; for (int i = 0; i < 3; i = i + 1) {} return i; }"
+assert 7 "int main() { int x = 3; int *p = &x; *p = 7; return x; }"
+
+assert 1 "int main() { return 1; 2; 3; }"
+assert 2 "int main() { 1; return 2; 3; }"
+assert 3 "int main() { 1; 2; return 3; }"
+assert 2 "int main() { return 2; 5 + 6; 9 - 3; }"
+assert 11 "int main() { 2; return 5 + 6; 9 - 3; }"
+assert 6 "int main() { 2; 5 + 6; return 9 - 3; }"
+
+assert 3 "int main() { {1; {2;} return 3; } }"
+
+assert 5 "int main() { ; ;; return 5; }"
+
+assert 3 "int main() { if (0) return 2; return 3; }"
+assert 3 "int main() { if (1-1) return 2; return 3; }"
+assert 2 "int main() { if (1) return 2; return 3; }"
+assert 2 "int main() { if (2-1) return 2; return 3; }"
+assert 4 "int main() { if (0) { 1; 2; return 3; } else { return 4; } }"
+assert 3 "int main() { if (1) { 1; 2; return 3; } else { return 4; } }"
+assert 2 "int main() { int i; i = 0; if (1) i = 2; else i = 5; return i; }"
+assert 5 "int main() { int i; i = 0; if (0) { i = 2; } else { i = 5; } return i; }"
+
+assert 55 "int main() { int i, j; i=0; j=0; for (i=0; i<=10; i=i+1) j=i+j; return j; }"
+assert 3 "int main() { for (;;) {return 3;} return 5; }"
+
+assert 10 "int main() { int i; i = 0; while (i < 10) { i = i + 1; } return i; }"
+assert 100 "int main() { int i, j; i = 0; j = 0; while (i < 10) { j = j + 10; i = i + 1; } return j; }"
+
+assert 5 "int main() { int i; i = 0; for (;;) { i = i + 1; if (i == 5) break; } return i; }"
+assert 5 "int main() { int i, j; i = 0; j = 0; for (i = 0; i < 10; i = i + 1) { if (i < 5) continue; j = j + 1; } return j; }"
+assert 3 "int main() { int i; i = 0; while (1) { if (i == 3) break; i = i + 1; } return i; }"
+assert 5 "int main() { int i, j; i = 0; j = 0; while (i < 10) { i = i + 1; if (i > 5) continue; j = j + 1; } return j; }"
+assert 3 "int main() { int i, j; i = 0; j = 0; for (i = 0; i < 3; i = i + 1) { for (;;) { j = j + 1; break; } } return j; }"
+assert 9 "int main() { int i, j, k; i = 0; j = 0; for (i = 0; i < 3; i = i + 1) { k = 0; while (k < 5) { k = k + 1; if (k > 3) continue; j = j + 1; } } return j; }"
+
+assert 10 "int main() { int i; i = 0; do { i = i + 1; } while (i < 10); return i; }"
+assert 1 "int main() { int i; i = 0; do i = i + 1; while (0); return i; }"
+assert 4 "int main() { int i; i = 0; do { i = i + 1; if (i == 4) break; } while (1); return i; }"
+assert 5 "int main() { int i, j; i = 0; j = 0; do { i = i + 1; if (i > 5) continue; j = j + 1; } while (i < 10); return j; }"
+
+assert 5 "int main() { int i; i = 2; switch (i) { case 1: return 3; case 2: return 5; } return 7; }"
+assert 7 "int main() { int i; i = 3; switch (i) { case 1: return 3; case 2: return 5; } return 7; }"
+assert 9 "int main() { int i; i = 3; switch (i) { case 1: return 3; default: return 9; case 2: return 5; } return 7; }"
+assert 6 "int main() { int i, j; i = 1; j = 0; switch (i) { case 1: j = j + 1; case 2: j = j + 2; case 3: j = j + 3; break; case 4: j = j + 4; } return j; }"
+assert 4 "int main() { int i, j; i = -2; j = 0; switch (i) { case -2: j = 4; break; case 2: j = 5; break; } return j; }"
+assert 8 "int main() { int i; i = 6; switch (i) { case 2 * 3: return 8; case 1 + 1: return 9; } return 0; }"
+assert 10 "int main() { int i, j; i = 0; j = 0; for (i = 0; i < 4; i = i + 1) { switch (i) { case 1: continue; case 2: j = j + 5; break; default: j = j + 1; } j = j + 1; } return j; }"
+assert 33 "int main() { int i; i = 3; switch (i) { case 0: return 30; case 1: return 31; case 2: return 32; case 3: return 33; case 4: return 34; case 5: return 35; } return 0; }"
+assert 40 "int main() { int i; i = 9; switch (i) { case 0: return 30; case 1: return 31; case 2: return 32; case 4: return 34; default: return 40; } return 0; }"
+assert 41 "int main() { int i; i = 3; switch (i) { case 0: return 30; case 1: return 31; case 2: return 32; case 4: return 34; } return 41; }"
+assert 42 "int main() { int i; i = -1; switch (i) { case 0: return 30; case 1: return 31; case 2: return 32; case 3: return 33; } return 42; }"
+assert 21 "int main() { int i, j; i = 2; j = 1; switch (i) { case 1: case 2: switch (j) { case 1: i = 20; break; } i = i + 1; break; } return i; }"
+
+assert 3 "int main() { int i; i = 0; goto skip; i = 5; skip: i = i + 3; return i; }"
+assert 10 "int main() { int i; i = 0; again: i = i + 1; if (i < 10) goto again; return i; }"
+assert 7 "int main() { int i; i = 0; for (;;) { for (;;) { i = 7; goto out; } } out: return i; }"
+assert 2 "int main() { end: return 2; }"
+
+assert 3 "int main() { return ret3(); }"
+assert 5 "int main() { return ret5(); }"
+
+assert 8 "int main() { return add(3, 5); }"
+assert 2 "int main() { return sub(5, 3); }"
+assert 21 "int main() { return add6(1, 2, 3, 4, 5, 6); }"
+assert 10 "int main() { return sub8(100, 50, 25, 5, 4, 3, 2, 1); }"
+
+assert 32 "int main() { return ret32(); } int ret32() { return 32; }"
+assert 35 "int main() { int ret20_result; ret20_result = test_ret20(); return test_ret5() + test_ret10() + ret20_result; } int test_ret5() { int a, b; a = 2; b = 3; return a + b; } int test_ret10() { return 10; } int test_ret20() { int a, b; a = 100; b = 80; return (a - b); }"
+
+assert 7 "int main() { return add2(3,4); } int add2(int x, int y) { return x+y; }"
+assert 1 "int main() { return sub2(4,3); } int sub2(int x, int y) { return x-y; }"
+assert 36 "int main() { return add8(1, 2, 3, 4, 5, 6, 7, 8); } int add8(int a, int b, int c, int d, int e, int f, int g, int h) { return a + b + c + d + e + f + g + h; }"
+assert 55 "int main() { return fib(9); } int fib(int x) { if (x<=1) return 1; return fib(x-1) + fib(x-2); }"
+assert 45 "int main() { return add9(1, 2, 3, 4, 5, 6, 7, 8, 9); } int add9(int a, int b, int c, int d, int e, int f, int g, int h, int i) { return a + b + c + d + e + f + g + h + i; }"
+assert 9 "int main() { return sub10(100, 50, 25, 5, 4, 3, 2, 1, 0, 1); } int sub10(int a, int b, int c, int d, int e, int f, int g, int h, int i, int j) { return a - b - c - d - e - f - g - h - i - j; }"
+assert 66 "int main() { return last(1, 2, 3, 4, 5, 6, 7, 8, 9, last(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 20), 36); } int last(int a, int b, int c, int d, int e, int f, int g, int h, int i, int j, int k) { return k + j; }"
+assert 6 "int sum_ints(int count, ...); int main() { return sum_ints(3, 1, 2, 3); }"
+assert 21 "int sum_ints(int count, ...); int main() { return sum_ints(6, 1, 2, 3, 4, 5, 6); }"
+assert 10 "int sum(int count, ...) { __builtin_va_list ap; __builtin_va_start(ap, count); int total = 0; for (int i = 0; i < count; i = i + 1) total = total + __builtin_va_arg(ap, int); __builtin_va_end(ap); return total; } int main() { return sum(4, 1, 2, 3, 4); }"
+assert 42 "int main() { return pick(0, 0, 0, 0, 0, 0, 0, 0, 7, 1, 35); } int pick(int a, int b, int c, int d, int e, int f, int g, int h, int i, ...) { __builtin_va_list ap; __builtin_va_start(ap, i); __builtin_va_arg(ap, int); return i + __builtin_va_arg(ap, int); }"
+assert 7 "int deref(int count, ...) { __builtin_va_list ap; __builtin_va_start(ap, count); int *p = __builtin_va_arg(ap, int *); __builtin_va_end(ap); return *p; } int main() { int x = 7; return deref(1, &x); }"
+assert 3 "int log(const char *fmt, ...) { __builtin_va_list ap; __builtin_va_start(ap, fmt); int n = __builtin_va_arg(ap, int); __builtin_va_end(ap); return n + fmt[0] - 37; } int main() { return log(\"%d\", 3); }"
+assert 7 "int add(int x, int y) { return x + y; } int main() { int (*fp)(int, int) = add; return fp(3, 4); }"
+assert 3 "int sub(int x, int y) { return x - y; } int main() { int (*fp)(int, int); fp = &sub; return (*fp)(5, 2); }"
+assert 3 "int add(int x, int y) { return x + y; } int main() { return (*add)(1, 2); }"
+assert 42 "int apply(int (*f)(int), int x) { return f(x); } int twice(int x) { return x + x; } int main() { return apply(twice, 21); }"
+assert 5 "int ret5(); int main() { int (*fp)() = ret5; return fp(); }"
+assert 12 "int one() { return 1; } int two() { return 2; } int main() { int (*fp)() = one; int r = fp(); fp = two; return r * 10 + fp(); }"
+assert 21 "int last(int a, int b, int c, int d, int e, int f, int g, int h, int i, int j, int k) { return k + j; } int main() { int (*fp)(int, int, int, int, int, int, int, int, int, int, int) = last; return fp(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11); }"
+assert 9 "int add(int x, int y) { return x + y; } int main() { int (*fp)(int, int) = add; int (**pp)(int, int) = &fp; return (*pp)(4, 5); }"
+assert 31 "int add1(int x) { return x + 1; } int twice(int x) { return x * 2; } int main() { int (*t[2])(int) = {add1, twice}; int (**pp)(int) = t; int (*(*q)[2])(int) = &t; return t[0](3) + t[1](4) + pp[1](1) + (*q)[0](0) + sizeof(t); }"
+assert 7 "int add1(int x) { return x + 1; } int (*t[2][2])(int); int main() { t[1][0] = add1; return t[1][0](6); }"
+assert 5 "struct S { int (*op)(int, int); }; int sub(int x, int y) { return x - y; } int main() { struct S s; s.op = sub; return s.op(8, 3); }"
+assert 6 "int apply(int (*fs[2])(int), int x) { return fs[1](fs[0](x)); } int inc(int x) { return x + 1; } int dbl(int x) { return x * 2; } int main() { int (*fs[2])(int) = {inc, dbl}; return apply(fs, 2); }"
+assert 4 "int ret4() { return 4; } int main() { int (*f)(int) = ret4; int (*g)() = f; return g(); }"
+assert 5 "int id(int g) { return g; } int g() { return 5; } int main() { return id(g()); }"
+assert 4 "int two() { return 2; } int main() { int r; r = two(); { int two; two = r; r = two + 2; } return r; }"
+assert 6 "int main() { double x; x = 1.5; return x * 4; }"
+assert 5 "int main() { float f = 2.5f; double d = f * 2; return d; }"
+assert 3 "int main() { double x = 7; int y = x / 2; return y; }"
+assert 6 "int main() { return .5 * 1e1 + 2.5e-1 * 4; }"
+assert 3 "int main() { return 0x1.8p1; }"
+assert 10 "int main() { return 0XAP0 + 0x.8p-1f * 0; }"
+assert 12 "int main() { return 1.2E+1L; }"
+assert 10 "int main() { float f = 10; return f / 4 * 4; }"
+assert 0 "int main() { return 0.1 + 0.2 == 0.3; }"
+assert 1 "int main() { return 0.5f + 0.25f == 0.75; }"
+assert 1 "int main() { return 1.0 < 2.0; }"
+assert 0 "int main() { return 2.0 <= 1.5; }"
+assert 0 "int main() { return 1.5 != 1.5; }"
+assert 5 "int main() { double x = -2.5; return -x * 2; }"
+assert 10 "int main() { double x = 1.25; double *p = &x; *p = *p * 8; return x; }"
+assert 3 "int main() { double x = 0.5; if (x) return 3; return 4; }"
+assert 4 "int main() { double x; int n = 0; for (x = 0; x < 2; x = x + 0.5) n = n + 1; return n; }"
+assert 4 "double half(double x) { return x / 2; } int main() { return half(9); }"
+assert 10 "double f(int a, double b, int c, float d) { return a + b * c - d; } int main() { return f(1, 2.5, 4, 0.5f); }"
+assert 126 "double s(double a, double b, double c, double d, double e, double f, double g, double h, double i, double j) { return a + b + c + d + e + f + g + h + i * j; } int main() { return s(1, 2, 3, 4, 5, 6, 7, 8, 9, 10); }"
+assert 7 "double sum(int n, ...) { __builtin_va_list ap; __builtin_va_start(ap, n); double s = 0; for (int i = 0; i < n; i = i + 1) s = s + __builtin_va_arg(ap, double); __builtin_va_end(ap); return s; } int main() { return sum(3, 1.5, 2.5f, 3.0); }"
+assert 7 $'#include <stdarg.h>\nint vsum(int n, va_list ap) { int s = 0; for (int i = 0; i < n; i = i + 1) s = s + va_arg(ap, int); return s; }\nint sum(int n, ...) { va_list ap; va_list aq; va_start(ap, n); va_copy(aq, ap); int r = vsum(n, aq) + va_arg(ap, int); va_end(aq); va_end(ap); return r; }\nint main() { return sum(3, 1, 2, 3); }'
+assert 78 $'#include <stdarg.h>\nint sum(int n, ...) { va_list ap; va_start(ap, n); int s = 0; for (int i = 0; i < n; i = i + 1) s = s + va_arg(ap, int); va_end(ap); return s; }\nint main() { return sum(12, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12); }'
+assert 32 $'#include <stdarg.h>\ndouble mix(double scale, int n, ...) { va_list ap; va_start(ap, n); double s = 0; for (int i = 0; i < n; i = i + 1) { int k = va_arg(ap, int); s = s + k * va_arg(ap, double); } va_end(ap); return s * scale; }\nint main() { return mix(2.0, 10, 1, 1.0, 1, 1.0, 1, 1.0, 1, 1.0, 1, 1.0, 1, 1.0, 1, 1.0, 1, 1.0, 2, 1.5, 5, 1.0); }'
+assert 6 "double scale_add(double x, int n, float y); int main() { return scale_add(1.5, 4, 0.25f); }"
+assert 3 "float third(float (*f)(float), float x) { return f(x); } float id(float x) { return x; } int main() { return third(id, 3.75f); }"
+assert 0 "int g; int main() { return g; }"
+assert 5 "int g = 5; int main() { return g; }"
+assert 7 "int g; int main() { g = 7; return g; }"
+assert 9 "int x = 4, y = 5; int main() { return x + y; }"
+assert 6 "int g; int g = 6; int g; int main() { return g; }"
+assert 8 "int g = 2; int twice() { g = g * 2; return g; } int main() { twice(); return twice(); }"
+assert 8 "int g = 8; int *address() { return &g; } int main() { return *address(); }"
+assert 3 "static int s = 3; int main() { return s; }"
+assert 5 "static int twice(int x) { return x * 2; } int main() { return twice(2) + 1; }"
+assert 4 "static int next(); int main() { next(); return next(); } int next() { static int n = 2; n = n + 1; return n; }"
+assert 3 "int count() { static int n; n = n + 1; return n; } int main() { count(); count(); return count(); }"
+assert 12 "int a() { static int n = 1; n = n + 1; return n; } int b() { static int n = 10; return n; } int main() { a(); return a() + b() - 1; }"
+assert 6 "int main() { static int x = 6; { static int x = 2; x = x + 1; } return x; }"
+assert 5 "int main() { extern int g; return g; } int g = 5;"
+assert 7 "extern int g; int g = 7; int main() { return g; }"
+assert 3 "extern int f(int); int main() { return f(2); } int f(int x) { return x + 1; }"
+assert 3 "int f(int, double); int main() { return f(1, 2.0); } int f(int a, double b) { return a + b; }"
+assert 5 "int apply(int (*)(int), int); int inc(int x) { return x + 1; } int main() { return apply(inc, 4); } int apply(int (*f)(int), int x) { return f(x); }"
+assert 4 "static int g = 4; extern int g; int main() { return g; }"
+assert 3 "static int f(); int f() { return 3; } int main() { return f(); }"
+assert 24 "int a[]; int a[3]; int main() { return sizeof(a); }"
+assert 2 "double d = 1.5; float f = 0.5f; int main() { return d + f; }"
+assert 3 "double d = 3; float f = -2; int main() { return d - f - 2; }"
+assert 4 "double d; int main() { d = 4.25; return d; }"
+assert 3 "int main() { int x = {3}; return x; }"
+assert 5 "int main() { int x = {{5},}; return x; }"
+assert 4 "int g = {4}; int main() { return g; }"
+assert 2 "double d = {2.5}; int main() { return d; }"
+assert 6 "int main() { int a[] = {1, 2, 3}; return a[0] + a[1] + a[2]; }"
+assert 24 "int main() { int a[] = {1, 2, 3}; return sizeof a; }"
+assert 4 "int main() { int a[5] = {[3] = 4}; return a[0] + a[1] + a[2] + a[3] + a[4]; }"
+assert 10 "int main() { int a[5] = {1, [3] = 4, 5}; return a[0] + a[3] + a[4] + a[1]; }"
+assert 56 "int main() { int a[] = {[6] = 1}; return sizeof a; }"
+assert 10 "int main() { int a[2][2] = {{1, 2}, {3, 4}}; return a[0][0] + a[0][1] + a[1][0] + a[1][1]; }"
+assert 4 "int main() { int a[2][2] = {1, 2, 3, 4}; return a[1][1]; }"
+assert 3 "int main() { int a[2][2] = {{1}, {3}}; return a[0][1] + a[1][0] + a[1][1]; }"
+assert 7 "int main() { int a[2][3] = {[1][2] = 7}; return a[1][2] + a[0][0]; }"
+assert 2 "struct P { int x; int y; }; int main() { struct P p = {.y = 2}; return p.x + p.y; }"
+assert 12 "struct P { int x; int y; }; int main() { struct P p = {1, 2}; return p.x * 10 + p.y; }"
+assert 12 "struct P { int x; int y; }; int main() { struct P p = {.y = 2, .x = 1}; return p.x * 10 + p.y; }"
+assert 5 "struct P { int x; int y; }; int main() { struct P p[2] = {{1, 2}, [1].y = 4}; return p[0].x + p[1].y + p[1].x; }"
+assert 5 "struct P { int x; int y; }; int main() { struct P p[2] = {1, 2, 3}; return p[1].x + p[0].y + p[1].y; }"
+assert 9 "struct S { int n; int v[3]; }; int main() { struct S s = {1, 2, 3}; return s.n + s.v[0] + s.v[1] + s.v[2] + 3; }"
+assert 9 "struct S { int n; int v[3]; }; int main() { struct S s = {.v[2] = 9}; return s.v[2] + s.n; }"
+assert 8 "struct S { int n; int v[3]; }; int main() { struct S s = {.v[1] = 5, 3}; return s.v[1] + s.v[2]; }"
+assert 3 "int main() { double d[2] = {1.5, 1.5}; return d[0] + d[1]; }"
+assert 3 "int main() { float f[3] = {0.5f, 1, 1.5}; return f[0] + f[1] + f[2]; }"
+assert 2 "int main() { _Bool b[3] = {1, 0, 5}; return b[0] + b[1] + b[2]; }"
+assert 7 "int main() { struct { unsigned a : 3; unsigned b : 5; } s = {5, 2}; return s.a + s.b; }"
+assert 1 "int main() { struct { int a : 3; int b; } s = {-1, 2}; return s.a + s.b; }"
+assert 0 "int main() { int a[3] = {}; return a[0] + a[1] + a[2]; }"
+assert 5 "int main() { int x = 2; int a[2] = {x, x + 1}; return a[0] + a[1]; }"
+assert 3 "int main() { int a[3] = {1, 2, 3}; int *p = a; int b[2] = {*p, p[1]}; return b[0] + b[1]; }"
+assert 4 "int main() { int a[4] = {[0] = 9, [0] = 4}; return a[0]; }"
+assert 6 "int a[] = {1, 2, 3}; int main() { return a[0] + a[1] + a[2]; }"
+assert 24 "int a[] = {1, 2, 3}; int main() { return sizeof a; }"
+assert 4 "int a[5] = {[3] = 4}; int main() { return a[0] + a[1] + a[2] + a[3] + a[4]; }"
+assert 2 "struct P { int x; int y; }; struct P g = {.y = 2}; int main() { return g.x + g.y; }"
+assert 10 "int a[2][2] = {{1, 2}, {3, 4}}; int main() { return a[0][0] + a[0][1] + a[1][0] + a[1][1]; }"
+assert 3 "double d[2] = {1.5, 1.5}; int main() { return d[0] + d[1]; }"
+assert 3 "float f[3] = {0.5f, 1, 1.5}; int main() { return f[0] + f[1] + f[2]; }"
+assert 2 "_Bool b[3] = {1, 0, 5}; int main() { return b[0] + b[1] + b[2]; }"
+assert 7 "struct { unsigned a : 3; unsigned b : 5; } s = {5, 2}; int main() { return s.a + s.b; }"
+assert 5 "int g = 5; int *p[2] = {0, &g}; int main() { return *p[1]; }"
+assert 3 "int a[3] = {1, 2, 3}; int *p = &a[1] + 1; int main() { return *p; }"
+assert 7 "struct P { int x; int y; }; struct P g = {1, 2}; int *p = &g.y; int main() { *p = 7; return g.y; }"
+assert 104 "int main() { char s[] = \"hi\"; return s[0]; }"
+assert 3 "int main() { char s[] = \"hi\"; return sizeof(s); }"
+assert 0 "int main() { char s[5] = \"hi\"; return s[2] + s[4]; }"
+assert 99 "int main() { char s[3] = \"abc\"; return s[2]; }"
+assert 98 "int main() { char s[] = {\"ab\"}; return s[1]; }"
+assert 111 "char s[] = \"foo\"; int main() { return s[2]; }"
+assert 4 "char s[] = \"foo\"; int main() { return sizeof(s); }"
+assert 98 "struct T { int n; char name[4]; }; int main() { struct T t = {1, \"abc\"}; return t.name[1]; }"
+assert 100 "struct T { int n; char name[4]; }; struct T g[] = {{1, \"ab\"}, {2, \"cd\"}}; int main() { return g[1].name[1]; }"
+assert 98 "struct T { char a[3]; char b[3]; }; int main() { struct T t = {.b = \"bc\"}; return t.b[0] + t.a[0]; }"
+assert 4 "int main() { return sizeof(\"abc\"); }"
+assert 6 "int main() { return sizeof(\"ab\" \"cde\"); }"
+assert 99 "int main() { return \"abc\"[2]; }"
+assert 10 "int main() { char *s = \"a\nb\"; return s[1]; }"
+assert 122 "char *p = \"xyz\"; int main() { return p[2]; }"
+assert 1 "int main() { char *a = \"ab\"; char *b = \"ab\"; return a != b; }"
+assert 7 "int count(const char *s) { int n = 0; while (*s) { n = n + 1; s = s + 1; } return n; } int main() { return count(\"rustyc!\"); }"
+assert 9 "int main() { static int a[] = {4, 5}; return a[0] + a[1]; }"
+assert 3 "int g = 3; int *p = &g; int main() { return *p; }"
+assert 7 "int g; int *p = &g; int main() { *p = 7; return g; }"
+assert 2 "int g; int *p = &g + 2; int main() { return p - &g; }"
+assert 1 "int g; int *p = &g + 3 - 2; int main() { return p - &g; }"
+assert 5 "static int g = 5; int *p = &g; int main() { return *p; }"
+assert 6 "int main() { static int x = 6; static int *p = &x; return *p; }"
+assert 9 "int nine() { return 9; } int (*f)() = nine; int main() { return f(); }"
+assert 8 "int eight() { return 8; } int (*f)() = &eight; int main() { return (*f)(); }"
+assert 7 "int g = 2 * 3 + 1; int main() { return g; }"
+assert 3 "int g = 7 / 2; int main() { return g; }"
+assert 1 "int g = -3 < 2; int main() { return g; }"
+assert 2 "int g = 2.9; int main() { return g; }"
+assert 3 "double d = 7 / 2; int main() { return d; }"
+assert 2 "double d = 1 / 4.0 * 8; int main() { return d; }"
+assert 5 "float f = 1.75f * 2 + 1.5; int main() { return f; }"
+assert 3 "int g; int d = &g + 3 - &g; int main() { return d; }"
+assert 1 "int g; int *p = &g + 4 - 3; int main() { return p - &g; }"
+assert 6 "int main() { switch (6) { case 2 * 3: return 6; case 6 / 2: return 3; } return 0; }"
+assert 3 "int main() { const int x = 3; return x; }"
+assert 4 "int main() { int const x = 4; return x + 0; }"
+assert 5 "int main() { int x = 2; const int *p = &x; x = 5; return *p; }"
+assert 6 "int main() { int x = 1; int *const p = &x; *p = 6; return x; }"
+assert 7 "int main() { volatile int x = 3; x = x + 4; return x; }"
+assert 8 "int main() { int x = 8; int *restrict p = &x; return *p; }"
+assert 9 "int main() { const volatile int x = 9; const int *const *p; const int *q = &x; p = &q; return **p; }"
+assert 3 "const int g = 3; int main() { return g; }"
+assert 4 "int get(const int *p) { return *p; } int main() { int x = 4; return get(&x); }"
+assert 5 "int id(const int x) { return x; } int main() { return id(5); }"
+assert 6 "int main() { const double d = 6.5; return d; }"
+assert 7 "int main() { for (const int x = 7; ;) return x; }"
+assert 3 "int g; void set(int x) { g = x; } int main() { set(3); return g; }"
+assert 4 "int g; void set(int x) { if (x > 3) { g = x; return; } g = 0; } int main() { set(4); return g; }"
+assert 5 "int five(void) { return 5; } int main() { return five(); }"
+assert 6 "void nothing(void) {} int main() { nothing(); return 6; }"
+assert 7 "int main() { int x = 7; void *p = &x; int *q = p; return *q; }"
+assert 8 "int get(void *p) { int *q = p; return *q; } int main() { int x = 8; return get(&x); }"
+assert 9 "int nine(void) { return 9; } int main() { int (*f)(void) = nine; return f(); }"
+assert 2 "int g; void bump(void) { g = g + 1; } int main() { void (*f)(void) = bump; f(); f(); return g; }"
+assert 1 "int main() { _Bool b = 5; return b; }"
+assert 0 "int main() { _Bool b = 0; return b; }"
+assert 1 "int main() { _Bool b; b = 256; return b; }"
+assert 1 "int main() { _Bool b = 0.5; return b; }"
+assert 0 "int main() { _Bool b = 0.0; return b; }"
+assert 1 "int main() { int x; _Bool b = &x; return b; }"
+assert 2 "int main() { _Bool a = 3; _Bool b = 7; return a + b; }"
+assert 1 "int main() { _Bool b = 2; _Bool *p = &b; *p = 9; return *p; }"
+assert 1 "_Bool g = 42; int main() { return g; }"
+assert 0 "_Bool g; int main() { return g; }"
+assert 1 "_Bool truth(int x) { return x; } int main() { return truth(-3); }"
+assert 3 "int main() { int x = 0; int *p = &x; if (p) x = 3; return x; }"
+assert 4 "int main() { int n = 4; int *p = 0; while (p) n = 0; return n; }"
+assert 5 "int main() { _Bool b = 1; if (b) return 5; return 0; }"
+assert 7 "int main() { double d = 0.25; if (d) return 7; return 0; }"
+assert 255 "int main() { _Bool b = 1; return -b; }"
+assert 3 "int main() { return (int){3}; }"
+assert 4 "int main() { int *p = &(int){4}; return *p; }"
+assert 5 "int main() { int *p = &(int){1}; *p = 5; return *p; }"
+assert 6 "int main() { return (int){2} + (int){4,}; }"
+assert 7 "int main() { double d = (double){7.5}; return d; }"
+assert 1 "int main() { return (_Bool){9}; }"
+assert 8 "int get(int *p) { return *p; } int main() { return get(&(const int){8}); }"
+assert 9 "int main() { int s = 0; for (int i = 0; i < 3; i = i + 1) s = s + *&(int){i + 2}; return s; }"
+assert 3 "struct point { int x; int y; }; int main() { struct point p = (struct point){1, 2}; return p.x + p.y; }"
+assert 2 "struct point { int x; int y; }; int main() { return (struct point){1, 2}.y; }"
+assert 6 "int main() { int *p = (int[]){1, 2, 3}; return p[0] + p[1] + p[2]; }"
+assert 24 "int main() { return sizeof (int[]){1, 2, 3}; }"
+assert 5 "int main() { return (int[3]){[2] = 5}[2]; }"
+assert 4 "struct point { int x; int y; }; int main() { struct point *p = &(struct point){.y = 4}; return p->x + p->y; }"
+assert 7 "struct point { int x; int y; }; int main() { struct point p = {3, 4}; struct point q = p; p.x = 0; return q.x + q.y; }"
+assert 9 "struct point { int x; int y; }; int main() { struct point p = {4, 5}; struct point q; q = p; p.y = 0; return q.x + q.y; }"
+assert 8 "struct point { int x; int y; }; int main() { struct point p = {4, 4}; struct point q; struct point r; r = q = p; return r.x + r.y; }"
+assert 6 "struct point { int x; int y; }; int main() { struct point p; p = (struct point){2, 4}; return p.x + p.y; }"
+assert 11 "struct point { int x; int y; }; struct line { struct point a; struct point b; }; int main() { struct point p = {1, 2}; struct line l = {p, {3, 5}}; return l.a.x + l.a.y + l.b.x + l.b.y; }"
+assert 5 "struct point { int x; int y; }; struct line { struct point a; struct point b; }; int main() { struct line l = {{1, 2}}; l.b = l.a; l.b.y = 3; return l.a.y + l.b.y; }"
+assert 3 "struct point { int x; int y; }; int main() { struct point a[2] = {{1, 2}}; struct point *p = &a[1]; *p = a[0]; return p->x + p->y; }"
+assert 6 "struct point { int x; _Bool b; int y; }; int main() { struct point p = {1, 1, 4}; struct point q = p; return q.x + q.b + q.y; }"
+assert 3 "struct point { int x; int y; }; struct point g = (struct point){1, 2}; int main() { return g.x + g.y; }"
+assert 7 "struct point { int x; int y; }; struct point g; int main() { struct point p = {3, 4}; g = p; return g.x + g.y; }"
+assert 10 "int main() { return ({ int t = 5; t * 2; }); }"
+assert 11 "int f() { return 10; } int main() { return ({ int t = f(); t + 1; }) ; }"
+assert 12 "int main() { int x = 2; int y = ({ x = x + 1; x * 4; }); return y; }"
+assert 13 "int main() { int a = 3; return ({ int a = 10; a; }) + a; }"
+assert 14 "int main() { int n = 0; ({ n = 14; }); return n; }"
+assert 15 "int main() { return 5 + ({ int s = 0; for (int i = 0; i < 5; i = i + 1) s = s + 2; s; }); }"
+assert 16 "int main() { return ({ int t = 4; ({ int u = t * 4; u; }); }); }"
+assert 17 "int main() { int i = 0; while (({ i = i + 1; i < 17; })) {} return i; }"
+assert 10 "int main() { return ({ int s = 0; for (int i = 0; i < 5; i = i + 1) { if (i == 1) continue; if (i == 3) break; s = s + 5; } s; }); }"
+assert 6 "int main() { return ({ int s = 0; switch (2) { case 2: s = 6; break; default: s = 1; } s; }); }"
+assert 1 "int main() { int r = ({ int v = 1; goto skip; v = 2; skip: ; v; }); return r; }"
+assert 3 "_Static_assert(1, \"ok\"); int main() { return 3; }"
+assert 4 "int main() { _Static_assert(2 + 2 == 4, \"math\"); return 4; }"
+assert 5 "int main() { int x = 5; _Static_assert(1 < 2, \"con\" \"cat\"); return x; }"
+assert 3 "_Static_assert(sizeof(int) - 9 > 0, \"\"); int main() { return 3; }"
+assert 4 "int main() { _Static_assert(0 - sizeof(char) == 18446744073709551615, \"\"); return 4; }"
+assert 7 "int main() { switch (sizeof(int) - 9) { case 0 - sizeof(char): return 7; } return 0; }"
+assert 2 "int main() { char a[0 - sizeof(int) + 10]; return sizeof(a); }"
+assert 44 "char g = 300; int main() { return g; }"
+assert 255 "unsigned char g = -1; int main() { return g; }"
+assert 1 "_Bool g = 256; int main() { return g; }"
+assert 6 "_Static_assert(1, \"a\"); int g = 6; _Static_assert(1 != 2, \"b\"); int main() { return g; }"
+assert 1 "int main() { return _Generic(1, int: 1, double: 2, default: 3); }"
+assert 2 "int main() { return _Generic(1.5, int: 1, double: 2, default: 3); }"
+assert 3 "int main() { float f = 1; return _Generic(f, int: 1, double: 2, default: 3); }"
+assert 4 "int main() { int x; return _Generic(&x, int *: 4, int: 5); }"
+assert 6 "int main() { const int c = 1; return _Generic(c, int: 6, default: 7); }"
+assert 8 "int main() { _Bool b = 1; return _Generic(b, _Bool: 8, int: 9); }"
+assert 10 "int main() { const int *p = 0; return _Generic(p, int *: 9, const int *: 10); }"
+assert 11 "int main() { int x = 1; _Generic(x, int: x = 11, default: x = 0); return x; }"
+assert 12 "int f(int x) { return x; } int main() { return _Generic(f, int: 1, default: f(12)); }"
+assert 13 "int main() { return _Generic(1, default: 13); }"
+
+assert 3 "int main() { struct { int a; int b; } s; s.a = 1; s.b = 2; return s.a + s.b; }"
+assert 16 "int main() { struct P { int x; _Bool y; }; return sizeof(struct P); }"
+assert 7 "struct P { int x; int y; }; int main() { struct P p; struct P *q = &p; q->y = 7; return p.y; }"
+assert 4 "struct N { int v; struct N *next; }; int main() { struct N a, b; a.v = 1; b.v = 3; a.next = &b; return a.v + a.next->v; }"
+assert 5 "struct S { int a; }; struct S g; int main() { g.a = 5; return g.a; }"
+assert 8 "int main() { int x; return sizeof x; }"
+assert 1 "int main() { unsigned x = 0; return x - 1 > 0; }"
+assert 0 "int main() { int x = 0; return x - 1 > 0; }"
+assert 4 "int main() { unsigned x = 9; return x / 2; }"
+assert 2 "int main() { double d = 2.5; unsigned u = d; return u; }"
+assert 1 "int main() { return sizeof(char); }"
+assert 255 "int main() { unsigned char c = 255; return c; }"
+assert 1 "int main() { char c = 255; return c == -1; }"
+assert 1 "int main() { signed char c = 128; return c < 0; }"
+assert 44 "int main() { char c = 300; return c; }"
+assert 3 "char f(char c) { return c + 1; } int main() { return f(2); }"
+assert 24 "struct S { char a; int b; char c; }; int main() { return sizeof(struct S); }"
+assert 1 "char g = -1; int main() { return g < 0; }"
+assert 0 "int main() { char c = -3; double d = c; return d != -3; }"
+
+assert 5 "int main() { struct { unsigned a : 3; unsigned b : 5; } s; s.a = 5; s.b = 17; return s.a; }"
+assert 17 "int main() { struct { unsigned a : 3; unsigned b : 5; } s; s.a = 5; s.b = 17; return s.b; }"
+assert 1 "int main() { struct { unsigned a : 3; } s; s.a = 9; return s.a; }"
+assert 1 "int main() { struct { int a : 3; } s; s.a = 7; return s.a == -1; }"
+assert 3 "int main() { struct { unsigned a : 2; } s; return s.a = 7; }"
+assert 6 "int main() { struct { unsigned a : 4, b : 4; } s; s.a = 0; s.b = 0; s.a = 15; s.b = 6; return s.b; }"
+assert 8 "int main() { struct { unsigned a : 4, b : 4; } s; return sizeof s; }"
+assert 16 "int main() { struct { unsigned a : 60; unsigned b : 8; } s; return sizeof s; }"
+assert 16 "int main() { struct { unsigned a : 1; unsigned : 0; unsigned b : 1; } s; return sizeof s; }"
+assert 1 "int main() { struct { _Bool a : 1; _Bool b : 1; } s; return sizeof s; }"
+assert 1 "int main() { struct { _Bool a : 1; _Bool b : 1; } s; s.a = 0; s.b = 5; return s.b + s.a; }"
+assert 2 "int main() { struct { unsigned a : 60; unsigned b : 8; } s; s.a = 1; s.b = 1; s.a = s.a + s.b; return s.a; }"
+
+assert 3 "int main() { int a[2]; a[0] = 1; a[1] = 2; return a[0] + a[1]; }"
+assert 24 "int main() { int a[3]; return sizeof a; }"
+assert 3 "int main() { _Bool a[3]; return sizeof(a); }"
+assert 5 "int main() { int a[3]; *(a + 2) = 5; return 2[a]; }"
+assert 6 "int main() { int a[4]; for (int i = 0; i < 4; i = i + 1) a[i] = i; int s = 0; for (int i = 0; i < 4; i = i + 1) s = s + a[i]; return s; }"
+assert 12 "int main() { int m[2][3]; m[1][2] = 12; return m[1][2]; }"
+assert 48 "int main() { int m[2][3]; return sizeof m; }"
+assert 24 "int main() { int m[2][3]; return sizeof m[1]; }"
+assert 6 "int main() { int m[2][3]; int (*r)[3] = m; r[1][0] = 6; return m[1][0]; }"
+assert 7 "int f(int a[]) { return a[1]; } int main() { int a[2]; a[1] = 7; return f(a); }"
+assert 8 "int f(int a[2]) { return sizeof a; } int main() { int a[2]; return f(a); }"
+assert 9 "struct S { int n; int v[3]; }; int main() { struct S s; s.v[2] = 9; return s.v[2]; }"
+assert 32 "struct S { int n; int v[3]; }; int main() { return sizeof(struct S); }"
+assert 4 "int g[5]; int main() { g[4] = 4; return g[4]; }"
+assert 2 "int g[3]; int *p = g + 1; int main() { *p = 2; return g[1]; }"
+assert 40 "int main() { return sizeof(int[5]); }"
+assert 1 "int main() { int a[2]; return &a[1] - a; }"
+assert 11 "int main() { int a[3]; int *p = a; p[0] = 11; return *a; }"
+assert 15 "int main() { int n = 5; int a[n]; for (int i = 0; i < n; i = i + 1) a[i] = i; int s = 0; for (int i = 0; i < n; i = i + 1) s = s + a[i]; return s + 5; }"
+assert 40 "int main() { int n = 5; int a[n]; return sizeof a; }"
+assert 24 "int main() { int n = 3; return sizeof(int[n]); }"
+assert 16 "int main() { int n = 3; _Bool a[n]; _Bool b[n]; return a - b; }"
+assert 3 "int main() { int n = 3; _Bool a[n]; return sizeof a; }"
+assert 6 "int main() { int n = 2; int a[n][3]; a[1][2] = 6; return a[1][2]; }"
+assert 48 "int main() { int n = 2; int a[n][3]; return sizeof a; }"
+assert 0 "int main() { int *p; int *q; int n = 3; for (int i = 0; i < 100; i = i + 1) { int a[n]; if (i == 0) p = a; q = a; } return p - q; }"
+assert 0 "int main() { int *p; int *q; int n = 8; for (int i = 0; i < 100; i = i + 1) { int a[n]; if (i == 0) p = a; q = a; if (i > 50) continue; n = n; } return p - q; }"
+assert 0 "int main() { int *p; int *q; int n = 8; while (1) { int a[n]; p = a; break; } { int b[n]; q = b; } return p - q; }"
+assert 9 "int main() { int n = 3; return ({ int a[n]; a[2] = 9; a[2]; }); }"
+assert 10 "int f(int n) { int i = 0; int s = 0; again: { int v[n]; v[0] = i; s = s + v[0]; i = i + 1; if (i < 5) goto again; } return s; } int main() { return f(1000); }"
+assert 3 "int main() { int n = 4; int c = 0; int *p; loop: { int v[n]; if (c == 0) p = v; if (p != v) return 99; c = c + 1; if (c < 3) goto loop; } return c; }"
+assert 3 "int main() { int n = 4; int c = 0; int *p; back: ; int v[n]; if (c == 0) p = v; if (p != v) return 99; c = c + 1; if (c < 3) goto back; return c; }"
+assert 1 "int main() { int n = 2; int *p; int *q; { int v[n]; p = v; goto out; } out: ; int w[n]; q = w; return p == q; }"
+assert 9 "int main() { int n = 2; int r = 0; for (int i = 0; i < 3; i = i + 1) { int v[n]; v[0] = i; switch (i) { case 0: r = r + 1; break; default: { int u[n]; u[0] = 4; r = r + u[0]; goto next; } } next: ; } return r; }"
+assert 21 "int sum(int n, int a[n]) { int s = 0; for (int i = 0; i < n; i = i + 1) s = s + a[i]; return s; } int main() { int n = 6; int a[n]; for (int i = 0; i < n; i = i + 1) a[i] = i + 1; return sum(n, a); }"
+assert 7 "int main() { int *p = __builtin_alloca(16); p[0] = 3; p[1] = 4; return p[0] + p[1]; }"
+assert 16 "int main() { int *p = __builtin_alloca(5); int *q = __builtin_alloca(5); return (p - q) * 8; }"
+assert 5 "int f(int a, int *b) { *b = a; return a + *b - a; } int main() { return f(5, __builtin_alloca(8)); }"
+assert 13 "int main() { int x = 3; return x + ({ int *p = __builtin_alloca(8); *p = 10; *p; }); }"
+
+assert 3 "int main() { int x; x=3; return *&x; }"
+assert 3 "int main() { int x; int *y; int **z; x=3; y=&x; z=&y; return **z; }"
+assert 5 "int main() { int x, y; x=3; y=5; return *(&x+1); }"
+assert 3 "int main() { int x, y; x=3; y=5; return *(&y-1); }"
+assert 5 "int main() { int x, y; x=3; y=5; return *(&x-(-1)); }"
+assert 5 "int main() { int x, *y; x=3; y=&x; *y=5; return x; }"
+assert 7 "int main() { int x, y; x=3; y=5; *(&x+1)=7; return y; }"
+assert 7 "int main() { int x, y; x=3; y=5; *(&y-2+1)=7; return x; }"
+assert 5 "int main() { int x; x=3; return (&x+2)-&x+3; }"
+assert 3 $'#define THREE 3\nint main() { return THREE; }'
+assert 6 $'#define SIX TWO * THREE\n#define TWO 2\n#define THREE 3\nint main() { return SIX; }'
+assert 4 $'#define ret3 ret3() + 1\nint main() { return ret3; }'
+assert 3 $'#define EMPTY\nint main() { EMPTY return 3 EMPTY; }'
+assert 2 $'#define VALUE 1\n#undef VALUE\n#define VALUE 2\nint main() { return VALUE; }'
+assert 8 $'#define LONG_VALUE 3 + \\\n 5\nint main() { return LONG_VALUE; }'
+assert 1 $'#define FLAG\n#ifdef FLAG\nint main() { return 1; }\n#else\nint main() { return 2; }\n#endif'
+assert 2 $'#ifndef FLAG\nint main() { return 2; }\n#else\nint main() { return 1; }\n#endif'
+assert 3 $'#ifdef OUTER\n#ifdef INNER\n#error\n#endif\n#else\nint main() { return 3; }\n#endif'
+assert 3 $'#\nint main() { return 3; }'
+assert 5 $'#include "include/guarded.h"\nint main() { return FIVE; }'
+assert 5 -I include $'#include <guarded.h>\n#include "include/guarded.h"\nint main() { return FIVE; }'
+assert 12 -I include $'#include <nested.h>\n#include <guarded.h>\nint main() { return guarded_add(SEVEN, FIVE); }'
+assert 9 -I include $'#define HEADER <nested.h>\n#include HEADER\nint main() { return SEVEN + 2; }'
+assert 3 $'#include <stdbool.h>\nbool f(int x) { return x; }\nint main() { bool b = 5; if (false) return 0; if (b == true) return f(7) + __bool_true_false_are_defined + sizeof(bool); return 0; }'
+assert 7 -I include $'#include <stdbool.h>\n#include "stdbool.h"\nint main() { return true + 6; }'
+assert 1 $'#define X 1 /* c */\nint main() { return X; }'
+assert 2 $'int main() { /* c */ return 2; } // trailing'
+assert 3 $'int main() { ret\\\nurn 3; }'
+assert 4 $'#if 0 /* hides\n#endif\n*/\nint main() { return 0; }\n#else\nint main() { return 4; }\n#endif'
+assert 5 $'#define F(a, b) a /* x */ + \\\n b\nint main() { return F(2, 3); }'
+assert 6 $'int main() { return 6; } /* "unterminated string\n*/'
+assert 7 $'#define ADD(a, b) ((a) + (b))\nint main() { return ADD(3, 4); }'
+assert 9 $'#define SQUARE(x) ((x) * (x))\n#define ADD(a, b) ((a) + (b))\nint main() { return SQUARE(ADD(1, 2)); }'
+assert 6 $'#define ADD(a, b) ((a) + (b))\nint main() { return ADD(add(1, 2), 3); }'
+assert 3 $'#define ADD(a, b) ((a) + (b))\nint main() { return ADD(1,\n 2); }'
+assert 4 $'#define TWO 2\n#define ADD(a, b) ((a) + (b))\nint main() { return ADD(TWO, TWO); }'
+assert 5 $'#define f(x) x\nint main() { int f = 5; return f; }'
+assert 6 $'#define SIX() 6\nint main() { return SIX(); }'
+assert 4 $'#define ret3() ret3() + 1\nint main() { return ret3(); }'
+assert 36 $'#define f(a) a*g\n#define g(a) f(a)\nint main() { int g = 2; return f(2)(9); }'
+assert 7 $'#define CAT(a, b) a ## b\nint main() { int xy = 7; return CAT(x, y); }'
+assert 12 $'#define CAT(a, b) a##b\nint main() { return CAT(1, 2); }'
+assert 5 $'#define CAT(a, b) a ## b\nint main() { return CAT(, 5) + CAT(,) 0; }'
+assert 12 $'#define CAT(a, b) a ## b\n#define XCAT(a, b) CAT(a, b)\n#define ONE 1\nint main() { return XCAT(ONE, 2); }'
+assert 3 $'#define CAT(a, b) a ## b\n#define ONE 1\nint main() { int ONE2 = 3; return CAT(ONE, 2); }'
+assert 9 $'#define CAT3(a, b, c) a ## b ## c\nint main() { int abc = 9; return CAT3(a, b, c); }'
+assert 1 $'#define ARROW(p, m) p -## > m\nstruct S { int m; };\nint main() { struct S s; s.m = 1; struct S *p = &s; return ARROW(p, m); }'
+assert 6 $'#define SUM(n, ...) sum_ints(n, __VA_ARGS__)\nint sum_ints(int count, ...);\nint main() { return SUM(3, 1, 2, 3); }'
+assert 10 $'#define CALL(...) __VA_ARGS__\nint main() { return CALL(add)(CALL(4, 6)); }'
+assert 3 $'#define CALL(f, ...) f(__VA_OPT__(__VA_ARGS__))\nint main() { return CALL(ret3); }'
+assert 5 $'#define CALL(f, ...) f(__VA_OPT__(__VA_ARGS__))\nint main() { return CALL(add, 2, 3); }'
+assert 0 $'#define COUNT(n, ...) sum_ints(n __VA_OPT__(,) __VA_ARGS__)\nint sum_ints(int count, ...);\nint main() { return COUNT(0); }'
+assert 0 $'#define EMPTY\n#define COUNT(n, ...) sum_ints(n __VA_OPT__(,) __VA_ARGS__)\nint sum_ints(int count, ...);\nint main() { return COUNT(0, EMPTY); }'
+assert 0 $'#define COUNT(n, ...) sum_ints(n, ##__VA_ARGS__)\nint sum_ints(int count, ...);\nint main() { return COUNT(0); }'
+assert 9 $'#define COUNT(n, ...) sum_ints(n, ##__VA_ARGS__)\nint sum_ints(int count, ...);\nint main() { return COUNT(2, 4, 5); }'
+assert 8 $'#define LOG(fmt, ...) sum_ints(fmt, ## __VA_ARGS__)\nint sum_ints(int count, ...);\nint main() { return LOG(1, 8); }'
+assert 3 $'#define F(x, ...) 0 __VA_OPT__(+ x ## __VA_ARGS__)\nint main() { int ab = 3; return F(1) + F(a, b); }'
+assert 1 $'#if 1\nint main() { return 1; }\n#else\nint main() { return 2; }\n#endif'
+assert 2 $'#if 0\nint main() { return 1; }\n#else\nint main() { return 2; }\n#endif'
+assert 3 $'#if 0\nint main() { return 1; }\n#elif 0\nint main() { return 2; }\n#elif 1\nint main() { return 3; }\n#else\nint main() { return 4; }\n#endif'
+assert 1 $'#if 1\nint main() { return 1; }\n#elif 1 / 0\nint main() { return 2; }\n#endif'
+assert 4 $'#if 0\n#if 1\nint main() { return 1; }\n#elif 1\nint main() { return 2; }\n#endif\n#elif 1\nint main() { return 4; }\n#endif'
+assert 5 $'#define FIVE 5\n#if FIVE == 5 && defined FIVE && defined(FIVE) && !defined UNKNOWN\nint main() { return 5; }\n#endif'
+assert 6 $'#if UNKNOWN == 0 && !UNKNOWN_FUNCTION\nint main() { return 6; }\n#endif'
+assert 7 $'#if (2 + 3 * 4) % 8 == 6 && (1 << 4) >> 2 == 4 && (6 & 3 | 8 ^ 1) == 11 && ~0 == -1\nint main() { return 7; }\n#endif'
+assert 8 $'#if -1 < 0 && !(-1 < 0u) && 0xFFFFFFFFFFFFFFFF > 0 && -1 / 2 == 0 && -7 % 3 == -1\nint main() { return 8; }\n#endif'
+assert 9 $'#if 0 && 1 / 0 || 1 || 1 % 0\nint main() { return 9; }\n#endif'
+assert 10 $'#if (1 ? 2 : 1 / 0) == 2 && (0 ? 1 / 0 : 3) == 3 && (1 ? -1 : 0u) > 0\nint main() { return 10; }\n#endif'
+assert 11 $'#if __STDC_VERSION__ >= 201112L || 017 == 15 && 0x1f == 31\nint main() { return 11; }\n#endif'
+assert 12 $'#define IS(x) (x + 0 == 12)\n#if IS(12) && IS()== 0\nint main() { return 12; }\n#endif'
+assert 13 $'#if 0\n#garbage \'\n#else\nint main() { return 13; }\n#endif'
+assert 14 -DVALUE=14 $'int main() { return VALUE; }'
+assert 1 -DFLAG $'int main() { return FLAG; }'
+assert 15 -DFLAG -UFLAG $'#ifdef FLAG\nint main() { return 1; }\n#else\nint main() { return 15; }\n#endif'
+assert 16 -UFLAG -DFLAG=16 $'int main() { return FLAG; }'
+assert 17 -D 'ADD(a,b)=a+b' $'int main() { return ADD(8, 9); }'
+assert 18 -DEMPTY= $'int main() { return 18 EMPTY; }'
+assert 2 $'\nint main() { return __LINE__; }'
+assert 3 $'#define LINE __LINE__\n\nint main() { return LINE; }'
+assert 1 $'int main() { return __STDC__; }'
+assert 1 $'#if __STDC_VERSION__ >= 201112L\nint main() { return 1; }\n#endif'
+assert 1 $'#if defined(__aarch64__) && defined(__APPLE__) && !defined(__linux__)\nint main() { return 1; }\n#endif'
+assert_preprocessed 'int main() { return 1; }' --target aarch64-unknown-linux-gnu $'#if defined(__aarch64__) && defined(__linux__) && !defined(__APPLE__)\nint main() { return 1; }\n#endif'
+assert_assembled aarch64-unknown-linux-gnu 'main:' 'int main() { return 0; }'
+assert_assembled aarch64-unknown-linux-gnu '  bl add' 'int add(int a, int b) { return a + b; } int main() { return add(1, 2); }'
+assert_assembled aarch64-unknown-linux-gnu '  ldr x0, [x0, :got_lo12:g]' 'int g; int main() { return g; }'
+assert_assembled aarch64-unknown-linux-gnu '  add x0, x0, :lo12:s' 'static int s = 3; int main() { return s; }'
+assert_assembled aarch64-unknown-linux-gnu '.zero 16' 'double d[2]; int main() { return 0; }'
+assert_assembled aarch64-unknown-linux-gnu '  .quad 0x3' 'int a[] = {1, 2, 3}; int main() { return 0; }'
+assert_assembled aarch64-unknown-linux-gnu '  .zero 56' 'int a[8] = {[7] = 4}; int main() { return 0; }'
+assert_assembled aarch64-unknown-linux-gnu '  .long 0x3fc00000' 'float f[2] = {0, 1.5f}; int main() { return 0; }'
+assert_assembled aarch64-unknown-linux-gnu '  .byte 0x1' '_Bool b[3] = {1, 0, 1}; int main() { return 0; }'
+assert_assembled aarch64-unknown-linux-gnu '  .quad g+8' 'int g; int *p[3] = {0, &g, &g + 1}; int main() { return 0; }'
+assert_assembled aarch64-unknown-linux-gnu '  .quad 0x15' 'struct { unsigned a : 3; unsigned b : 5; } s = {5, 2}; int main() { return 0; }'
+assert_assembled aarch64-unknown-linux-gnu '.section .rodata' 'int main() { double d = 1.5; return d; }'
+assert_assembled aarch64-apple-darwin '_main:' 'int main() { return 0; }'
+assert_assembled aarch64-unknown-linux-gnu '  stp q6, q7, [fp, #-96]' 'int f(int n, ...) { return n; } int main() { return f(1, 2); }'
+assert_assembled aarch64-unknown-linux-gnu '  ldrsw x2, [x1, #28]' $'#include <stdarg.h>\ndouble f(int n, ...) { va_list ap; va_start(ap, n); return va_arg(ap, double); } int main() { return f(1, 2.0); }'
+assert 11 $'#ifdef __DATE__\n#ifdef __TIME__\nint main() { return 11; }\n#endif\n#endif'
+assert 100 $'#line 100\nint main() { return __LINE__; }'
+assert 21 $'#define BASE 20\n#line BASE "renamed.c"\n\nint main() { return __LINE__; }'
+assert 0 $'#if 0\n#error skipped\n#endif\nint main() { return 0; }'
+assert 3 $'#warning still compiles\nint main() { return 3; }'
+assert 4 $'#pragma unknown\n#pragma\nint main() { return 4; }'
+assert 19 $'#include "include/once.h"\n#include "include/once.h"\nint main() { return once_value; }'
+assert 12 line_markers.c
+assert 12 "$($RUSTYC_PATH -E line_markers.c)"
+assert 21 "$($RUSTYC_PATH -E $'#define ADD(a, b) a + b\n\n\nint main() { return ADD(10, 11); }')"
+assert 5 "$($RUSTYC_PATH -E $'#define PLUS +\nint main() { int x = 5; return +PLUS x; }')"
+assert 100 "$($RUSTYC_PATH -E $'#line 100\nint main() { return __LINE__; }')"
+assert 3 $'# 3 "marked.c" 1\nint main() { return __LINE__; }'
+assert_preprocessed '- -1' $'#define f(x) -x\n-f(1)'
+assert_preprocessed 'a + + + +a' $'#define Q(x) x\n#define P +\na Q(+)+ P+a'
+assert_preprocessed 'x = y - -z' $'#define NEG -z\nx = y -NEG'
+assert_preprocessed 'x 1 . 5 . . . / / L "s" 0x1e +1 a = =b' $'#define E(x) x\nx E(1)E(.)5 E(.)E(.)E(.) E(/)E(/) E(L)"s" E(0x1e)E(+)1 a E(=)=b'
+
+assert_error 'exponent has no digits' '<source>:1:25' 'int main() { double x = 1e; return 0; }'
+assert_error 'exponent has no digits' '<source>:1:25' 'int main() { double x = 0x1p-; return 0; }'
+assert_error 'hexadecimal floating constant requires an exponent' '<source>:1:25' 'int main() { double x = 0x1.8; return 0; }'
+assert_error '`break` statement not within a loop or switch' '<source>:1:14' 'int main() { break; return 0; }'
+assert_error '`continue` statement not within a loop' '<source>:1:35' 'int main() { switch (1) { case 1: continue; } return 0; }'
+assert_error 'duplicate case value `1`' '<source>:1:40' 'int main() { switch (1) { case 1: case 1: return 0; } return 0; }'
+assert_error 'expression is not a compile-time constant' '<source>:1:43' 'int main() { int x = 1; switch (1) { case x: return 0; } return 0; }'
+assert_error 'multiple default labels in one switch' '<source>:1:36' 'int main() { switch (1) { default: default: return 0; } return 0; }'
+assert_error 'use of undeclared label `missing`' '<source>:1:14' 'int main() { goto missing; return 0; }'
+assert_error 'redefinition of label `a`' '<source>:1:17' 'int main() { a: a: return 0; }'
+assert_error 'cannot jump out of a statement expression' '<source>:1:44' 'int main() { int i = 0; while (1) { i = ({ break; 1; }); } return i; }'
+assert_error 'cannot jump out of a statement expression' '<source>:1:84' 'int main() { int s = 0; for (int i = 0; i < 3; i = i + 1) { s = s + ({ if (i == 1) continue; i; }); } return s; }'
+assert_error 'cannot jump out of a statement expression' '<source>:1:38' 'int main() { switch (1) { case 1: ({ break; 0; }); } return 0; }'
+assert_error 'cannot jump out of a statement expression' '<source>:1:25' 'int main() { int x = ({ goto out; 1; }); out: return x; }'
+assert_error 'cannot jump into a statement expression' '<source>:1:14' 'int main() { goto in; return ({ in: 1; }); }'
+assert_error 'redefinition of label `a`' '<source>:1:20' 'int main() { a: ({ a: 0; }); return 0; }'
+assert_error '`continue` statement not within a loop' '<source>:1:17' 'int main() { ({ continue; }); return 0; }'
+assert_error 'use of undeclared identifier `x`' '<source>:1:21' 'int main() { return x; }'
+assert_error 'use of undeclared identifier `g`' '<source>:1:21' 'int main() { return g; } int g;'
+assert_error 'use of undeclared identifier `a`' '<source>:1:36' 'int main() { { int a = 1; } return a; }'
+assert_error 'redefinition of `a`' '<source>:1:25' 'int main() { int a; int a; return 0; }'
+assert_error 'redefinition of `a`' '<source>:1:23' 'int main(int a) { int a; return a; }'
+assert_error 'redefinition of `g`' '<source>:1:16' 'int g = 1; int g = 2; int main() { return 0; }'
+assert_error 'redefinition of function `f`' '<source>:1:23' 'int f() { return 0; } int f() { return 1; } int main() { return 0; }'
+assert_error 'parameter name omitted' '<source>:1:7' 'int f(int) { return 0; } int main() { return 0; }'
+assert_error 'conflicting types for `g`' '<source>:1:15' 'int g; double g = 2.5; int main() { return g; }'
+assert_error 'conflicting types for `g`' '<source>:1:35' 'int g; int main() { extern double g; return 0; }'
+assert_error 'conflicting types for `f`' '<source>:1:13' 'int f(int); int f(double x) { return 0; } int main() { return 0; }'
+assert_error 'conflicting types for `f`' '<source>:1:28' 'int main() { return f(); } double f() { return 1.0; }'
+assert_error 'non-static declaration of `g` follows static declaration' '<source>:1:19' 'static int g; int g; int main() { return 0; }'
+assert_error 'static declaration of `g` follows non-static declaration' '<source>:1:19' 'int g; static int g; int main() { return 0; }'
+assert_error 'static declaration of `f` follows non-static declaration' '<source>:1:10' 'int f(); static int f() { return 0; } int main() { return 0; }'
+assert_error 'static declaration of `f` follows non-static declaration' '<source>:1:28' 'int main() { return f(); } static int f() { return 0; }'
+assert_error '`extern` variable at block scope cannot have an initializer' '<source>:1:25' 'int main() { extern int x = 1; return x; }'
+assert_error 'use of undeclared identifier `undeclared`' '<source>:1:28' 'int main() { return sizeof(undeclared); }'
+assert_error 'use of undeclared identifier `undeclared`' '<source>:1:30' 'int main() { return _Generic(undeclared, int: 1); }'
+assert_error 'use of undeclared identifier `nope`' '<source>:1:23' '_Static_assert(sizeof(nope) == 8, ""); int main() { return 0; }'
+assert_error 'use of undeclared identifier `nope`' '<source>:1:36' 'int main() { _Static_assert(sizeof(nope) == 8, ""); return 0; }'
+assert_error 'division by zero in constant expression' '<source>:1:13' 'int x = 1 / 0; int main() { return 0; }'
+assert_error 'division by zero in constant expression' '<source>:1:36' 'int main() { switch (1) { case 1 / 0: return 0; } return 0; }'
+assert_error 'overflow in constant expression' '<source>:1:16' '_Static_assert(9223372036854775807 + 1 < 0, ""); int main() { return 0; }'
+assert_error 'overflow in constant expression' '<source>:1:16' '_Static_assert(-(-9223372036854775807 - 1) < 0, ""); int main() { return 0; }'
+assert_error 'overflow in constant expression' '<source>:1:32' 'int main() { switch (0) { case 3037000500 * 3037000500: return 1; } return 0; }'
+assert_error 'array length is not an integer constant expression' '<source>:1:7' 'int a[9223372036854775807 + 1]; int main() { return 0; }'
+assert_error 'array is too large' '<source>:1:20' 'int main() { int a[sizeof(int) - 9]; return 0; }'
+assert_error 'cannot assign to a const-qualified lvalue' '<source>:1:31' 'int main() { const int x = 1; x = 2; return 0; }'
+assert_warning 'conversion discards qualifiers from pointer target type' '<source>:1:40' 'int main() { const int x = 1; int *p = &x; return *p; }'
+assert_error 'void function should not return a value' '<source>:1:19' 'void f() { return 1; } int main() { return 0; }'
+assert_error 'non-void function should return a value' '<source>:1:11' 'int f() { return; } int main() { return 0; }'
+assert_error 'static assertion failed: "nope"' '<source>:1:14' 'int main() { _Static_assert(0, "nope"); return 0; }'
+assert_error 'static assertion failed: "file scope"' '<source>:1:1' '_Static_assert(1 == 2, "file scope"); int main() { return 0; }'
+assert_error 'cannot take the address of a bit-field' '<source>:1:60' 'struct S { int a : 3; }; int main() { struct S s; int *p = &s.a; return 0; }'
+assert_error 'invalid application of `sizeof` to a bit-field' '<source>:1:58' 'struct S { int a : 3; }; int main() { struct S s; return sizeof(s.a); }'
+assert_error 'width of bit-field exceeds the width of its type' '<source>:1:20' 'struct S { int a : 65; }; int main() { return 0; }'
+assert_error 'bit-field has negative width' '<source>:1:20' 'struct S { int a : -1; }; int main() { return 0; }'
+assert_error 'bit-field has non-integer type' '<source>:1:19' 'struct S { double a : 3; }; int main() { return 0; }'
+assert_error 'named bit-field has zero width' '<source>:1:20' 'struct S { int a : 0; }; int main() { return 0; }'
+assert_error 'width of bit-field exceeds the width of its type' '<source>:1:33' 'int main() { struct S { int a : 65; }; return 0; }'
+assert_error 'unterminated `#if`' '<source>:2:2' $'int x;\n#if 1\n#ifdef X\n#endif\nint main() { return 0; }'
+assert_error '`#endif` without `#if`' '<source>:2:2' $'int main() { return 0; }\n#endif'
+assert_error '#error custom message' '<source>:2:2' $'#if 1\n#error custom message\n#endif\nint main() { return 0; }'
+
+assert_error 'excess elements in initializer' '<source>:1:32' 'int main() { int a[2] = {1, 2, 3}; return 0; }'
+assert_error 'excess elements in initializer' '<source>:1:39' 'struct P { int x; }; struct P g = {1, 2}; int main() { return 0; }'
+assert_error 'excess elements in scalar initializer' '<source>:1:26' 'int main() { int x = {1, 2}; return 0; }'
+assert_error 'initializer-string for char array is too long' '<source>:1:26' 'int main() { char s[2] = "abc"; return 0; }'
+assert_error 'initializer-string for char array is too long' '<source>:1:14' 'char s[1] = {"ab"}; int main() { return 0; }'
+assert_error 'array initializer must be an initializer list' '<source>:1:24' 'int main() { int a[] = "abc"; return 0; }'
+assert_error 'array initializer must be an initializer list' '<source>:1:25' 'int main() { int a[2] = 1; return 0; }'
+assert_error 'array designator index `2` exceeds array bounds' '<source>:1:27' 'int main() { int a[2] = {[2] = 1}; return 0; }'
+assert_error 'array designator index `-1` exceeds array bounds' '<source>:1:27' 'int main() { int a[2] = {[-1] = 1}; return 0; }'
+assert_error 'designator in initializer for scalar type' '<source>:1:23' 'int main() { int x = {[0] = 1}; return 0; }'
+assert_error 'array designator cannot initialize non-array type' '<source>:1:49' 'struct P { int x; }; int main() { struct P p = {[0] = 1}; return 0; }'
+assert_error 'field designator cannot initialize a non-structure type' '<source>:1:26' 'int main() { int a[2] = {.x = 1}; return 0; }'
+assert_error 'no member named `y` in the structure' '<source>:1:49' 'struct P { int x; }; int main() { struct P p = {.y = 1}; return 0; }'
+assert_error 'variable-sized object may not be initialized' '<source>:1:29' 'int main() { int n = 2; int a[n] = {1}; return 0; }'
+assert_error 'expression is not a compile-time constant' '<source>:1:20' 'int x; int a[1] = {x}; int main() { return 0; }'
+assert_error 'incompatible types when converting to or from a structure' '<source>:1:81' 'struct P { int x; }; struct Q { int x; }; int main() { struct P p; struct Q q = p; return 0; }'
+assert_error 'incompatible types when converting to or from a structure' '<source>:1:55' 'struct P { int x; }; int main() { struct P p; int x = p; return 0; }'
+assert_error 'incompatible types when converting to or from a structure' '<source>:1:51' 'struct P { int x; }; int main() { struct P p; p = 1; return 0; }'
+assert_error 'incompatible types when converting to or from a structure' '<source>:1:56' 'struct P { int x; }; struct Q { int x; }; struct Q g = (struct P){1}; int main() { return 0; }'
+assert_error 'cannot assign to a const-qualified lvalue' '<source>:1:78' 'struct P { int x; }; int main() { const struct P p = {1}; struct P q; q = p; p = q; return 0; }'
+assert_error 'expression is not a compile-time constant' '<source>:1:47' 'struct P { int x; }; struct P g; struct P h = g; int main() { return 0; }'
+assert_error 'incompatible function pointer types' '<source>:1:68' 'int add1(int x) { return x + 1; } int main() { float (*f)(float) = add1; return 0; }'
+assert_error 'incompatible function pointer types' '<source>:1:72' 'int add1(int x) { return x + 1; } int main() { int (*f)(int, int); f = add1; return 0; }'
+assert_error 'incompatible function pointer types' '<source>:1:55' 'int add1(int x) { return x + 1; } float (*g)(float) = &add1; int main() { return 0; }'
+assert_error 'incompatible function pointer types' '<source>:1:62' 'int f(int x, ...) { return x; } int main() { int (*g)(int) = f; return 0; }'
+assert_error 'incompatible function pointer types' '<source>:1:70' 'int add1(int x) { return x + 1; } int main() { int (*t[2])(float) = {add1}; return 0; }'
+assert_error 'jump into the scope of a variable length array' '<source>:1:25' 'int main() { int n = 2; goto in; { int v[n]; in: v[0] = 1; } return 0; }'
+assert_error 'jump into the scope of a variable length array' '<source>:1:25' 'int main() { int n = 2; goto x; int v[n]; x: return 0; }'
+assert_error 'jump into the scope of a variable length array' '<source>:1:37' 'int main() { int n = 2; { int v[n]; goto x; } { int w[n]; x: ; } return 0; }'
+assert_error 'jump into the scope of a variable length array' '<source>:1:48' 'int main() { int n = 2; switch (n) { int v[n]; case 2: return 1; } return 0; }'
+assert_error 'jump into the scope of a variable length array' '<source>:1:58' 'int main() { int n = 2; switch (n) { case 1: { int v[n]; default: return 1; } } return 0; }'
 popd >/dev/null
 
 echo
@@ -1,3 +1,5 @@
+#include <stdarg.h>
+
 int ret3();
 
 int ret5();
@@ -10,6 +12,10 @@ int add6(int a, int b, int c, int d, int e, int f);
 
 int sub8(int a, int b, int c, int d, int e, int f, int g, int h);
 
+int sum_ints(int count, ...);
+
+double scale_add(double x, int n, float y);
+
 int ret3() { return 3; }
 
 int ret5() { return 5; }
@@ -25,3 +31,15 @@ int add6(int a, int b, int c, int d, int e, int f) {
 int sub8(int a, int b, int c, int d, int e, int f, int g, int h) {
   return a - b - c - d - e - f - g - h;
 }
+
+int sum_ints(int count, ...) {
+  va_list ap;
+  va_start(ap, count);
+  int sum = 0;
+  for (int i = 0; i < count; i++)
+    sum += va_arg(ap, int);
+  va_end(ap);
+  return sum;
+}
+
+double scale_add(double x, int n, float y) { return x * n + y; }